@@ -14,7 +14,7 @@ pub struct ResourceManager(pub ResourceAddress);
 impl ResourceManager {
     pub fn new_fungible<Y, E: Debug + ScryptoDecode>(
         divisibility: u8,
-        metadata: BTreeMap<String, String>,
+        metadata: BTreeMap<String, MetadataEntry>,
         access_rules: BTreeMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
         api: &mut Y,
     ) -> Result<Self, E>
@@ -24,6 +24,8 @@ impl ResourceManager {
         api.invoke(ResourceManagerCreateFungibleInvocation {
             metadata,
             access_rules,
+            max_supply: None,
+            mint_limit_per_epoch: None,
             divisibility,
         })
         .map(|address| ResourceManager(address))
@@ -32,7 +34,7 @@ impl ResourceManager {
     pub fn new_fungible_with_initial_supply<Y, E: Debug + ScryptoDecode>(
         divisibility: u8,
         amount: Decimal,
-        metadata: BTreeMap<String, String>,
+        metadata: BTreeMap<String, MetadataEntry>,
         access_rules: BTreeMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
         api: &mut Y,
     ) -> Result<(Self, Bucket), E>
@@ -43,6 +45,8 @@ impl ResourceManager {
             resource_address: None,
             metadata,
             access_rules,
+            max_supply: None,
+            mint_limit_per_epoch: None,
             divisibility,
             initial_supply: amount,
         })
@@ -51,7 +55,7 @@ impl ResourceManager {
 
     pub fn new_non_fungible<Y, E: Debug + ScryptoDecode>(
         id_type: NonFungibleIdType,
-        metadata: BTreeMap<String, String>,
+        metadata: BTreeMap<String, MetadataEntry>,
         access_rules: BTreeMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
         api: &mut Y,
     ) -> Result<Self, E>
@@ -63,6 +67,9 @@ impl ResourceManager {
             id_type,
             metadata,
             access_rules,
+            max_supply: None,
+            mint_limit_per_epoch: None,
+            mutable_data_schema: None,
         })
         .map(|address| ResourceManager(address))
     }
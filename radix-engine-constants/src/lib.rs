@@ -30,6 +30,18 @@ pub const DEFAULT_MAX_EPOCH_RANGE: u64 = 100;
 /// The max transaction size
 pub const MAX_TRANSACTION_SIZE: usize = 1 * 1024 * 1024;
 
+/// The max size of a transaction message, plaintext or encrypted
+pub const MAX_MESSAGE_SIZE: usize = 2048;
+
+/// The max length of the MIME type of a plaintext message
+pub const MAX_MESSAGE_MIME_TYPE_LENGTH: usize = 128;
+
+/// The max number of recipients of an encrypted message
+pub const MAX_NUMBER_OF_MESSAGE_DECRYPTORS: usize = 20;
+
+/// The max length of the wrapped symmetric key of an encrypted message decryptor
+pub const MAX_MESSAGE_WRAPPED_KEY_LENGTH: usize = 128;
+
 //==========================
 // Transaction execution
 //==========================
@@ -1,6 +1,9 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use sbor_derive_common::utils::extract_attributes;
+use sbor_derive_common::utils::{
+    extract_attributes, get_transparent_field, get_variant_discriminators, is_encoding_skipped,
+    is_transparent,
+};
 use syn::*;
 
 macro_rules! trace {
@@ -18,6 +21,7 @@ pub fn handle_describe(input: TokenStream) -> Result<TokenStream> {
     trace!("handle_describe() starts");
 
     let DeriveInput {
+        attrs,
         ident,
         data,
         generics,
@@ -34,6 +38,24 @@ pub fn handle_describe(input: TokenStream) -> Result<TokenStream> {
     trace!("Describing: {}", ident);
 
     let output = match data {
+        // A transparent struct is encoded as its field, so is described as it too
+        Data::Struct(s) if is_transparent(&attrs) => {
+            let (field, _) = get_transparent_field(&ident, &s.fields, is_encoding_skipped)?;
+            let field_type = &field.ty;
+            quote! {
+                impl scrypto_abi::LegacyDescribe for #ident {
+                    fn describe() -> scrypto_abi::Type {
+                        <#field_type as scrypto_abi::LegacyDescribe>::describe()
+                    }
+                }
+            }
+        }
+        _ if is_transparent(&attrs) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Only structs can be transparent",
+            ));
+        }
         Data::Struct(s) => match s.fields {
             syn::Fields::Named(FieldsNamed { named, .. }) => {
                 // ns: not skipped
@@ -102,6 +124,17 @@ pub fn handle_describe(input: TokenStream) -> Result<TokenStream> {
             }
         },
         Data::Enum(DataEnum { variants, .. }) => {
+            // The variants of an ABI enum are identified by their position
+            let discriminators = get_variant_discriminators(&variants)?;
+            if let Some(variant) = variants.iter().zip(discriminators).enumerate().find_map(
+                |(i, (variant, discriminator))| (discriminator as usize != i).then_some(variant),
+            ) {
+                return Err(Error::new(
+                    variant.ident.span(),
+                    "Custom discriminators are not supported with LegacyDescribe",
+                ));
+            }
+
             let names = variants.iter().map(|v| v.ident.to_string());
             let fields = variants.iter().map(|v| {
                 let f = &v.fields;
@@ -259,6 +292,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_describe_transparent_struct() {
+        let input =
+            TokenStream::from_str("#[sbor(transparent)] struct Test {a: u32, #[sbor(skip)] b: u8}")
+                .unwrap();
+        let output = handle_describe(input).unwrap();
+
+        assert_code_eq(
+            output,
+            quote! {
+                impl scrypto_abi::LegacyDescribe for Test {
+                    fn describe() -> scrypto_abi::Type {
+                        <u32 as scrypto_abi::LegacyDescribe>::describe()
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_describe_enum_with_custom_discriminators_fails() {
+        let input =
+            TokenStream::from_str("enum Test {A, #[sbor(discriminator = 5)] B (u32)}").unwrap();
+        let output = handle_describe(input);
+
+        assert!(output.is_err());
+    }
+
     #[test]
     fn test_skip_field_1() {
         let input = TokenStream::from_str("struct Test {#[legacy_skip] a: u32}").unwrap();
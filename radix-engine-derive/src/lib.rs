@@ -114,6 +114,9 @@ pub fn scrypto_sbor(input: TokenStream) -> TokenStream {
 
 /// Derive code that describe a non-fungible data structure.
 ///
+/// Fields marked `#[mutable]` can be updated after minting, either all at once or
+/// one field at a time, each with its own update access rule.
+///
 /// # Example
 ///
 /// ```ignore
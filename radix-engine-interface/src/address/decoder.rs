@@ -1,5 +1,7 @@
+use sbor::rust::string::ToString;
 use sbor::rust::vec::Vec;
 
+use crate::api::types::GlobalAddress;
 use crate::model::*;
 use crate::node::NetworkDefinition;
 
@@ -59,11 +61,64 @@ impl Bech32Decoder {
         )?)
     }
 
+    /// Decodes an address of any entity type from a Bech32 string into a `GlobalAddress` and returns an
+    /// `AddressError` on validation or decoding failure. The entity type can be inspected with
+    /// [`EntityType::global_address`].
+    pub fn parse_any(&self, address: &str) -> Result<GlobalAddress, AddressError> {
+        let data = self.validate_and_decode(address)?;
+        let global_address = match EntityType::try_from(data[0])
+            .map_err(|_| AddressError::InvalidEntityTypeId(data[0]))?
+        {
+            EntityType::Package => {
+                GlobalAddress::Package(PackageAddress::try_from(data.as_slice())?)
+            }
+            EntityType::Resource => {
+                GlobalAddress::Resource(ResourceAddress::try_from(data.as_slice())?)
+            }
+            _ => GlobalAddress::Component(ComponentAddress::try_from(data.as_slice())?),
+        };
+        Ok(global_address)
+    }
+
+    /// Detects the network of a Bech32 address from its HRP, out of the given networks.
+    /// The address itself is not validated.
+    pub fn detect_network<'a>(
+        address: &str,
+        networks: &'a [NetworkDefinition],
+    ) -> Result<&'a NetworkDefinition, AddressError> {
+        let (hrp, _, _) = bech32::decode(address).map_err(Self::map_bech32_error)?;
+        let (_, hrp_suffix) =
+            HrpSet::split_hrp(&hrp).ok_or_else(|| AddressError::UnknownHrp(hrp.clone()))?;
+        networks
+            .iter()
+            .find(|network| network.hrp_suffix == hrp_suffix)
+            .ok_or_else(|| AddressError::UnknownHrp(hrp.clone()))
+    }
+
+    fn map_bech32_error(err: bech32::Error) -> AddressError {
+        match err {
+            bech32::Error::InvalidChecksum => AddressError::InvalidChecksum,
+            err => AddressError::Bech32mDecodingError(err),
+        }
+    }
+
     /// Low level method which performs the Bech32 validation and decoding of the data.
     fn validate_and_decode(&self, address: &str) -> Result<Vec<u8>, AddressError> {
         // Decode the address string
         let (actual_hrp, data, variant) =
-            bech32::decode(address).map_err(|err| AddressError::Bech32mDecodingError(err))?;
+            bech32::decode(address).map_err(Self::map_bech32_error)?;
+
+        // Validate that the address is for this network, before looking at its entity type
+        match HrpSet::split_hrp(&actual_hrp) {
+            Some((_, hrp_suffix)) if hrp_suffix == self.hrp_set.hrp_suffix() => {}
+            Some((_, hrp_suffix)) => {
+                return Err(AddressError::WrongNetwork {
+                    expected_hrp_suffix: self.hrp_set.hrp_suffix().to_string(),
+                    actual_hrp_suffix: hrp_suffix.to_string(),
+                })
+            }
+            None => return Err(AddressError::UnknownHrp(actual_hrp.clone())),
+        }
 
         // Validate the Bech32 variant to ensure that is is Bech32m
         match variant {
@@ -0,0 +1,97 @@
+use sbor::rust::str::FromStr;
+use sbor::rust::string::String;
+use sbor::rust::vec::Vec;
+
+use crate::crypto::PublicKey;
+use crate::model::*;
+
+use super::encoder::Bech32Encoder;
+use super::entity::EntityType;
+
+/// The kinds of component which are addressable from a public key, before being created on ledger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VirtualEntityKind {
+    Account,
+    Identity,
+}
+
+impl VirtualEntityKind {
+    /// The entity type of the virtual component for a key of the given type.
+    pub fn entity_type(&self, public_key: &PublicKey) -> EntityType {
+        match (self, public_key) {
+            (Self::Account, PublicKey::EcdsaSecp256k1(_)) => {
+                EntityType::EcdsaSecp256k1VirtualAccountComponent
+            }
+            (Self::Account, PublicKey::EddsaEd25519(_)) => {
+                EntityType::EddsaEd25519VirtualAccountComponent
+            }
+            (Self::Identity, PublicKey::EcdsaSecp256k1(_)) => {
+                EntityType::EcdsaSecp256k1VirtualIdentityComponent
+            }
+            (Self::Identity, PublicKey::EddsaEd25519(_)) => {
+                EntityType::EddsaEd25519VirtualIdentityComponent
+            }
+        }
+    }
+}
+
+impl FromStr for VirtualEntityKind {
+    type Err = ParseVirtualEntityKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "account" => Ok(Self::Account),
+            "identity" => Ok(Self::Identity),
+            _ => Err(ParseVirtualEntityKindError::UnknownKind),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVirtualEntityKindError {
+    UnknownKind,
+}
+
+/// Derives the address of the virtual account or identity controlled by a public key.
+pub fn derive_virtual_address(public_key: &PublicKey, kind: VirtualEntityKind) -> ComponentAddress {
+    match kind {
+        VirtualEntityKind::Account => ComponentAddress::virtual_account_from_public_key(public_key),
+        VirtualEntityKind::Identity => {
+            ComponentAddress::virtual_identity_from_public_key(public_key)
+        }
+    }
+}
+
+/// Derives the Bech32 addresses of the virtual accounts or identities of many public keys, in order.
+pub fn derive_virtual_addresses(
+    public_keys: &[PublicKey],
+    kind: VirtualEntityKind,
+    bech32_encoder: &Bech32Encoder,
+) -> Vec<String> {
+    public_keys
+        .iter()
+        .map(|public_key| {
+            bech32_encoder
+                .encode_component_address_to_string(&derive_virtual_address(public_key, kind))
+        })
+        .collect()
+}
+
+/// Whether a Bech32 address starts with the given characters, right after the characters which are
+/// fixed by its HRP and entity type.
+///
+/// The first two characters of the data part are skipped, as they encode the entity byte. A pattern
+/// with characters outside of the Bech32 charset never matches.
+pub fn has_vanity_prefix(address: &str, pattern: &str) -> bool {
+    const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+    if !pattern.chars().all(|c| BECH32_CHARSET.contains(c)) {
+        return false;
+    }
+    match address.rsplit_once('1') {
+        Some((_, data)) => data
+            .get(2..)
+            .map_or(false, |data| data.starts_with(pattern)),
+        None => false,
+    }
+}
@@ -1,3 +1,4 @@
+use crate::api::types::GlobalAddress;
 use crate::model::*;
 
 /// A unique identifier used in the addressing of Resource Addresses.
@@ -87,6 +88,49 @@ impl EntityType {
             }
         }
     }
+    pub fn global_address(address: &GlobalAddress) -> Self {
+        match address {
+            GlobalAddress::Package(address) => Self::package(address),
+            GlobalAddress::Resource(address) => Self::resource(address),
+            GlobalAddress::Component(address) => Self::component(address),
+        }
+    }
+
+    /// Whether addresses of this entity type are component addresses.
+    pub fn is_component(&self) -> bool {
+        !matches!(self, Self::Resource | Self::Package)
+    }
+
+    /// Whether this is an account, virtual or not.
+    pub fn is_account(&self) -> bool {
+        matches!(
+            self,
+            Self::AccountComponent
+                | Self::EcdsaSecp256k1VirtualAccountComponent
+                | Self::EddsaEd25519VirtualAccountComponent
+        )
+    }
+
+    /// Whether this is an identity, virtual or not.
+    pub fn is_identity(&self) -> bool {
+        matches!(
+            self,
+            Self::IdentityComponent
+                | Self::EcdsaSecp256k1VirtualIdentityComponent
+                | Self::EddsaEd25519VirtualIdentityComponent
+        )
+    }
+
+    /// Whether the address is derived from a public key, rather than allocated on creation.
+    pub fn is_virtual(&self) -> bool {
+        matches!(
+            self,
+            Self::EcdsaSecp256k1VirtualAccountComponent
+                | Self::EddsaEd25519VirtualAccountComponent
+                | Self::EcdsaSecp256k1VirtualIdentityComponent
+                | Self::EddsaEd25519VirtualIdentityComponent
+        )
+    }
 
     pub fn id(&self) -> u8 {
         match self {
@@ -146,6 +190,7 @@ impl TryFrom<u8> for EntityType {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EntityTypeError {
     InvalidEntityTypeId(u8),
 }
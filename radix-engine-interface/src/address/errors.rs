@@ -1,5 +1,6 @@
 use bech32;
 use sbor::rust::fmt;
+use sbor::rust::string::String;
 
 /// Represents an error in addressing.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -13,6 +14,15 @@ pub enum AddressError {
     InvalidLength(usize),
     InvalidEntityTypeId(u8),
     InvalidHrp,
+    /// The Bech32m checksum doesn't match, eg because of a typo
+    InvalidChecksum,
+    /// The HRP is of another network, with the given suffix
+    WrongNetwork {
+        expected_hrp_suffix: String,
+        actual_hrp_suffix: String,
+    },
+    /// The HRP is not the HRP of any entity type
+    UnknownHrp(String),
 }
 
 #[cfg(not(feature = "alloc"))]
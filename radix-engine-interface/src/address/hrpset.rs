@@ -4,9 +4,33 @@ use sbor::rust::string::String;
 use crate::address::entity::EntityType;
 use crate::node::NetworkDefinition;
 
+const PACKAGE_HRP_PREFIX: &str = "package_";
+const RESOURCE_HRP_PREFIX: &str = "resource_";
+const NORMAL_COMPONENT_HRP_PREFIX: &str = "component_";
+const ACCOUNT_COMPONENT_HRP_PREFIX: &str = "account_";
+const IDENTITY_COMPONENT_HRP_PREFIX: &str = "identity_";
+const EPOCH_MANAGER_COMPONENT_HRP_PREFIX: &str = "epochmanager_";
+const CLOCK_COMPONENT_HRP_PREFIX: &str = "clock_";
+const VALIDATOR_COMPONENT_HRP_PREFIX: &str = "validator_";
+const ACCESS_CONTROLLER_COMPONENT_HRP_PREFIX: &str = "accesscontroller_";
+
+/// The prefixes of the entity HRPs, which are followed by the suffix of the network.
+const ENTITY_HRP_PREFIXES: [&str; 9] = [
+    PACKAGE_HRP_PREFIX,
+    RESOURCE_HRP_PREFIX,
+    NORMAL_COMPONENT_HRP_PREFIX,
+    ACCOUNT_COMPONENT_HRP_PREFIX,
+    IDENTITY_COMPONENT_HRP_PREFIX,
+    EPOCH_MANAGER_COMPONENT_HRP_PREFIX,
+    CLOCK_COMPONENT_HRP_PREFIX,
+    VALIDATOR_COMPONENT_HRP_PREFIX,
+    ACCESS_CONTROLLER_COMPONENT_HRP_PREFIX,
+];
+
 /// Represents an HRP set (typically corresponds to a network).
 #[derive(Debug, Clone)]
 pub struct HrpSet {
+    hrp_suffix: String,
     package: String,
     resource: String,
     normal_component: String,
@@ -37,21 +61,37 @@ impl HrpSet {
             EntityType::AccessControllerComponent => &self.access_controller_component,
         }
     }
+
+    /// The suffix shared by all HRPs of the network, eg `sim`.
+    pub fn hrp_suffix(&self) -> &str {
+        &self.hrp_suffix
+    }
+
+    /// Splits an HRP of any network into its entity prefix, eg `account_`, and its network suffix.
+    pub fn split_hrp(hrp: &str) -> Option<(&str, &str)> {
+        ENTITY_HRP_PREFIXES.iter().find_map(|prefix| {
+            hrp.strip_prefix(prefix)
+                .filter(|suffix| !suffix.is_empty())
+                .map(|suffix| (&hrp[..prefix.len()], suffix))
+        })
+    }
 }
 
 impl From<&NetworkDefinition> for HrpSet {
     fn from(network_definition: &NetworkDefinition) -> Self {
         let suffix = &network_definition.hrp_suffix;
+        let hrp = |prefix: &str| format!("{}{}", prefix, suffix);
         HrpSet {
-            package: format!("package_{}", suffix),
-            resource: format!("resource_{}", suffix),
-            normal_component: format!("component_{}", suffix),
-            account_component: format!("account_{}", suffix),
-            identity_component: format!("identity_{}", suffix),
-            epoch_manager_component: format!("epochmanager_{}", suffix),
-            clock_component: format!("clock_{}", suffix),
-            validator_component: format!("validator_{}", suffix),
-            access_controller_component: format!("accesscontroller_{}", suffix),
+            hrp_suffix: suffix.clone(),
+            package: hrp(PACKAGE_HRP_PREFIX),
+            resource: hrp(RESOURCE_HRP_PREFIX),
+            normal_component: hrp(NORMAL_COMPONENT_HRP_PREFIX),
+            account_component: hrp(ACCOUNT_COMPONENT_HRP_PREFIX),
+            identity_component: hrp(IDENTITY_COMPONENT_HRP_PREFIX),
+            epoch_manager_component: hrp(EPOCH_MANAGER_COMPONENT_HRP_PREFIX),
+            clock_component: hrp(CLOCK_COMPONENT_HRP_PREFIX),
+            validator_component: hrp(VALIDATOR_COMPONENT_HRP_PREFIX),
+            access_controller_component: hrp(ACCESS_CONTROLLER_COMPONENT_HRP_PREFIX),
         }
     }
 }
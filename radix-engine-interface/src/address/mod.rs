@@ -1,4 +1,5 @@
 mod decoder;
+mod derivation;
 mod display;
 mod encoder;
 mod entity;
@@ -7,6 +8,7 @@ mod hrpset;
 mod macros;
 
 pub use decoder::Bech32Decoder;
+pub use derivation::*;
 pub use display::*;
 pub use encoder::Bech32Encoder;
 pub use entity::*;
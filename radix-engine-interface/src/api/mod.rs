@@ -63,6 +63,9 @@ pub trait InvokableModel<E>:
     + Invokable<ValidatorClaimXrdInvocation, E>
     + Invokable<ValidatorUpdateKeyInvocation, E>
     + Invokable<ValidatorUpdateAcceptDelegatedStakeInvocation, E>
+    + Invokable<ValidatorUpdateFeeInvocation, E>
+    + Invokable<ValidatorClaimFeesInvocation, E>
+    + Invokable<ValidatorApplyEmissionInvocation, E>
     + Invokable<EpochManagerCreateValidatorInvocation, E>
     + Invokable<ClockCreateInvocation, E>
     + Invokable<ClockSetCurrentTimeInvocation, E>
@@ -70,6 +73,8 @@ pub trait InvokableModel<E>:
     + Invokable<ClockCompareCurrentTimeInvocation, E>
     + Invokable<MetadataSetInvocation, E>
     + Invokable<MetadataGetInvocation, E>
+    + Invokable<MetadataRemoveInvocation, E>
+    + Invokable<MetadataLockInvocation, E>
     + Invokable<AccessRulesAddAccessCheckInvocation, E>
     + Invokable<AccessRulesSetMethodAccessRuleInvocation, E>
     + Invokable<AccessRulesSetMethodMutabilityInvocation, E>
@@ -121,6 +126,11 @@ pub trait InvokableModel<E>:
     + Invokable<ResourceManagerGetResourceTypeInvocation, E>
     + Invokable<ResourceManagerGetTotalSupplyInvocation, E>
     + Invokable<ResourceManagerUpdateNonFungibleDataInvocation, E>
+    + Invokable<ResourceManagerUpdateNonFungibleDataFieldInvocation, E>
+    + Invokable<ResourceManagerFreezeVaultInvocation, E>
+    + Invokable<ResourceManagerUnfreezeVaultInvocation, E>
+    + Invokable<ResourceManagerSetMaxSupplyInvocation, E>
+    + Invokable<ResourceManagerSetMintLimitPerEpochInvocation, E>
     + Invokable<ResourceManagerNonFungibleExistsInvocation, E>
     + Invokable<ResourceManagerGetNonFungibleInvocation, E>
     + Invokable<VaultTakeInvocation, E>
@@ -194,6 +194,8 @@ pub enum AccessRulesChainFn {
 pub enum MetadataFn {
     Set,
     Get,
+    Remove,
+    Lock,
 }
 
 #[derive(
@@ -306,6 +308,9 @@ pub enum ValidatorFn {
     ClaimXrd,
     UpdateKey,
     UpdateAcceptDelegatedStake,
+    UpdateFee,
+    ClaimFees,
+    ApplyEmission,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
@@ -345,6 +350,8 @@ impl EpochManagerPackage {
                             EpochManagerNextRoundInvocation {
                                 receiver,
                                 round: args.round,
+                                leader: args.leader,
+                                missed_leaders: args.missed_leaders,
                             },
                         ))
                     }
@@ -458,6 +465,36 @@ impl EpochManagerPackage {
                             ),
                         )
                     }
+
+                    ValidatorFn::UpdateFee => {
+                        let args: ValidatorUpdateFeeMethodArgs =
+                            scrypto_decode(args).map_err(ResolveError::DecodeError)?;
+                        NativeInvocation::Validator(ValidatorInvocation::UpdateFee(
+                            ValidatorUpdateFeeInvocation {
+                                receiver,
+                                fee_factor: args.fee_factor,
+                            },
+                        ))
+                    }
+
+                    ValidatorFn::ClaimFees => {
+                        let _args: ValidatorClaimFeesMethodArgs =
+                            scrypto_decode(args).map_err(ResolveError::DecodeError)?;
+                        NativeInvocation::Validator(ValidatorInvocation::ClaimFees(
+                            ValidatorClaimFeesInvocation { receiver },
+                        ))
+                    }
+
+                    ValidatorFn::ApplyEmission => {
+                        let args: ValidatorApplyEmissionMethodArgs =
+                            scrypto_decode(args).map_err(ResolveError::DecodeError)?;
+                        NativeInvocation::Validator(ValidatorInvocation::ApplyEmission(
+                            ValidatorApplyEmissionInvocation {
+                                receiver,
+                                xrd_bucket: args.xrd_bucket,
+                            },
+                        ))
+                    }
                 }
             }
             _ => return Err(ResolveError::NotAMethod),
@@ -531,6 +568,11 @@ pub enum ResourceManagerFn {
     UpdateVaultAuth,
     LockAuth,
     UpdateNonFungibleData,
+    UpdateNonFungibleDataField,
+    FreezeVault,
+    UnfreezeVault,
+    SetMaxSupply,
+    SetMintLimitPerEpoch,
     GetNonFungible,
     GetResourceType,
     GetTotalSupply,
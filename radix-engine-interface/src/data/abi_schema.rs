@@ -0,0 +1,432 @@
+use crate::crypto::hash;
+use crate::data::*;
+use sbor::rust::borrow::Cow;
+use sbor::rust::collections::BTreeMap;
+use sbor::rust::string::String;
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
+use sbor::*;
+use scrypto_abi::{Fields, Type};
+
+/// Converts a legacy ABI type into a schema, so that values can be checked with
+/// [`validate_payload_against_schema`].
+///
+/// The result accepts exactly the values accepted by [`match_schema_with_value`].
+pub fn generate_schema_from_abi_type(ty: &Type) -> (LocalTypeIndex, ScryptoSchema) {
+    let mut schema = ScryptoSchema {
+        type_kinds: Vec::new(),
+        type_metadata: Vec::new(),
+        type_validations: Vec::new(),
+    };
+    let index = add_abi_type(&mut schema, ty);
+    (index, schema)
+}
+
+fn add_abi_type(schema: &mut ScryptoSchema, ty: &Type) -> LocalTypeIndex {
+    use sbor::basic_well_known_types::*;
+
+    let well_known_index = match ty {
+        Type::Any => Some(ANY_ID),
+        Type::Bool => Some(BOOL_ID),
+        Type::I8 => Some(I8_ID),
+        Type::I16 => Some(I16_ID),
+        Type::I32 => Some(I32_ID),
+        Type::I64 => Some(I64_ID),
+        Type::I128 => Some(I128_ID),
+        Type::U8 => Some(U8_ID),
+        Type::U16 => Some(U16_ID),
+        Type::U32 => Some(U32_ID),
+        Type::U64 => Some(U64_ID),
+        Type::U128 => Some(U128_ID),
+        Type::String => Some(STRING_ID),
+        Type::PackageAddress => Some(VALUE_KIND_PACKAGE_ADDRESS),
+        Type::ComponentAddress => Some(VALUE_KIND_COMPONENT_ADDRESS),
+        Type::ResourceAddress => Some(VALUE_KIND_RESOURCE_ADDRESS),
+        Type::Own => Some(VALUE_KIND_OWN),
+        Type::Hash => Some(VALUE_KIND_HASH),
+        Type::EcdsaSecp256k1PublicKey => Some(VALUE_KIND_ECDSA_SECP256K1_PUBLIC_KEY),
+        Type::EcdsaSecp256k1Signature => Some(VALUE_KIND_ECDSA_SECP256K1_SIGNATURE),
+        Type::EddsaEd25519PublicKey => Some(VALUE_KIND_EDDSA_ED25519_PUBLIC_KEY),
+        Type::EddsaEd25519Signature => Some(VALUE_KIND_EDDSA_ED25519_SIGNATURE),
+        Type::Decimal => Some(VALUE_KIND_DECIMAL),
+        Type::PreciseDecimal => Some(VALUE_KIND_PRECISE_DECIMAL),
+        Type::NonFungibleLocalId => Some(VALUE_KIND_NON_FUNGIBLE_LOCAL_ID),
+        _ => None,
+    };
+    if let Some(well_known_index) = well_known_index {
+        return LocalTypeIndex::WellKnown(well_known_index);
+    }
+
+    // Reserve the index first, so that the parent comes before its children
+    let index = schema.type_kinds.len();
+    schema.type_kinds.push(TypeKind::Any);
+    schema
+        .type_metadata
+        .push(TypeMetadata::default().with_type_hash(type_hash(ty)));
+    schema.type_validations.push(TypeValidation::None);
+
+    let (kind, metadata, validation) = match ty {
+        Type::Array {
+            element_type,
+            length,
+        } => (
+            TypeKind::Array {
+                element_type: add_abi_type(schema, element_type),
+            },
+            named("Array"),
+            TypeValidation::Array {
+                length_validation: LengthValidation {
+                    min: Some((*length).into()),
+                    max: Some((*length).into()),
+                },
+            },
+        ),
+        Type::Vec { element_type } => array(schema, "Vec", element_type),
+        Type::HashSet { element_type } => array(schema, "HashSet", element_type),
+        Type::TreeSet { element_type } => array(schema, "TreeSet", element_type),
+        Type::HashMap {
+            key_type,
+            value_type,
+        } => map(schema, "HashMap", key_type, value_type),
+        Type::TreeMap {
+            key_type,
+            value_type,
+        } => map(schema, "TreeMap", key_type, value_type),
+        Type::Tuple { element_types } => (
+            TypeKind::Tuple {
+                field_types: element_types
+                    .iter()
+                    .map(|t| add_abi_type(schema, t))
+                    .collect(),
+            },
+            named("Tuple"),
+            TypeValidation::None,
+        ),
+        Type::Struct { name, fields } => {
+            let (field_types, child_names) = add_fields(schema, fields);
+            (
+                TypeKind::Tuple { field_types },
+                TypeMetadata {
+                    type_name: Cow::Owned(name.clone()),
+                    child_names,
+                },
+                TypeValidation::None,
+            )
+        }
+        Type::NonFungibleGlobalId => (
+            TypeKind::Tuple {
+                field_types: vec![
+                    LocalTypeIndex::WellKnown(VALUE_KIND_RESOURCE_ADDRESS),
+                    LocalTypeIndex::WellKnown(VALUE_KIND_NON_FUNGIBLE_LOCAL_ID),
+                ],
+            },
+            named("NonFungibleGlobalId"),
+            TypeValidation::None,
+        ),
+        Type::Enum { name, variants } => {
+            let mut variant_types = BTreeMap::new();
+            let mut variant_names = BTreeMap::new();
+            for (discriminator, variant) in variants.iter().enumerate() {
+                // Variants beyond u8::MAX can't be encoded, so they can't match anything either
+                let Ok(discriminator) = u8::try_from(discriminator) else {
+                    break;
+                };
+                let (field_types, child_names) = add_fields(schema, &variant.fields);
+                variant_types.insert(discriminator, field_types);
+                variant_names.insert(
+                    discriminator,
+                    TypeMetadata {
+                        type_name: Cow::Owned(variant.name.clone()),
+                        child_names,
+                    },
+                );
+            }
+            (
+                TypeKind::Enum {
+                    variants: variant_types,
+                },
+                TypeMetadata {
+                    type_name: Cow::Owned(name.clone()),
+                    child_names: ChildNames::VariantNames(variant_names),
+                },
+                TypeValidation::None,
+            )
+        }
+        Type::Option { some_type } => {
+            let some_type = add_abi_type(schema, some_type);
+            enumeration(
+                "Option",
+                [
+                    (OPTION_VARIANT_NONE, "None", vec![]),
+                    (OPTION_VARIANT_SOME, "Some", vec![some_type]),
+                ],
+            )
+        }
+        Type::Result {
+            okay_type,
+            err_type,
+        } => {
+            let okay_type = add_abi_type(schema, okay_type);
+            let err_type = add_abi_type(schema, err_type);
+            enumeration(
+                "Result",
+                [
+                    (RESULT_VARIANT_OK, "Ok", vec![okay_type]),
+                    (RESULT_VARIANT_ERR, "Err", vec![err_type]),
+                ],
+            )
+        }
+        Type::Bucket => own("Bucket", OwnValidation::IsBucket),
+        Type::Proof => own("Proof", OwnValidation::IsProof),
+        Type::Vault => own("Vault", OwnValidation::IsVault),
+        Type::Component => own("Component", OwnValidation::IsComponent),
+        Type::KeyValueStore {
+            key_type,
+            value_type,
+        } => (
+            TypeKind::Custom(ScryptoCustomTypeKind::KeyValueStore {
+                key_type: add_abi_type(schema, key_type),
+                value_type: add_abi_type(schema, value_type),
+            }),
+            named("KeyValueStore"),
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
+                OwnValidation::IsKeyValueStore,
+            )),
+        ),
+        _ => unreachable!("Well-known types are handled above"),
+    };
+
+    schema.type_kinds[index] = kind;
+    schema.type_metadata[index].type_metadata = metadata;
+    schema.type_validations[index] = validation;
+    LocalTypeIndex::SchemaLocalIndex(index)
+}
+
+type TypeParts = (
+    ScryptoTypeKind<LocalTypeIndex>,
+    TypeMetadata,
+    TypeValidation<ScryptoCustomTypeValidation>,
+);
+
+fn named(name: &'static str) -> TypeMetadata {
+    TypeMetadata::named_no_child_names(name)
+}
+
+fn array(schema: &mut ScryptoSchema, name: &'static str, element_type: &Type) -> TypeParts {
+    (
+        TypeKind::Array {
+            element_type: add_abi_type(schema, element_type),
+        },
+        named(name),
+        TypeValidation::None,
+    )
+}
+
+fn map(
+    schema: &mut ScryptoSchema,
+    name: &'static str,
+    key_type: &Type,
+    value_type: &Type,
+) -> TypeParts {
+    (
+        TypeKind::Map {
+            key_type: add_abi_type(schema, key_type),
+            value_type: add_abi_type(schema, value_type),
+        },
+        named(name),
+        TypeValidation::None,
+    )
+}
+
+fn enumeration<const N: usize>(
+    name: &'static str,
+    variants: [(u8, &'static str, Vec<LocalTypeIndex>); N],
+) -> TypeParts {
+    let mut variant_types = BTreeMap::new();
+    let mut variant_names = BTreeMap::new();
+    for (discriminator, variant_name, field_types) in variants {
+        variant_types.insert(discriminator, field_types);
+        variant_names.insert(discriminator, named(variant_name));
+    }
+    (
+        TypeKind::Enum {
+            variants: variant_types,
+        },
+        TypeMetadata::named_with_variants(name, variant_names),
+        TypeValidation::None,
+    )
+}
+
+fn own(name: &'static str, validation: OwnValidation) -> TypeParts {
+    (
+        TypeKind::Custom(ScryptoCustomTypeKind::Own),
+        named(name),
+        TypeValidation::Custom(ScryptoCustomTypeValidation::Own(validation)),
+    )
+}
+
+fn add_fields(schema: &mut ScryptoSchema, fields: &Fields) -> (Vec<LocalTypeIndex>, ChildNames) {
+    match fields {
+        Fields::Unit => (Vec::new(), ChildNames::None),
+        Fields::Unnamed { unnamed } => (
+            unnamed.iter().map(|t| add_abi_type(schema, t)).collect(),
+            ChildNames::None,
+        ),
+        Fields::Named { named } => {
+            let field_types = named.iter().map(|(_, t)| add_abi_type(schema, t)).collect();
+            let field_names = named
+                .iter()
+                .map(|(name, _)| Cow::Owned(String::from(name.as_str())))
+                .collect();
+            (field_types, ChildNames::FieldNames(field_names))
+        }
+    }
+}
+
+fn type_hash(ty: &Type) -> TypeHash {
+    let mut type_hash = [0u8; 20];
+    let encoded = scrypto_encode(ty).expect("Failed to encode ABI type");
+    type_hash.copy_from_slice(&hash(encoded).0[..20]);
+    type_hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::types::Own;
+    use crate::math::Decimal;
+    use sbor::rust::boxed::Box;
+    use sbor::rust::string::ToString;
+    use scrypto_abi::Variant;
+
+    fn validate(ty: &Type, value: &ScryptoValue) -> Result<(), PayloadValidationError> {
+        let (index, schema) = generate_schema_from_abi_type(ty);
+        let result = validate_value_against_schema(value, &schema, index);
+        assert_eq!(result.is_ok(), match_schema_with_value(ty, value));
+        result
+    }
+
+    fn value<T: ScryptoEncode>(value: &T) -> ScryptoValue {
+        scrypto_decode(&scrypto_encode(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_struct_fields_are_validated_with_path() {
+        let ty = Type::Struct {
+            name: "Deposit".into(),
+            fields: Fields::Named {
+                named: vec![
+                    ("amount".into(), Type::Decimal),
+                    (
+                        "tags".into(),
+                        Type::Vec {
+                            element_type: Box::new(Type::String),
+                        },
+                    ),
+                ],
+            },
+        };
+
+        assert_eq!(
+            validate(&ty, &value(&(Decimal::ONE, vec!["a".to_string()]))),
+            Ok(())
+        );
+        assert_eq!(
+            validate(&ty, &value(&(Decimal::ONE, vec!["a".to_string()], 1u8))),
+            Err(PayloadValidationError {
+                path: "$".into(),
+                error: PayloadValidationErrorKind::MismatchingFieldCount {
+                    expected: 2,
+                    actual: 3
+                }
+            })
+        );
+        assert_eq!(
+            validate(&ty, &value(&(Decimal::ONE, vec![1u8]))),
+            Err(PayloadValidationError {
+                path: "$.tags".into(),
+                error: PayloadValidationErrorKind::MismatchingValueKind {
+                    type_name: "String".into(),
+                    value_kind: VALUE_KIND_U8,
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_fixed_length_array() {
+        let ty = Type::Array {
+            element_type: Box::new(Type::U8),
+            length: 2,
+        };
+        assert_eq!(validate(&ty, &value(&[1u8, 2u8])), Ok(()));
+        assert_eq!(
+            validate(&ty, &value(&[1u8, 2u8, 3u8])),
+            Err(PayloadValidationError {
+                path: "$".into(),
+                error: PayloadValidationErrorKind::LengthValidationFailed {
+                    type_name: "Array".into(),
+                    length: 3
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_enums_and_owned_nodes() {
+        let ty = Type::Enum {
+            name: "Payment".into(),
+            variants: vec![
+                Variant {
+                    name: "Nothing".into(),
+                    fields: Fields::Unit,
+                },
+                Variant {
+                    name: "Bucket".into(),
+                    fields: Fields::Unnamed {
+                        unnamed: vec![Type::Bucket],
+                    },
+                },
+            ],
+        };
+
+        let bucket = ScryptoValue::Enum {
+            discriminator: 1,
+            fields: vec![ScryptoValue::Custom {
+                value: ScryptoCustomValue::Own(Own::Bucket(5)),
+            }],
+        };
+        assert_eq!(validate(&ty, &bucket), Ok(()));
+
+        let vault = ScryptoValue::Enum {
+            discriminator: 1,
+            fields: vec![ScryptoValue::Custom {
+                value: ScryptoCustomValue::Own(Own::Vault([0u8; 36])),
+            }],
+        };
+        assert_eq!(
+            validate(&ty, &vault),
+            Err(PayloadValidationError {
+                path: "$::Bucket.0".into(),
+                error: PayloadValidationErrorKind::CustomValidationFailed {
+                    type_name: "Bucket".into(),
+                }
+            })
+        );
+
+        let unknown = ScryptoValue::Enum {
+            discriminator: 2,
+            fields: vec![],
+        };
+        assert!(matches!(
+            validate(&ty, &unknown),
+            Err(PayloadValidationError {
+                error: PayloadValidationErrorKind::UnknownEnumVariant {
+                    discriminator: 2,
+                    ..
+                },
+                ..
+            })
+        ));
+    }
+}
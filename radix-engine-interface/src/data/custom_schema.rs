@@ -1,5 +1,8 @@
+use super::types::Own;
 use super::*;
 use sbor::rust::collections::*;
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
 use sbor::*;
 
 pub type ScryptoTypeKind<L> = TypeKind<ScryptoCustomValueKind, ScryptoCustomTypeKind<L>, L>;
@@ -41,7 +44,19 @@ impl<L: SchemaTypeLink> CustomTypeKind<L> for ScryptoCustomTypeKind<L> {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum ScryptoCustomTypeValidation {}
+pub enum ScryptoCustomTypeValidation {
+    Own(OwnValidation),
+}
+
+/// Restricts an [`Own`] to a particular kind of node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OwnValidation {
+    IsBucket,
+    IsProof,
+    IsVault,
+    IsComponent,
+    IsKeyValueStore,
+}
 
 impl CustomTypeValidation for ScryptoCustomTypeValidation {}
 
@@ -142,6 +157,135 @@ impl CustomTypeExtension for ScryptoCustomTypeExtension {
     }
 }
 
+impl ValidatableCustomTypeExtension for ScryptoCustomTypeExtension {
+    type CustomValue = ScryptoCustomValue;
+
+    fn decode_payload(payload: &[u8]) -> Result<ScryptoValue, DecodeError> {
+        scrypto_decode(payload)
+    }
+
+    fn custom_value_kind(custom_value: &Self::CustomValue) -> Self::CustomValueKind {
+        match custom_value {
+            ScryptoCustomValue::PackageAddress(_) => ScryptoCustomValueKind::PackageAddress,
+            ScryptoCustomValue::ComponentAddress(_) => ScryptoCustomValueKind::ComponentAddress,
+            ScryptoCustomValue::ResourceAddress(_) => ScryptoCustomValueKind::ResourceAddress,
+            ScryptoCustomValue::Own(_) => ScryptoCustomValueKind::Own,
+            ScryptoCustomValue::Bucket(_) => ScryptoCustomValueKind::Bucket,
+            ScryptoCustomValue::Proof(_) => ScryptoCustomValueKind::Proof,
+            ScryptoCustomValue::Expression(_) => ScryptoCustomValueKind::Expression,
+            ScryptoCustomValue::Blob(_) => ScryptoCustomValueKind::Blob,
+            ScryptoCustomValue::Hash(_) => ScryptoCustomValueKind::Hash,
+            ScryptoCustomValue::EcdsaSecp256k1PublicKey(_) => {
+                ScryptoCustomValueKind::EcdsaSecp256k1PublicKey
+            }
+            ScryptoCustomValue::EcdsaSecp256k1Signature(_) => {
+                ScryptoCustomValueKind::EcdsaSecp256k1Signature
+            }
+            ScryptoCustomValue::EddsaEd25519PublicKey(_) => {
+                ScryptoCustomValueKind::EddsaEd25519PublicKey
+            }
+            ScryptoCustomValue::EddsaEd25519Signature(_) => {
+                ScryptoCustomValueKind::EddsaEd25519Signature
+            }
+            ScryptoCustomValue::Decimal(_) => ScryptoCustomValueKind::Decimal,
+            ScryptoCustomValue::PreciseDecimal(_) => ScryptoCustomValueKind::PreciseDecimal,
+            ScryptoCustomValue::NonFungibleLocalId(_) => ScryptoCustomValueKind::NonFungibleLocalId,
+        }
+    }
+
+    fn custom_type_kind_matches_value_kind<L: SchemaTypeLink>(
+        custom_type_kind: &Self::CustomTypeKind<L>,
+        value_kind: ScryptoValueKind,
+    ) -> bool {
+        let expected_value_kind = match custom_type_kind {
+            ScryptoCustomTypeKind::PackageAddress => ScryptoCustomValueKind::PackageAddress,
+            ScryptoCustomTypeKind::ComponentAddress => ScryptoCustomValueKind::ComponentAddress,
+            ScryptoCustomTypeKind::ResourceAddress => ScryptoCustomValueKind::ResourceAddress,
+            ScryptoCustomTypeKind::Own | ScryptoCustomTypeKind::KeyValueStore { .. } => {
+                ScryptoCustomValueKind::Own
+            }
+            // A non-fungible global id is encoded as a (ResourceAddress, NonFungibleLocalId) tuple
+            ScryptoCustomTypeKind::NonFungibleGlobalId => return value_kind == ValueKind::Tuple,
+            ScryptoCustomTypeKind::Blob => ScryptoCustomValueKind::Blob,
+            ScryptoCustomTypeKind::Bucket => ScryptoCustomValueKind::Bucket,
+            ScryptoCustomTypeKind::Proof => ScryptoCustomValueKind::Proof,
+            ScryptoCustomTypeKind::Expression => ScryptoCustomValueKind::Expression,
+            ScryptoCustomTypeKind::Hash => ScryptoCustomValueKind::Hash,
+            ScryptoCustomTypeKind::EcdsaSecp256k1PublicKey => {
+                ScryptoCustomValueKind::EcdsaSecp256k1PublicKey
+            }
+            ScryptoCustomTypeKind::EcdsaSecp256k1Signature => {
+                ScryptoCustomValueKind::EcdsaSecp256k1Signature
+            }
+            ScryptoCustomTypeKind::EddsaEd25519PublicKey => {
+                ScryptoCustomValueKind::EddsaEd25519PublicKey
+            }
+            ScryptoCustomTypeKind::EddsaEd25519Signature => {
+                ScryptoCustomValueKind::EddsaEd25519Signature
+            }
+            ScryptoCustomTypeKind::Decimal => ScryptoCustomValueKind::Decimal,
+            ScryptoCustomTypeKind::PreciseDecimal => ScryptoCustomValueKind::PreciseDecimal,
+            ScryptoCustomTypeKind::NonFungibleLocalId => ScryptoCustomValueKind::NonFungibleLocalId,
+        };
+        value_kind == ValueKind::Custom(expected_value_kind)
+    }
+
+    fn custom_validation_is_satisfied(
+        custom_validation: &Self::CustomTypeValidation,
+        value: &ScryptoValue,
+    ) -> bool {
+        match custom_validation {
+            ScryptoCustomTypeValidation::Own(own_validation) => match value {
+                Value::Custom {
+                    value: ScryptoCustomValue::Own(own),
+                } => matches!(
+                    (own_validation, own),
+                    (OwnValidation::IsBucket, Own::Bucket(_))
+                        | (OwnValidation::IsProof, Own::Proof(_))
+                        | (OwnValidation::IsVault, Own::Vault(_))
+                        | (OwnValidation::IsComponent, Own::Component(_))
+                        | (OwnValidation::IsKeyValueStore, Own::KeyValueStore(_))
+                ),
+                _ => false,
+            },
+        }
+    }
+}
+
+impl ComparableCustomTypeExtension for ScryptoCustomTypeExtension {
+    fn matching_custom_type_kind_children(
+        old: &Self::CustomTypeKind<LocalTypeIndex>,
+        new: &Self::CustomTypeKind<LocalTypeIndex>,
+    ) -> Option<Vec<(&'static str, LocalTypeIndex, LocalTypeIndex)>> {
+        match (old, new) {
+            (
+                ScryptoCustomTypeKind::KeyValueStore {
+                    key_type: old_key,
+                    value_type: old_value,
+                },
+                ScryptoCustomTypeKind::KeyValueStore {
+                    key_type: new_key,
+                    value_type: new_value,
+                },
+            ) => Some(vec![
+                ("[].key", *old_key, *new_key),
+                ("[].value", *old_value, *new_value),
+            ]),
+            // The remaining kinds have no children
+            (old, new) if old == new => Some(vec![]),
+            _ => None,
+        }
+    }
+
+    fn custom_validation_is_relaxed_by(
+        old: &Self::CustomTypeValidation,
+        new: &Self::CustomTypeValidation,
+    ) -> bool {
+        // Each own validation accepts a disjoint set of values
+        old == new
+    }
+}
+
 use well_known_scrypto_types::*;
 
 mod well_known_scrypto_types {
@@ -1,3 +1,5 @@
+/// Converts legacy ABI types into Scrypto schemas.
+mod abi_schema;
 /// Defines the custom Scrypto schema types.
 mod custom_schema;
 /// Defines the model of Scrypto custom values.
@@ -6,6 +8,9 @@ mod custom_value;
 mod custom_value_kind;
 /// Indexed Scrypto value.
 mod indexed_value;
+#[cfg(feature = "serde")]
+/// Converts Scrypto values to and from JSON, guided by a schema.
+mod schema_json;
 /// Matches a Scrypto schema type with a Scrypto value.
 mod schema_matcher;
 /// Defines a way to uniquely identify an element within a Scrypto schema type.
@@ -14,24 +19,30 @@ mod schema_path;
 pub mod types;
 /// Format any Scrypto value using the Manifest syntax.
 mod value_formatter;
+/// Parses Scrypto values written in the Manifest syntax.
+mod value_parser;
 #[cfg(feature = "serde")]
 /// One-way serialize any Scrypto value.
 mod value_serializer;
 
 pub use crate::args;
 
+pub use abi_schema::*;
 pub use custom_schema::*;
 pub use custom_value::*;
 pub use custom_value_kind::*;
 pub use indexed_value::*;
 use sbor::rust::vec::Vec;
 use sbor::{
-    Categorize, Decode, DecodeError, Decoder, Encode, EncodeError, Encoder, Value, ValueKind,
-    VecDecoder, VecEncoder,
+    Categorize, Decode, DecodeError, Decoder, Encode, EncodeError, Encoder, RawPayload, Value,
+    ValueKind, VecDecoder, VecEncoder,
 };
+#[cfg(feature = "serde")]
+pub use schema_json::*;
 pub use schema_matcher::*;
 pub use schema_path::*;
 pub use value_formatter::*;
+pub use value_parser::*;
 #[cfg(feature = "serde")]
 pub use value_serializer::*;
 
@@ -41,6 +52,7 @@ pub type ScryptoEncoder<'a> = VecEncoder<'a, ScryptoCustomValueKind, MAX_SCRYPTO
 pub type ScryptoDecoder<'a> = VecDecoder<'a, ScryptoCustomValueKind, MAX_SCRYPTO_SBOR_DEPTH>;
 pub type ScryptoValueKind = ValueKind<ScryptoCustomValueKind>;
 pub type ScryptoValue = Value<ScryptoCustomValueKind, ScryptoCustomValue>;
+pub type ScryptoRawPayload<'de> = RawPayload<'de, ScryptoCustomValueKind, ScryptoCustomValue>;
 
 // 0x5c for [5c]rypto - (91 in decimal)
 pub const SCRYPTO_SBOR_V1_PAYLOAD_PREFIX: u8 = 0x5c;
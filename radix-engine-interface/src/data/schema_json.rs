@@ -0,0 +1,901 @@
+use crate::address::{Bech32Decoder, Bech32Encoder};
+use crate::crypto::*;
+use crate::data::types::*;
+use crate::data::*;
+use crate::math::{Decimal, PreciseDecimal};
+use crate::model::NonFungibleLocalId;
+use crate::node::NetworkDefinition;
+use sbor::rust::borrow::Cow;
+use sbor::rust::format;
+use sbor::rust::str::FromStr;
+use sbor::rust::string::{String, ToString};
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
+use sbor::*;
+use serde_json::{json, Map, Value as JsonValue};
+
+/// Converts a Scrypto payload into JSON, using the schema to name struct fields and enum variants.
+///
+/// The output is lossless: [`json_to_scrypto_payload`] turns it back into exactly the same bytes.
+/// Values whose shape isn't pinned down by the schema (ie anything of type `Any`, and collections
+/// of `Any`) are emitted as `{ "sbor": "<hex of payload>" }`.
+pub fn scrypto_payload_to_json(
+    payload: &[u8],
+    schema: &ScryptoSchema,
+    index: LocalTypeIndex,
+    network: &NetworkDefinition,
+) -> Result<JsonValue, SchemaJsonError> {
+    let value = scrypto_decode::<ScryptoValue>(payload).map_err(|e| SchemaJsonError {
+        path: "$".to_string(),
+        error: SchemaJsonErrorKind::DecodeError(e),
+    })?;
+    scrypto_value_to_json(&value, schema, index, network)
+}
+
+pub fn scrypto_value_to_json(
+    value: &ScryptoValue,
+    schema: &ScryptoSchema,
+    index: LocalTypeIndex,
+    network: &NetworkDefinition,
+) -> Result<JsonValue, SchemaJsonError> {
+    let mut converter = SchemaJsonConverter::new(schema, network);
+    converter
+        .to_json(value, index)
+        .map_err(|error| converter.error(error))
+}
+
+/// Parses JSON in the format of [`scrypto_payload_to_json`] back into a Scrypto payload.
+pub fn json_to_scrypto_payload(
+    json: &JsonValue,
+    schema: &ScryptoSchema,
+    index: LocalTypeIndex,
+    network: &NetworkDefinition,
+) -> Result<Vec<u8>, SchemaJsonError> {
+    let value = json_to_scrypto_value(json, schema, index, network)?;
+    scrypto_encode(&value).map_err(|e| SchemaJsonError {
+        path: "$".to_string(),
+        error: SchemaJsonErrorKind::EncodeError(e),
+    })
+}
+
+pub fn json_to_scrypto_value(
+    json: &JsonValue,
+    schema: &ScryptoSchema,
+    index: LocalTypeIndex,
+    network: &NetworkDefinition,
+) -> Result<ScryptoValue, SchemaJsonError> {
+    let mut converter = SchemaJsonConverter::new(schema, network);
+    converter
+        .from_json(json, index)
+        .map_err(|error| converter.error(error))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaJsonError {
+    /// The location of the offending value, e.g. `$.vaults[2]` or `$::Some.0`.
+    pub path: String,
+    pub error: SchemaJsonErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaJsonErrorKind {
+    DecodeError(DecodeError),
+    EncodeError(EncodeError),
+    TypeNotFound(LocalTypeIndex),
+    /// The SBOR value doesn't match the type in the schema
+    MismatchingValue {
+        type_name: String,
+    },
+    /// The JSON doesn't have the shape expected for the type in the schema
+    InvalidJson {
+        type_name: String,
+        expected: String,
+    },
+    UnknownField(String),
+    MissingField(String),
+    UnknownVariant(String),
+}
+
+struct SchemaJsonConverter<'s> {
+    schema: &'s ScryptoSchema,
+    encoder: Bech32Encoder,
+    decoder: Bech32Decoder,
+    path: Vec<String>,
+}
+
+type ResolvedScryptoTypeData<'s> = ResolvedTypeData<'s, ScryptoCustomTypeExtension>;
+
+impl<'s> SchemaJsonConverter<'s> {
+    fn new(schema: &'s ScryptoSchema, network: &NetworkDefinition) -> Self {
+        Self {
+            schema,
+            encoder: Bech32Encoder::new(network),
+            decoder: Bech32Decoder::new(network),
+            path: vec!["$".to_string()],
+        }
+    }
+
+    fn error(&self, error: SchemaJsonErrorKind) -> SchemaJsonError {
+        SchemaJsonError {
+            path: self.path.concat(),
+            error,
+        }
+    }
+
+    fn resolve(
+        &self,
+        index: LocalTypeIndex,
+    ) -> Result<ResolvedScryptoTypeData<'s>, SchemaJsonErrorKind> {
+        self.schema
+            .resolve(index)
+            .ok_or(SchemaJsonErrorKind::TypeNotFound(index))
+    }
+
+    //=======================
+    // SBOR => JSON
+    //=======================
+
+    fn to_json(
+        &mut self,
+        value: &ScryptoValue,
+        index: LocalTypeIndex,
+    ) -> Result<JsonValue, SchemaJsonErrorKind> {
+        let type_data = self.resolve(index)?;
+        let mismatch = || SchemaJsonErrorKind::MismatchingValue {
+            type_name: type_data.metadata.type_name.to_string(),
+        };
+
+        let json = match (type_data.kind.as_ref(), value) {
+            (TypeKind::Any, value) => to_opaque_json(value)?,
+            (TypeKind::Bool, Value::Bool { value }) => json!(value),
+            (TypeKind::I8, Value::I8 { value }) => json!(value),
+            (TypeKind::I16, Value::I16 { value }) => json!(value),
+            (TypeKind::I32, Value::I32 { value }) => json!(value),
+            (TypeKind::U8, Value::U8 { value }) => json!(value),
+            (TypeKind::U16, Value::U16 { value }) => json!(value),
+            (TypeKind::U32, Value::U32 { value }) => json!(value),
+            // Javascript only safely decodes JSON integers up to 2^53, so larger integer types
+            // are encoded as strings
+            (TypeKind::I64, Value::I64 { value }) => json!(value.to_string()),
+            (TypeKind::I128, Value::I128 { value }) => json!(value.to_string()),
+            (TypeKind::U64, Value::U64 { value }) => json!(value.to_string()),
+            (TypeKind::U128, Value::U128 { value }) => json!(value.to_string()),
+            (TypeKind::String, Value::String { value }) => json!(value),
+            (
+                TypeKind::Array { element_type },
+                Value::Array {
+                    element_value_kind,
+                    elements,
+                },
+            ) => match self.value_kind_of(*element_type)? {
+                None => to_opaque_json(value)?,
+                Some(kind) if kind != *element_value_kind => return Err(mismatch()),
+                Some(ValueKind::U8) => {
+                    let mut bytes = Vec::with_capacity(elements.len());
+                    for element in elements {
+                        let Value::U8 { value: byte } = element else {
+                            return Err(mismatch());
+                        };
+                        bytes.push(*byte);
+                    }
+                    json!(hex::encode(bytes))
+                }
+                Some(_) => {
+                    let mut array = Vec::with_capacity(elements.len());
+                    for (i, element) in elements.iter().enumerate() {
+                        self.path.push(format!("[{}]", i));
+                        array.push(self.to_json(element, *element_type)?);
+                        self.path.pop();
+                    }
+                    JsonValue::Array(array)
+                }
+            },
+            (TypeKind::Tuple { field_types }, Value::Tuple { fields }) => {
+                self.fields_to_json(fields, field_types, field_names(&type_data.metadata))?
+            }
+            (
+                TypeKind::Enum { variants },
+                Value::Enum {
+                    discriminator,
+                    fields,
+                },
+            ) => {
+                let field_types = variants.get(discriminator).ok_or_else(|| {
+                    SchemaJsonErrorKind::UnknownVariant(discriminator.to_string())
+                })?;
+                let variant_metadata = variant_metadata(&type_data.metadata, *discriminator);
+                let variant_name = match variant_metadata {
+                    Some(metadata) => json!(metadata.type_name),
+                    None => json!(discriminator),
+                };
+                self.path
+                    .push(format!("::{}", display_variant(&variant_name)));
+                let fields = self.fields_to_json(
+                    fields,
+                    field_types,
+                    variant_metadata.and_then(field_names),
+                )?;
+                self.path.pop();
+                json!({ "variant": variant_name, "fields": fields })
+            }
+            (
+                TypeKind::Map {
+                    key_type,
+                    value_type,
+                },
+                Value::Map {
+                    key_value_kind,
+                    value_value_kind,
+                    entries,
+                },
+            ) => match (
+                self.value_kind_of(*key_type)?,
+                self.value_kind_of(*value_type)?,
+            ) {
+                (Some(k), Some(v)) if k == *key_value_kind && v == *value_value_kind => {
+                    let mut array = Vec::with_capacity(entries.len());
+                    for (i, (key, value)) in entries.iter().enumerate() {
+                        self.path.push(format!("[{}]", i));
+                        let entry = json!([
+                            self.to_json(key, *key_type)?,
+                            self.to_json(value, *value_type)?
+                        ]);
+                        self.path.pop();
+                        array.push(entry);
+                    }
+                    JsonValue::Array(array)
+                }
+                (Some(_), Some(_)) => return Err(mismatch()),
+                _ => to_opaque_json(value)?,
+            },
+            (
+                TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleGlobalId),
+                Value::Tuple { fields },
+            ) => self.fields_to_json(fields, &non_fungible_global_id_field_types(), None)?,
+            (TypeKind::Custom(custom_type_kind), Value::Custom { value }) => {
+                if !ScryptoCustomTypeExtension::custom_type_kind_matches_value_kind(
+                    custom_type_kind,
+                    ValueKind::Custom(ScryptoCustomTypeExtension::custom_value_kind(value)),
+                ) {
+                    return Err(mismatch());
+                }
+                self.custom_value_to_json(value)
+            }
+            _ => return Err(mismatch()),
+        };
+
+        Ok(json)
+    }
+
+    fn fields_to_json(
+        &mut self,
+        fields: &[ScryptoValue],
+        field_types: &[LocalTypeIndex],
+        field_names: Option<&[Cow<'static, str>]>,
+    ) -> Result<JsonValue, SchemaJsonErrorKind> {
+        if fields.len() != field_types.len() {
+            return Err(SchemaJsonErrorKind::MismatchingValue {
+                type_name: format!("{}-field tuple", field_types.len()),
+            });
+        }
+
+        match field_names {
+            Some(names) if names.len() == fields.len() => {
+                let mut object = Map::new();
+                for ((field, field_type), name) in fields.iter().zip(field_types).zip(names) {
+                    self.path.push(format!(".{}", name));
+                    object.insert(name.to_string(), self.to_json(field, *field_type)?);
+                    self.path.pop();
+                }
+                Ok(JsonValue::Object(object))
+            }
+            _ => {
+                let mut array = Vec::with_capacity(fields.len());
+                for (i, (field, field_type)) in fields.iter().zip(field_types).enumerate() {
+                    self.path.push(format!(".{}", i));
+                    array.push(self.to_json(field, *field_type)?);
+                    self.path.pop();
+                }
+                Ok(JsonValue::Array(array))
+            }
+        }
+    }
+
+    fn custom_value_to_json(&self, value: &ScryptoCustomValue) -> JsonValue {
+        match value {
+            ScryptoCustomValue::PackageAddress(address) => {
+                json!(self.encoder.encode_package_address_to_string(address))
+            }
+            ScryptoCustomValue::ComponentAddress(address) => {
+                json!(self.encoder.encode_component_address_to_string(address))
+            }
+            ScryptoCustomValue::ResourceAddress(address) => {
+                json!(self.encoder.encode_resource_address_to_string(address))
+            }
+            ScryptoCustomValue::Own(own) => match own {
+                Own::Bucket(id) => json!({ "Bucket": id }),
+                Own::Proof(id) => json!({ "Proof": id }),
+                Own::Vault(id) => json!({ "Vault": hex::encode(id) }),
+                Own::Component(id) => json!({ "Component": hex::encode(id) }),
+                Own::KeyValueStore(id) => json!({ "KeyValueStore": hex::encode(id) }),
+            },
+            ScryptoCustomValue::Bucket(bucket) => json!(bucket.0),
+            ScryptoCustomValue::Proof(proof) => json!(proof.0),
+            ScryptoCustomValue::Expression(expression) => json!(match expression {
+                ManifestExpression::EntireWorktop => "ENTIRE_WORKTOP",
+                ManifestExpression::EntireAuthZone => "ENTIRE_AUTH_ZONE",
+            }),
+            ScryptoCustomValue::Blob(blob) => json!(blob.0.to_string()),
+            ScryptoCustomValue::Hash(value) => json!(value.to_string()),
+            ScryptoCustomValue::EcdsaSecp256k1PublicKey(value) => json!(value.to_string()),
+            ScryptoCustomValue::EcdsaSecp256k1Signature(value) => json!(value.to_string()),
+            ScryptoCustomValue::EddsaEd25519PublicKey(value) => json!(value.to_string()),
+            ScryptoCustomValue::EddsaEd25519Signature(value) => json!(value.to_string()),
+            ScryptoCustomValue::Decimal(value) => json!(value.to_string()),
+            ScryptoCustomValue::PreciseDecimal(value) => json!(value.to_string()),
+            ScryptoCustomValue::NonFungibleLocalId(value) => json!(value.to_string()),
+        }
+    }
+
+    //=======================
+    // JSON => SBOR
+    //=======================
+
+    fn from_json(
+        &mut self,
+        json: &JsonValue,
+        index: LocalTypeIndex,
+    ) -> Result<ScryptoValue, SchemaJsonErrorKind> {
+        let type_data = self.resolve(index)?;
+        let invalid = |expected: &str| SchemaJsonErrorKind::InvalidJson {
+            type_name: type_data.metadata.type_name.to_string(),
+            expected: expected.to_string(),
+        };
+
+        let value = match type_data.kind.as_ref() {
+            TypeKind::Any => from_opaque_json(json).ok_or_else(|| invalid("opaque value"))?,
+            TypeKind::Bool => Value::Bool {
+                value: json.as_bool().ok_or_else(|| invalid("boolean"))?,
+            },
+            TypeKind::I8 => Value::I8 {
+                value: json_integer(json).ok_or_else(|| invalid("i8"))?,
+            },
+            TypeKind::I16 => Value::I16 {
+                value: json_integer(json).ok_or_else(|| invalid("i16"))?,
+            },
+            TypeKind::I32 => Value::I32 {
+                value: json_integer(json).ok_or_else(|| invalid("i32"))?,
+            },
+            TypeKind::U8 => Value::U8 {
+                value: json_integer(json).ok_or_else(|| invalid("u8"))?,
+            },
+            TypeKind::U16 => Value::U16 {
+                value: json_integer(json).ok_or_else(|| invalid("u16"))?,
+            },
+            TypeKind::U32 => Value::U32 {
+                value: json_integer(json).ok_or_else(|| invalid("u32"))?,
+            },
+            TypeKind::I64 => Value::I64 {
+                value: json_parse(json).ok_or_else(|| invalid("i64 string"))?,
+            },
+            TypeKind::I128 => Value::I128 {
+                value: json_parse(json).ok_or_else(|| invalid("i128 string"))?,
+            },
+            TypeKind::U64 => Value::U64 {
+                value: json_parse(json).ok_or_else(|| invalid("u64 string"))?,
+            },
+            TypeKind::U128 => Value::U128 {
+                value: json_parse(json).ok_or_else(|| invalid("u128 string"))?,
+            },
+            TypeKind::String => Value::String {
+                value: json.as_str().ok_or_else(|| invalid("string"))?.to_string(),
+            },
+            TypeKind::Array { element_type } => match self.value_kind_of(*element_type)? {
+                None => from_opaque_json(json)
+                    .filter(|value| matches!(value, Value::Array { .. }))
+                    .ok_or_else(|| invalid("opaque array"))?,
+                Some(ValueKind::U8) => Value::Array {
+                    element_value_kind: ValueKind::U8,
+                    elements: json
+                        .as_str()
+                        .and_then(|s| hex::decode(s).ok())
+                        .ok_or_else(|| invalid("hex string"))?
+                        .into_iter()
+                        .map(|value| Value::U8 { value })
+                        .collect(),
+                },
+                Some(element_value_kind) => {
+                    let array = json.as_array().ok_or_else(|| invalid("array"))?;
+                    let mut elements = Vec::with_capacity(array.len());
+                    for (i, element) in array.iter().enumerate() {
+                        self.path.push(format!("[{}]", i));
+                        elements.push(self.from_json(element, *element_type)?);
+                        self.path.pop();
+                    }
+                    Value::Array {
+                        element_value_kind,
+                        elements,
+                    }
+                }
+            },
+            TypeKind::Tuple { field_types } => Value::Tuple {
+                fields: self.fields_from_json(
+                    json,
+                    field_types,
+                    field_names(&type_data.metadata),
+                )?,
+            },
+            TypeKind::Enum { variants } => {
+                let object = json
+                    .as_object()
+                    .ok_or_else(|| invalid("object with variant and fields"))?;
+                let variant = object
+                    .get("variant")
+                    .ok_or_else(|| SchemaJsonErrorKind::MissingField("variant".to_string()))?;
+                let discriminator = match variant {
+                    JsonValue::String(name) => match &type_data.metadata.child_names {
+                        ChildNames::VariantNames(names) => names
+                            .iter()
+                            .find(|(_, metadata)| metadata.type_name == name.as_str())
+                            .map(|(discriminator, _)| *discriminator),
+                        _ => None,
+                    },
+                    variant => json_integer(variant),
+                }
+                .filter(|discriminator| variants.contains_key(discriminator))
+                .ok_or_else(|| SchemaJsonErrorKind::UnknownVariant(display_variant(variant)))?;
+                let field_types = &variants[&discriminator];
+
+                let variant_metadata = variant_metadata(&type_data.metadata, discriminator);
+                self.path.push(format!("::{}", display_variant(variant)));
+                let fields = match object.get("fields") {
+                    Some(fields) => self.fields_from_json(
+                        fields,
+                        field_types,
+                        variant_metadata.and_then(field_names),
+                    )?,
+                    None if field_types.is_empty() => Vec::new(),
+                    None => return Err(SchemaJsonErrorKind::MissingField("fields".to_string())),
+                };
+                self.path.pop();
+                Value::Enum {
+                    discriminator,
+                    fields,
+                }
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => match (
+                self.value_kind_of(*key_type)?,
+                self.value_kind_of(*value_type)?,
+            ) {
+                (Some(key_value_kind), Some(value_value_kind)) => {
+                    let array = json.as_array().ok_or_else(|| invalid("array of entries"))?;
+                    let mut entries = Vec::with_capacity(array.len());
+                    for (i, entry) in array.iter().enumerate() {
+                        self.path.push(format!("[{}]", i));
+                        let (key, value) = match entry.as_array().map(|e| e.as_slice()) {
+                            Some([key, value]) => (key, value),
+                            _ => return Err(invalid("[key, value] entry")),
+                        };
+                        entries.push((
+                            self.from_json(key, *key_type)?,
+                            self.from_json(value, *value_type)?,
+                        ));
+                        self.path.pop();
+                    }
+                    Value::Map {
+                        key_value_kind,
+                        value_value_kind,
+                        entries,
+                    }
+                }
+                _ => from_opaque_json(json)
+                    .filter(|value| matches!(value, Value::Map { .. }))
+                    .ok_or_else(|| invalid("opaque map"))?,
+            },
+            TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleGlobalId) => Value::Tuple {
+                fields: self.fields_from_json(json, &non_fungible_global_id_field_types(), None)?,
+            },
+            TypeKind::Custom(custom_type_kind) => Value::Custom {
+                value: self
+                    .custom_value_from_json(custom_type_kind, json)
+                    .ok_or_else(|| invalid(custom_json_format(custom_type_kind)))?,
+            },
+        };
+
+        Ok(value)
+    }
+
+    fn fields_from_json(
+        &mut self,
+        json: &JsonValue,
+        field_types: &[LocalTypeIndex],
+        field_names: Option<&[Cow<'static, str>]>,
+    ) -> Result<Vec<ScryptoValue>, SchemaJsonErrorKind> {
+        let mut fields = Vec::with_capacity(field_types.len());
+        match (json, field_names) {
+            (JsonValue::Object(object), Some(names)) if names.len() == field_types.len() => {
+                if let Some(unknown) = object
+                    .keys()
+                    .find(|key| !names.iter().any(|name| name == key.as_str()))
+                {
+                    return Err(SchemaJsonErrorKind::UnknownField(unknown.clone()));
+                }
+                for (name, field_type) in names.iter().zip(field_types) {
+                    let field = object
+                        .get(name.as_ref())
+                        .ok_or_else(|| SchemaJsonErrorKind::MissingField(name.to_string()))?;
+                    self.path.push(format!(".{}", name));
+                    fields.push(self.from_json(field, *field_type)?);
+                    self.path.pop();
+                }
+            }
+            (JsonValue::Array(array), _) if array.len() == field_types.len() => {
+                for (i, (field, field_type)) in array.iter().zip(field_types).enumerate() {
+                    self.path.push(format!(".{}", i));
+                    fields.push(self.from_json(field, *field_type)?);
+                    self.path.pop();
+                }
+            }
+            _ => {
+                return Err(SchemaJsonErrorKind::InvalidJson {
+                    type_name: format!("{}-field tuple", field_types.len()),
+                    expected: match field_names {
+                        Some(_) => "object or array of fields".to_string(),
+                        None => format!("array of {} fields", field_types.len()),
+                    },
+                })
+            }
+        }
+        Ok(fields)
+    }
+
+    fn custom_value_from_json(
+        &self,
+        custom_type_kind: &ScryptoCustomTypeKind<LocalTypeIndex>,
+        json: &JsonValue,
+    ) -> Option<ScryptoCustomValue> {
+        let value = match custom_type_kind {
+            ScryptoCustomTypeKind::PackageAddress => ScryptoCustomValue::PackageAddress(
+                self.decoder
+                    .validate_and_decode_package_address(json.as_str()?)
+                    .ok()?,
+            ),
+            ScryptoCustomTypeKind::ComponentAddress => ScryptoCustomValue::ComponentAddress(
+                self.decoder
+                    .validate_and_decode_component_address(json.as_str()?)
+                    .ok()?,
+            ),
+            ScryptoCustomTypeKind::ResourceAddress => ScryptoCustomValue::ResourceAddress(
+                self.decoder
+                    .validate_and_decode_resource_address(json.as_str()?)
+                    .ok()?,
+            ),
+            ScryptoCustomTypeKind::Own | ScryptoCustomTypeKind::KeyValueStore { .. } => {
+                let object = json.as_object()?;
+                if object.len() != 1 {
+                    return None;
+                }
+                let (kind, id) = object.iter().next()?;
+                let own = match kind.as_str() {
+                    "Bucket" => Own::Bucket(json_integer(id)?),
+                    "Proof" => Own::Proof(json_integer(id)?),
+                    "Vault" => Own::Vault(json_node_id(id)?),
+                    "Component" => Own::Component(json_node_id(id)?),
+                    "KeyValueStore" => Own::KeyValueStore(json_node_id(id)?),
+                    _ => return None,
+                };
+                ScryptoCustomValue::Own(own)
+            }
+            ScryptoCustomTypeKind::Bucket => {
+                ScryptoCustomValue::Bucket(ManifestBucket(json_integer(json)?))
+            }
+            ScryptoCustomTypeKind::Proof => {
+                ScryptoCustomValue::Proof(ManifestProof(json_integer(json)?))
+            }
+            ScryptoCustomTypeKind::Expression => {
+                ScryptoCustomValue::Expression(match json.as_str()? {
+                    "ENTIRE_WORKTOP" => ManifestExpression::EntireWorktop,
+                    "ENTIRE_AUTH_ZONE" => ManifestExpression::EntireAuthZone,
+                    _ => return None,
+                })
+            }
+            ScryptoCustomTypeKind::Blob => {
+                ScryptoCustomValue::Blob(ManifestBlobRef(json_parse::<Hash>(json)?))
+            }
+            ScryptoCustomTypeKind::Hash => ScryptoCustomValue::Hash(json_parse(json)?),
+            ScryptoCustomTypeKind::EcdsaSecp256k1PublicKey => {
+                ScryptoCustomValue::EcdsaSecp256k1PublicKey(json_parse(json)?)
+            }
+            ScryptoCustomTypeKind::EcdsaSecp256k1Signature => {
+                ScryptoCustomValue::EcdsaSecp256k1Signature(json_parse(json)?)
+            }
+            ScryptoCustomTypeKind::EddsaEd25519PublicKey => {
+                ScryptoCustomValue::EddsaEd25519PublicKey(json_parse(json)?)
+            }
+            ScryptoCustomTypeKind::EddsaEd25519Signature => {
+                ScryptoCustomValue::EddsaEd25519Signature(json_parse(json)?)
+            }
+            ScryptoCustomTypeKind::Decimal => {
+                ScryptoCustomValue::Decimal(json_parse::<Decimal>(json)?)
+            }
+            ScryptoCustomTypeKind::PreciseDecimal => {
+                ScryptoCustomValue::PreciseDecimal(json_parse::<PreciseDecimal>(json)?)
+            }
+            ScryptoCustomTypeKind::NonFungibleLocalId => {
+                ScryptoCustomValue::NonFungibleLocalId(json_parse::<NonFungibleLocalId>(json)?)
+            }
+            ScryptoCustomTypeKind::NonFungibleGlobalId => return None,
+        };
+        Some(value)
+    }
+
+    /// The value kind of values of the given type, or `None` if it isn't fixed by the schema.
+    fn value_kind_of(
+        &self,
+        index: LocalTypeIndex,
+    ) -> Result<Option<ScryptoValueKind>, SchemaJsonErrorKind> {
+        let value_kind = match self.resolve(index)?.kind.as_ref() {
+            TypeKind::Any => return Ok(None),
+            TypeKind::Bool => ValueKind::Bool,
+            TypeKind::I8 => ValueKind::I8,
+            TypeKind::I16 => ValueKind::I16,
+            TypeKind::I32 => ValueKind::I32,
+            TypeKind::I64 => ValueKind::I64,
+            TypeKind::I128 => ValueKind::I128,
+            TypeKind::U8 => ValueKind::U8,
+            TypeKind::U16 => ValueKind::U16,
+            TypeKind::U32 => ValueKind::U32,
+            TypeKind::U64 => ValueKind::U64,
+            TypeKind::U128 => ValueKind::U128,
+            TypeKind::String => ValueKind::String,
+            TypeKind::Array { .. } => ValueKind::Array,
+            TypeKind::Tuple { .. } => ValueKind::Tuple,
+            TypeKind::Enum { .. } => ValueKind::Enum,
+            TypeKind::Map { .. } => ValueKind::Map,
+            TypeKind::Custom(custom_type_kind) => ValueKind::Custom(match custom_type_kind {
+                ScryptoCustomTypeKind::PackageAddress => ScryptoCustomValueKind::PackageAddress,
+                ScryptoCustomTypeKind::ComponentAddress => ScryptoCustomValueKind::ComponentAddress,
+                ScryptoCustomTypeKind::ResourceAddress => ScryptoCustomValueKind::ResourceAddress,
+                ScryptoCustomTypeKind::Own | ScryptoCustomTypeKind::KeyValueStore { .. } => {
+                    ScryptoCustomValueKind::Own
+                }
+                ScryptoCustomTypeKind::NonFungibleGlobalId => return Ok(Some(ValueKind::Tuple)),
+                ScryptoCustomTypeKind::Blob => ScryptoCustomValueKind::Blob,
+                ScryptoCustomTypeKind::Bucket => ScryptoCustomValueKind::Bucket,
+                ScryptoCustomTypeKind::Proof => ScryptoCustomValueKind::Proof,
+                ScryptoCustomTypeKind::Expression => ScryptoCustomValueKind::Expression,
+                ScryptoCustomTypeKind::Hash => ScryptoCustomValueKind::Hash,
+                ScryptoCustomTypeKind::EcdsaSecp256k1PublicKey => {
+                    ScryptoCustomValueKind::EcdsaSecp256k1PublicKey
+                }
+                ScryptoCustomTypeKind::EcdsaSecp256k1Signature => {
+                    ScryptoCustomValueKind::EcdsaSecp256k1Signature
+                }
+                ScryptoCustomTypeKind::EddsaEd25519PublicKey => {
+                    ScryptoCustomValueKind::EddsaEd25519PublicKey
+                }
+                ScryptoCustomTypeKind::EddsaEd25519Signature => {
+                    ScryptoCustomValueKind::EddsaEd25519Signature
+                }
+                ScryptoCustomTypeKind::Decimal => ScryptoCustomValueKind::Decimal,
+                ScryptoCustomTypeKind::PreciseDecimal => ScryptoCustomValueKind::PreciseDecimal,
+                ScryptoCustomTypeKind::NonFungibleLocalId => {
+                    ScryptoCustomValueKind::NonFungibleLocalId
+                }
+            }),
+        };
+        Ok(Some(value_kind))
+    }
+}
+
+fn field_names(metadata: &TypeMetadata) -> Option<&[Cow<'static, str>]> {
+    match &metadata.child_names {
+        ChildNames::FieldNames(names) => Some(names),
+        _ => None,
+    }
+}
+
+fn variant_metadata(metadata: &TypeMetadata, discriminator: u8) -> Option<&TypeMetadata> {
+    match &metadata.child_names {
+        ChildNames::VariantNames(variants) => variants.get(&discriminator),
+        _ => None,
+    }
+}
+
+fn display_variant(variant: &JsonValue) -> String {
+    match variant {
+        JsonValue::String(name) => name.clone(),
+        variant => variant.to_string(),
+    }
+}
+
+fn non_fungible_global_id_field_types() -> [LocalTypeIndex; 2] {
+    [
+        LocalTypeIndex::WellKnown(VALUE_KIND_RESOURCE_ADDRESS),
+        LocalTypeIndex::WellKnown(VALUE_KIND_NON_FUNGIBLE_LOCAL_ID),
+    ]
+}
+
+fn custom_json_format(custom_type_kind: &ScryptoCustomTypeKind<LocalTypeIndex>) -> &'static str {
+    match custom_type_kind {
+        ScryptoCustomTypeKind::PackageAddress
+        | ScryptoCustomTypeKind::ComponentAddress
+        | ScryptoCustomTypeKind::ResourceAddress => "Bech32 address",
+        ScryptoCustomTypeKind::Own | ScryptoCustomTypeKind::KeyValueStore { .. } => {
+            "object with a single node kind and id"
+        }
+        ScryptoCustomTypeKind::Bucket | ScryptoCustomTypeKind::Proof => "u32",
+        ScryptoCustomTypeKind::Expression => "ENTIRE_WORKTOP or ENTIRE_AUTH_ZONE",
+        ScryptoCustomTypeKind::NonFungibleGlobalId => "array of 2 fields",
+        _ => "string",
+    }
+}
+
+fn to_opaque_json(value: &ScryptoValue) -> Result<JsonValue, SchemaJsonErrorKind> {
+    let payload = scrypto_encode(value).map_err(SchemaJsonErrorKind::EncodeError)?;
+    Ok(json!({ "sbor": hex::encode(payload) }))
+}
+
+fn from_opaque_json(json: &JsonValue) -> Option<ScryptoValue> {
+    let object = json.as_object()?;
+    if object.len() != 1 {
+        return None;
+    }
+    let payload = hex::decode(object.get("sbor")?.as_str()?).ok()?;
+    scrypto_decode(&payload).ok()
+}
+
+fn json_integer<T: TryFrom<i64> + TryFrom<u64>>(json: &JsonValue) -> Option<T> {
+    match json {
+        JsonValue::Number(number) => match (number.as_u64(), number.as_i64()) {
+            (Some(value), _) => T::try_from(value).ok(),
+            (None, Some(value)) => T::try_from(value).ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn json_parse<T: FromStr>(json: &JsonValue) -> Option<T> {
+    T::from_str(json.as_str()?).ok()
+}
+
+fn json_node_id(json: &JsonValue) -> Option<[u8; 36]> {
+    hex::decode(json.as_str()?).ok()?.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::RADIX_TOKEN;
+    use crate::model::ResourceAddress;
+    use crate::{LegacyDescribe, ScryptoCategorize, ScryptoDecode, ScryptoEncode};
+    use sbor::rust::collections::BTreeMap;
+    use scrypto_abi::LegacyDescribe as _;
+
+    #[derive(ScryptoCategorize, ScryptoEncode, ScryptoDecode, LegacyDescribe)]
+    pub struct Transfer {
+        pub resource: ResourceAddress,
+        pub amount: Decimal,
+        pub memo: Option<String>,
+        pub ids: Vec<NonFungibleLocalId>,
+        pub payload: Vec<u8>,
+        pub big: u128,
+        pub kind: TransferKind,
+        pub extra: BTreeMap<String, (i8, bool)>,
+    }
+
+    #[derive(ScryptoCategorize, ScryptoEncode, ScryptoDecode, LegacyDescribe)]
+    pub enum TransferKind {
+        Direct,
+        Escrow { until_epoch: u64 },
+    }
+
+    fn sample() -> Transfer {
+        Transfer {
+            resource: RADIX_TOKEN,
+            amount: Decimal::from("12.5"),
+            memo: Some("rent".to_string()),
+            ids: vec![
+                NonFungibleLocalId::integer(1),
+                NonFungibleLocalId::bytes(vec![7]).unwrap(),
+            ],
+            payload: vec![1, 2, 255],
+            big: u128::MAX,
+            kind: TransferKind::Escrow { until_epoch: 10 },
+            extra: BTreeMap::from([("a".to_string(), (-1, true))]),
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip_is_lossless() {
+        let network = NetworkDefinition::simulator();
+        let (index, schema) = generate_schema_from_abi_type(&Transfer::describe());
+        let payload = scrypto_encode(&sample()).unwrap();
+
+        let json = scrypto_payload_to_json(&payload, &schema, index, &network).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "resource": Bech32Encoder::new(&network).encode_resource_address_to_string(&RADIX_TOKEN),
+                "amount": "12.5",
+                "memo": { "variant": "Some", "fields": ["rent"] },
+                "ids": ["#1#", "[07]"],
+                "payload": "0102ff",
+                "big": u128::MAX.to_string(),
+                "kind": { "variant": "Escrow", "fields": { "until_epoch": "10" } },
+                "extra": [["a", [-1, true]]],
+            })
+        );
+        assert_eq!(
+            json_to_scrypto_payload(&json, &schema, index, &network).unwrap(),
+            payload
+        );
+    }
+
+    #[test]
+    fn test_any_values_are_opaque() {
+        let network = NetworkDefinition::simulator();
+        let schema = ScryptoSchema {
+            type_kinds: vec![],
+            type_metadata: vec![],
+            type_validations: vec![],
+        };
+        let any = LocalTypeIndex::WellKnown(basic_well_known_types::ANY_ID);
+        let payload = scrypto_encode(&(1u8, vec![Decimal::ONE])).unwrap();
+
+        let json = scrypto_payload_to_json(&payload, &schema, any, &network).unwrap();
+        assert_eq!(json, json!({ "sbor": hex::encode(&payload) }));
+        assert_eq!(
+            json_to_scrypto_payload(&json, &schema, any, &network).unwrap(),
+            payload
+        );
+    }
+
+    #[test]
+    fn test_invalid_json_is_reported_with_path() {
+        let network = NetworkDefinition::simulator();
+        let (index, schema) = generate_schema_from_abi_type(&Transfer::describe());
+        let mut json = scrypto_value_to_json(
+            &scrypto_decode(&scrypto_encode(&sample()).unwrap()).unwrap(),
+            &schema,
+            index,
+            &network,
+        )
+        .unwrap();
+
+        json["kind"]["fields"]["until_epoch"] = json!(10);
+        assert_eq!(
+            json_to_scrypto_payload(&json, &schema, index, &network),
+            Err(SchemaJsonError {
+                path: "$.kind::Escrow.until_epoch".to_string(),
+                error: SchemaJsonErrorKind::InvalidJson {
+                    type_name: "U64".to_string(),
+                    expected: "u64 string".to_string(),
+                }
+            })
+        );
+
+        json["kind"] = json!({ "variant": "Refund", "fields": [] });
+        assert_eq!(
+            json_to_scrypto_payload(&json, &schema, index, &network),
+            Err(SchemaJsonError {
+                path: "$.kind".to_string(),
+                error: SchemaJsonErrorKind::UnknownVariant("Refund".to_string()),
+            })
+        );
+
+        json["kind"] = json!({ "variant": "Direct" });
+        json["colour"] = json!("red");
+        assert_eq!(
+            json_to_scrypto_payload(&json, &schema, index, &network),
+            Err(SchemaJsonError {
+                path: "$".to_string(),
+                error: SchemaJsonErrorKind::UnknownField("colour".to_string()),
+            })
+        );
+    }
+}
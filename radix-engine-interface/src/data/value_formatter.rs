@@ -97,7 +97,7 @@ pub fn format_scrypto_value<F: fmt::Write>(
         Value::U32 { value } => write!(f, "{}u32", value)?,
         Value::U64 { value } => write!(f, "{}u64", value)?,
         Value::U128 { value } => write!(f, "{}u128", value)?,
-        Value::String { value } => write!(f, "\"{}\"", value)?,
+        Value::String { value } => format_string(f, value)?,
         Value::Tuple { fields } => {
             if fields.len() == 2 {
                 if let (
@@ -180,6 +180,129 @@ pub fn format_scrypto_value<F: fmt::Write>(
     Ok(())
 }
 
+/// Formats a value like [`format_scrypto_value`], but with each child of a tuple, enum, array or
+/// map on its own line, indented by four spaces per level.
+pub fn format_scrypto_value_pretty<F: fmt::Write>(
+    f: &mut F,
+    value: &ScryptoValue,
+    context: &ValueFormattingContext,
+) -> fmt::Result {
+    format_scrypto_value_pretty_with_indent(f, value, context, 0)
+}
+
+fn format_scrypto_value_pretty_with_indent<F: fmt::Write>(
+    f: &mut F,
+    value: &ScryptoValue,
+    context: &ValueFormattingContext,
+    indent: usize,
+) -> fmt::Result {
+    let children: Vec<&ScryptoValue> = match value {
+        Value::Tuple { fields } if !is_non_fungible_global_id(fields) => {
+            f.write_str("Tuple(")?;
+            fields.iter().collect()
+        }
+        Value::Enum {
+            discriminator,
+            fields,
+        } => {
+            write!(f, "Enum({}u8", discriminator)?;
+            if fields.is_empty() {
+                return f.write_str(")");
+            }
+            f.write_str(",")?;
+            fields.iter().collect()
+        }
+        Value::Array {
+            element_value_kind,
+            elements,
+        } if *element_value_kind != ValueKind::U8 => {
+            f.write_str("Array<")?;
+            format_value_kind(f, element_value_kind)?;
+            f.write_str(">(")?;
+            elements.iter().collect()
+        }
+        Value::Map {
+            key_value_kind,
+            value_value_kind,
+            entries,
+        } => {
+            f.write_str("Map<")?;
+            format_value_kind(f, key_value_kind)?;
+            f.write_str(", ")?;
+            format_value_kind(f, value_value_kind)?;
+            f.write_str(">(")?;
+            if entries.is_empty() {
+                return f.write_str(")");
+            }
+            // Each entry goes on one line
+            for (i, (key, value)) in entries.iter().enumerate() {
+                if i != 0 {
+                    f.write_str(",")?;
+                }
+                write_newline_and_indent(f, indent + 1)?;
+                format_scrypto_value_pretty_with_indent(f, key, context, indent + 1)?;
+                f.write_str(", ")?;
+                format_scrypto_value_pretty_with_indent(f, value, context, indent + 1)?;
+            }
+            write_newline_and_indent(f, indent)?;
+            return f.write_str(")");
+        }
+        _ => return format_scrypto_value(f, value, context),
+    };
+
+    if children.is_empty() {
+        return f.write_str(")");
+    }
+    for (i, child) in children.into_iter().enumerate() {
+        if i != 0 {
+            f.write_str(",")?;
+        }
+        write_newline_and_indent(f, indent + 1)?;
+        format_scrypto_value_pretty_with_indent(f, child, context, indent + 1)?;
+    }
+    write_newline_and_indent(f, indent)?;
+    f.write_str(")")
+}
+
+fn write_newline_and_indent<F: fmt::Write>(f: &mut F, indent: usize) -> fmt::Result {
+    f.write_str("\n")?;
+    for _ in 0..indent {
+        f.write_str("    ")?;
+    }
+    Ok(())
+}
+
+fn is_non_fungible_global_id(fields: &[ScryptoValue]) -> bool {
+    matches!(
+        fields,
+        [
+            ScryptoValue::Custom {
+                value: ScryptoCustomValue::ResourceAddress(_),
+            },
+            ScryptoValue::Custom {
+                value: ScryptoCustomValue::NonFungibleLocalId(_),
+            },
+        ]
+    )
+}
+
+/// Formats a string literal, escaping it as in JSON.
+pub fn format_string<F: fmt::Write>(f: &mut F, value: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
 pub fn format_tuple<F: fmt::Write>(
     f: &mut F,
     name: &'static str,
@@ -319,7 +442,9 @@ pub fn format_custom_value<F: fmt::Write>(
             f.write_str("\")")?;
         }
         ScryptoCustomValue::Own(value) => {
-            write!(f, "Own(\"{:?}\")", value)?; // TODO: fix syntax
+            // The encoded body, without the payload prefix and value kind
+            let encoded = scrypto_encode(value).map_err(|_| fmt::Error)?;
+            write!(f, "Own(\"{}\")", hex::encode(&encoded[2..]))?;
         }
         // TX interpreted
         ScryptoCustomValue::Bucket(value) => {
@@ -0,0 +1,814 @@
+use super::types::*;
+use crate::address::Bech32Decoder;
+use crate::crypto::*;
+use crate::data::*;
+use crate::math::{Decimal, PreciseDecimal};
+use crate::model::*;
+use sbor::rust::format;
+use sbor::rust::str::FromStr;
+use sbor::rust::string::{String, ToString};
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+/// An error when parsing a value, along with the byte offset in the text at which it occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseScryptoValueError {
+    pub position: usize,
+    pub error: ParseScryptoValueErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseScryptoValueErrorKind {
+    UnexpectedEndOfInput,
+    UnexpectedCharacter(char),
+    InvalidInteger(String),
+    InvalidEscape,
+    UnknownValueKind(String),
+    UnknownValue(String),
+    InvalidCustomValue { value_kind: String, value: String },
+    MismatchingElementKind { expected: String, actual: String },
+    MaxDepthExceeded,
+}
+
+/// Parses a value written in the syntax of [`format_scrypto_value`] or
+/// [`format_scrypto_value_pretty`], such that any printed value parses back to itself.
+///
+/// Addresses are accepted in Bech32 if a decoder is given, and otherwise only in the hex form which
+/// is printed without an encoder. Buckets and proofs must be given by id, eg `Bucket(1u32)`, as
+/// there are no names to resolve. Line comments start with `#`.
+pub fn parse_scrypto_value(
+    text: &str,
+    bech32_decoder: Option<&Bech32Decoder>,
+) -> Result<ScryptoValue, ParseScryptoValueError> {
+    let mut parser = ValueParser {
+        text,
+        position: 0,
+        bech32_decoder,
+    };
+    let value = parser.parse_value(0)?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(value),
+        Some(c) => Err(parser.error(ParseScryptoValueErrorKind::UnexpectedCharacter(c))),
+    }
+}
+
+struct ValueParser<'t, 'd> {
+    text: &'t str,
+    position: usize,
+    bech32_decoder: Option<&'d Bech32Decoder>,
+}
+
+impl<'t, 'd> ValueParser<'t, 'd> {
+    fn error(&self, error: ParseScryptoValueErrorKind) -> ParseScryptoValueError {
+        ParseScryptoValueError {
+            position: self.position,
+            error,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn advance(&mut self) -> Result<char, ParseScryptoValueError> {
+        let c = self
+            .peek()
+            .ok_or_else(|| self.error(ParseScryptoValueErrorKind::UnexpectedEndOfInput))?;
+        self.position += c.len_utf8();
+        Ok(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == '#' {
+                while !matches!(self.peek(), None | Some('\n')) {
+                    self.position += self.peek().unwrap().len_utf8();
+                }
+            } else if c.is_whitespace() {
+                self.position += c.len_utf8();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseScryptoValueError> {
+        self.skip_whitespace();
+        let position = self.position;
+        match self.advance()? {
+            c if c == expected => Ok(()),
+            c => Err(ParseScryptoValueError {
+                position,
+                error: ParseScryptoValueErrorKind::UnexpectedCharacter(c),
+            }),
+        }
+    }
+
+    /// Reads a run of alphanumeric characters, which may be empty
+    fn read_word(&mut self) -> &'t str {
+        let text = self.text;
+        let start = self.position;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_') {
+            self.position += 1;
+        }
+        &text[start..self.position]
+    }
+
+    /// Parses a comma separated list of items, up to and including the closing bracket.
+    fn parse_list<T>(
+        &mut self,
+        mut parse_item: impl FnMut(&mut Self) -> Result<T, ParseScryptoValueError>,
+    ) -> Result<Vec<T>, ParseScryptoValueError> {
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(')') {
+            self.position += 1;
+            return Ok(items);
+        }
+        loop {
+            items.push(parse_item(self)?);
+            self.skip_whitespace();
+            match self.advance()? {
+                ',' => {}
+                ')' => return Ok(items),
+                c => {
+                    self.position -= c.len_utf8();
+                    return Err(self.error(ParseScryptoValueErrorKind::UnexpectedCharacter(c)));
+                }
+            }
+        }
+    }
+
+    fn parse_value(&mut self, depth: u8) -> Result<ScryptoValue, ParseScryptoValueError> {
+        if depth >= MAX_SCRYPTO_SBOR_DEPTH {
+            return Err(self.error(ParseScryptoValueErrorKind::MaxDepthExceeded));
+        }
+        self.skip_whitespace();
+        let start = self.position;
+        match self.peek() {
+            Some('"') => {
+                return Ok(Value::String {
+                    value: self.parse_string()?,
+                })
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => return self.parse_integer(),
+            _ => {}
+        }
+
+        let word = self.read_word();
+        let value = match word {
+            "true" => Value::Bool { value: true },
+            "false" => Value::Bool { value: false },
+            "Tuple" => {
+                self.expect('(')?;
+                Value::Tuple {
+                    fields: self.parse_list(|parser| parser.parse_value(depth + 1))?,
+                }
+            }
+            "Enum" => {
+                self.expect('(')?;
+                self.skip_whitespace();
+                let discriminator = match self.parse_integer()? {
+                    Value::U8 { value } => value,
+                    _ => {
+                        return Err(ParseScryptoValueError {
+                            position: start,
+                            error: ParseScryptoValueErrorKind::MismatchingElementKind {
+                                expected: "U8".to_string(),
+                                actual: "integer".to_string(),
+                            },
+                        })
+                    }
+                };
+                self.skip_whitespace();
+                let fields = match self.advance()? {
+                    ')' => Vec::new(),
+                    ',' => self.parse_list(|parser| parser.parse_value(depth + 1))?,
+                    c => {
+                        self.position -= c.len_utf8();
+                        return Err(self.error(ParseScryptoValueErrorKind::UnexpectedCharacter(c)));
+                    }
+                };
+                Value::Enum {
+                    discriminator,
+                    fields,
+                }
+            }
+            "Array" => {
+                self.expect('<')?;
+                let element_value_kind = self.parse_value_kind()?;
+                self.expect('>')?;
+                self.expect('(')?;
+                let elements = self.parse_list(|parser| {
+                    parser.parse_value_of_kind(element_value_kind, depth + 1)
+                })?;
+                Value::Array {
+                    element_value_kind,
+                    elements,
+                }
+            }
+            "Map" => {
+                self.expect('<')?;
+                let key_value_kind = self.parse_value_kind()?;
+                self.expect(',')?;
+                let value_value_kind = self.parse_value_kind()?;
+                self.expect('>')?;
+                self.expect('(')?;
+                let mut entries = Vec::new();
+                self.skip_whitespace();
+                if self.peek() == Some(')') {
+                    self.position += 1;
+                } else {
+                    loop {
+                        let key = self.parse_value_of_kind(key_value_kind, depth + 1)?;
+                        self.expect(',')?;
+                        let value = self.parse_value_of_kind(value_value_kind, depth + 1)?;
+                        entries.push((key, value));
+                        self.skip_whitespace();
+                        match self.advance()? {
+                            ',' => {}
+                            ')' => break,
+                            c => {
+                                self.position -= c.len_utf8();
+                                return Err(
+                                    self.error(ParseScryptoValueErrorKind::UnexpectedCharacter(c))
+                                );
+                            }
+                        }
+                    }
+                }
+                Value::Map {
+                    key_value_kind,
+                    value_value_kind,
+                    entries,
+                }
+            }
+            "Bytes" => {
+                let hex = self.parse_string_argument()?;
+                let bytes = hex::decode(&hex).map_err(|_| ParseScryptoValueError {
+                    position: start,
+                    error: ParseScryptoValueErrorKind::InvalidCustomValue {
+                        value_kind: word.to_string(),
+                        value: hex.clone(),
+                    },
+                })?;
+                Value::Array {
+                    element_value_kind: ValueKind::U8,
+                    elements: bytes.into_iter().map(|value| Value::U8 { value }).collect(),
+                }
+            }
+            "NonFungibleGlobalId" => {
+                let global_id = self.parse_string_argument()?;
+                let parsed = global_id.split_once(':').and_then(|(address, local_id)| {
+                    Some((
+                        self.parse_address(address, ResourceAddress::try_from_hex, |decoder| {
+                            decoder.validate_and_decode_resource_address(address)
+                        })?,
+                        NonFungibleLocalId::from_str(local_id).ok()?,
+                    ))
+                });
+                let (resource_address, local_id) = parsed.ok_or(ParseScryptoValueError {
+                    position: start,
+                    error: ParseScryptoValueErrorKind::InvalidCustomValue {
+                        value_kind: word.to_string(),
+                        value: global_id,
+                    },
+                })?;
+                Value::Tuple {
+                    fields: vec![
+                        Value::Custom {
+                            value: ScryptoCustomValue::ResourceAddress(resource_address),
+                        },
+                        Value::Custom {
+                            value: ScryptoCustomValue::NonFungibleLocalId(local_id),
+                        },
+                    ],
+                }
+            }
+            _ => match parse_custom_value_kind(word) {
+                Some(value_kind) => Value::Custom {
+                    value: self.parse_custom_value(value_kind, start)?,
+                },
+                None => {
+                    self.position = start;
+                    return Err(match self.peek() {
+                        Some(c) if word.is_empty() => {
+                            self.error(ParseScryptoValueErrorKind::UnexpectedCharacter(c))
+                        }
+                        None => self.error(ParseScryptoValueErrorKind::UnexpectedEndOfInput),
+                        _ => self.error(ParseScryptoValueErrorKind::UnknownValue(word.to_string())),
+                    });
+                }
+            },
+        };
+        Ok(value)
+    }
+
+    fn parse_value_of_kind(
+        &mut self,
+        value_kind: ScryptoValueKind,
+        depth: u8,
+    ) -> Result<ScryptoValue, ParseScryptoValueError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let value = self.parse_value(depth)?;
+        let actual = value_kind_of(&value);
+        if actual != value_kind {
+            return Err(ParseScryptoValueError {
+                position: start,
+                error: ParseScryptoValueErrorKind::MismatchingElementKind {
+                    expected: display_value_kind(&value_kind).to_string(),
+                    actual: display_value_kind(&actual).to_string(),
+                },
+            });
+        }
+        Ok(value)
+    }
+
+    fn parse_value_kind(&mut self) -> Result<ScryptoValueKind, ParseScryptoValueError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let word = self.read_word();
+        let value_kind = match word {
+            "Bool" => ValueKind::Bool,
+            "I8" => ValueKind::I8,
+            "I16" => ValueKind::I16,
+            "I32" => ValueKind::I32,
+            "I64" => ValueKind::I64,
+            "I128" => ValueKind::I128,
+            "U8" => ValueKind::U8,
+            "U16" => ValueKind::U16,
+            "U32" => ValueKind::U32,
+            "U64" => ValueKind::U64,
+            "U128" => ValueKind::U128,
+            "String" => ValueKind::String,
+            "Enum" => ValueKind::Enum,
+            "Array" => ValueKind::Array,
+            "Tuple" => ValueKind::Tuple,
+            "Map" => ValueKind::Map,
+            _ => match parse_custom_value_kind(word) {
+                Some(value_kind) => ValueKind::Custom(value_kind),
+                None => {
+                    return Err(ParseScryptoValueError {
+                        position: start,
+                        error: ParseScryptoValueErrorKind::UnknownValueKind(word.to_string()),
+                    })
+                }
+            },
+        };
+        Ok(value_kind)
+    }
+
+    fn parse_integer(&mut self) -> Result<ScryptoValue, ParseScryptoValueError> {
+        let text = self.text;
+        let start = self.position;
+        if self.peek() == Some('-') {
+            self.position += 1;
+        }
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        let digits = &text[start..self.position];
+        let suffix = self.read_word();
+        let invalid = || ParseScryptoValueError {
+            position: start,
+            error: ParseScryptoValueErrorKind::InvalidInteger(format!("{}{}", digits, suffix)),
+        };
+        let value = match suffix {
+            "i8" => Value::I8 {
+                value: digits.parse().map_err(|_| invalid())?,
+            },
+            "i16" => Value::I16 {
+                value: digits.parse().map_err(|_| invalid())?,
+            },
+            "i32" => Value::I32 {
+                value: digits.parse().map_err(|_| invalid())?,
+            },
+            "i64" => Value::I64 {
+                value: digits.parse().map_err(|_| invalid())?,
+            },
+            "i128" => Value::I128 {
+                value: digits.parse().map_err(|_| invalid())?,
+            },
+            "u8" => Value::U8 {
+                value: digits.parse().map_err(|_| invalid())?,
+            },
+            "u16" => Value::U16 {
+                value: digits.parse().map_err(|_| invalid())?,
+            },
+            "u32" => Value::U32 {
+                value: digits.parse().map_err(|_| invalid())?,
+            },
+            "u64" => Value::U64 {
+                value: digits.parse().map_err(|_| invalid())?,
+            },
+            "u128" => Value::U128 {
+                value: digits.parse().map_err(|_| invalid())?,
+            },
+            _ => return Err(invalid()),
+        };
+        Ok(value)
+    }
+
+    /// Parses a string literal, with the escapes of JSON
+    fn parse_string(&mut self) -> Result<String, ParseScryptoValueError> {
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.advance()? {
+                '"' => return Ok(value),
+                '\\' => {
+                    let escape_start = self.position - 1;
+                    let invalid_escape = ParseScryptoValueError {
+                        position: escape_start,
+                        error: ParseScryptoValueErrorKind::InvalidEscape,
+                    };
+                    let c = match self.advance()? {
+                        '"' => '"',
+                        '\\' => '\\',
+                        '/' => '/',
+                        'b' => '\x08',
+                        'f' => '\x0c',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => {
+                            let mut code = self.parse_utf16_unit().ok_or(invalid_escape.clone())?;
+                            if (0xD800..0xDC00).contains(&code) {
+                                // A surrogate pair
+                                if self.advance()? != '\\' || self.advance()? != 'u' {
+                                    return Err(invalid_escape);
+                                }
+                                let low = self.parse_utf16_unit().ok_or(invalid_escape.clone())?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(invalid_escape);
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            char::from_u32(code).ok_or(invalid_escape)?
+                        }
+                        _ => return Err(invalid_escape),
+                    };
+                    value.push(c);
+                }
+                c => value.push(c),
+            }
+        }
+    }
+
+    fn parse_utf16_unit(&mut self) -> Option<u32> {
+        let digits = self.text.get(self.position..self.position + 4)?;
+        let code = u32::from_str_radix(digits, 16).ok()?;
+        self.position += 4;
+        Some(code)
+    }
+
+    /// Parses `("...")`, as used by most custom values
+    fn parse_string_argument(&mut self) -> Result<String, ParseScryptoValueError> {
+        self.expect('(')?;
+        self.skip_whitespace();
+        let value = self.parse_string()?;
+        self.expect(')')?;
+        Ok(value)
+    }
+
+    fn parse_custom_value(
+        &mut self,
+        value_kind: ScryptoCustomValueKind,
+        start: usize,
+    ) -> Result<ScryptoCustomValue, ParseScryptoValueError> {
+        // Buckets and proofs are given by id
+        match value_kind {
+            ScryptoCustomValueKind::Bucket | ScryptoCustomValueKind::Proof => {
+                self.expect('(')?;
+                self.skip_whitespace();
+                let id = match self.parse_integer()? {
+                    Value::U32 { value } => value,
+                    _ => {
+                        return Err(ParseScryptoValueError {
+                            position: start,
+                            error: ParseScryptoValueErrorKind::MismatchingElementKind {
+                                expected: "U32".to_string(),
+                                actual: "integer".to_string(),
+                            },
+                        })
+                    }
+                };
+                self.expect(')')?;
+                return Ok(match value_kind {
+                    ScryptoCustomValueKind::Bucket => {
+                        ScryptoCustomValue::Bucket(ManifestBucket(id))
+                    }
+                    _ => ScryptoCustomValue::Proof(ManifestProof(id)),
+                });
+            }
+            _ => {}
+        }
+
+        let text = self.parse_string_argument()?;
+        let value = match value_kind {
+            ScryptoCustomValueKind::PackageAddress => self
+                .parse_address(&text, PackageAddress::try_from_hex, |decoder| {
+                    decoder.validate_and_decode_package_address(&text)
+                })
+                .map(ScryptoCustomValue::PackageAddress),
+            ScryptoCustomValueKind::ComponentAddress => self
+                .parse_address(&text, ComponentAddress::try_from_hex, |decoder| {
+                    decoder.validate_and_decode_component_address(&text)
+                })
+                .map(ScryptoCustomValue::ComponentAddress),
+            ScryptoCustomValueKind::ResourceAddress => self
+                .parse_address(&text, ResourceAddress::try_from_hex, |decoder| {
+                    decoder.validate_and_decode_resource_address(&text)
+                })
+                .map(ScryptoCustomValue::ResourceAddress),
+            ScryptoCustomValueKind::Own => hex::decode(&text).ok().and_then(|body| {
+                let mut payload = vec![SCRYPTO_SBOR_V1_PAYLOAD_PREFIX, VALUE_KIND_OWN];
+                payload.extend(body);
+                scrypto_decode(&payload).ok().map(ScryptoCustomValue::Own)
+            }),
+            ScryptoCustomValueKind::Expression => match text.as_str() {
+                "ENTIRE_WORKTOP" => Some(ScryptoCustomValue::Expression(
+                    ManifestExpression::EntireWorktop,
+                )),
+                "ENTIRE_AUTH_ZONE" => Some(ScryptoCustomValue::Expression(
+                    ManifestExpression::EntireAuthZone,
+                )),
+                _ => None,
+            },
+            ScryptoCustomValueKind::Blob => Hash::from_str(&text)
+                .ok()
+                .map(|hash| ScryptoCustomValue::Blob(ManifestBlobRef(hash))),
+            ScryptoCustomValueKind::Hash => {
+                Hash::from_str(&text).ok().map(ScryptoCustomValue::Hash)
+            }
+            ScryptoCustomValueKind::EcdsaSecp256k1PublicKey => {
+                EcdsaSecp256k1PublicKey::from_str(&text)
+                    .ok()
+                    .map(ScryptoCustomValue::EcdsaSecp256k1PublicKey)
+            }
+            ScryptoCustomValueKind::EcdsaSecp256k1Signature => {
+                EcdsaSecp256k1Signature::from_str(&text)
+                    .ok()
+                    .map(ScryptoCustomValue::EcdsaSecp256k1Signature)
+            }
+            ScryptoCustomValueKind::EddsaEd25519PublicKey => EddsaEd25519PublicKey::from_str(&text)
+                .ok()
+                .map(ScryptoCustomValue::EddsaEd25519PublicKey),
+            ScryptoCustomValueKind::EddsaEd25519Signature => EddsaEd25519Signature::from_str(&text)
+                .ok()
+                .map(ScryptoCustomValue::EddsaEd25519Signature),
+            ScryptoCustomValueKind::Decimal => Decimal::from_str(&text)
+                .ok()
+                .map(ScryptoCustomValue::Decimal),
+            ScryptoCustomValueKind::PreciseDecimal => PreciseDecimal::from_str(&text)
+                .ok()
+                .map(ScryptoCustomValue::PreciseDecimal),
+            ScryptoCustomValueKind::NonFungibleLocalId => NonFungibleLocalId::from_str(&text)
+                .ok()
+                .map(ScryptoCustomValue::NonFungibleLocalId),
+            ScryptoCustomValueKind::Bucket | ScryptoCustomValueKind::Proof => {
+                unreachable!("Handled above")
+            }
+        };
+        value.ok_or_else(|| ParseScryptoValueError {
+            position: start,
+            error: ParseScryptoValueErrorKind::InvalidCustomValue {
+                value_kind: display_value_kind(&ValueKind::Custom(value_kind)).to_string(),
+                value: text,
+            },
+        })
+    }
+
+    /// Addresses are either Bech32 encoded, or in the form `NormalComponent[<hex>]` as printed
+    /// without an encoder.
+    fn parse_address<T, E1, E2>(
+        &self,
+        text: &str,
+        from_hex: impl FnOnce(&str) -> Result<T, E1>,
+        from_bech32: impl FnOnce(&Bech32Decoder) -> Result<T, E2>,
+    ) -> Option<T> {
+        match text.strip_suffix(']').and_then(|text| text.split_once('[')) {
+            Some((_, hex)) => from_hex(hex).ok(),
+            None => from_bech32(self.bech32_decoder?).ok(),
+        }
+    }
+}
+
+fn parse_custom_value_kind(name: &str) -> Option<ScryptoCustomValueKind> {
+    let value_kind = match name {
+        "PackageAddress" => ScryptoCustomValueKind::PackageAddress,
+        "ComponentAddress" => ScryptoCustomValueKind::ComponentAddress,
+        "ResourceAddress" => ScryptoCustomValueKind::ResourceAddress,
+        "Own" => ScryptoCustomValueKind::Own,
+        "Bucket" => ScryptoCustomValueKind::Bucket,
+        "Proof" => ScryptoCustomValueKind::Proof,
+        "Expression" => ScryptoCustomValueKind::Expression,
+        "Blob" => ScryptoCustomValueKind::Blob,
+        "Hash" => ScryptoCustomValueKind::Hash,
+        "EcdsaSecp256k1PublicKey" => ScryptoCustomValueKind::EcdsaSecp256k1PublicKey,
+        "EcdsaSecp256k1Signature" => ScryptoCustomValueKind::EcdsaSecp256k1Signature,
+        "EddsaEd25519PublicKey" => ScryptoCustomValueKind::EddsaEd25519PublicKey,
+        "EddsaEd25519Signature" => ScryptoCustomValueKind::EddsaEd25519Signature,
+        "Decimal" => ScryptoCustomValueKind::Decimal,
+        "PreciseDecimal" => ScryptoCustomValueKind::PreciseDecimal,
+        "NonFungibleLocalId" => ScryptoCustomValueKind::NonFungibleLocalId,
+        _ => return None,
+    };
+    Some(value_kind)
+}
+
+fn value_kind_of(value: &ScryptoValue) -> ScryptoValueKind {
+    match value {
+        Value::Bool { .. } => ValueKind::Bool,
+        Value::I8 { .. } => ValueKind::I8,
+        Value::I16 { .. } => ValueKind::I16,
+        Value::I32 { .. } => ValueKind::I32,
+        Value::I64 { .. } => ValueKind::I64,
+        Value::I128 { .. } => ValueKind::I128,
+        Value::U8 { .. } => ValueKind::U8,
+        Value::U16 { .. } => ValueKind::U16,
+        Value::U32 { .. } => ValueKind::U32,
+        Value::U64 { .. } => ValueKind::U64,
+        Value::U128 { .. } => ValueKind::U128,
+        Value::String { .. } => ValueKind::String,
+        Value::Enum { .. } => ValueKind::Enum,
+        Value::Array { .. } => ValueKind::Array,
+        Value::Tuple { .. } => ValueKind::Tuple,
+        Value::Map { .. } => ValueKind::Map,
+        Value::Custom { value } => ValueKind::Custom(match value {
+            ScryptoCustomValue::PackageAddress(_) => ScryptoCustomValueKind::PackageAddress,
+            ScryptoCustomValue::ComponentAddress(_) => ScryptoCustomValueKind::ComponentAddress,
+            ScryptoCustomValue::ResourceAddress(_) => ScryptoCustomValueKind::ResourceAddress,
+            ScryptoCustomValue::Own(_) => ScryptoCustomValueKind::Own,
+            ScryptoCustomValue::Bucket(_) => ScryptoCustomValueKind::Bucket,
+            ScryptoCustomValue::Proof(_) => ScryptoCustomValueKind::Proof,
+            ScryptoCustomValue::Expression(_) => ScryptoCustomValueKind::Expression,
+            ScryptoCustomValue::Blob(_) => ScryptoCustomValueKind::Blob,
+            ScryptoCustomValue::Hash(_) => ScryptoCustomValueKind::Hash,
+            ScryptoCustomValue::EcdsaSecp256k1PublicKey(_) => {
+                ScryptoCustomValueKind::EcdsaSecp256k1PublicKey
+            }
+            ScryptoCustomValue::EcdsaSecp256k1Signature(_) => {
+                ScryptoCustomValueKind::EcdsaSecp256k1Signature
+            }
+            ScryptoCustomValue::EddsaEd25519PublicKey(_) => {
+                ScryptoCustomValueKind::EddsaEd25519PublicKey
+            }
+            ScryptoCustomValue::EddsaEd25519Signature(_) => {
+                ScryptoCustomValueKind::EddsaEd25519Signature
+            }
+            ScryptoCustomValue::Decimal(_) => ScryptoCustomValueKind::Decimal,
+            ScryptoCustomValue::PreciseDecimal(_) => ScryptoCustomValueKind::PreciseDecimal,
+            ScryptoCustomValue::NonFungibleLocalId(_) => ScryptoCustomValueKind::NonFungibleLocalId,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::Bech32Encoder;
+    use crate::node::NetworkDefinition;
+    use radix_engine_derive::*;
+    use sbor::rust::collections::BTreeMap;
+    use utils::ContextualDisplay;
+
+    #[derive(ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+    struct Everything {
+        text: String,
+        numbers: (i8, i16, i32, i64, i128, u8, u16, u32, u64, u128),
+        option: Option<Vec<bool>>,
+        bytes: Vec<u8>,
+        map: BTreeMap<String, Decimal>,
+        component: ComponentAddress,
+        global_id: NonFungibleGlobalId,
+        ids: Vec<NonFungibleLocalId>,
+        own: Own,
+        bucket: ManifestBucket,
+        expression: ManifestExpression,
+        blob: ManifestBlobRef,
+        precise: PreciseDecimal,
+        hash: Hash,
+        key: EcdsaSecp256k1PublicKey,
+    }
+
+    fn everything() -> ScryptoValue {
+        let value = Everything {
+            text: "quote \" backslash \\ newline \n tab \t bell \u{7} emoji \u{1F980}".to_string(),
+            numbers: (-1, -2, -3, -4, i128::MIN, 1, 2, 3, 4, u128::MAX),
+            option: Some(vec![true, false]),
+            bytes: vec![0xde, 0xad],
+            map: BTreeMap::from([
+                ("a".to_string(), Decimal::from(1)),
+                ("b".to_string(), Decimal::from_str("-2.5").unwrap()),
+            ]),
+            component: ComponentAddress::Normal([1; 26]),
+            global_id: NonFungibleGlobalId::new(
+                ResourceAddress::Normal([2; 26]),
+                NonFungibleLocalId::string("hello".to_string()).unwrap(),
+            ),
+            ids: vec![
+                NonFungibleLocalId::integer(7),
+                NonFungibleLocalId::bytes(vec![1, 2]).unwrap(),
+                NonFungibleLocalId::uuid(0x7a2b_0000_0000_4000_8000_0000_0000_0001).unwrap(),
+            ],
+            own: Own::Vault([3; 36]),
+            bucket: ManifestBucket(5),
+            expression: ManifestExpression::EntireWorktop,
+            blob: ManifestBlobRef(hash("blob")),
+            precise: PreciseDecimal::from_str("0.000000000000000000000000000000000001").unwrap(),
+            hash: hash("hash"),
+            key: EcdsaSecp256k1PublicKey([4; EcdsaSecp256k1PublicKey::LENGTH]),
+        };
+        scrypto_decode(&scrypto_encode(&value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_compact_text_round_trips() {
+        let value = everything();
+        let text = value
+            .display(ValueFormattingContext::no_context())
+            .to_string();
+        assert_eq!(parse_scrypto_value(&text, None), Ok(value));
+    }
+
+    #[test]
+    fn test_pretty_text_round_trips_with_bech32_addresses() {
+        let network = NetworkDefinition::simulator();
+        let encoder = Bech32Encoder::new(&network);
+        let decoder = Bech32Decoder::new(&network);
+
+        let value = everything();
+        let mut text = String::new();
+        format_scrypto_value_pretty(&mut text, &value, &(&encoder).into()).unwrap();
+        assert!(text.contains("\n    \"quote"));
+        assert!(text.contains("component_sim1"));
+        assert_eq!(parse_scrypto_value(&text, Some(&decoder)), Ok(value));
+    }
+
+    #[test]
+    fn test_pretty_format() {
+        let value = parse_scrypto_value(
+            r#"Tuple(Enum(1u8, Array<U32>(1u32, 2u32)), Map<String, Bool>(), Bytes("ff"))"#,
+            None,
+        )
+        .unwrap();
+        let mut text = String::new();
+        format_scrypto_value_pretty(&mut text, &value, &ValueFormattingContext::no_context())
+            .unwrap();
+        assert_eq!(
+            text,
+            r#"Tuple(
+    Enum(1u8,
+        Array<U32>(
+            1u32,
+            2u32
+        )
+    ),
+    Map<String, Bool>(),
+    Bytes("ff")
+)"#
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_scrypto_value("Array<U8>(1u8, 2u16)", None),
+            Err(ParseScryptoValueError {
+                position: 15,
+                error: ParseScryptoValueErrorKind::MismatchingElementKind {
+                    expected: "U8".to_string(),
+                    actual: "U16".to_string(),
+                }
+            })
+        );
+        assert_eq!(
+            parse_scrypto_value("Tuple(300u8)", None),
+            Err(ParseScryptoValueError {
+                position: 6,
+                error: ParseScryptoValueErrorKind::InvalidInteger("300u8".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_scrypto_value(r#"Decimal("abc")"#, None),
+            Err(ParseScryptoValueError {
+                position: 0,
+                error: ParseScryptoValueErrorKind::InvalidCustomValue {
+                    value_kind: "Decimal".to_string(),
+                    value: "abc".to_string(),
+                }
+            })
+        );
+        assert_eq!(
+            parse_scrypto_value("Tuple() # comment\n Tuple()", None),
+            Err(ParseScryptoValueError {
+                position: 19,
+                error: ParseScryptoValueErrorKind::UnexpectedCharacter('T'),
+            })
+        );
+    }
+}
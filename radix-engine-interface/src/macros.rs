@@ -213,7 +213,10 @@ macro_rules! access_and_or {
 #[macro_export]
 macro_rules! access_rule_node {
     // Handle leaves
-    ($rule:ident $args:tt) => {{ radix_engine_interface::model::AccessRuleNode::ProofRule($rule $args) }};
+    ($rule:ident $args:tt) => {{
+        let node: radix_engine_interface::model::AccessRuleNode = ($rule $args).into();
+        node
+    }};
 
     // Handle group
     (($($tt:tt)+)) => {{ $crate::access_rule_node!($($tt)+) }};
@@ -210,6 +210,15 @@ impl Decimal {
         }
     }
 
+    /// Multiplies by `other`, returning `None` if the result overflows.
+    pub fn checked_mul(&self, other: Self) -> Option<Self> {
+        // Use BnumI384 (BInt<6>) to not overflow before scaling back down.
+        let a = BnumI384::from(self.0);
+        let b = BnumI384::from(other.0);
+        let c = BnumI384(a.0.checked_mul(b.0)?) / BnumI384::from(Self::ONE.0);
+        BnumI256::try_from(c).ok().map(Decimal)
+    }
+
     /// Square root of a Decimal
     pub fn sqrt(&self) -> Option<Self> {
         if self.is_negative() {
@@ -673,6 +682,15 @@ mod tests {
         let _ = (-Decimal::MAX) * dec!("-1.000000000000000001");
     }
 
+    #[test]
+    fn test_checked_mul_decimal() {
+        assert_eq!(dec!(5).checked_mul(dec!("7.5")), Some(dec!("37.5")));
+        assert_eq!(Decimal::MAX.checked_mul(dec!(1)), Some(Decimal::MAX));
+        assert_eq!(Decimal::MAX.checked_mul(dec!("1.000000000000000001")), None);
+        assert_eq!((-Decimal::MAX).checked_mul(dec!("-1.1")), None);
+        assert_eq!(Decimal::MAX.checked_mul(Decimal::MAX), None);
+    }
+
     #[test]
     #[should_panic]
     fn test_div_by_zero_decimal() {
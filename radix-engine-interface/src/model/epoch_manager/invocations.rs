@@ -2,6 +2,7 @@ use radix_engine_interface::crypto::EcdsaSecp256k1PublicKey;
 use radix_engine_interface::math::Decimal;
 use sbor::rust::collections::BTreeMap;
 use sbor::rust::fmt::Debug;
+use sbor::rust::vec::Vec;
 
 use crate::api::wasm::*;
 use crate::api::*;
@@ -23,6 +24,10 @@ pub struct EpochManagerCreateInvocation {
     pub initial_epoch: u64,
     pub rounds_per_epoch: u64,
     pub num_unstake_epochs: u64,
+    pub emission_xrd_per_epoch: Decimal,
+    pub max_validators: u32,
+    pub min_validator_stake: Decimal,
+    pub min_validator_reliability: Decimal,
 }
 
 impl Clone for EpochManagerCreateInvocation {
@@ -46,6 +51,10 @@ impl Clone for EpochManagerCreateInvocation {
             initial_epoch: self.initial_epoch,
             rounds_per_epoch: self.rounds_per_epoch,
             num_unstake_epochs: self.num_unstake_epochs,
+            emission_xrd_per_epoch: self.emission_xrd_per_epoch,
+            max_validators: self.max_validators,
+            min_validator_stake: self.min_validator_stake,
+            min_validator_reliability: self.min_validator_reliability,
         }
     }
 }
@@ -114,12 +123,18 @@ impl Into<CallTableInvocation> for EpochManagerSetEpochInvocation {
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct EpochManagerNextRoundMethodArgs {
     pub round: u64,
+    pub leader: Option<ComponentAddress>,
+    pub missed_leaders: Vec<ComponentAddress>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct EpochManagerNextRoundInvocation {
     pub receiver: ComponentAddress,
     pub round: u64,
+    /// The validator which proposed the new round, if any
+    pub leader: Option<ComponentAddress>,
+    /// The leaders of the rounds skipped since the previous round, who missed their proposals
+    pub missed_leaders: Vec<ComponentAddress>,
 }
 
 impl Invocation for EpochManagerNextRoundInvocation {
@@ -392,3 +407,84 @@ impl Into<CallTableInvocation> for ValidatorUpdateAcceptDelegatedStakeInvocation
         NativeInvocation::Validator(ValidatorInvocation::UpdateAcceptDelegatedStake(self)).into()
     }
 }
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ValidatorUpdateFeeMethodArgs {
+    pub fee_factor: Decimal,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ValidatorUpdateFeeInvocation {
+    pub receiver: ComponentAddress,
+    pub fee_factor: Decimal,
+}
+
+impl Invocation for ValidatorUpdateFeeInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for ValidatorUpdateFeeInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for ValidatorUpdateFeeInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Validator(ValidatorInvocation::UpdateFee(self)).into()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ValidatorClaimFeesMethodArgs {}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ValidatorClaimFeesInvocation {
+    pub receiver: ComponentAddress,
+}
+
+impl Invocation for ValidatorClaimFeesInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for ValidatorClaimFeesInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<CallTableInvocation> for ValidatorClaimFeesInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Validator(ValidatorInvocation::ClaimFees(self)).into()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ValidatorApplyEmissionMethodArgs {
+    pub xrd_bucket: Bucket,
+}
+
+#[derive(Debug, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ValidatorApplyEmissionInvocation {
+    pub receiver: ComponentAddress,
+    pub xrd_bucket: Bucket,
+}
+
+impl Clone for ValidatorApplyEmissionInvocation {
+    fn clone(&self) -> Self {
+        Self {
+            receiver: self.receiver,
+            xrd_bucket: Bucket(self.xrd_bucket.0),
+        }
+    }
+}
+
+impl Invocation for ValidatorApplyEmissionInvocation {
+    type Output = Decimal;
+}
+
+impl SerializableInvocation for ValidatorApplyEmissionInvocation {
+    type ScryptoOutput = Decimal;
+}
+
+impl Into<CallTableInvocation> for ValidatorApplyEmissionInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Validator(ValidatorInvocation::ApplyEmission(self)).into()
+    }
+}
@@ -0,0 +1,238 @@
+use sbor::rust::fmt;
+use sbor::rust::string::String;
+use sbor::rust::vec::Vec;
+use utils::ContextualDisplay;
+
+use crate::address::AddressDisplayContext;
+use crate::api::types::GlobalAddress;
+use crate::crypto::PublicKey;
+use crate::math::Decimal;
+use crate::model::*;
+use crate::*;
+
+/// A URL, such as a link to a website or an icon.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct Url(pub String);
+
+impl Url {
+    pub fn of<S: Into<String>>(url: S) -> Self {
+        Self(url.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Url {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A single typed metadata value.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub enum MetadataValue {
+    String(String),
+    Bool(bool),
+    U8(u8),
+    U32(u32),
+    U64(u64),
+    I32(i32),
+    I64(i64),
+    Decimal(Decimal),
+    Url(Url),
+    Address(GlobalAddress),
+    PublicKey(PublicKey),
+    NonFungibleGlobalId(NonFungibleGlobalId),
+}
+
+/// What is stored under a metadata key: either a single value or a list of values.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub enum MetadataEntry {
+    Value(MetadataValue),
+    List(Vec<MetadataValue>),
+}
+
+/// A type which can be read back out of a metadata entry.
+pub trait FromMetadataEntry: Sized {
+    /// Returns `None` if the entry holds a value of a different type.
+    fn from_metadata_entry(entry: MetadataEntry) -> Option<Self>;
+}
+
+macro_rules! metadata_value_type {
+    ($t:ty, $variant:ident) => {
+        impl From<$t> for MetadataValue {
+            fn from(value: $t) -> Self {
+                MetadataValue::$variant(value)
+            }
+        }
+
+        impl From<$t> for MetadataEntry {
+            fn from(value: $t) -> Self {
+                MetadataEntry::Value(MetadataValue::$variant(value))
+            }
+        }
+
+        impl From<Vec<$t>> for MetadataEntry {
+            fn from(values: Vec<$t>) -> Self {
+                MetadataEntry::List(values.into_iter().map(MetadataValue::$variant).collect())
+            }
+        }
+
+        impl FromMetadataEntry for $t {
+            fn from_metadata_entry(entry: MetadataEntry) -> Option<Self> {
+                match entry {
+                    MetadataEntry::Value(MetadataValue::$variant(value)) => Some(value),
+                    _ => None,
+                }
+            }
+        }
+
+        impl FromMetadataEntry for Vec<$t> {
+            fn from_metadata_entry(entry: MetadataEntry) -> Option<Self> {
+                match entry {
+                    MetadataEntry::List(values) => values
+                        .into_iter()
+                        .map(|value| match value {
+                            MetadataValue::$variant(value) => Some(value),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+metadata_value_type!(String, String);
+metadata_value_type!(bool, Bool);
+metadata_value_type!(u8, U8);
+metadata_value_type!(u32, U32);
+metadata_value_type!(u64, U64);
+metadata_value_type!(i32, I32);
+metadata_value_type!(i64, I64);
+metadata_value_type!(Decimal, Decimal);
+metadata_value_type!(Url, Url);
+metadata_value_type!(GlobalAddress, Address);
+metadata_value_type!(PublicKey, PublicKey);
+metadata_value_type!(NonFungibleGlobalId, NonFungibleGlobalId);
+
+impl From<&str> for MetadataValue {
+    fn from(value: &str) -> Self {
+        MetadataValue::String(value.into())
+    }
+}
+
+impl From<&str> for MetadataEntry {
+    fn from(value: &str) -> Self {
+        MetadataEntry::Value(MetadataValue::String(value.into()))
+    }
+}
+
+impl FromMetadataEntry for MetadataEntry {
+    fn from_metadata_entry(entry: MetadataEntry) -> Option<Self> {
+        Some(entry)
+    }
+}
+
+//======
+// text
+//======
+
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for MetadataValue {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        match self {
+            MetadataValue::String(value) => write!(f, "{}", value),
+            MetadataValue::Bool(value) => write!(f, "{}", value),
+            MetadataValue::U8(value) => write!(f, "{}", value),
+            MetadataValue::U32(value) => write!(f, "{}", value),
+            MetadataValue::U64(value) => write!(f, "{}", value),
+            MetadataValue::I32(value) => write!(f, "{}", value),
+            MetadataValue::I64(value) => write!(f, "{}", value),
+            MetadataValue::Decimal(value) => write!(f, "{}", value),
+            MetadataValue::Url(value) => write!(f, "{}", value),
+            MetadataValue::Address(GlobalAddress::Component(address)) => {
+                write!(f, "{}", address.display(*context))
+            }
+            MetadataValue::Address(GlobalAddress::Package(address)) => {
+                write!(f, "{}", address.display(*context))
+            }
+            MetadataValue::Address(GlobalAddress::Resource(address)) => {
+                write!(f, "{}", address.display(*context))
+            }
+            MetadataValue::PublicKey(PublicKey::EcdsaSecp256k1(public_key)) => {
+                write!(f, "{}", public_key)
+            }
+            MetadataValue::PublicKey(PublicKey::EddsaEd25519(public_key)) => {
+                write!(f, "{}", public_key)
+            }
+            MetadataValue::NonFungibleGlobalId(non_fungible_global_id) => {
+                write!(f, "{}", non_fungible_global_id.display(*context))
+            }
+        }
+    }
+}
+
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for MetadataEntry {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        match self {
+            MetadataEntry::Value(value) => value.contextual_format(f, context),
+            MetadataEntry::List(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    value.contextual_format(f, context)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::rust::vec;
+
+    #[test]
+    fn test_typed_round_trip() {
+        let entry: MetadataEntry = Url::of("https://example.com/icon.png").into();
+        assert_eq!(
+            Url::from_metadata_entry(entry.clone()),
+            Some(Url::of("https://example.com/icon.png"))
+        );
+        assert_eq!(String::from_metadata_entry(entry), None);
+
+        let entry: MetadataEntry = vec![1u32, 2u32].into();
+        assert_eq!(
+            Vec::<u32>::from_metadata_entry(entry.clone()),
+            Some(vec![1, 2])
+        );
+        assert_eq!(u32::from_metadata_entry(entry), None);
+    }
+
+    #[test]
+    fn test_mixed_list_is_not_typed() {
+        let entry = MetadataEntry::List(vec![
+            MetadataValue::U32(1),
+            MetadataValue::String("two".into()),
+        ]);
+        assert_eq!(Vec::<u32>::from_metadata_entry(entry), None);
+    }
+}
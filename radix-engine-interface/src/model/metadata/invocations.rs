@@ -1,15 +1,31 @@
 use sbor::rust::fmt::Debug;
+use sbor::rust::format;
+use sbor::rust::string::String;
 
 use crate::api::types::*;
 use crate::api::wasm::*;
 use crate::api::*;
+use crate::model::*;
 use crate::*;
 
+const METADATA_KEY_GROUP_PREFIX: &str = "metadata.";
+
+/// Returns the name of the access rule group which guards setting and removing a single
+/// metadata key.
+pub fn metadata_key_group(key: &str) -> String {
+    format!("{}{}", METADATA_KEY_GROUP_PREFIX, key)
+}
+
+/// Returns whether the given access rule group guards a single metadata key.
+pub fn is_metadata_key_group(name: &str) -> bool {
+    name.starts_with(METADATA_KEY_GROUP_PREFIX)
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct MetadataSetInvocation {
     pub receiver: RENodeId,
     pub key: String,
-    pub value: String,
+    pub value: MetadataEntry,
 }
 
 impl Invocation for MetadataSetInvocation {
@@ -33,11 +49,11 @@ pub struct MetadataGetInvocation {
 }
 
 impl Invocation for MetadataGetInvocation {
-    type Output = Option<String>;
+    type Output = Option<MetadataEntry>;
 }
 
 impl SerializableInvocation for MetadataGetInvocation {
-    type ScryptoOutput = Option<String>;
+    type ScryptoOutput = Option<MetadataEntry>;
 }
 
 impl Into<CallTableInvocation> for MetadataGetInvocation {
@@ -45,3 +61,44 @@ impl Into<CallTableInvocation> for MetadataGetInvocation {
         NativeInvocation::Metadata(MetadataInvocation::Get(self)).into()
     }
 }
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct MetadataRemoveInvocation {
+    pub receiver: RENodeId,
+    pub key: String,
+}
+
+impl Invocation for MetadataRemoveInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for MetadataRemoveInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for MetadataRemoveInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Metadata(MetadataInvocation::Remove(self)).into()
+    }
+}
+
+/// Locks a single metadata key so that it can no longer be set or removed.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct MetadataLockInvocation {
+    pub receiver: RENodeId,
+    pub key: String,
+}
+
+impl Invocation for MetadataLockInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for MetadataLockInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for MetadataLockInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::Metadata(MetadataInvocation::Lock(self)).into()
+    }
+}
@@ -1,3 +1,5 @@
+mod data;
 mod invocations;
 
+pub use data::*;
 pub use invocations::*;
@@ -14,7 +14,7 @@ pub struct PackagePublishInvocation {
     pub code: Vec<u8>,
     pub abi: Vec<u8>,
     pub royalty_config: BTreeMap<String, RoyaltyConfig>,
-    pub metadata: BTreeMap<String, String>,
+    pub metadata: BTreeMap<String, MetadataEntry>,
     pub access_rules: AccessRules,
 }
 
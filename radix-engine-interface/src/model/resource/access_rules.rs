@@ -125,9 +125,15 @@ impl AccessRules {
     }
 
     pub fn get_group_mutability(&self, key: &str) -> &AccessRule {
-        self.grouped_auth_mutability
-            .get(key)
-            .unwrap_or(&self.default_auth_mutability)
+        match self.grouped_auth_mutability.get(key) {
+            Some(mutability) => mutability,
+            // Until a metadata key is given its own mutability, its rule may be changed by
+            // whoever may change the rule for setting metadata
+            None if is_metadata_key_group(key) => {
+                self.get_mutability(&AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Set)))
+            }
+            None => &self.default_auth_mutability,
+        }
     }
 
     pub fn set_mutability(&mut self, key: AccessRuleKey, method_auth: AccessRule) {
@@ -240,6 +246,16 @@ pub fn package_access_rules_from_owner_badge(owner_badge: &NonFungibleGlobalId)
         rule!(require(owner_badge.clone())),
         rule!(require(owner_badge.clone())),
     );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Remove)),
+        rule!(require(owner_badge.clone())),
+        rule!(require(owner_badge.clone())),
+    );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Lock)),
+        rule!(require(owner_badge.clone())),
+        rule!(require(owner_badge.clone())),
+    );
     access_rules.set_access_rule_and_mutability(
         AccessRuleKey::Native(NativeFn::Package(PackageFn::SetRoyaltyConfig)),
         rule!(require(owner_badge.clone())),
@@ -269,6 +285,18 @@ pub fn resource_access_rules_from_owner_badge(
         ResourceMethodAuthKey::Recall,
         (AccessRule::DenyAll, rule!(require(owner_badge.clone()))),
     );
+    access_rules.insert(
+        ResourceMethodAuthKey::Freeze,
+        (AccessRule::DenyAll, rule!(require(owner_badge.clone()))),
+    );
+    access_rules.insert(
+        ResourceMethodAuthKey::UpdateMaxSupply,
+        (AccessRule::DenyAll, rule!(require(owner_badge.clone()))),
+    );
+    access_rules.insert(
+        ResourceMethodAuthKey::UpdateMintLimitPerEpoch,
+        (AccessRule::DenyAll, rule!(require(owner_badge.clone()))),
+    );
     access_rules.insert(
         Mint,
         (AccessRule::DenyAll, rule!(require(owner_badge.clone()))),
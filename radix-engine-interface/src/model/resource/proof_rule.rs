@@ -2,6 +2,7 @@ use crate::data::SchemaPath;
 use crate::math::Decimal;
 use crate::model::AccessRuleNode::{AllOf, AnyOf};
 use crate::model::*;
+use crate::time::Instant;
 use crate::*;
 use sbor::rust::borrow::ToOwned;
 use sbor::rust::boxed::Box;
@@ -225,6 +226,8 @@ pub enum ProofRule {
     CountOf(SoftCount, SoftResourceOrNonFungibleList),
     AllOf(SoftResourceOrNonFungibleList),
     AnyOf(SoftResourceOrNonFungibleList),
+    /// Passes when the summed weights of the present resources reach the threshold
+    WeightedThresholdOf(u64, Vec<(SoftResourceOrNonFungible, u64)>),
 }
 
 impl From<ResourceAddress> for ProofRule {
@@ -249,6 +252,22 @@ pub enum AccessRuleNode {
     ProofRule(ProofRule),
     AnyOf(Vec<AccessRuleNode>),
     AllOf(Vec<AccessRuleNode>),
+    /// Passes once the current time, as tracked by the Clock to minute precision, is at or
+    /// after the instant
+    AfterTime(Instant),
+    /// Passes while the current time, as tracked by the Clock to minute precision, is before
+    /// the instant
+    BeforeTime(Instant),
+    /// Passes once the current epoch is at or after the given epoch
+    AfterEpoch(u64),
+    /// Passes while the current epoch is before the given epoch
+    BeforeEpoch(u64),
+}
+
+impl From<ProofRule> for AccessRuleNode {
+    fn from(proof_rule: ProofRule) -> Self {
+        AccessRuleNode::ProofRule(proof_rule)
+    }
 }
 
 // FIXME: describe types with cycles
@@ -279,6 +298,30 @@ impl scrypto_abi::LegacyDescribe for AccessRuleNode {
                         }],
                     },
                 },
+                Variant {
+                    name: "AfterTime".to_string(),
+                    fields: Fields::Unnamed {
+                        unnamed: vec![Type::Any],
+                    },
+                },
+                Variant {
+                    name: "BeforeTime".to_string(),
+                    fields: Fields::Unnamed {
+                        unnamed: vec![Type::Any],
+                    },
+                },
+                Variant {
+                    name: "AfterEpoch".to_string(),
+                    fields: Fields::Unnamed {
+                        unnamed: vec![Type::U64],
+                    },
+                },
+                Variant {
+                    name: "BeforeEpoch".to_string(),
+                    fields: Fields::Unnamed {
+                        unnamed: vec![Type::U64],
+                    },
+                },
             ],
         }
     }
@@ -343,6 +386,35 @@ where
     ProofRule::AmountOf(amount.into(), resource.into())
 }
 
+pub fn require_weighted_threshold<T>(threshold: u64, weighted_resources: Vec<(T, u64)>) -> ProofRule
+where
+    T: Into<SoftResourceOrNonFungible>,
+{
+    ProofRule::WeightedThresholdOf(
+        threshold,
+        weighted_resources
+            .into_iter()
+            .map(|(resource, weight)| (resource.into(), weight))
+            .collect(),
+    )
+}
+
+pub fn after_time(instant: Instant) -> AccessRuleNode {
+    AccessRuleNode::AfterTime(instant)
+}
+
+pub fn before_time(instant: Instant) -> AccessRuleNode {
+    AccessRuleNode::BeforeTime(instant)
+}
+
+pub fn after_epoch(epoch: u64) -> AccessRuleNode {
+    AccessRuleNode::AfterEpoch(epoch)
+}
+
+pub fn before_epoch(epoch: u64) -> AccessRuleNode {
+    AccessRuleNode::BeforeEpoch(epoch)
+}
+
 #[derive(
     Debug,
     Clone,
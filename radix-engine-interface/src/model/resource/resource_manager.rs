@@ -1,5 +1,6 @@
 use sbor::rust::collections::{BTreeMap, BTreeSet};
 use sbor::rust::fmt;
+use sbor::rust::format;
 use sbor::rust::string::String;
 use sbor::rust::vec::Vec;
 use sbor::*;
@@ -7,6 +8,7 @@ use scrypto_abi::*;
 use utils::{copy_u8_array, ContextualDisplay};
 
 use crate::address::*;
+use crate::api::types::VaultId;
 use crate::api::wasm::*;
 use crate::api::*;
 use crate::data::types::Own;
@@ -59,14 +61,20 @@ pub enum ResourceMethodAuthKey {
     Withdraw,
     Deposit,
     Recall,
+    Freeze,
+    UpdateMaxSupply,
+    UpdateMintLimitPerEpoch,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct ResourceManagerCreateNonFungibleInvocation {
     pub resource_address: Option<[u8; 26]>, // TODO: Clean this up
     pub id_type: NonFungibleIdType,
-    pub metadata: BTreeMap<String, String>,
+    pub metadata: BTreeMap<String, MetadataEntry>,
     pub access_rules: BTreeMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
+    pub max_supply: Option<Decimal>,
+    pub mint_limit_per_epoch: Option<Decimal>,
+    pub mutable_data_schema: Option<Type>,
 }
 
 impl Invocation for ResourceManagerCreateNonFungibleInvocation {
@@ -86,8 +94,10 @@ impl Into<CallTableInvocation> for ResourceManagerCreateNonFungibleInvocation {
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct ResourceManagerCreateFungibleInvocation {
     pub divisibility: u8,
-    pub metadata: BTreeMap<String, String>,
+    pub metadata: BTreeMap<String, MetadataEntry>,
     pub access_rules: BTreeMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
+    pub max_supply: Option<Decimal>,
+    pub mint_limit_per_epoch: Option<Decimal>,
 }
 
 impl Invocation for ResourceManagerCreateFungibleInvocation {
@@ -107,8 +117,11 @@ impl Into<CallTableInvocation> for ResourceManagerCreateFungibleInvocation {
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct ResourceManagerCreateNonFungibleWithInitialSupplyInvocation {
     pub id_type: NonFungibleIdType,
-    pub metadata: BTreeMap<String, String>,
+    pub metadata: BTreeMap<String, MetadataEntry>,
     pub access_rules: BTreeMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
+    pub max_supply: Option<Decimal>,
+    pub mint_limit_per_epoch: Option<Decimal>,
+    pub mutable_data_schema: Option<Type>,
     pub entries: BTreeMap<NonFungibleLocalId, (Vec<u8>, Vec<u8>)>,
 }
 
@@ -131,8 +144,11 @@ impl Into<CallTableInvocation> for ResourceManagerCreateNonFungibleWithInitialSu
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct ResourceManagerCreateUuidNonFungibleWithInitialSupplyInvocation {
-    pub metadata: BTreeMap<String, String>,
+    pub metadata: BTreeMap<String, MetadataEntry>,
     pub access_rules: BTreeMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
+    pub max_supply: Option<Decimal>,
+    pub mint_limit_per_epoch: Option<Decimal>,
+    pub mutable_data_schema: Option<Type>,
     pub entries: BTreeSet<(Vec<u8>, Vec<u8>)>,
 }
 
@@ -157,8 +173,10 @@ impl Into<CallTableInvocation> for ResourceManagerCreateUuidNonFungibleWithIniti
 pub struct ResourceManagerCreateFungibleWithInitialSupplyInvocation {
     pub resource_address: Option<[u8; 26]>, // TODO: Clean this up
     pub divisibility: u8,
-    pub metadata: BTreeMap<String, String>,
+    pub metadata: BTreeMap<String, MetadataEntry>,
     pub access_rules: BTreeMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
+    pub max_supply: Option<Decimal>,
+    pub mint_limit_per_epoch: Option<Decimal>,
     pub initial_supply: Decimal,
 }
 
@@ -432,6 +450,120 @@ impl Into<CallTableInvocation> for ResourceManagerUpdateNonFungibleDataInvocatio
     }
 }
 
+/// Returns the name of the access rule group which guards updates to a single mutable field
+/// of a resource's non-fungible data.
+pub fn non_fungible_data_field_group(field: &str) -> String {
+    format!("update_non_fungible_data.{}", field)
+}
+
+/// Updates a single mutable field of a non-fungible's data.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ResourceManagerUpdateNonFungibleDataFieldInvocation {
+    pub receiver: ResourceAddress,
+    pub id: NonFungibleLocalId,
+    pub field: String,
+    /// The SBOR-encoded new value of the field.
+    pub value: Vec<u8>,
+}
+
+impl Invocation for ResourceManagerUpdateNonFungibleDataFieldInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for ResourceManagerUpdateNonFungibleDataFieldInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for ResourceManagerUpdateNonFungibleDataFieldInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::ResourceManager(ResourceInvocation::UpdateNonFungibleDataField(self))
+            .into()
+    }
+}
+
+/// Blocks withdraws and deposits on a vault of this resource until it is unfrozen.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ResourceManagerFreezeVaultInvocation {
+    pub receiver: ResourceAddress,
+    pub vault_id: VaultId,
+}
+
+impl Invocation for ResourceManagerFreezeVaultInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for ResourceManagerFreezeVaultInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for ResourceManagerFreezeVaultInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::ResourceManager(ResourceInvocation::FreezeVault(self)).into()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ResourceManagerUnfreezeVaultInvocation {
+    pub receiver: ResourceAddress,
+    pub vault_id: VaultId,
+}
+
+impl Invocation for ResourceManagerUnfreezeVaultInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for ResourceManagerUnfreezeVaultInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for ResourceManagerUnfreezeVaultInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::ResourceManager(ResourceInvocation::UnfreezeVault(self)).into()
+    }
+}
+
+/// Sets the maximum total supply of this resource, or removes it if `None`.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ResourceManagerSetMaxSupplyInvocation {
+    pub receiver: ResourceAddress,
+    pub max_supply: Option<Decimal>,
+}
+
+impl Invocation for ResourceManagerSetMaxSupplyInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for ResourceManagerSetMaxSupplyInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for ResourceManagerSetMaxSupplyInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::ResourceManager(ResourceInvocation::SetMaxSupply(self)).into()
+    }
+}
+
+/// Sets the maximum amount of this resource which can be minted in a single epoch, or removes it if `None`.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ResourceManagerSetMintLimitPerEpochInvocation {
+    pub receiver: ResourceAddress,
+    pub mint_limit_per_epoch: Option<Decimal>,
+}
+
+impl Invocation for ResourceManagerSetMintLimitPerEpochInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for ResourceManagerSetMintLimitPerEpochInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<CallTableInvocation> for ResourceManagerSetMintLimitPerEpochInvocation {
+    fn into(self) -> CallTableInvocation {
+        NativeInvocation::ResourceManager(ResourceInvocation::SetMintLimitPerEpoch(self)).into()
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct ResourceManagerNonFungibleExistsInvocation {
     pub receiver: ResourceAddress,
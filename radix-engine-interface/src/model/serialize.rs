@@ -82,6 +82,8 @@ pub enum AccessRulesChainInvocation {
 pub enum MetadataInvocation {
     Set(MetadataSetInvocation),
     Get(MetadataGetInvocation),
+    Remove(MetadataRemoveInvocation),
+    Lock(MetadataLockInvocation),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
@@ -136,6 +138,9 @@ pub enum ValidatorInvocation {
     ClaimXrd(ValidatorClaimXrdInvocation),
     UpdateKey(ValidatorUpdateKeyInvocation),
     UpdateAcceptDelegatedStake(ValidatorUpdateAcceptDelegatedStakeInvocation),
+    UpdateFee(ValidatorUpdateFeeInvocation),
+    ClaimFees(ValidatorClaimFeesInvocation),
+    ApplyEmission(ValidatorApplyEmissionInvocation),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
@@ -171,6 +176,11 @@ pub enum ResourceInvocation {
     LockVaultAuth(ResourceManagerSetVaultAuthMutabilityInvocation),
     GetTotalSupply(ResourceManagerGetTotalSupplyInvocation),
     UpdateNonFungibleData(ResourceManagerUpdateNonFungibleDataInvocation),
+    UpdateNonFungibleDataField(ResourceManagerUpdateNonFungibleDataFieldInvocation),
+    FreezeVault(ResourceManagerFreezeVaultInvocation),
+    UnfreezeVault(ResourceManagerUnfreezeVaultInvocation),
+    SetMaxSupply(ResourceManagerSetMaxSupplyInvocation),
+    SetMintLimitPerEpoch(ResourceManagerSetMintLimitPerEpochInvocation),
     GetNonFungible(ResourceManagerGetNonFungibleInvocation),
     NonFungibleExists(ResourceManagerNonFungibleExistsInvocation),
 }
@@ -344,6 +354,12 @@ impl NativeInvocation {
                 MetadataInvocation::Get(invocation) => {
                     refs.insert(invocation.receiver);
                 }
+                MetadataInvocation::Remove(invocation) => {
+                    refs.insert(invocation.receiver);
+                }
+                MetadataInvocation::Lock(invocation) => {
+                    refs.insert(invocation.receiver);
+                }
             },
             NativeInvocation::ResourceManager(resman_method) => match resman_method {
                 ResourceInvocation::CreateNonFungible(..) => {}
@@ -407,6 +423,31 @@ impl NativeInvocation {
                         invocation.receiver,
                     )));
                 }
+                ResourceInvocation::UpdateNonFungibleDataField(invocation) => {
+                    refs.insert(RENodeId::Global(GlobalAddress::Resource(
+                        invocation.receiver,
+                    )));
+                }
+                ResourceInvocation::FreezeVault(invocation) => {
+                    refs.insert(RENodeId::Global(GlobalAddress::Resource(
+                        invocation.receiver,
+                    )));
+                }
+                ResourceInvocation::UnfreezeVault(invocation) => {
+                    refs.insert(RENodeId::Global(GlobalAddress::Resource(
+                        invocation.receiver,
+                    )));
+                }
+                ResourceInvocation::SetMaxSupply(invocation) => {
+                    refs.insert(RENodeId::Global(GlobalAddress::Resource(
+                        invocation.receiver,
+                    )));
+                }
+                ResourceInvocation::SetMintLimitPerEpoch(invocation) => {
+                    refs.insert(RENodeId::Global(GlobalAddress::Resource(
+                        invocation.receiver,
+                    )));
+                }
                 ResourceInvocation::NonFungibleExists(invocation) => {
                     refs.insert(RENodeId::Global(GlobalAddress::Resource(
                         invocation.receiver,
@@ -491,6 +532,21 @@ impl NativeInvocation {
                         invocation.receiver,
                     )));
                 }
+                ValidatorInvocation::UpdateFee(invocation) => {
+                    refs.insert(RENodeId::Global(GlobalAddress::Component(
+                        invocation.receiver,
+                    )));
+                }
+                ValidatorInvocation::ClaimFees(invocation) => {
+                    refs.insert(RENodeId::Global(GlobalAddress::Component(
+                        invocation.receiver,
+                    )));
+                }
+                ValidatorInvocation::ApplyEmission(invocation) => {
+                    refs.insert(RENodeId::Global(GlobalAddress::Component(
+                        invocation.receiver,
+                    )));
+                }
             },
             NativeInvocation::Clock(clock_method) => match clock_method {
                 ClockInvocation::Create(..) => {}
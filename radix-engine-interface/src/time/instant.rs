@@ -4,7 +4,7 @@ use sbor::*;
 /// Represents a Unix timestamp, capturing the seconds since the unix epoch.
 ///
 /// See also the [`UtcDateTime`](super::UtcDateTime) type which supports conversion to/from `Instant`.
-#[derive(Encode, Decode, Categorize, Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Encode, Decode, Categorize, Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Instant {
     pub seconds_since_unix_epoch: i64,
 }
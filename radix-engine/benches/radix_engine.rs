@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use radix_engine::engine::ScryptoInterpreter;
+use radix_engine::engine::{SchemaCache, ScryptoInterpreter};
 use radix_engine::ledger::*;
 use radix_engine::transaction::execute_and_commit_transaction;
 use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
@@ -20,6 +20,7 @@ fn bench_transfer(c: &mut Criterion) {
     let mut scrypto_interpreter = ScryptoInterpreter {
         wasm_engine: DefaultWasmEngine::default(),
         wasm_instrumenter: WasmInstrumenter::default(),
+        schema_cache: SchemaCache::default(),
         wasm_metering_config: WasmMeteringConfig::V0,
     };
     let mut substate_store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
@@ -115,7 +115,7 @@ pub enum KernelError {
 
     RENodeNotFound(RENodeId),
 
-    InvalidScryptoFnOutput,
+    InvalidScryptoFnOutput(PayloadValidationError),
 
     // ID allocation
     IdAllocationError(IdAllocationError),
@@ -180,7 +180,7 @@ pub enum CallFrameError {
 pub enum ScryptoFnResolvingError {
     BlueprintNotFound,
     MethodNotFound,
-    InvalidInput,
+    InvalidInput(PayloadValidationError),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
@@ -318,6 +318,8 @@ pub enum ApplicationError {
     AuthZoneError(AuthZoneError),
 
     AccessControllerError(AccessControllerError),
+
+    MetadataError(MetadataError),
 }
 
 impl From<TransactionProcessorError> for ApplicationError {
@@ -386,6 +388,12 @@ impl From<AuthZoneError> for ApplicationError {
     }
 }
 
+impl From<MetadataError> for ApplicationError {
+    fn from(value: MetadataError) -> Self {
+        Self::MetadataError(value)
+    }
+}
+
 impl fmt::Display for RuntimeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
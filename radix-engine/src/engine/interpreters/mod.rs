@@ -1,9 +1,11 @@
 mod native_interpreter;
+mod schema_cache;
 mod scrypto_interface;
 mod scrypto_interpreter;
 mod wasm_runtime;
 
 pub use native_interpreter::*;
+pub use schema_cache::*;
 pub use scrypto_interface::*;
 pub use scrypto_interpreter::*;
 pub use wasm_runtime::*;
@@ -0,0 +1,81 @@
+use crate::types::*;
+use radix_engine_interface::data::{generate_schema_from_abi_type, ScryptoSchema};
+use sbor::rust::sync::Arc;
+use sbor::LocalTypeIndex;
+
+/// The schemas of the input and output of a blueprint function, generated from its ABI.
+pub struct FnSchemas {
+    pub input: (LocalTypeIndex, ScryptoSchema),
+    pub output: (LocalTypeIndex, ScryptoSchema),
+}
+
+/// A function is identified by its package and export name, which is unique within the package.
+pub type FnSchemasKey = (PackageAddress, String);
+
+/// Caches the schemas generated from blueprint ABIs, so that they aren't regenerated on every
+/// invocation.
+///
+/// Entries are never invalidated: this relies on packages being immutable once published, so
+/// the ABI behind a key can't change. If package upgrades are ever supported, upgrading must
+/// evict the package's entries (or the key must include a package version).
+pub struct SchemaCache {
+    #[cfg(not(feature = "moka"))]
+    cache: RefCell<lru::LruCache<FnSchemasKey, Arc<FnSchemas>>>,
+    #[cfg(feature = "moka")]
+    cache: moka::sync::Cache<FnSchemasKey, Arc<FnSchemas>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SchemaCacheOptions {
+    max_cached_fns: usize,
+}
+
+impl Default for SchemaCache {
+    fn default() -> Self {
+        Self::new(SchemaCacheOptions {
+            max_cached_fns: 10_000,
+        })
+    }
+}
+
+impl SchemaCache {
+    pub fn new(options: SchemaCacheOptions) -> Self {
+        #[cfg(not(feature = "moka"))]
+        let cache = RefCell::new(lru::LruCache::new(
+            NonZeroUsize::new(options.max_cached_fns).unwrap(),
+        ));
+        #[cfg(feature = "moka")]
+        let cache = moka::sync::Cache::builder()
+            .max_capacity(options.max_cached_fns as u64)
+            .build();
+
+        Self { cache }
+    }
+
+    pub fn fn_schemas(&self, package_address: PackageAddress, fn_abi: &Fn) -> Arc<FnSchemas> {
+        let key = (package_address, fn_abi.export_name.clone());
+
+        #[cfg(not(feature = "moka"))]
+        {
+            if let Some(cached) = self.cache.borrow_mut().get(&key) {
+                return cached.clone();
+            }
+        }
+        #[cfg(feature = "moka")]
+        if let Some(cached) = self.cache.get(&key) {
+            return cached;
+        }
+
+        let schemas = Arc::new(FnSchemas {
+            input: generate_schema_from_abi_type(&fn_abi.input),
+            output: generate_schema_from_abi_type(&fn_abi.output),
+        });
+
+        #[cfg(not(feature = "moka"))]
+        self.cache.borrow_mut().put(key, schemas.clone());
+        #[cfg(feature = "moka")]
+        self.cache.insert(key, schemas.clone());
+
+        schemas
+    }
+}
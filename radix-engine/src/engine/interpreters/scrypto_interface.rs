@@ -7,7 +7,7 @@ use crate::model::{
     AccessRulesChainSubstate, ComponentInfoSubstate, ComponentRoyaltyAccumulatorSubstate,
     ComponentRoyaltyConfigSubstate, ComponentStateSubstate, KeyValueStore, RuntimeSubstate,
 };
-use crate::types::BTreeMap;
+use crate::types::{BTreeMap, BTreeSet};
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::types::{
     ComponentFn, LockHandle, NativeFn, RENodeId, RENodeType, ScryptoRENode, SubstateOffset,
@@ -75,6 +75,7 @@ where
                     royalty_accumulator,
                     MetadataSubstate {
                         metadata: BTreeMap::new(),
+                        locked_keys: BTreeSet::new(),
                     },
                     AccessRulesChainSubstate {
                         access_rules_chain: vec![access_rules],
@@ -3,7 +3,8 @@ use crate::types::*;
 use crate::wasm::{WasmEngine, WasmInstance, WasmInstrumenter, WasmMeteringConfig, WasmRuntime};
 use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::api::{ActorApi, ComponentApi, EngineApi, InvokableModel};
-use radix_engine_interface::data::{match_schema_with_value, ScryptoValue};
+use radix_engine_interface::data::ScryptoValue;
+use sbor::validate_value_against_schema;
 
 pub struct ScryptoExecutor {
     pub package_address: PackageAddress,
@@ -41,7 +42,10 @@ impl Executor for ScryptoExecutor {
         let fn_abi = package
             .fn_abi(&self.export_name)
             .expect("TODO: Remove this expect");
-        let rtn_type = fn_abi.output.clone();
+        let schemas = api
+            .vm()
+            .schema_cache
+            .fn_schemas(self.package_address, fn_abi);
 
         // Emit event
         api.on_wasm_instantiation(package.code())?;
@@ -74,9 +78,12 @@ impl Executor for ScryptoExecutor {
             RuntimeError::InterpreterError(InterpreterError::InvalidScryptoReturn(e))
         })?;
 
-        let rtn = if !match_schema_with_value(&rtn_type, output.as_value()) {
+        let (rtn_type, rtn_schema) = &schemas.output;
+        let rtn = if let Err(e) =
+            validate_value_against_schema(output.as_value(), rtn_schema, *rtn_type)
+        {
             Err(RuntimeError::KernelError(
-                KernelError::InvalidScryptoFnOutput,
+                KernelError::InvalidScryptoFnOutput(e),
             ))
         } else {
             let update = CallFrameUpdate {
@@ -104,6 +111,8 @@ pub struct ScryptoInterpreter<W: WasmEngine> {
     pub wasm_instrumenter: WasmInstrumenter,
     /// WASM metering config
     pub wasm_metering_config: WasmMeteringConfig,
+    /// Schemas generated from blueprint ABIs
+    pub schema_cache: SchemaCache,
 }
 
 impl<W: WasmEngine + Default> Default for ScryptoInterpreter<W> {
@@ -112,6 +121,7 @@ impl<W: WasmEngine + Default> Default for ScryptoInterpreter<W> {
             wasm_engine: W::default(),
             wasm_instrumenter: WasmInstrumenter::default(),
             wasm_metering_config: WasmMeteringConfig::default(),
+            schema_cache: SchemaCache::default(),
         }
     }
 }
@@ -659,6 +659,10 @@ where
     fn deref(&mut self, node_id: RENodeId) -> Result<Option<(RENodeId, LockHandle)>, RuntimeError> {
         self.node_method_deref(node_id)
     }
+
+    fn schema_cache(&self) -> &SchemaCache {
+        &self.scrypto_interpreter.schema_cache
+    }
 }
 
 pub trait Executor {
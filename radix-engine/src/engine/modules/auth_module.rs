@@ -2,10 +2,11 @@ use crate::engine::*;
 use crate::model::*;
 use crate::types::*;
 use radix_engine_interface::api::types::{
-    AuthZoneStackOffset, ComponentOffset, GlobalAddress, PackageOffset, RENodeId, SubstateOffset,
-    VaultOffset,
+    AuthZoneStackOffset, ClockOffset, ComponentOffset, EpochManagerOffset, GlobalAddress,
+    PackageOffset, RENodeId, SubstateOffset, VaultOffset,
 };
 use radix_engine_interface::api::ActorApi;
+use radix_engine_interface::time::Instant;
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub enum AuthError {
@@ -14,12 +15,69 @@ pub enum AuthError {
         actor: ResolvedActor,
         authorization: MethodAuthorization,
         error: MethodAuthorizationError,
+        explanation: AuthExplanation,
     },
 }
 
 pub struct AuthModule;
 
 impl AuthModule {
+    /// Reads the current epoch and time, if any of the given authorizations depend on them and
+    /// the EpochManager and Clock are visible to the current frame.
+    pub fn system_state<Y>(
+        method_auths: &[MethodAuthorization],
+        system_api: &mut Y,
+    ) -> Result<AuthSystemState, RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        if !method_auths
+            .iter()
+            .any(|method_auth| method_auth.depends_on_system_state())
+        {
+            return Ok(AuthSystemState::default());
+        }
+
+        let refed = system_api.get_visible_nodes()?;
+        let epoch_manager_node_id = RENodeId::Global(GlobalAddress::Component(EPOCH_MANAGER));
+        let clock_node_id = RENodeId::Global(GlobalAddress::Component(CLOCK));
+
+        let current_epoch = if refed.contains(&epoch_manager_node_id) {
+            let handle = system_api.lock_substate(
+                epoch_manager_node_id,
+                SubstateOffset::EpochManager(EpochManagerOffset::EpochManager),
+                LockFlags::read_only(),
+            )?;
+            let substate_ref = system_api.get_ref(handle)?;
+            let epoch = substate_ref.epoch_manager().epoch;
+            system_api.drop_lock(handle)?;
+            Some(epoch)
+        } else {
+            None
+        };
+
+        let current_time = if refed.contains(&clock_node_id) {
+            let handle = system_api.lock_substate(
+                clock_node_id,
+                SubstateOffset::Clock(ClockOffset::CurrentTimeRoundedToMinutes),
+                LockFlags::read_only(),
+            )?;
+            let substate_ref = system_api.get_ref(handle)?;
+            let current_time_ms = substate_ref
+                .current_time_rounded_to_minutes()
+                .current_time_rounded_to_minutes_ms;
+            system_api.drop_lock(handle)?;
+            Some(Instant::new(current_time_ms / 1000))
+        } else {
+            None
+        };
+
+        Ok(AuthSystemState {
+            current_epoch,
+            current_time,
+        })
+    }
+
     pub fn on_before_frame_start<Y>(
         actor: &ResolvedActor,
         system_api: &mut Y,
@@ -52,6 +110,8 @@ impl AuthModule {
                     (NativeFn::AccessRulesChain(AccessRulesChainFn::SetMethodAccessRule), ..) => {
                         vec![]
                     }
+                    // Metadata key auth depends on the key, so is done manually within the method
+                    (NativeFn::Metadata(MetadataFn::Set | MetadataFn::Remove), ..) => vec![],
                     (method, ..)
                         if matches!(method, NativeFn::Metadata(..))
                             || matches!(method, NativeFn::EpochManager(..))
@@ -196,6 +256,20 @@ impl AuthModule {
             _ => vec![],
         };
 
+        Self::check_authorization(actor, method_auths, system_api)
+    }
+
+    /// Checks the given authorizations of the actor against the auth zone stack.
+    pub fn check_authorization<Y>(
+        actor: &ResolvedActor,
+        method_auths: Vec<MethodAuthorization>,
+        system_api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let system_state = Self::system_state(&method_auths, system_api)?;
+
         let refed = system_api.get_visible_nodes()?;
         let auth_zone_id = refed
             .into_iter()
@@ -213,12 +287,19 @@ impl AuthModule {
 
         // Authorization check
         auth_zone_stack
-            .check_auth(is_barrier, method_auths)
+            .check_auth(is_barrier, method_auths, &system_state)
             .map_err(|(authorization, error)| {
+                let explanation = AuthExplainer::explain(
+                    &authorization,
+                    auth_zone_stack,
+                    is_barrier,
+                    &system_state,
+                );
                 RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
                     actor: actor.clone(),
                     authorization,
                     error,
+                    explanation,
                 }))
             })?;
 
@@ -234,11 +315,29 @@ impl AuthModule {
     ) -> Result<(), RuntimeError> {
         let refed = system_api.get_visible_nodes()?;
         let auth_zone_id = refed
-            .into_iter()
+            .iter()
+            .cloned()
             .find(|e| matches!(e, RENodeId::AuthZoneStack(..)))
             .unwrap();
         call_frame_update.node_refs_to_copy.insert(auth_zone_id);
 
+        // Auth checked manually within these frames may depend on the current epoch and time
+        if matches!(
+            actor.identifier,
+            FnIdentifier::Native(NativeFn::AuthZoneStack(..))
+                | FnIdentifier::Native(NativeFn::AccessRulesChain(..))
+                | FnIdentifier::Native(NativeFn::Metadata(..))
+        ) {
+            for node_id in [
+                RENodeId::Global(GlobalAddress::Component(EPOCH_MANAGER)),
+                RENodeId::Global(GlobalAddress::Component(CLOCK)),
+            ] {
+                if refed.contains(&node_id) {
+                    call_frame_update.node_refs_to_copy.insert(node_id);
+                }
+            }
+        }
+
         if !matches!(
             actor.identifier,
             FnIdentifier::Native(NativeFn::AuthZoneStack(..))
@@ -43,6 +43,7 @@ pub enum RENodeInit {
         EpochManagerSubstate,
         ValidatorSetSubstate,
         ValidatorSetSubstate,
+        ValidatorLivenessSubstate,
         AccessRulesChainSubstate,
     ),
     Validator(
@@ -231,6 +232,7 @@ impl RENodeInit {
                 epoch_manager,
                 current_validator_set_substate,
                 preparing_validator_set_substate,
+                validator_liveness_substate,
                 access_rules,
             ) => {
                 substates.insert(
@@ -245,6 +247,10 @@ impl RENodeInit {
                     SubstateOffset::EpochManager(EpochManagerOffset::PreparingValidatorSet),
                     preparing_validator_set_substate.into(),
                 );
+                substates.insert(
+                    SubstateOffset::EpochManager(EpochManagerOffset::ValidatorLiveness),
+                    validator_liveness_substate.into(),
+                );
                 substates.insert(
                     SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain),
                     access_rules.into(),
@@ -4,10 +4,10 @@ use crate::engine::{
 };
 use crate::model::GlobalAddressSubstate;
 use radix_engine_interface::api::types::{
-    AccessControllerOffset, AccessRulesChainOffset, AuthZoneStackOffset, BucketOffset,
-    ComponentOffset, FnIdentifier, GlobalOffset, KeyValueStoreOffset, NativeFn, PackageOffset,
-    ProofOffset, RENodeId, ResourceManagerOffset, ScryptoFnIdentifier, SubstateOffset,
-    TransactionProcessorFn, ValidatorOffset, VaultOffset, WorktopOffset,
+    AccessControllerOffset, AccessRulesChainOffset, AuthZoneStackOffset, BucketOffset, ClockOffset,
+    ComponentOffset, EpochManagerOffset, FnIdentifier, GlobalOffset, KeyValueStoreOffset, NativeFn,
+    PackageOffset, ProofOffset, RENodeId, ResourceManagerOffset, ScryptoFnIdentifier,
+    SubstateOffset, TransactionProcessorFn, ValidatorOffset, VaultOffset, WorktopOffset,
 };
 
 pub struct VisibilityProperties;
@@ -142,6 +142,12 @@ impl VisibilityProperties {
                 SubstateOffset::VaultAccessRulesChain(AccessRulesChainOffset::AccessRulesChain) => {
                     flags == LockFlags::read_only()
                 }
+                SubstateOffset::EpochManager(EpochManagerOffset::EpochManager) => {
+                    flags == LockFlags::read_only()
+                }
+                SubstateOffset::Clock(ClockOffset::CurrentTimeRoundedToMinutes) => {
+                    flags == LockFlags::read_only()
+                }
                 _ => false,
             },
             (ExecutionMode::Resolver, offset) => match offset {
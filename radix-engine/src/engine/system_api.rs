@@ -87,4 +87,5 @@ pub trait VmApi<W: WasmEngine> {
 // TODO: Clean this up
 pub trait ResolverApi {
     fn deref(&mut self, node_id: RENodeId) -> Result<Option<(RENodeId, LockHandle)>, RuntimeError>;
+    fn schema_cache(&self) -> &SchemaCache;
 }
@@ -484,6 +484,13 @@ impl<'s, R: FeeReserve> Track<'s, R> {
                     "tx_payload_cost",
                 )
             })
+            .and_then(|()| {
+                self.fee_reserve.consume_deferred(
+                    self.fee_table.tx_message_cost_per_byte(),
+                    executable.message_size(),
+                    "tx_message_cost",
+                )
+            })
             .and_then(|()| {
                 self.fee_reserve.consume_deferred(
                     self.fee_table.tx_signature_verification_per_sig(),
@@ -268,7 +268,7 @@ impl PreExecutionFeeReserve for SystemLoanFeeReserve {
         multiplier: usize,
         reason: &'static str,
     ) -> Result<(), FeeReserveError> {
-        if amount == 0 {
+        if amount == 0 || multiplier == 0 {
             return Ok(());
         }
 
@@ -54,6 +54,7 @@ pub enum SystemApiCostingEntry {
 pub struct FeeTable {
     tx_base_fee: u32,
     tx_payload_cost_per_byte: u32,
+    tx_message_cost_per_byte: u32,
     tx_signature_verification_per_sig: u32,
     tx_blob_price_per_byte: u32,
     fixed_low: u32,
@@ -67,6 +68,7 @@ impl FeeTable {
         Self {
             tx_base_fee: 50_000,
             tx_payload_cost_per_byte: 5,
+            tx_message_cost_per_byte: 10,
             tx_signature_verification_per_sig: 100_000,
             tx_blob_price_per_byte: 5,
             wasm_instantiation_per_byte: 1, // TODO: Re-enable WASM instantiation cost if it's unavoidable
@@ -84,6 +86,10 @@ impl FeeTable {
         self.tx_payload_cost_per_byte
     }
 
+    pub fn tx_message_cost_per_byte(&self) -> u32 {
+        self.tx_message_cost_per_byte
+    }
+
     pub fn tx_signature_verification_per_sig(&self) -> u32 {
         self.tx_signature_verification_per_sig
     }
@@ -126,6 +132,9 @@ impl FeeTable {
                 ValidatorFn::ClaimXrd => self.fixed_low,
                 ValidatorFn::UpdateKey => self.fixed_low,
                 ValidatorFn::UpdateAcceptDelegatedStake => self.fixed_low,
+                ValidatorFn::UpdateFee => self.fixed_low,
+                ValidatorFn::ClaimFees => self.fixed_low,
+                ValidatorFn::ApplyEmission => self.fixed_low,
             },
             NativeFn::Clock(clock_method) => match clock_method {
                 ClockFn::Create => self.fixed_low,
@@ -168,6 +177,11 @@ impl FeeTable {
                 ResourceManagerFn::GetResourceType => self.fixed_low,
                 ResourceManagerFn::GetTotalSupply => self.fixed_low,
                 ResourceManagerFn::UpdateNonFungibleData => self.fixed_medium,
+                ResourceManagerFn::UpdateNonFungibleDataField => self.fixed_medium,
+                ResourceManagerFn::FreezeVault => self.fixed_low,
+                ResourceManagerFn::UnfreezeVault => self.fixed_low,
+                ResourceManagerFn::SetMaxSupply => self.fixed_low,
+                ResourceManagerFn::SetMintLimitPerEpoch => self.fixed_low,
                 ResourceManagerFn::NonFungibleExists => self.fixed_low,
                 ResourceManagerFn::GetNonFungible => self.fixed_medium,
                 ResourceManagerFn::Burn => self.fixed_medium,
@@ -196,6 +210,8 @@ impl FeeTable {
             NativeFn::Metadata(metadata_method) => match metadata_method {
                 MetadataFn::Set => self.fixed_low,
                 MetadataFn::Get => self.fixed_low,
+                MetadataFn::Remove => self.fixed_low,
+                MetadataFn::Lock => self.fixed_low,
             },
             NativeFn::Component(method_ident) => match method_ident {
                 ComponentFn::Globalize => self.fixed_high,
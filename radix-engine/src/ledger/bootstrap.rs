@@ -23,13 +23,50 @@ pub struct GenesisReceipt {
     pub faucet_component: ComponentAddress,
 }
 
-pub fn create_genesis(
-    validator_set_and_stake_owners: BTreeMap<EcdsaSecp256k1PublicKey, (Decimal, ComponentAddress)>,
-    account_xrd_allocations: BTreeMap<EcdsaSecp256k1PublicKey, Decimal>,
-    initial_epoch: u64,
-    rounds_per_epoch: u64,
-    num_unstake_epochs: u64,
-) -> SystemTransaction {
+/// The initial state of the ledger and the parameters of the epoch manager.
+#[derive(Debug, Clone)]
+pub struct GenesisConfig {
+    pub validator_set_and_stake_owners:
+        BTreeMap<EcdsaSecp256k1PublicKey, (Decimal, ComponentAddress)>,
+    pub account_xrd_allocations: BTreeMap<EcdsaSecp256k1PublicKey, Decimal>,
+    pub initial_epoch: u64,
+    pub rounds_per_epoch: u64,
+    pub num_unstake_epochs: u64,
+    pub emission_xrd_per_epoch: Decimal,
+    pub max_validators: u32,
+    pub min_validator_stake: Decimal,
+    pub min_validator_reliability: Decimal,
+}
+
+impl Default for GenesisConfig {
+    fn default() -> Self {
+        Self {
+            validator_set_and_stake_owners: BTreeMap::new(),
+            account_xrd_allocations: BTreeMap::new(),
+            initial_epoch: 1,
+            rounds_per_epoch: 1,
+            num_unstake_epochs: 1,
+            emission_xrd_per_epoch: Decimal::zero(),
+            max_validators: 100,
+            min_validator_stake: Decimal::zero(),
+            min_validator_reliability: Decimal::zero(),
+        }
+    }
+}
+
+pub fn create_genesis(config: GenesisConfig) -> SystemTransaction {
+    let GenesisConfig {
+        validator_set_and_stake_owners,
+        account_xrd_allocations,
+        initial_epoch,
+        rounds_per_epoch,
+        num_unstake_epochs,
+        emission_xrd_per_epoch,
+        max_validators,
+        min_validator_stake,
+        min_validator_reliability,
+    } = config;
+
     // NOTES
     // * Create resources before packages to avoid circular dependencies.
 
@@ -40,13 +77,22 @@ pub fn create_genesis(
     // XRD
     {
         let mut metadata = BTreeMap::new();
-        metadata.insert("symbol".to_owned(), XRD_SYMBOL.to_owned());
-        metadata.insert("name".to_owned(), XRD_NAME.to_owned());
-        metadata.insert("description".to_owned(), XRD_DESCRIPTION.to_owned());
-        metadata.insert("url".to_owned(), XRD_URL.to_owned());
+        metadata.insert("symbol".to_owned(), XRD_SYMBOL.into());
+        metadata.insert("name".to_owned(), XRD_NAME.into());
+        metadata.insert("description".to_owned(), XRD_DESCRIPTION.into());
+        metadata.insert("url".to_owned(), XRD_URL.into());
 
         let mut access_rules = BTreeMap::new();
         access_rules.insert(Withdraw, (rule!(allow_all), rule!(deny_all)));
+        // The epoch manager mints the staking emissions
+        {
+            let non_fungible_local_id = NonFungibleLocalId::bytes(
+                scrypto_encode(&PackageIdentifier::Native(NativePackage::EpochManager)).unwrap(),
+            )
+            .unwrap();
+            let global_id = NonFungibleGlobalId::new(PACKAGE_TOKEN, non_fungible_local_id);
+            access_rules.insert(Mint, (rule!(require(global_id)), rule!(deny_all)));
+        }
         let initial_supply: Decimal = XRD_MAX_SUPPLY.into();
         let resource_address = RADIX_TOKEN.raw();
         pre_allocated_ids.insert(RENodeId::Global(GlobalAddress::Resource(RADIX_TOKEN)));
@@ -57,6 +103,8 @@ pub fn create_genesis(
                     divisibility: 18,
                     metadata,
                     access_rules,
+                    max_supply: None,
+                    mint_limit_per_epoch: None,
                     initial_supply,
                 },
             ),
@@ -65,7 +113,7 @@ pub fn create_genesis(
 
     // ECDSA
     {
-        let metadata: BTreeMap<String, String> = BTreeMap::new();
+        let metadata: BTreeMap<String, MetadataEntry> = BTreeMap::new();
         let mut access_rules = BTreeMap::new();
         access_rules.insert(Withdraw, (rule!(allow_all), rule!(deny_all)));
         let resource_address = ECDSA_SECP256K1_TOKEN.raw();
@@ -78,6 +126,9 @@ pub fn create_genesis(
                 id_type: NonFungibleIdType::Bytes,
                 metadata,
                 access_rules,
+                max_supply: None,
+                mint_limit_per_epoch: None,
+                mutable_data_schema: None,
             }),
         )));
     }
@@ -85,7 +136,7 @@ pub fn create_genesis(
     // TODO: Perhaps combine with ecdsa token?
     // EDDSA ED25519 Token
     {
-        let metadata: BTreeMap<String, String> = BTreeMap::new();
+        let metadata: BTreeMap<String, MetadataEntry> = BTreeMap::new();
         let mut access_rules = BTreeMap::new();
         access_rules.insert(Withdraw, (rule!(allow_all), rule!(deny_all)));
         let resource_address = EDDSA_ED25519_TOKEN.raw();
@@ -98,6 +149,9 @@ pub fn create_genesis(
                 id_type: NonFungibleIdType::Bytes,
                 metadata,
                 access_rules,
+                max_supply: None,
+                mint_limit_per_epoch: None,
+                mutable_data_schema: None,
             }),
         )));
     }
@@ -105,7 +159,7 @@ pub fn create_genesis(
     // TODO: Perhaps combine with ecdsa token?
     // System Token
     {
-        let metadata: BTreeMap<String, String> = BTreeMap::new();
+        let metadata: BTreeMap<String, MetadataEntry> = BTreeMap::new();
         let mut access_rules = BTreeMap::new();
         access_rules.insert(Withdraw, (rule!(allow_all), rule!(deny_all)));
         let resource_address = SYSTEM_TOKEN.raw();
@@ -116,13 +170,16 @@ pub fn create_genesis(
                 id_type: NonFungibleIdType::Bytes,
                 metadata,
                 access_rules,
+                max_supply: None,
+                mint_limit_per_epoch: None,
+                mutable_data_schema: None,
             }),
         )));
     }
 
     // Package Token
     {
-        let metadata: BTreeMap<String, String> = BTreeMap::new();
+        let metadata: BTreeMap<String, MetadataEntry> = BTreeMap::new();
         let mut access_rules = BTreeMap::new();
         access_rules.insert(Withdraw, (rule!(allow_all), rule!(deny_all)));
         let resource_address = PACKAGE_TOKEN.raw();
@@ -133,6 +190,9 @@ pub fn create_genesis(
                 id_type: NonFungibleIdType::Bytes,
                 metadata,
                 access_rules,
+                max_supply: None,
+                mint_limit_per_epoch: None,
+                mutable_data_schema: None,
             }),
         )));
     }
@@ -215,6 +275,10 @@ pub fn create_genesis(
                 initial_epoch,
                 rounds_per_epoch,
                 num_unstake_epochs,
+                emission_xrd_per_epoch,
+                max_validators,
+                min_validator_stake,
+                min_validator_reliability,
             }),
         )));
     }
@@ -284,22 +348,14 @@ where
     bootstrap_with_validator_set(
         substate_store,
         scrypto_interpreter,
-        BTreeMap::new(),
-        BTreeMap::new(),
-        1u64,
-        1u64,
-        1u64,
+        GenesisConfig::default(),
     )
 }
 
 pub fn bootstrap_with_validator_set<S, W>(
     substate_store: &mut S,
     scrypto_interpreter: &ScryptoInterpreter<W>,
-    validator_set_and_stake_owners: BTreeMap<EcdsaSecp256k1PublicKey, (Decimal, ComponentAddress)>,
-    account_xrd_allocations: BTreeMap<EcdsaSecp256k1PublicKey, Decimal>,
-    initial_epoch: u64,
-    rounds_per_epoch: u64,
-    num_unstake_epochs: u64,
+    genesis_config: GenesisConfig,
 ) -> Option<TransactionReceipt>
 where
     S: ReadableSubstateStore + WriteableSubstateStore,
@@ -312,13 +368,7 @@ where
         ))
         .is_none()
     {
-        let genesis_transaction = create_genesis(
-            validator_set_and_stake_owners,
-            account_xrd_allocations,
-            initial_epoch,
-            rounds_per_epoch,
-            num_unstake_epochs,
-        );
+        let genesis_transaction = create_genesis(genesis_config);
 
         let transaction_receipt = execute_transaction(
             substate_store,
@@ -355,8 +405,10 @@ mod tests {
             EcdsaSecp256k1PublicKey([0; 33]),
             (Decimal::one(), account_address),
         );
-        let genesis_transaction =
-            create_genesis(initial_validator_set, BTreeMap::new(), 1u64, 1u64, 1u64);
+        let genesis_transaction = create_genesis(GenesisConfig {
+            validator_set_and_stake_owners: initial_validator_set,
+            ..Default::default()
+        });
 
         let transaction_receipt = execute_transaction(
             &substate_store,
@@ -383,8 +435,10 @@ mod tests {
         let allocation_amount = dec!("100");
         let mut account_xrd_allocations = BTreeMap::new();
         account_xrd_allocations.insert(account_public_key, allocation_amount);
-        let genesis_transaction =
-            create_genesis(BTreeMap::new(), account_xrd_allocations, 1u64, 1u64, 1u64);
+        let genesis_transaction = create_genesis(GenesisConfig {
+            account_xrd_allocations,
+            ..Default::default()
+        });
 
         let transaction_receipt = execute_transaction(
             &substate_store,
@@ -24,7 +24,7 @@ impl VaultFinder {
 
 impl StateTreeVisitor for VaultFinder {
     fn visit_vault(&mut self, vault_id: VaultId, vault: &VaultSubstate) {
-        if self.resource_address.eq(&vault.0.resource_address()) {
+        if self.resource_address.eq(&vault.resource.resource_address()) {
             self.vaults.push(vault_id);
         }
     }
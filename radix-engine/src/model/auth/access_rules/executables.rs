@@ -1,6 +1,6 @@
 use crate::engine::{
-    deref_and_update, ApplicationError, CallFrameUpdate, ExecutableInvocation, Executor,
-    InterpreterError, LockFlags, ResolvedActor, ResolverApi, RuntimeError, SystemApi,
+    deref_and_update, ApplicationError, AuthModule, CallFrameUpdate, ExecutableInvocation,
+    Executor, InterpreterError, LockFlags, ResolvedActor, ResolverApi, RuntimeError, SystemApi,
 };
 use crate::model::{MethodAuthorization, MethodAuthorizationError};
 use crate::types::*;
@@ -194,18 +194,20 @@ impl Executor for AccessRulesSetMethodAccessRuleInvocation {
                 .find(|n| matches!(n, RENodeId::AuthZoneStack(..)))
                 .expect("AuthZone does not exist");
 
+            let system_state = AuthModule::system_state(&authorization, api)?;
+
             let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
             let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
             let substate_ref = api.get_ref(handle)?;
             let auth_zone_stack = substate_ref.auth_zone_stack();
 
-            auth_zone_stack.check_auth(false, authorization).map_err(
-                |(authorization, error)| {
+            auth_zone_stack
+                .check_auth(false, authorization, &system_state)
+                .map_err(|(authorization, error)| {
                     RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
                         AccessRulesChainError::Unauthorized(authorization, error),
                     ))
-                },
-            )?;
+                })?;
         }
 
         let mut substate_ref_mut = api.get_ref_mut(handle)?;
@@ -286,13 +288,15 @@ impl Executor for AccessRulesSetGroupAccessRuleInvocation {
                 .find(|n| matches!(n, RENodeId::AuthZoneStack(..)))
                 .expect("AuthZone does not exist");
 
+            let system_state = AuthModule::system_state(&authorization, api)?;
+
             let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
             let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
             let substate_ref = api.get_ref(handle)?;
             let auth_zone_substate = substate_ref.auth_zone_stack();
 
             auth_zone_substate
-                .check_auth(false, authorization)
+                .check_auth(false, authorization, &system_state)
                 .map_err(|(authorization, error)| {
                     RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
                         AccessRulesChainError::Unauthorized(authorization, error),
@@ -401,18 +405,20 @@ impl Executor for AccessRulesSetMethodMutabilityInvocation {
                 .find(|n| matches!(n, RENodeId::AuthZoneStack(..)))
                 .expect("AuthZone does not exist");
 
+            let system_state = AuthModule::system_state(&authorization, api)?;
+
             let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
             let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
             let substate_ref = api.get_ref(handle)?;
             let auth_zone_stack = substate_ref.auth_zone_stack();
 
-            auth_zone_stack.check_auth(false, authorization).map_err(
-                |(authorization, error)| {
+            auth_zone_stack
+                .check_auth(false, authorization, &system_state)
+                .map_err(|(authorization, error)| {
                     RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
                         AccessRulesChainError::Unauthorized(authorization, error),
                     ))
-                },
-            )?;
+                })?;
         }
 
         let mut substate_ref_mut = api.get_ref_mut(handle)?;
@@ -490,13 +496,15 @@ impl Executor for AccessRulesSetGroupMutabilityInvocation {
                 .find(|n| matches!(n, RENodeId::AuthZoneStack(..)))
                 .expect("AuthZone does not exist");
 
+            let system_state = AuthModule::system_state(&authorization, api)?;
+
             let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
             let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
             let substate_ref = api.get_ref(handle)?;
             let auth_zone_substate = substate_ref.auth_zone_stack();
 
             auth_zone_substate
-                .check_auth(false, authorization)
+                .check_auth(false, authorization, &system_state)
                 .map_err(|(authorization, error)| {
                     RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
                         AccessRulesChainError::Unauthorized(authorization, error),
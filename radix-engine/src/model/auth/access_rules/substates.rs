@@ -48,6 +48,29 @@ impl AccessRulesChainSubstate {
         authorizations
     }
 
+    /// Returns the authorization for setting or removing the given metadata key, which is the
+    /// key's own rule or, until the key is given one, the rule for the metadata method.
+    pub fn metadata_key_authorization(
+        &self,
+        metadata_fn: MetadataFn,
+        key: &str,
+    ) -> Vec<MethodAuthorization> {
+        let group = metadata_key_group(key);
+
+        let mut authorizations = Vec::new();
+        for auth in &self.access_rules_chain {
+            let method_auth = auth.get_all_grouped_auth().get(&group).unwrap_or_else(|| {
+                auth.get(&AccessRuleKey::Native(NativeFn::Metadata(metadata_fn)))
+            });
+
+            // TODO: Remove
+            let authorization = convert_contextless(method_auth);
+            authorizations.push(authorization);
+        }
+
+        authorizations
+    }
+
     pub fn method_mutability_authorization(&self, key: &AccessRuleKey) -> Vec<MethodAuthorization> {
         let mut authorizations = Vec::new();
         for auth in &self.access_rules_chain {
@@ -1,6 +1,6 @@
 use crate::engine::{
-    ApplicationError, CallFrameUpdate, ExecutableInvocation, Executor, LockFlags, RENodeInit,
-    ResolvedActor, ResolvedReceiver, ResolverApi, RuntimeError, SystemApi,
+    ApplicationError, AuthModule, CallFrameUpdate, ExecutableInvocation, Executor, LockFlags,
+    RENodeInit, ResolvedActor, ResolvedReceiver, ResolverApi, RuntimeError, SystemApi,
 };
 use crate::model::{
     convert_contextless, MethodAuthorization, MethodAuthorizationError, ProofError,
@@ -471,16 +471,18 @@ impl Executor for AuthZoneAssertAccessRuleInvocation {
     where
         Y: SystemApi,
     {
+        let authorization = convert_contextless(&self.access_rule);
+        let system_state = AuthModule::system_state(&[authorization.clone()], api)?;
+
         let node_id = RENodeId::AuthZoneStack(self.receiver);
         let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
         let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
         let substate_ref = api.get_ref(handle)?;
         let auth_zone_stack = substate_ref.auth_zone_stack();
-        let authorization = convert_contextless(&self.access_rule);
 
         // Authorization check
         auth_zone_stack
-            .check_auth(false, vec![authorization])
+            .check_auth(false, vec![authorization], &system_state)
             .map_err(|(authorization, error)| {
                 RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
                     AuthZoneError::AssertAccessRuleError(authorization, error),
@@ -1,13 +1,14 @@
 use crate::model::MethodAuthorizationError::NotAuthorized;
 use crate::model::{
-    AuthZoneError, HardAuthRule, HardCount, HardDecimal, HardProofRule, HardProofRuleResourceList,
-    HardResourceOrNonFungible, InvokeError, MethodAuthorization, MethodAuthorizationError,
-    ProofSubstate,
+    AuthSystemState, AuthZoneError, HardAuthRule, HardCount, HardDecimal, HardProofRule,
+    HardProofRuleResourceList, HardResourceOrNonFungible, InvokeError, MethodAuthorization,
+    MethodAuthorizationError, ProofSubstate,
 };
 use crate::types::*;
+use radix_engine_interface::time::Instant;
 use sbor::rust::ops::Fn;
 
-struct AuthVerification;
+pub(crate) struct AuthVerification;
 
 impl AuthVerification {
     pub fn proof_matches(resource_rule: &HardResourceOrNonFungible, proof: &ProofSubstate) -> bool {
@@ -169,14 +170,87 @@ impl AuthVerification {
                 }
                 Err(NotAuthorized)
             }
+            HardProofRule::WeightedThresholdOf(threshold, weighted_resources) => {
+                let mut total_weight = 0u64;
+                for (resource, weight) in weighted_resources {
+                    if Self::verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
+                        total_weight = total_weight.saturating_add(*weight);
+                        if total_weight >= *threshold {
+                            return Ok(());
+                        }
+                    }
+                }
+                if total_weight >= *threshold {
+                    Ok(())
+                } else {
+                    Err(NotAuthorized)
+                }
+            }
             _ => Err(NotAuthorized),
         }
     }
 
+    // The current time is only known rounded down to minutes, so is compared with instants rounded
+    // down for `BeforeTime` and rounded up for `AfterTime`, for neither to be satisfied early.
+    fn rounded_down_to_minutes(instant: &Instant) -> Instant {
+        Instant::new(instant.seconds_since_unix_epoch.div_euclid(60) * 60)
+    }
+
+    fn rounded_up_to_minutes(instant: &Instant) -> Option<Instant> {
+        let seconds = instant.seconds_since_unix_epoch;
+        let minutes = seconds.div_euclid(60) + i64::from(seconds.rem_euclid(60) > 0);
+        minutes.checked_mul(60).map(Instant::new)
+    }
+
+    pub fn verify_system_rule(
+        auth_rule: &HardAuthRule,
+        system_state: &AuthSystemState,
+    ) -> Result<(), MethodAuthorizationError> {
+        let satisfied = match (auth_rule, system_state) {
+            (
+                HardAuthRule::AfterTime(instant),
+                AuthSystemState {
+                    current_time: Some(current_time),
+                    ..
+                },
+            ) => Self::rounded_up_to_minutes(instant)
+                .map_or(false, |instant| *current_time >= instant),
+            (
+                HardAuthRule::BeforeTime(instant),
+                AuthSystemState {
+                    current_time: Some(current_time),
+                    ..
+                },
+            ) => *current_time < Self::rounded_down_to_minutes(instant),
+            (
+                HardAuthRule::AfterEpoch(epoch),
+                AuthSystemState {
+                    current_epoch: Some(current_epoch),
+                    ..
+                },
+            ) => *current_epoch >= *epoch,
+            (
+                HardAuthRule::BeforeEpoch(epoch),
+                AuthSystemState {
+                    current_epoch: Some(current_epoch),
+                    ..
+                },
+            ) => *current_epoch < *epoch,
+            _ => false,
+        };
+
+        if satisfied {
+            Ok(())
+        } else {
+            Err(NotAuthorized)
+        }
+    }
+
     pub fn verify_auth_rule(
         barrier_crossings_allowed: u32,
         auth_rule: &HardAuthRule,
         auth_zone: &AuthZoneStackSubstate,
+        system_state: &AuthSystemState,
     ) -> Result<(), MethodAuthorizationError> {
         match auth_rule {
             HardAuthRule::ProofRule(rule) => {
@@ -184,7 +258,8 @@ impl AuthVerification {
             }
             HardAuthRule::AnyOf(rules) => {
                 if !rules.iter().any(|r| {
-                    Self::verify_auth_rule(barrier_crossings_allowed, r, auth_zone).is_ok()
+                    Self::verify_auth_rule(barrier_crossings_allowed, r, auth_zone, system_state)
+                        .is_ok()
                 }) {
                     return Err(NotAuthorized);
                 }
@@ -192,12 +267,17 @@ impl AuthVerification {
             }
             HardAuthRule::AllOf(rules) => {
                 if rules.iter().any(|r| {
-                    Self::verify_auth_rule(barrier_crossings_allowed, r, auth_zone).is_err()
+                    Self::verify_auth_rule(barrier_crossings_allowed, r, auth_zone, system_state)
+                        .is_err()
                 }) {
                     return Err(NotAuthorized);
                 }
                 Ok(())
             }
+            HardAuthRule::AfterTime(..)
+            | HardAuthRule::BeforeTime(..)
+            | HardAuthRule::AfterEpoch(..)
+            | HardAuthRule::BeforeEpoch(..) => Self::verify_system_rule(auth_rule, system_state),
         }
     }
 
@@ -205,10 +285,11 @@ impl AuthVerification {
         barrier_crossings_allowed: u32,
         method_auth: &MethodAuthorization,
         auth_zone: &AuthZoneStackSubstate,
+        system_state: &AuthSystemState,
     ) -> Result<(), MethodAuthorizationError> {
         match method_auth {
             MethodAuthorization::Protected(rule) => {
-                Self::verify_auth_rule(barrier_crossings_allowed, rule, auth_zone)
+                Self::verify_auth_rule(barrier_crossings_allowed, rule, auth_zone, system_state)
             }
             MethodAuthorization::AllowAll => Ok(()),
             MethodAuthorization::DenyAll => Err(NotAuthorized),
@@ -239,24 +320,51 @@ impl AuthZoneStackSubstate {
         }
     }
 
+    pub(crate) fn barrier_crossings_allowed(is_barrier: bool) -> u32 {
+        if is_barrier {
+            0
+        } else {
+            1
+        }
+    }
+
     pub fn check_auth(
         &self,
         is_barrier: bool,
         method_auths: Vec<MethodAuthorization>,
+        system_state: &AuthSystemState,
     ) -> Result<(), (MethodAuthorization, MethodAuthorizationError)> {
-        let mut barrier_crossings_allowed = 1u32;
-        if is_barrier {
-            barrier_crossings_allowed -= 1;
-        }
+        let barrier_crossings_allowed = Self::barrier_crossings_allowed(is_barrier);
 
         for method_auth in method_auths {
-            AuthVerification::verify_method_auth(barrier_crossings_allowed, &method_auth, &self)
-                .map_err(|e| (method_auth, e))?;
+            AuthVerification::verify_method_auth(
+                barrier_crossings_allowed,
+                &method_auth,
+                &self,
+                system_state,
+            )
+            .map_err(|e| (method_auth, e))?;
         }
 
         Ok(())
     }
 
+    /// The auth zones which an authorization check may inspect, from the current frame outwards.
+    pub(crate) fn visible_auth_zones(&self, mut barrier_crossings_allowed: u32) -> Vec<&AuthZone> {
+        let mut auth_zones = Vec::new();
+        for auth_zone in self.auth_zones.iter().rev() {
+            auth_zones.push(auth_zone);
+
+            if auth_zone.barrier {
+                if barrier_crossings_allowed == 0 {
+                    break;
+                }
+                barrier_crossings_allowed -= 1;
+            }
+        }
+        auth_zones
+    }
+
     pub fn new_frame(
         &mut self,
         virtual_non_fungibles_non_extending: BTreeSet<NonFungibleGlobalId>,
@@ -327,6 +435,22 @@ impl AuthZone {
         }
     }
 
+    pub(crate) fn proofs(&self) -> &[ProofSubstate] {
+        &self.proofs
+    }
+
+    pub(crate) fn virtual_resources(&self) -> &BTreeSet<ResourceAddress> {
+        &self.virtual_resources
+    }
+
+    pub(crate) fn virtual_non_fungibles(&self) -> &BTreeSet<NonFungibleGlobalId> {
+        &self.virtual_non_fungibles
+    }
+
+    pub(crate) fn virtual_non_fungibles_non_extending(&self) -> &BTreeSet<NonFungibleGlobalId> {
+        &self.virtual_non_fungibles_non_extending
+    }
+
     pub fn pop(&mut self) -> Result<ProofSubstate, InvokeError<AuthZoneError>> {
         if self.proofs.is_empty() {
             return Err(InvokeError::SelfError(AuthZoneError::EmptyAuthZone));
@@ -0,0 +1,506 @@
+use crate::model::{
+    AuthSystemState, AuthVerification, AuthZone, AuthZoneStackSubstate, HardAuthRule, HardCount,
+    HardDecimal, HardProofRule, HardProofRuleResourceList, HardResourceOrNonFungible,
+    MethodAuthorization, ProofSubstate,
+};
+use crate::types::*;
+use radix_engine_interface::address::AddressDisplayContext;
+use radix_engine_interface::time::Instant;
+use utils::ContextualDisplay;
+
+/// A node of the authorization being explained
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub enum AuthRuleNode {
+    AllowAll,
+    DenyAll,
+    Unsupported,
+    AnyOf,
+    AllOf,
+    CountOf(HardCount),
+    WeightedThresholdOf(u64),
+    Require(HardResourceOrNonFungible),
+    RequireWeighted(u64, HardResourceOrNonFungible),
+    AmountOf(HardDecimal, HardResourceOrNonFungible),
+    AfterTime(Instant),
+    BeforeTime(Instant),
+    AfterEpoch(u64),
+    BeforeEpoch(u64),
+    /// A resource list which could not be resolved when the rule was converted
+    InvalidResourceList,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub enum ConsideredProofKind {
+    Proof {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        non_fungible_ids: Option<BTreeSet<NonFungibleLocalId>>,
+    },
+    VirtualResource(ResourceAddress),
+    VirtualNonFungible(NonFungibleGlobalId),
+}
+
+/// A proof, real or virtual, which was checked against a rule
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ConsideredProof {
+    /// The number of auth zones between the current frame and the one holding the proof
+    pub depth: usize,
+    pub kind: ConsideredProofKind,
+    pub matched: bool,
+}
+
+/// Explains the outcome of an authorization check, marking every rule node as satisfied or
+/// not, along with the proofs each leaf was checked against.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct AuthExplanation {
+    pub node: AuthRuleNode,
+    pub satisfied: bool,
+    pub considered_proofs: Vec<ConsideredProof>,
+    pub children: Vec<AuthExplanation>,
+}
+
+impl AuthExplanation {
+    fn composite(node: AuthRuleNode, satisfied: bool, children: Vec<AuthExplanation>) -> Self {
+        Self {
+            node,
+            satisfied,
+            considered_proofs: Vec::new(),
+            children,
+        }
+    }
+
+    fn leaf(node: AuthRuleNode, satisfied: bool, considered_proofs: Vec<ConsideredProof>) -> Self {
+        Self {
+            node,
+            satisfied,
+            considered_proofs,
+            children: Vec::new(),
+        }
+    }
+
+    /// The unsatisfied leaves of this explanation, i.e. the rules which need more proofs or a
+    /// different system state.
+    pub fn failed_leaves(&self) -> Vec<&AuthExplanation> {
+        if self.satisfied {
+            return Vec::new();
+        }
+        if self.children.is_empty() {
+            return vec![self];
+        }
+        self.children
+            .iter()
+            .flat_map(|child| child.failed_leaves())
+            .collect()
+    }
+}
+
+/// Dry-runs an authorization check against an auth zone stack, without mutating it, and
+/// explains the result.
+///
+/// Whether each node is satisfied is decided by the same verification used by the auth module,
+/// so the explanation always agrees with the actual check.
+pub struct AuthExplainer<'a> {
+    auth_zones: &'a AuthZoneStackSubstate,
+    barrier_crossings_allowed: u32,
+    system_state: &'a AuthSystemState,
+}
+
+impl<'a> AuthExplainer<'a> {
+    pub fn explain(
+        method_auth: &MethodAuthorization,
+        auth_zones: &'a AuthZoneStackSubstate,
+        is_barrier: bool,
+        system_state: &'a AuthSystemState,
+    ) -> AuthExplanation {
+        let explainer = Self {
+            auth_zones,
+            barrier_crossings_allowed: AuthZoneStackSubstate::barrier_crossings_allowed(is_barrier),
+            system_state,
+        };
+
+        match method_auth {
+            MethodAuthorization::Protected(rule) => explainer.explain_auth_rule(rule),
+            MethodAuthorization::AllowAll => {
+                AuthExplanation::leaf(AuthRuleNode::AllowAll, true, Vec::new())
+            }
+            MethodAuthorization::DenyAll => {
+                AuthExplanation::leaf(AuthRuleNode::DenyAll, false, Vec::new())
+            }
+            MethodAuthorization::Unsupported => {
+                AuthExplanation::leaf(AuthRuleNode::Unsupported, false, Vec::new())
+            }
+        }
+    }
+
+    fn explain_auth_rule(&self, auth_rule: &HardAuthRule) -> AuthExplanation {
+        let satisfied = AuthVerification::verify_auth_rule(
+            self.barrier_crossings_allowed,
+            auth_rule,
+            self.auth_zones,
+            self.system_state,
+        )
+        .is_ok();
+
+        match auth_rule {
+            HardAuthRule::ProofRule(rule) => self.explain_proof_rule(rule),
+            HardAuthRule::AnyOf(rules) => AuthExplanation::composite(
+                AuthRuleNode::AnyOf,
+                satisfied,
+                rules.iter().map(|r| self.explain_auth_rule(r)).collect(),
+            ),
+            HardAuthRule::AllOf(rules) => AuthExplanation::composite(
+                AuthRuleNode::AllOf,
+                satisfied,
+                rules.iter().map(|r| self.explain_auth_rule(r)).collect(),
+            ),
+            HardAuthRule::AfterTime(instant) => {
+                AuthExplanation::leaf(AuthRuleNode::AfterTime(*instant), satisfied, Vec::new())
+            }
+            HardAuthRule::BeforeTime(instant) => {
+                AuthExplanation::leaf(AuthRuleNode::BeforeTime(*instant), satisfied, Vec::new())
+            }
+            HardAuthRule::AfterEpoch(epoch) => {
+                AuthExplanation::leaf(AuthRuleNode::AfterEpoch(*epoch), satisfied, Vec::new())
+            }
+            HardAuthRule::BeforeEpoch(epoch) => {
+                AuthExplanation::leaf(AuthRuleNode::BeforeEpoch(*epoch), satisfied, Vec::new())
+            }
+        }
+    }
+
+    fn explain_proof_rule(&self, proof_rule: &HardProofRule) -> AuthExplanation {
+        let satisfied = AuthVerification::verify_proof_rule(
+            self.barrier_crossings_allowed,
+            proof_rule,
+            self.auth_zones,
+        )
+        .is_ok();
+
+        match proof_rule {
+            HardProofRule::Require(resource) => self.explain_resource_rule(resource),
+            HardProofRule::AmountOf(amount, resource) => AuthExplanation::leaf(
+                AuthRuleNode::AmountOf(amount.clone(), resource.clone()),
+                satisfied,
+                self.considered_proofs_by_amount(amount, resource),
+            ),
+            HardProofRule::AllOf(resources) => {
+                self.explain_resource_list(AuthRuleNode::AllOf, satisfied, resources)
+            }
+            HardProofRule::AnyOf(resources) => {
+                self.explain_resource_list(AuthRuleNode::AnyOf, satisfied, resources)
+            }
+            HardProofRule::CountOf(count, resources) => self.explain_resource_list(
+                AuthRuleNode::CountOf(count.clone()),
+                satisfied,
+                resources,
+            ),
+            HardProofRule::WeightedThresholdOf(threshold, weighted_resources) => {
+                let children = weighted_resources
+                    .iter()
+                    .map(|(resource, weight)| {
+                        let explanation = self.explain_resource_rule(resource);
+                        AuthExplanation {
+                            node: AuthRuleNode::RequireWeighted(*weight, resource.clone()),
+                            ..explanation
+                        }
+                    })
+                    .collect();
+                AuthExplanation::composite(
+                    AuthRuleNode::WeightedThresholdOf(*threshold),
+                    satisfied,
+                    children,
+                )
+            }
+        }
+    }
+
+    fn explain_resource_list(
+        &self,
+        node: AuthRuleNode,
+        satisfied: bool,
+        resources: &HardProofRuleResourceList,
+    ) -> AuthExplanation {
+        let children = match resources {
+            HardProofRuleResourceList::List(resources) => resources
+                .iter()
+                .map(|resource| self.explain_resource_rule(resource))
+                .collect(),
+            HardProofRuleResourceList::InvalidSchemaPath
+            | HardProofRuleResourceList::DisallowdValueType => vec![AuthExplanation::leaf(
+                AuthRuleNode::InvalidResourceList,
+                false,
+                Vec::new(),
+            )],
+        };
+        AuthExplanation::composite(node, satisfied, children)
+    }
+
+    fn explain_resource_rule(&self, resource: &HardResourceOrNonFungible) -> AuthExplanation {
+        let satisfied = AuthVerification::verify_resource_rule(
+            self.barrier_crossings_allowed,
+            resource,
+            self.auth_zones,
+        );
+
+        let mut considered_proofs = Vec::new();
+        for (depth, auth_zone) in self.visible_auth_zones().into_iter().enumerate() {
+            if let HardResourceOrNonFungible::NonFungible(non_fungible_global_id) = resource {
+                // Non-extending virtual proofs only apply to the current frame
+                let non_extending = auth_zone
+                    .virtual_non_fungibles_non_extending()
+                    .iter()
+                    .filter(|_| depth == 0);
+                for virtual_non_fungible in
+                    non_extending.chain(auth_zone.virtual_non_fungibles().iter())
+                {
+                    considered_proofs.push(ConsideredProof {
+                        depth,
+                        kind: ConsideredProofKind::VirtualNonFungible(virtual_non_fungible.clone()),
+                        matched: virtual_non_fungible == non_fungible_global_id,
+                    });
+                }
+                for virtual_resource in auth_zone.virtual_resources() {
+                    considered_proofs.push(ConsideredProof {
+                        depth,
+                        kind: ConsideredProofKind::VirtualResource(*virtual_resource),
+                        matched: *virtual_resource == non_fungible_global_id.resource_address(),
+                    });
+                }
+            }
+
+            for proof in auth_zone.proofs() {
+                considered_proofs.push(ConsideredProof {
+                    depth,
+                    kind: Self::proof_kind(proof),
+                    matched: AuthVerification::proof_matches(resource, proof),
+                });
+            }
+        }
+
+        AuthExplanation::leaf(
+            AuthRuleNode::Require(resource.clone()),
+            satisfied,
+            considered_proofs,
+        )
+    }
+
+    fn considered_proofs_by_amount(
+        &self,
+        amount: &HardDecimal,
+        resource: &HardResourceOrNonFungible,
+    ) -> Vec<ConsideredProof> {
+        let mut considered_proofs = Vec::new();
+        for (depth, auth_zone) in self.visible_auth_zones().into_iter().enumerate() {
+            for proof in auth_zone.proofs() {
+                let matched = match amount {
+                    HardDecimal::Amount(amount) => {
+                        AuthVerification::proof_matches(resource, proof)
+                            && proof.total_amount() >= *amount
+                    }
+                    HardDecimal::InvalidSchemaPath | HardDecimal::DisallowdValueType => false,
+                };
+                considered_proofs.push(ConsideredProof {
+                    depth,
+                    kind: Self::proof_kind(proof),
+                    matched,
+                });
+            }
+        }
+        considered_proofs
+    }
+
+    fn visible_auth_zones(&self) -> Vec<&'a AuthZone> {
+        self.auth_zones
+            .visible_auth_zones(self.barrier_crossings_allowed)
+    }
+
+    fn proof_kind(proof: &ProofSubstate) -> ConsideredProofKind {
+        ConsideredProofKind::Proof {
+            resource_address: proof.resource_address(),
+            amount: proof.total_amount(),
+            non_fungible_ids: proof.total_ids().ok(),
+        }
+    }
+}
+
+struct ResourceDisplay<'r>(&'r HardResourceOrNonFungible);
+
+impl<'r, 'a> ContextualDisplay<AddressDisplayContext<'a>> for ResourceDisplay<'r> {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        match self.0 {
+            HardResourceOrNonFungible::NonFungible(non_fungible_global_id) => {
+                write!(f, "{}", non_fungible_global_id.display(*context))
+            }
+            HardResourceOrNonFungible::Resource(resource_address) => {
+                write!(f, "{}", resource_address.display(*context))
+            }
+            HardResourceOrNonFungible::InvalidSchemaPath => write!(f, "<invalid schema path>"),
+            HardResourceOrNonFungible::DisallowdValueType => write!(f, "<disallowed value type>"),
+        }
+    }
+}
+
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for AuthRuleNode {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        match self {
+            AuthRuleNode::AllowAll => write!(f, "AllowAll"),
+            AuthRuleNode::DenyAll => write!(f, "DenyAll"),
+            AuthRuleNode::Unsupported => write!(f, "Unsupported"),
+            AuthRuleNode::AnyOf => write!(f, "AnyOf"),
+            AuthRuleNode::AllOf => write!(f, "AllOf"),
+            AuthRuleNode::CountOf(HardCount::Count(count)) => write!(f, "CountOf({})", count),
+            AuthRuleNode::CountOf(..) => write!(f, "CountOf(<invalid count>)"),
+            AuthRuleNode::WeightedThresholdOf(threshold) => {
+                write!(f, "WeightedThresholdOf({})", threshold)
+            }
+            AuthRuleNode::Require(resource) => {
+                write!(
+                    f,
+                    "Require({})",
+                    ResourceDisplay(resource).display(*context)
+                )
+            }
+            AuthRuleNode::RequireWeighted(weight, resource) => write!(
+                f,
+                "Require({}) with weight {}",
+                ResourceDisplay(resource).display(*context),
+                weight
+            ),
+            AuthRuleNode::AmountOf(HardDecimal::Amount(amount), resource) => write!(
+                f,
+                "AmountOf({}, {})",
+                amount,
+                ResourceDisplay(resource).display(*context)
+            ),
+            AuthRuleNode::AmountOf(_, resource) => write!(
+                f,
+                "AmountOf(<invalid amount>, {})",
+                ResourceDisplay(resource).display(*context)
+            ),
+            AuthRuleNode::AfterTime(instant) => write!(
+                f,
+                "AfterTime({}s since unix epoch)",
+                instant.seconds_since_unix_epoch
+            ),
+            AuthRuleNode::BeforeTime(instant) => write!(
+                f,
+                "BeforeTime({}s since unix epoch)",
+                instant.seconds_since_unix_epoch
+            ),
+            AuthRuleNode::AfterEpoch(epoch) => write!(f, "AfterEpoch({})", epoch),
+            AuthRuleNode::BeforeEpoch(epoch) => write!(f, "BeforeEpoch({})", epoch),
+            AuthRuleNode::InvalidResourceList => write!(f, "<invalid resource list>"),
+        }
+    }
+}
+
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for ConsideredProof {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        match &self.kind {
+            ConsideredProofKind::Proof {
+                resource_address,
+                amount,
+                non_fungible_ids,
+            } => {
+                write!(
+                    f,
+                    "Proof of {} {}",
+                    amount,
+                    resource_address.display(*context)
+                )?;
+                if let Some(ids) = non_fungible_ids {
+                    let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+                    write!(f, " [{}]", ids.join(", "))?;
+                }
+            }
+            ConsideredProofKind::VirtualResource(resource_address) => {
+                write!(f, "Virtual proof of {}", resource_address.display(*context))?;
+            }
+            ConsideredProofKind::VirtualNonFungible(non_fungible_global_id) => {
+                write!(
+                    f,
+                    "Virtual proof of {}",
+                    non_fungible_global_id.display(*context)
+                )?;
+            }
+        }
+        write!(
+            f,
+            " in auth zone {} ({})",
+            self.depth,
+            if self.matched { "matched" } else { "no match" }
+        )
+    }
+}
+
+impl AuthExplanation {
+    fn format_tree<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext,
+        indent: &str,
+    ) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "[{}] {}",
+            if self.satisfied { "PASS" } else { "FAIL" },
+            self.node.display(*context)
+        )?;
+
+        let count = self.considered_proofs.len() + self.children.len();
+        let mut index = 0;
+        for considered_proof in &self.considered_proofs {
+            index += 1;
+            let branch = if index == count { "└─" } else { "├─" };
+            write!(
+                f,
+                "\n{}{} {}",
+                indent,
+                branch,
+                considered_proof.display(*context)
+            )?;
+        }
+        for child in &self.children {
+            index += 1;
+            let (branch, continuation) = if index == count {
+                ("└─", "   ")
+            } else {
+                ("├─", "│  ")
+            };
+            write!(f, "\n{}{} ", indent, branch)?;
+            child.format_tree(f, context, &format!("{}{}", indent, continuation))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for AuthExplanation {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        self.format_tree(f, context, "")
+    }
+}
@@ -1,5 +1,7 @@
 mod access_rules;
 mod auth_zone;
+mod explainer;
 
 pub use access_rules::*;
 pub use auth_zone::*;
+pub use explainer::*;
@@ -234,6 +234,17 @@ fn soft_to_hard_proof_rule(
             let hard_resources = soft_to_hard_resource_list(schema, resources, value);
             HardProofRule::CountOf(hard_count, hard_resources)
         }
+        ProofRule::WeightedThresholdOf(threshold, weighted_resources) => {
+            let hard_weighted_resources = weighted_resources
+                .iter()
+                .map(|(soft_resource, weight)| {
+                    let resource =
+                        soft_to_hard_resource_or_non_fungible(schema, soft_resource, value);
+                    (resource, *weight)
+                })
+                .collect();
+            HardProofRule::WeightedThresholdOf(*threshold, hard_weighted_resources)
+        }
     }
 }
 
@@ -260,6 +271,10 @@ fn soft_to_hard_auth_rule(
                 .collect();
             HardAuthRule::AllOf(hard_rules)
         }
+        AccessRuleNode::AfterTime(instant) => HardAuthRule::AfterTime(*instant),
+        AccessRuleNode::BeforeTime(instant) => HardAuthRule::BeforeTime(*instant),
+        AccessRuleNode::AfterEpoch(epoch) => HardAuthRule::AfterEpoch(*epoch),
+        AccessRuleNode::BeforeEpoch(epoch) => HardAuthRule::BeforeEpoch(*epoch),
     }
 }
 
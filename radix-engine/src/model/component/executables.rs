@@ -133,6 +133,16 @@ impl Executor for ComponentGlobalizeWithOwnerInvocation {
             rule!(require(self.owner_badge.clone())),
             rule!(require(self.owner_badge.clone())),
         );
+        access_rules.set_access_rule_and_mutability(
+            AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Remove)),
+            rule!(require(self.owner_badge.clone())),
+            rule!(require(self.owner_badge.clone())),
+        );
+        access_rules.set_access_rule_and_mutability(
+            AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Lock)),
+            rule!(require(self.owner_badge.clone())),
+            rule!(require(self.owner_badge.clone())),
+        );
         access_rules.set_access_rule_and_mutability(
             AccessRuleKey::Native(NativeFn::Component(ComponentFn::SetRoyaltyConfig)),
             rule!(require(self.owner_badge.clone())),
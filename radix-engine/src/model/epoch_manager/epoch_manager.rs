@@ -26,6 +26,10 @@ pub struct EpochManagerSubstate {
     // TODO: Move configuration to an immutable substate
     pub rounds_per_epoch: u64,
     pub num_unstake_epochs: u64,
+    pub emission_xrd_per_epoch: Decimal,
+    pub max_validators: u32,
+    pub min_validator_stake: Decimal,
+    pub min_validator_reliability: Decimal,
 }
 
 #[derive(
@@ -42,9 +46,37 @@ pub struct ValidatorSetSubstate {
     pub epoch: u64,
 }
 
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode,
+)]
+pub struct ValidatorLiveness {
+    pub proposals_made: u64,
+    pub proposals_missed: u64,
+}
+
+impl ValidatorLiveness {
+    /// The fraction of its proposals which a validator has made, or one if it has had none
+    pub fn reliability(&self) -> Decimal {
+        let proposals = self.proposals_made + self.proposals_missed;
+        if proposals == 0 {
+            Decimal::one()
+        } else {
+            Decimal::from(self.proposals_made) / Decimal::from(proposals)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct ValidatorLivenessSubstate {
+    pub validator_liveness: BTreeMap<ComponentAddress, ValidatorLiveness>,
+    pub epoch: u64,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Categorize, Encode, Decode)]
 pub enum EpochManagerError {
     InvalidRoundUpdate { from: u64, to: u64 },
+    InvalidLeader(ComponentAddress),
+    EmissionOverflow { skipped_epochs: u64 },
 }
 
 pub struct EpochManager;
@@ -118,10 +150,14 @@ impl Executor for EpochManagerCreateInvocation {
             round: 0,
             rounds_per_epoch: self.rounds_per_epoch,
             num_unstake_epochs: self.num_unstake_epochs,
+            emission_xrd_per_epoch: self.emission_xrd_per_epoch,
+            max_validators: self.max_validators,
+            min_validator_stake: self.min_validator_stake,
+            min_validator_reliability: self.min_validator_reliability,
         };
 
         let mut olympia_validator_token_resman: ResourceManager = {
-            let metadata: BTreeMap<String, String> = BTreeMap::new();
+            let metadata: BTreeMap<String, MetadataEntry> = BTreeMap::new();
             let mut access_rules = BTreeMap::new();
 
             // TODO: remove mint and premint all tokens
@@ -142,6 +178,9 @@ impl Executor for EpochManagerCreateInvocation {
                     id_type: NonFungibleIdType::Bytes,
                     metadata,
                     access_rules,
+                    max_supply: None,
+                    mint_limit_per_epoch: None,
+                    mutable_data_schema: None,
                 })?;
             ResourceManager(resource_address)
         };
@@ -188,7 +227,11 @@ impl Executor for EpochManagerCreateInvocation {
 
         let current_validator_set = ValidatorSetSubstate {
             epoch: self.initial_epoch,
-            validator_set: validator_set.clone(),
+            validator_set: EpochManager::select_active_validators(
+                &validator_set,
+                self.max_validators,
+                self.min_validator_stake,
+            ),
         };
 
         let preparing_validator_set = ValidatorSetSubstate {
@@ -196,6 +239,11 @@ impl Executor for EpochManagerCreateInvocation {
             validator_set,
         };
 
+        let validator_liveness = ValidatorLivenessSubstate {
+            epoch: self.initial_epoch,
+            validator_liveness: BTreeMap::new(),
+        };
+
         let mut access_rules = AccessRules::new();
         access_rules.set_method_access_rule(
             AccessRuleKey::Native(NativeFn::EpochManager(EpochManagerFn::NextRound)),
@@ -229,6 +277,7 @@ impl Executor for EpochManagerCreateInvocation {
                 epoch_manager,
                 current_validator_set,
                 preparing_validator_set,
+                validator_liveness,
                 AccessRulesChainSubstate {
                     access_rules_chain: vec![access_rules],
                 },
@@ -302,6 +351,8 @@ impl Executor for EpochManagerGetCurrentEpochExecutable {
 pub struct EpochManagerNextRoundExecutable {
     node_id: RENodeId,
     round: u64,
+    leader: Option<ComponentAddress>,
+    missed_leaders: Vec<ComponentAddress>,
 }
 
 impl ExecutableInvocation for EpochManagerNextRoundInvocation {
@@ -318,6 +369,8 @@ impl ExecutableInvocation for EpochManagerNextRoundInvocation {
         let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
         let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
 
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Resource(RADIX_TOKEN)));
+
         let actor = ResolvedActor::method(
             NativeFn::EpochManager(EpochManagerFn::NextRound),
             resolved_receiver,
@@ -325,6 +378,8 @@ impl ExecutableInvocation for EpochManagerNextRoundInvocation {
         let executor = EpochManagerNextRoundExecutable {
             node_id: resolved_receiver.receiver,
             round: self.round,
+            leader: self.leader,
+            missed_leaders: self.missed_leaders,
         };
 
         Ok((actor, call_frame_update, executor))
@@ -339,7 +394,7 @@ impl Executor for EpochManagerNextRoundExecutable {
         system_api: &mut Y,
     ) -> Result<((), CallFrameUpdate), RuntimeError>
     where
-        Y: SystemApi,
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
         let offset = SubstateOffset::EpochManager(EpochManagerOffset::EpochManager);
         let mgr_handle = system_api.lock_substate(self.node_id, offset, LockFlags::MUTABLE)?;
@@ -355,13 +410,41 @@ impl Executor for EpochManagerNextRoundExecutable {
             ));
         }
 
-        if self.round >= epoch_manager.rounds_per_epoch {
+        let rounds_per_epoch = epoch_manager.rounds_per_epoch;
+        let emission = epoch_manager.emission_xrd_per_epoch;
+        let max_validators = epoch_manager.max_validators;
+        let min_validator_stake = epoch_manager.min_validator_stake;
+        let min_validator_reliability = epoch_manager.min_validator_reliability;
+
+        EpochManager::record_proposals(
+            self.node_id,
+            self.leader,
+            &self.missed_leaders,
+            system_api,
+        )?;
+
+        if self.round >= rounds_per_epoch {
+            let unreliable_validators = EpochManager::distribute_emission(
+                self.node_id,
+                emission,
+                min_validator_reliability,
+                system_api,
+            )?;
+
             let offset = SubstateOffset::EpochManager(EpochManagerOffset::PreparingValidatorSet);
             let handle = system_api.lock_substate(self.node_id, offset, LockFlags::MUTABLE)?;
             let mut substate_mut = system_api.get_ref_mut(handle)?;
             let preparing_validator_set = substate_mut.validator_set();
             let prepared_epoch = preparing_validator_set.epoch;
-            let next_validator_set = preparing_validator_set.validator_set.clone();
+            let mut candidates = preparing_validator_set.validator_set.clone();
+            for validator_address in &unreliable_validators {
+                candidates.remove(validator_address);
+            }
+            let next_validator_set = EpochManager::select_active_validators(
+                &candidates,
+                max_validators,
+                min_validator_stake,
+            );
             preparing_validator_set.epoch = prepared_epoch + 1;
 
             let mut substate_mut = system_api.get_ref_mut(mgr_handle)?;
@@ -375,7 +458,11 @@ impl Executor for EpochManagerNextRoundExecutable {
             let validator_set = substate_mut.validator_set();
             validator_set.epoch = prepared_epoch;
             validator_set.validator_set = next_validator_set;
+
+            EpochManager::reset_liveness(self.node_id, prepared_epoch, system_api)?;
         } else {
+            let mut substate_mut = system_api.get_ref_mut(mgr_handle)?;
+            let epoch_manager = substate_mut.epoch_manager();
             epoch_manager.round = self.round;
         }
 
@@ -399,6 +486,8 @@ impl ExecutableInvocation for EpochManagerSetEpochInvocation {
         let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
         let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
 
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Resource(RADIX_TOKEN)));
+
         let actor = ResolvedActor::method(
             NativeFn::EpochManager(EpochManagerFn::SetEpoch),
             resolved_receiver,
@@ -417,12 +506,28 @@ impl Executor for EpochManagerSetEpochExecutable {
         system_api: &mut Y,
     ) -> Result<((), CallFrameUpdate), RuntimeError>
     where
-        Y: SystemApi,
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
         let offset = SubstateOffset::EpochManager(EpochManagerOffset::EpochManager);
         let handle = system_api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
         let mut substate_mut = system_api.get_ref_mut(handle)?;
-        substate_mut.epoch_manager().epoch = self.1;
+        let epoch_manager = substate_mut.epoch_manager();
+        let skipped_epochs = self.1.saturating_sub(epoch_manager.epoch);
+        let emission = epoch_manager
+            .emission_xrd_per_epoch
+            .checked_mul(skipped_epochs.into())
+            .ok_or(RuntimeError::ApplicationError(
+                ApplicationError::EpochManagerError(EpochManagerError::EmissionOverflow {
+                    skipped_epochs,
+                }),
+            ))?;
+        let min_validator_reliability = epoch_manager.min_validator_reliability;
+        epoch_manager.epoch = self.1;
+
+        // Skipping epochs emits as if each of them had been run with the current validator set
+        EpochManager::distribute_emission(self.0, emission, min_validator_reliability, system_api)?;
+        EpochManager::reset_liveness(self.0, self.1, system_api)?;
+
         Ok(((), CallFrameUpdate::empty()))
     }
 }
@@ -502,6 +607,10 @@ impl ExecutableInvocation for EpochManagerUpdateValidatorInvocation {
         let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
         let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
 
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(
+            self.validator_address,
+        )));
+
         let actor = ResolvedActor::method(
             NativeFn::EpochManager(EpochManagerFn::UpdateValidator),
             resolved_receiver,
@@ -543,6 +652,177 @@ impl Executor for EpochManagerUpdateValidatorExecutable {
 }
 
 impl EpochManager {
+    /// Selects the validators which are active for an epoch: the `max_validators` with the most
+    /// stake, of those with at least `min_validator_stake`. Ties in stake are broken by address.
+    pub fn select_active_validators(
+        registered_validators: &BTreeMap<ComponentAddress, Validator>,
+        max_validators: u32,
+        min_validator_stake: Decimal,
+    ) -> BTreeMap<ComponentAddress, Validator> {
+        let mut candidates: Vec<(&ComponentAddress, &Validator)> = registered_validators
+            .iter()
+            .filter(|(_, validator)| validator.stake >= min_validator_stake)
+            .collect();
+        candidates.sort_by(|(address_a, validator_a), (address_b, validator_b)| {
+            validator_b
+                .stake
+                .cmp(&validator_a.stake)
+                .then_with(|| address_a.cmp(address_b))
+        });
+        candidates
+            .into_iter()
+            .take(max_validators as usize)
+            .map(|(address, validator)| (*address, validator.clone()))
+            .collect()
+    }
+
+    /// Records the proposal made by the leader of the new round, and the proposals missed by the
+    /// leaders of any rounds skipped since the previous one.
+    fn record_proposals<Y>(
+        node_id: RENodeId,
+        leader: Option<ComponentAddress>,
+        missed_leaders: &[ComponentAddress],
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        if leader.is_none() && missed_leaders.is_empty() {
+            return Ok(());
+        }
+
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::CurrentValidatorSet);
+        let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        let substate_ref = api.get_ref(handle)?;
+        let validator_set = &substate_ref.validator_set().validator_set;
+        for validator_address in leader.iter().chain(missed_leaders.iter()) {
+            if !validator_set.contains_key(validator_address) {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::EpochManagerError(EpochManagerError::InvalidLeader(
+                        *validator_address,
+                    )),
+                ));
+            }
+        }
+        api.drop_lock(handle)?;
+
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorLiveness);
+        let handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+        let mut substate_mut = api.get_ref_mut(handle)?;
+        let validator_liveness = &mut substate_mut.validator_liveness().validator_liveness;
+        if let Some(leader) = leader {
+            validator_liveness.entry(leader).or_default().proposals_made += 1;
+        }
+        for missed_leader in missed_leaders {
+            validator_liveness
+                .entry(*missed_leader)
+                .or_default()
+                .proposals_missed += 1;
+        }
+        api.drop_lock(handle)?;
+
+        Ok(())
+    }
+
+    /// Clears the proposal counters so that liveness is tracked afresh for the given epoch.
+    fn reset_liveness<Y>(node_id: RENodeId, epoch: u64, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorLiveness);
+        let handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+        let mut substate_mut = api.get_ref_mut(handle)?;
+        let validator_liveness = substate_mut.validator_liveness();
+        validator_liveness.epoch = epoch;
+        validator_liveness.validator_liveness.clear();
+        api.drop_lock(handle)?;
+
+        Ok(())
+    }
+
+    /// Mints an emission of XRD and splits it between the validators of the current validator set,
+    /// pro rata by stake and scaled by each validator's reliability over the epoch. Validators
+    /// whose reliability falls below the minimum receive nothing and are returned so that they
+    /// can be left out of the next validator set. The stake of each rewarded validator in the
+    /// preparing validator set is updated to include its rewards, less its fee.
+    fn distribute_emission<Y>(
+        node_id: RENodeId,
+        emission: Decimal,
+        min_validator_reliability: Decimal,
+        api: &mut Y,
+    ) -> Result<BTreeSet<ComponentAddress>, RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::ValidatorLiveness);
+        let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        let substate_ref = api.get_ref(handle)?;
+        let validator_liveness = substate_ref.validator_liveness().validator_liveness.clone();
+        api.drop_lock(handle)?;
+
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::CurrentValidatorSet);
+        let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        let substate_ref = api.get_ref(handle)?;
+        let validator_set = substate_ref.validator_set().validator_set.clone();
+
+        let mut unreliable_validators = BTreeSet::new();
+        let mut reliabilities = BTreeMap::new();
+        for validator_address in validator_set.keys() {
+            let reliability = validator_liveness
+                .get(validator_address)
+                .map(ValidatorLiveness::reliability)
+                .unwrap_or(Decimal::one());
+            if reliability < min_validator_reliability {
+                unreliable_validators.insert(*validator_address);
+            }
+            reliabilities.insert(*validator_address, reliability);
+        }
+
+        let total_stake = validator_set
+            .values()
+            .fold(Decimal::zero(), |total, validator| total + validator.stake);
+        if !emission.is_positive() || !total_stake.is_positive() {
+            api.drop_lock(handle)?;
+            return Ok(unreliable_validators);
+        }
+
+        let mut xrd_resman = ResourceManager(RADIX_TOKEN);
+        let mut new_stakes = BTreeMap::new();
+        for (validator_address, validator) in validator_set {
+            if unreliable_validators.contains(&validator_address) {
+                continue;
+            }
+            let reward =
+                emission * validator.stake / total_stake * reliabilities[&validator_address];
+            if !reward.is_positive() {
+                continue;
+            }
+            let xrd_bucket = xrd_resman.mint_fungible(reward, api)?;
+            let new_stake: Decimal = api.invoke(ValidatorApplyEmissionInvocation {
+                receiver: validator_address,
+                xrd_bucket,
+            })?;
+            new_stakes.insert(validator_address, new_stake);
+        }
+        api.drop_lock(handle)?;
+
+        let offset = SubstateOffset::EpochManager(EpochManagerOffset::PreparingValidatorSet);
+        let handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+        let mut substate_mut = api.get_ref_mut(handle)?;
+        let preparing_validator_set = substate_mut.validator_set();
+        for (validator_address, new_stake) in new_stakes {
+            if let Some(validator) = preparing_validator_set
+                .validator_set
+                .get_mut(&validator_address)
+            {
+                validator.stake = new_stake;
+            }
+        }
+        api.drop_lock(handle)?;
+
+        Ok(unreliable_validators)
+    }
+
     pub fn create_auth() -> Vec<MethodAuthorization> {
         vec![MethodAuthorization::Protected(HardAuthRule::ProofRule(
             HardProofRule::Require(HardResourceOrNonFungible::NonFungible(
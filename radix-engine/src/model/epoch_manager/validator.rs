@@ -22,12 +22,17 @@ pub struct ValidatorSubstate {
     pub liquidity_token: ResourceAddress,
     pub stake_xrd_vault_id: VaultId,
     pub pending_xrd_withdraw_vault_id: VaultId,
+
+    /// The fraction of each emission which is kept by the owner rather than staked, from 0 to 1
+    pub fee_factor: Decimal,
+    pub owner_fee_xrd_vault_id: VaultId,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub enum ValidatorError {
     InvalidClaimResource,
     EpochUnlockHasNotOccurredYet,
+    InvalidFeeFactor(Decimal),
 }
 
 pub struct ValidatorRegisterExecutable(RENodeId);
@@ -562,6 +567,161 @@ impl Executor for ValidatorUpdateAcceptDelegatedStakeExecutable {
     }
 }
 
+pub struct ValidatorUpdateFeeExecutable(RENodeId, Decimal);
+
+impl ExecutableInvocation for ValidatorUpdateFeeInvocation {
+    type Exec = ValidatorUpdateFeeExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+        let actor = ResolvedActor::method(
+            NativeFn::Validator(ValidatorFn::UpdateFee),
+            resolved_receiver,
+        );
+        let executor = ValidatorUpdateFeeExecutable(resolved_receiver.receiver, self.fee_factor);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for ValidatorUpdateFeeExecutable {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(self, api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        if self.1.is_negative() || self.1 > Decimal::one() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ValidatorError(ValidatorError::InvalidFeeFactor(self.1)),
+            ));
+        }
+
+        let offset = SubstateOffset::Validator(ValidatorOffset::Validator);
+        let handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let mut substate = api.get_ref_mut(handle)?;
+        substate.validator().fee_factor = self.1;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct ValidatorClaimFeesExecutable(RENodeId);
+
+impl ExecutableInvocation for ValidatorClaimFeesInvocation {
+    type Exec = ValidatorClaimFeesExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+        let actor = ResolvedActor::method(
+            NativeFn::Validator(ValidatorFn::ClaimFees),
+            resolved_receiver,
+        );
+        let executor = ValidatorClaimFeesExecutable(resolved_receiver.receiver);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for ValidatorClaimFeesExecutable {
+    type Output = Bucket;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::Validator(ValidatorOffset::Validator);
+        let handle = api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let substate = api.get_ref(handle)?;
+        let mut fee_vault = Vault(substate.validator().owner_fee_xrd_vault_id);
+
+        let fee_amount = fee_vault.sys_amount(api)?;
+        let fee_bucket = fee_vault.sys_take(fee_amount, api)?;
+        let update = CallFrameUpdate::move_node(RENodeId::Bucket(fee_bucket.0));
+        Ok((fee_bucket, update))
+    }
+}
+
+/// Splits an emission between the owner, by the fee factor, and the stake vault, so that the
+/// liquidity tokens become worth more XRD. Returns the new amount staked.
+pub struct ValidatorApplyEmissionExecutable(RENodeId, Bucket);
+
+impl ExecutableInvocation for ValidatorApplyEmissionInvocation {
+    type Exec = ValidatorApplyEmissionExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+        call_frame_update
+            .nodes_to_move
+            .push(RENodeId::Bucket(self.xrd_bucket.0));
+
+        let actor = ResolvedActor::method(
+            NativeFn::Validator(ValidatorFn::ApplyEmission),
+            resolved_receiver,
+        );
+        let executor =
+            ValidatorApplyEmissionExecutable(resolved_receiver.receiver, self.xrd_bucket);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl Executor for ValidatorApplyEmissionExecutable {
+    type Output = Decimal;
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Decimal, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::Validator(ValidatorOffset::Validator);
+        let handle = api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let substate = api.get_ref(handle)?;
+        let validator = substate.validator();
+        let fee_factor = validator.fee_factor;
+        let mut stake_vault = Vault(validator.stake_xrd_vault_id);
+        let mut fee_vault = Vault(validator.owner_fee_xrd_vault_id);
+
+        let xrd_bucket = self.1;
+        let emission_amount = xrd_bucket.sys_amount(api)?;
+        let fee_amount = emission_amount * fee_factor;
+        if fee_amount.is_positive() {
+            let fee_bucket = xrd_bucket.sys_take(fee_amount, api)?;
+            fee_vault.sys_put(fee_bucket, api)?;
+        }
+        stake_vault.sys_put(xrd_bucket, api)?;
+
+        let stake_amount = stake_vault.sys_amount(api)?;
+        Ok((stake_amount, CallFrameUpdate::empty()))
+    }
+}
+
 pub(crate) struct ValidatorCreator;
 
 impl ValidatorCreator {
@@ -679,6 +839,14 @@ impl ValidatorCreator {
             AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Set)),
             "owner".to_string(),
         );
+        access_rules.set_method_access_rule_to_group(
+            AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Remove)),
+            "owner".to_string(),
+        );
+        access_rules.set_method_access_rule_to_group(
+            AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Lock)),
+            "owner".to_string(),
+        );
         access_rules.set_method_access_rule(
             AccessRuleKey::Native(NativeFn::Validator(ValidatorFn::Register)),
             "owner".to_string(),
@@ -695,6 +863,14 @@ impl ValidatorCreator {
             AccessRuleKey::Native(NativeFn::Validator(ValidatorFn::UpdateAcceptDelegatedStake)),
             "owner".to_string(),
         );
+        access_rules.set_method_access_rule(
+            AccessRuleKey::Native(NativeFn::Validator(ValidatorFn::UpdateFee)),
+            "owner".to_string(),
+        );
+        access_rules.set_method_access_rule(
+            AccessRuleKey::Native(NativeFn::Validator(ValidatorFn::ClaimFees)),
+            "owner".to_string(),
+        );
 
         let non_fungible_local_id = NonFungibleLocalId::bytes(
             scrypto_encode(&PackageIdentifier::Native(NativePackage::EpochManager)).unwrap(),
@@ -704,6 +880,10 @@ impl ValidatorCreator {
         access_rules.set_group_and_mutability(
             AccessRuleKey::Native(NativeFn::Validator(ValidatorFn::Stake)),
             "owner".to_string(),
+            rule!(require(non_fungible_global_id.clone())),
+        );
+        access_rules.set_method_access_rule(
+            AccessRuleKey::Native(NativeFn::Validator(ValidatorFn::ApplyEmission)),
             rule!(require(non_fungible_global_id)),
         );
 
@@ -741,6 +921,7 @@ impl ValidatorCreator {
         let mut stake_vault = Vault::sys_new(RADIX_TOKEN, api)?;
         stake_vault.sys_put(initial_stake, api)?;
         let unstake_vault = Vault::sys_new(RADIX_TOKEN, api)?;
+        let owner_fee_vault = Vault::sys_new(RADIX_TOKEN, api)?;
         let unstake_nft = Self::create_unstake_nft(api)?;
         let (liquidity_token, liquidity_bucket) =
             Self::create_liquidity_token_with_initial_amount(initial_liquidity_amount, api)?;
@@ -753,10 +934,13 @@ impl ValidatorCreator {
                 unstake_nft,
                 stake_xrd_vault_id: stake_vault.0,
                 pending_xrd_withdraw_vault_id: unstake_vault.0,
+                fee_factor: Decimal::zero(),
+                owner_fee_xrd_vault_id: owner_fee_vault.0,
                 is_registered,
             },
             MetadataSubstate {
                 metadata: BTreeMap::new(),
+                locked_keys: BTreeSet::new(),
             },
             AccessRulesChainSubstate {
                 access_rules_chain: vec![Self::build_access_rules(owner_access_rule)],
@@ -786,6 +970,7 @@ impl ValidatorCreator {
         let address: ComponentAddress = global_node_id.into();
         let stake_vault = Vault::sys_new(RADIX_TOKEN, api)?;
         let unstake_vault = Vault::sys_new(RADIX_TOKEN, api)?;
+        let owner_fee_vault = Vault::sys_new(RADIX_TOKEN, api)?;
         let unstake_nft = Self::create_unstake_nft(api)?;
         let liquidity_token = Self::create_liquidity_token(api)?;
         let node = RENodeInit::Validator(
@@ -797,10 +982,13 @@ impl ValidatorCreator {
                 unstake_nft,
                 stake_xrd_vault_id: stake_vault.0,
                 pending_xrd_withdraw_vault_id: unstake_vault.0,
+                fee_factor: Decimal::zero(),
+                owner_fee_xrd_vault_id: owner_fee_vault.0,
                 is_registered,
             },
             MetadataSubstate {
                 metadata: BTreeMap::new(),
+                locked_keys: BTreeSet::new(),
             },
             AccessRulesChainSubstate {
                 access_rules_chain: vec![Self::build_access_rules(owner_access_rule)],
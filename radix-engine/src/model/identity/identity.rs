@@ -69,6 +69,16 @@ impl Identity {
         access_rules.set_access_rule_and_mutability(
             AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Set)),
             access_rule.clone(),
+            access_rule.clone(),
+        );
+        access_rules.set_access_rule_and_mutability(
+            AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Remove)),
+            access_rule.clone(),
+            access_rule.clone(),
+        );
+        access_rules.set_access_rule_and_mutability(
+            AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Lock)),
+            access_rule.clone(),
             access_rule,
         );
         access_rules.set_access_rule_and_mutability(
@@ -82,6 +92,7 @@ impl Identity {
             RENodeInit::Identity(
                 MetadataSubstate {
                     metadata: BTreeMap::new(),
+                    locked_keys: BTreeSet::new(),
                 },
                 AccessRulesChainSubstate {
                     access_rules_chain: vec![access_rules],
@@ -1,13 +1,46 @@
 use crate::engine::{
-    deref_and_update, CallFrameUpdate, ExecutableInvocation, Executor, InterpreterError, LockFlags,
-    ResolvedActor, ResolverApi, RuntimeError, SystemApi,
+    deref_and_update, ApplicationError, AuthModule, CallFrameUpdate, ExecutableInvocation,
+    Executor, InterpreterError, LockFlags, ResolvedActor, ResolvedReceiver, ResolverApi,
+    RuntimeError, SystemApi,
 };
 use crate::types::*;
 use crate::wasm::WasmEngine;
-use radix_engine_interface::api::types::{NativeFn, RENodeId, SubstateOffset};
+use radix_engine_interface::api::types::{
+    AccessRulesChainOffset, NativeFn, RENodeId, SubstateOffset,
+};
 use radix_engine_interface::api::EngineApi;
 use radix_engine_interface::model::*;
 
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub enum MetadataError {
+    KeyLocked(String),
+}
+
+/// Checks that the metadata key may be set or removed, by its own rule or else by the rule for
+/// the metadata method.
+fn check_metadata_key_auth<Y: SystemApi>(
+    receiver: RENodeId,
+    metadata_fn: MetadataFn,
+    key: &str,
+    api: &mut Y,
+) -> Result<(), RuntimeError> {
+    let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+    let handle = api.lock_substate(receiver, offset, LockFlags::read_only())?;
+    let authorizations = {
+        let substate_ref = api.get_ref(handle)?;
+        substate_ref
+            .access_rules_chain()
+            .metadata_key_authorization(metadata_fn, key)
+    };
+    api.drop_lock(handle)?;
+
+    let actor = ResolvedActor::method(
+        NativeFn::Metadata(metadata_fn),
+        ResolvedReceiver::new(receiver),
+    );
+    AuthModule::check_authorization(&actor, authorizations, api)
+}
+
 impl ExecutableInvocation for MetadataSetInvocation {
     type Exec = Self;
 
@@ -50,12 +83,16 @@ impl Executor for MetadataSetInvocation {
     where
         Y: SystemApi + EngineApi<RuntimeError>,
     {
+        check_metadata_key_auth(self.receiver, MetadataFn::Set, &self.key, system_api)?;
+
         let offset = SubstateOffset::Metadata(MetadataOffset::Metadata);
         let handle = system_api.lock_substate(self.receiver, offset, LockFlags::MUTABLE)?;
 
         let mut substate_ref_mut = system_api.get_ref_mut(handle)?;
         let metadata = substate_ref_mut.metadata();
-        metadata.metadata.insert(self.key, self.value);
+        metadata
+            .insert(self.key, self.value)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::MetadataError(e)))?;
 
         Ok(((), CallFrameUpdate::empty()))
     }
@@ -94,7 +131,7 @@ impl ExecutableInvocation for MetadataGetInvocation {
 }
 
 impl Executor for MetadataGetInvocation {
-    type Output = Option<String>;
+    type Output = Option<MetadataEntry>;
 
     fn execute<Y, W: WasmEngine>(
         self,
@@ -114,3 +151,114 @@ impl Executor for MetadataGetInvocation {
         Ok((rtn, CallFrameUpdate::empty()))
     }
 }
+
+impl ExecutableInvocation for MetadataRemoveInvocation {
+    type Exec = Self;
+
+    fn resolve<D: ResolverApi>(
+        mut self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+
+        let resolved_receiver = deref_and_update(self.receiver, &mut call_frame_update, deref)?;
+
+        // TODO: Move this into a more static check once node types implemented
+        match &resolved_receiver.receiver {
+            RENodeId::Package(..)
+            | RENodeId::ResourceManager(..)
+            | RENodeId::Component(..)
+            | RENodeId::Validator(..)
+            | RENodeId::Identity(..) => {}
+            _ => {
+                return Err(RuntimeError::InterpreterError(
+                    InterpreterError::InvalidInvocation,
+                ))
+            }
+        }
+
+        self.receiver = resolved_receiver.receiver;
+        let actor =
+            ResolvedActor::method(NativeFn::Metadata(MetadataFn::Remove), resolved_receiver);
+
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for MetadataRemoveInvocation {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError>,
+    {
+        check_metadata_key_auth(self.receiver, MetadataFn::Remove, &self.key, api)?;
+
+        let offset = SubstateOffset::Metadata(MetadataOffset::Metadata);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::MUTABLE)?;
+
+        let mut substate_ref_mut = api.get_ref_mut(handle)?;
+        let metadata = substate_ref_mut.metadata();
+        metadata
+            .remove(&self.key)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::MetadataError(e)))?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+impl ExecutableInvocation for MetadataLockInvocation {
+    type Exec = Self;
+
+    fn resolve<D: ResolverApi>(
+        mut self,
+        deref: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+
+        let resolved_receiver = deref_and_update(self.receiver, &mut call_frame_update, deref)?;
+
+        // TODO: Move this into a more static check once node types implemented
+        match &resolved_receiver.receiver {
+            RENodeId::Package(..)
+            | RENodeId::ResourceManager(..)
+            | RENodeId::Component(..)
+            | RENodeId::Validator(..)
+            | RENodeId::Identity(..) => {}
+            _ => {
+                return Err(RuntimeError::InterpreterError(
+                    InterpreterError::InvalidInvocation,
+                ))
+            }
+        }
+
+        self.receiver = resolved_receiver.receiver;
+        let actor = ResolvedActor::method(NativeFn::Metadata(MetadataFn::Lock), resolved_receiver);
+
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for MetadataLockInvocation {
+    type Output = ();
+
+    fn execute<Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError>,
+    {
+        let offset = SubstateOffset::Metadata(MetadataOffset::Metadata);
+        let handle = api.lock_substate(self.receiver, offset, LockFlags::MUTABLE)?;
+
+        let mut substate_ref_mut = api.get_ref_mut(handle)?;
+        let metadata = substate_ref_mut.metadata();
+        metadata.lock(self.key);
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
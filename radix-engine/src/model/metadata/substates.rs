@@ -1,13 +1,43 @@
+use crate::model::MetadataError;
 use crate::types::*;
 
 /// A transient resource container.
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct MetadataSubstate {
-    pub metadata: BTreeMap<String, String>,
+    pub metadata: BTreeMap<String, MetadataEntry>,
+    /// Keys which can no longer be set or removed.
+    pub locked_keys: BTreeSet<String>,
 }
 
 impl MetadataSubstate {
-    pub fn insert(&mut self, key: String, value: String) {
+    /// Creates the metadata of a new entity, with no keys locked.
+    pub fn new(metadata: BTreeMap<String, MetadataEntry>) -> Self {
+        Self {
+            metadata,
+            locked_keys: BTreeSet::new(),
+        }
+    }
+
+    pub fn is_locked(&self, key: &str) -> bool {
+        self.locked_keys.contains(key)
+    }
+
+    pub fn insert(&mut self, key: String, value: MetadataEntry) -> Result<(), MetadataError> {
+        if self.is_locked(&key) {
+            return Err(MetadataError::KeyLocked(key));
+        }
         self.metadata.insert(key, value);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, key: &str) -> Result<Option<MetadataEntry>, MetadataError> {
+        if self.is_locked(key) {
+            return Err(MetadataError::KeyLocked(key.to_string()));
+        }
+        Ok(self.metadata.remove(key))
+    }
+
+    pub fn lock(&mut self, key: String) {
+        self.locked_keys.insert(key);
     }
 }
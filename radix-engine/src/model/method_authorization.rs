@@ -1,5 +1,6 @@
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
+use radix_engine_interface::time::Instant;
 use radix_engine_interface::*;
 use sbor::rust::vec::Vec;
 
@@ -45,6 +46,7 @@ pub enum HardProofRule {
     AllOf(HardProofRuleResourceList),
     AnyOf(HardProofRuleResourceList),
     CountOf(HardCount, HardProofRuleResourceList),
+    WeightedThresholdOf(u64, Vec<(HardResourceOrNonFungible, u64)>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
@@ -52,6 +54,33 @@ pub enum HardAuthRule {
     ProofRule(HardProofRule),
     AnyOf(Vec<HardAuthRule>),
     AllOf(Vec<HardAuthRule>),
+    AfterTime(Instant),
+    BeforeTime(Instant),
+    AfterEpoch(u64),
+    BeforeEpoch(u64),
+}
+
+impl HardAuthRule {
+    fn depends_on_system_state(&self) -> bool {
+        match self {
+            HardAuthRule::ProofRule(..) => false,
+            HardAuthRule::AnyOf(rules) | HardAuthRule::AllOf(rules) => {
+                rules.iter().any(|rule| rule.depends_on_system_state())
+            }
+            HardAuthRule::AfterTime(..)
+            | HardAuthRule::BeforeTime(..)
+            | HardAuthRule::AfterEpoch(..)
+            | HardAuthRule::BeforeEpoch(..) => true,
+        }
+    }
+}
+
+/// The system state which time and epoch rules are checked against. Rules whose state is
+/// unavailable are not satisfied.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AuthSystemState {
+    pub current_epoch: Option<u64>,
+    pub current_time: Option<Instant>,
 }
 
 /// Authorization of a method call
@@ -62,3 +91,13 @@ pub enum MethodAuthorization {
     DenyAll,
     Unsupported,
 }
+
+impl MethodAuthorization {
+    /// Whether checking this authorization requires the current time or epoch
+    pub fn depends_on_system_state(&self) -> bool {
+        match self {
+            MethodAuthorization::Protected(rule) => rule.depends_on_system_state(),
+            _ => false,
+        }
+    }
+}
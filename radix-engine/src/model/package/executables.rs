@@ -84,9 +84,7 @@ impl Executor for PackagePublishInvocation {
         let package_royalty_accumulator = PackageRoyaltyAccumulatorSubstate {
             royalty: Own::Vault(royalty_vault_id),
         };
-        let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
-        };
+        let metadata_substate = MetadataSubstate::new(self.metadata);
         let access_rules = AccessRulesChainSubstate {
             access_rules_chain: vec![self.access_rules],
         };
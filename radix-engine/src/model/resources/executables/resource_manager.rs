@@ -13,10 +13,11 @@ use native_sdk::resource::SysBucket;
 use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::types::{
     GlobalAddress, NativeFn, NonFungibleStoreId, NonFungibleStoreOffset, RENodeId,
-    ResourceManagerFn, ResourceManagerOffset, SubstateOffset,
+    ResourceManagerFn, ResourceManagerOffset, SubstateOffset, VaultOffset,
 };
 use radix_engine_interface::api::{EngineApi, InvokableModel};
 use radix_engine_interface::data::types::Own;
+use radix_engine_interface::data::{match_schema_with_value, ScryptoValue};
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::AccessRule::{AllowAll, DenyAll};
 use radix_engine_interface::model::VaultMethodAuthKey::{Deposit, Recall, Withdraw};
@@ -35,6 +36,14 @@ pub enum ResourceManagerError {
     NonFungibleIdTypeDoesNotMatch(NonFungibleIdType, NonFungibleIdType),
     ResourceTypeDoesNotMatch,
     InvalidNonFungibleIdType,
+    NonFungibleDataSchemaMissing,
+    NonFungibleDataFieldNotFound(String),
+    InvalidNonFungibleDataFieldValue(String),
+    MaxSupplyExceeded,
+    MintLimitPerEpochExceeded,
+    MaxSupplyBelowTotalSupply,
+    MismatchingVaultResource,
+    InvalidSupplyLimit(Decimal),
 }
 
 impl ExecutableInvocation for ResourceManagerBucketBurnInvocation {
@@ -68,12 +77,18 @@ impl Executor for ResourceManagerBucketBurnInvocation {
 fn build_non_fungible_resource_manager_substate_with_initial_supply<Y>(
     resource_address: ResourceAddress,
     id_type: NonFungibleIdType,
+    mutable_data_schema: Option<Type>,
+    max_supply: Option<Decimal>,
+    mint_limit_per_epoch: Option<Decimal>,
     entries: BTreeMap<NonFungibleLocalId, (Vec<u8>, Vec<u8>)>,
     api: &mut Y,
 ) -> Result<(ResourceManagerSubstate, Bucket), RuntimeError>
 where
     Y: SystemApi,
 {
+    ResourceManagerSubstate::check_supply_limit(max_supply)?;
+    ResourceManagerSubstate::check_supply_limit(mint_limit_per_epoch)?;
+
     let nf_store_node_id = api.allocate_node_id(RENodeType::NonFungibleStore)?;
     api.create_node(
         nf_store_node_id,
@@ -84,10 +99,14 @@ where
     let mut resource_manager = ResourceManagerSubstate::new(
         ResourceType::NonFungible { id_type },
         Some(nf_store_id),
+        mutable_data_schema,
+        max_supply,
+        mint_limit_per_epoch,
         resource_address,
     );
 
     let bucket = {
+        resource_manager.check_max_supply(entries.len().into())?;
         for (non_fungible_local_id, data) in &entries {
             if non_fungible_local_id.id_type() != id_type {
                 return Err(RuntimeError::ApplicationError(
@@ -127,15 +146,23 @@ where
 fn build_fungible_resource_manager_substate_with_initial_supply<Y>(
     resource_address: ResourceAddress,
     divisibility: u8,
+    max_supply: Option<Decimal>,
+    mint_limit_per_epoch: Option<Decimal>,
     initial_supply: Decimal,
     api: &mut Y,
 ) -> Result<(ResourceManagerSubstate, Bucket), RuntimeError>
 where
     Y: SystemApi,
 {
+    ResourceManagerSubstate::check_supply_limit(max_supply)?;
+    ResourceManagerSubstate::check_supply_limit(mint_limit_per_epoch)?;
+
     let mut resource_manager = ResourceManagerSubstate::new(
         ResourceType::Fungible { divisibility },
         None,
+        None,
+        max_supply,
+        mint_limit_per_epoch,
         resource_address,
     );
 
@@ -147,6 +174,7 @@ where
                 ApplicationError::ResourceManagerError(ResourceManagerError::MaxMintAmountExceeded),
             ));
         }
+        resource_manager.check_max_supply(initial_supply)?;
         resource_manager.total_supply = initial_supply;
         let container = Resource::new_fungible(resource_address, divisibility, initial_supply);
         let node_id = api.allocate_node_id(RENodeType::Bucket)?;
@@ -158,8 +186,32 @@ where
     Ok((resource_manager, bucket))
 }
 
+/// Looks up the current epoch, but only if the resource has a per-epoch mint limit to check.
+fn current_epoch_if_mint_limited<Y>(
+    resman_handle: LockHandle,
+    api: &mut Y,
+) -> Result<Option<u64>, RuntimeError>
+where
+    Y: SystemApi + InvokableModel<RuntimeError>,
+{
+    let mint_limited = {
+        let substate_ref = api.get_ref(resman_handle)?;
+        substate_ref
+            .resource_manager()
+            .mint_limit_per_epoch
+            .is_some()
+    };
+
+    if mint_limited {
+        Ok(Some(Runtime::sys_current_epoch(api)?))
+    } else {
+        Ok(None)
+    }
+}
+
 fn build_substates(
     mut access_rules_map: BTreeMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
+    mutable_data_schema: Option<&Type>,
 ) -> (AccessRulesChainSubstate, AccessRulesChainSubstate) {
     let (mint_access_rule, mint_mutability) = access_rules_map
         .remove(&Mint)
@@ -174,10 +226,30 @@ fn build_substates(
     let (update_metadata_access_rule, update_metadata_mutability) = access_rules_map
         .remove(&UpdateMetadata)
         .unwrap_or((DenyAll, rule!(deny_all)));
+    let (freeze_access_rule, freeze_mutability) = access_rules_map
+        .remove(&Freeze)
+        .unwrap_or((DenyAll, rule!(deny_all)));
+    let (update_max_supply_access_rule, update_max_supply_mutability) = access_rules_map
+        .remove(&UpdateMaxSupply)
+        .unwrap_or((DenyAll, rule!(deny_all)));
+    let (update_mint_limit_per_epoch_access_rule, update_mint_limit_per_epoch_mutability) =
+        access_rules_map
+            .remove(&UpdateMintLimitPerEpoch)
+            .unwrap_or((DenyAll, rule!(deny_all)));
 
     let mut access_rules = AccessRules::new();
     access_rules.set_access_rule_and_mutability(
         AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Set)),
+        update_metadata_access_rule.clone(),
+        update_metadata_mutability.clone(),
+    );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Remove)),
+        update_metadata_access_rule.clone(),
+        update_metadata_mutability.clone(),
+    );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Lock)),
         update_metadata_access_rule,
         update_metadata_mutability,
     );
@@ -216,6 +288,20 @@ fn build_substates(
         burn_access_rule,
         burn_mutability,
     );
+    // Until a mutable field is given its own rule, it's guarded by the rule for updating the
+    // whole data, and whoever may change that rule may give the field its own
+    if let Some(Type::Struct {
+        fields: Fields::Named { named },
+        ..
+    }) = mutable_data_schema
+    {
+        for (field, _) in named {
+            access_rules.set_group_mutability(
+                non_fungible_data_field_group(field),
+                update_non_fungible_data_mutability.clone(),
+            );
+        }
+    }
     access_rules.set_access_rule_and_mutability(
         AccessRuleKey::Native(NativeFn::ResourceManager(
             ResourceManagerFn::UpdateNonFungibleData,
@@ -223,6 +309,40 @@ fn build_substates(
         update_non_fungible_data_access_rule,
         update_non_fungible_data_mutability,
     );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::ResourceManager(
+            ResourceManagerFn::UpdateNonFungibleDataField,
+        )),
+        AllowAll, // Access verification occurs within method
+        DenyAll,
+    );
+    access_rules.set_group_access_rule_and_mutability(
+        "freeze".to_string(),
+        freeze_access_rule,
+        freeze_mutability,
+    );
+    access_rules.set_group_and_mutability(
+        AccessRuleKey::Native(NativeFn::ResourceManager(ResourceManagerFn::FreezeVault)),
+        "freeze".to_string(),
+        DenyAll,
+    );
+    access_rules.set_group_and_mutability(
+        AccessRuleKey::Native(NativeFn::ResourceManager(ResourceManagerFn::UnfreezeVault)),
+        "freeze".to_string(),
+        DenyAll,
+    );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::ResourceManager(ResourceManagerFn::SetMaxSupply)),
+        update_max_supply_access_rule,
+        update_max_supply_mutability,
+    );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::ResourceManager(
+            ResourceManagerFn::SetMintLimitPerEpoch,
+        )),
+        update_mint_limit_per_epoch_access_rule,
+        update_mint_limit_per_epoch_mutability,
+    );
     access_rules.set_access_rule_and_mutability(
         AccessRuleKey::Native(NativeFn::ResourceManager(ResourceManagerFn::CreateBucket)),
         AllowAll,
@@ -379,6 +499,9 @@ impl Executor for ResourceManagerCreateNonFungibleInvocation {
     where
         Y: SystemApi,
     {
+        ResourceManagerSubstate::check_supply_limit(self.max_supply)?;
+        ResourceManagerSubstate::check_supply_limit(self.mint_limit_per_epoch)?;
+
         let global_node_id = if let Some(address) = self.resource_address {
             // If address isn't user frame allocated or pre_allocated then
             // using this node_id will fail on create_node below
@@ -399,12 +522,14 @@ impl Executor for ResourceManagerCreateNonFungibleInvocation {
                 id_type: self.id_type,
             },
             Some(nf_store_id),
+            self.mutable_data_schema.clone(),
+            self.max_supply,
+            self.mint_limit_per_epoch,
             resource_address,
         );
-        let (substate, vault_substate) = build_substates(self.access_rules);
-        let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
-        };
+        let (substate, vault_substate) =
+            build_substates(self.access_rules, self.mutable_data_schema.as_ref());
+        let metadata_substate = MetadataSubstate::new(self.metadata);
 
         let underlying_node_id = api.allocate_node_id(RENodeType::ResourceManager)?;
         api.create_node(
@@ -452,6 +577,9 @@ impl Executor for ResourceManagerCreateFungibleInvocation {
     where
         Y: SystemApi,
     {
+        ResourceManagerSubstate::check_supply_limit(self.max_supply)?;
+        ResourceManagerSubstate::check_supply_limit(self.mint_limit_per_epoch)?;
+
         let global_node_id = api.allocate_node_id(RENodeType::GlobalResourceManager)?;
         let resource_address: ResourceAddress = global_node_id.into();
 
@@ -460,12 +588,13 @@ impl Executor for ResourceManagerCreateFungibleInvocation {
                 divisibility: self.divisibility,
             },
             None,
+            None,
+            self.max_supply,
+            self.mint_limit_per_epoch,
             resource_address,
         );
-        let (substate, vault_substate) = build_substates(self.access_rules);
-        let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
-        };
+        let (substate, vault_substate) = build_substates(self.access_rules, None);
+        let metadata_substate = MetadataSubstate::new(self.metadata);
 
         let underlying_node_id = api.allocate_node_id(RENodeType::ResourceManager)?;
         api.create_node(
@@ -530,13 +659,15 @@ impl Executor for ResourceManagerCreateNonFungibleWithInitialSupplyInvocation {
             build_non_fungible_resource_manager_substate_with_initial_supply(
                 resource_address,
                 self.id_type,
+                self.mutable_data_schema.clone(),
+                self.max_supply,
+                self.mint_limit_per_epoch,
                 self.entries,
                 api,
             )?;
-        let (substate, vault_substate) = build_substates(self.access_rules);
-        let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
-        };
+        let (substate, vault_substate) =
+            build_substates(self.access_rules, self.mutable_data_schema.as_ref());
+        let metadata_substate = MetadataSubstate::new(self.metadata);
 
         let underlying_node_id = api.allocate_node_id(RENodeType::ResourceManager)?;
         api.create_node(
@@ -608,13 +739,15 @@ impl Executor for ResourceManagerCreateUuidNonFungibleWithInitialSupplyInvocatio
             build_non_fungible_resource_manager_substate_with_initial_supply(
                 resource_address,
                 NonFungibleIdType::UUID,
+                self.mutable_data_schema.clone(),
+                self.max_supply,
+                self.mint_limit_per_epoch,
                 entries,
                 api,
             )?;
-        let (substate, vault_substate) = build_substates(self.access_rules);
-        let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
-        };
+        let (substate, vault_substate) =
+            build_substates(self.access_rules, self.mutable_data_schema.as_ref());
+        let metadata_substate = MetadataSubstate::new(self.metadata);
 
         let underlying_node_id = api.allocate_node_id(RENodeType::ResourceManager)?;
         api.create_node(
@@ -685,13 +818,13 @@ impl Executor for ResourceManagerCreateFungibleWithInitialSupplyInvocation {
             build_fungible_resource_manager_substate_with_initial_supply(
                 resource_address,
                 self.divisibility,
+                self.max_supply,
+                self.mint_limit_per_epoch,
                 self.initial_supply,
                 api,
             )?;
-        let (substate, vault_substate) = build_substates(self.access_rules);
-        let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
-        };
+        let (substate, vault_substate) = build_substates(self.access_rules, None);
+        let metadata_substate = MetadataSubstate::new(self.metadata);
 
         let underlying_node_id = api.allocate_node_id(RENodeType::ResourceManager)?;
         api.create_node(
@@ -1127,6 +1260,8 @@ impl ExecutableInvocation for ResourceManagerMintNonFungibleInvocation {
             &mut call_frame_update,
             api,
         )?;
+        // Mint limits are tracked per epoch
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(EPOCH_MANAGER)));
         let actor = ResolvedActor::method(
             NativeFn::ResourceManager(ResourceManagerFn::MintNonFungible),
             resolved_receiver,
@@ -1145,10 +1280,11 @@ impl Executor for ResourceManagerMintNonFungibleExecutable {
         api: &mut Y,
     ) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
     where
-        Y: SystemApi,
+        Y: SystemApi + InvokableModel<RuntimeError>,
     {
         let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
         let resman_handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let current_epoch = current_epoch_if_mint_limited(resman_handle, api)?;
 
         let (resource, non_fungibles) = {
             let mut substate_mut = api.get_ref_mut(resman_handle)?;
@@ -1174,7 +1310,7 @@ impl Executor for ResourceManagerMintNonFungibleExecutable {
             }
 
             let amount: Decimal = self.1.len().into();
-            resource_manager.total_supply += amount;
+            resource_manager.record_mint(amount, current_epoch)?;
             // Allocate non-fungibles
             let mut ids = BTreeSet::new();
             let mut non_fungibles = BTreeMap::new();
@@ -1262,6 +1398,8 @@ impl ExecutableInvocation for ResourceManagerMintUuidNonFungibleInvocation {
             &mut call_frame_update,
             api,
         )?;
+        // Mint limits are tracked per epoch
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(EPOCH_MANAGER)));
         let actor = ResolvedActor::method(
             NativeFn::ResourceManager(ResourceManagerFn::MintUuidNonFungible),
             resolved_receiver,
@@ -1284,6 +1422,7 @@ impl Executor for ResourceManagerMintUuidNonFungibleExecutable {
     {
         let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
         let resman_handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let current_epoch = current_epoch_if_mint_limited(resman_handle, api)?;
 
         let bucket_id = {
             let mut substate_mut = api.get_ref_mut(resman_handle)?;
@@ -1310,7 +1449,7 @@ impl Executor for ResourceManagerMintUuidNonFungibleExecutable {
             }
 
             let amount: Decimal = self.1.len().into();
-            resource_manager.total_supply += amount;
+            resource_manager.record_mint(amount, current_epoch)?;
             // Allocate non-fungibles
             let mut ids = BTreeSet::new();
             for data in self.1 {
@@ -1369,6 +1508,8 @@ impl ExecutableInvocation for ResourceManagerMintFungibleInvocation {
             &mut call_frame_update,
             api,
         )?;
+        // Mint limits are tracked per epoch
+        call_frame_update.add_ref(RENodeId::Global(GlobalAddress::Component(EPOCH_MANAGER)));
         let actor = ResolvedActor::method(
             NativeFn::ResourceManager(ResourceManagerFn::MintFungible),
             resolved_receiver,
@@ -1387,16 +1528,20 @@ impl Executor for ResourceManagerMintFungibleExecutable {
         api: &mut Y,
     ) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
     where
-        Y: SystemApi,
+        Y: SystemApi + InvokableModel<RuntimeError>,
     {
         let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
         let resman_handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        let current_epoch = current_epoch_if_mint_limited(resman_handle, api)?;
 
         let resource = {
             let mut substate_mut = api.get_ref_mut(resman_handle)?;
             let resource_manager = substate_mut.resource_manager();
-            let result =
-                resource_manager.mint_fungible(self.1, resource_manager.resource_address)?;
+            let result = resource_manager.mint_fungible(
+                self.1,
+                current_epoch,
+                resource_manager.resource_address,
+            )?;
             result
         };
 
@@ -1570,6 +1715,349 @@ impl Executor for ResourceManagerUpdateNonFungibleDataExecutable {
     }
 }
 
+impl ExecutableInvocation for ResourceManagerUpdateNonFungibleDataFieldInvocation {
+    type Exec = ResourceManagerUpdateNonFungibleDataFieldExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = ResolvedActor::method(
+            NativeFn::ResourceManager(ResourceManagerFn::UpdateNonFungibleDataField),
+            resolved_receiver,
+        );
+        let executor = ResourceManagerUpdateNonFungibleDataFieldExecutable {
+            node_id: resolved_receiver.receiver,
+            id: self.id,
+            field: self.field,
+            value: self.value,
+        };
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerUpdateNonFungibleDataFieldExecutable {
+    node_id: RENodeId,
+    id: NonFungibleLocalId,
+    field: String,
+    value: Vec<u8>,
+}
+
+impl Executor for ResourceManagerUpdateNonFungibleDataFieldExecutable {
+    type Output = ();
+
+    fn execute<'a, Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+        let resman_handle = api.lock_substate(self.node_id, offset, LockFlags::read_only())?;
+        let substate_ref = api.get_ref(resman_handle)?;
+        let resource_manager = substate_ref.resource_manager();
+        let nf_store_id = resource_manager
+            .nf_store_id
+            .ok_or(InvokeError::SelfError(ResourceManagerError::NotNonFungible))?;
+        let resource_address = resource_manager.resource_address;
+        let (field_index, field_type) = resource_manager.mutable_data_field(&self.field)?;
+        api.drop_lock(resman_handle)?;
+
+        let field_value = scrypto_decode::<ScryptoValue>(&self.value)
+            .ok()
+            .filter(|value| match_schema_with_value(&field_type, value))
+            .ok_or(InvokeError::SelfError(
+                ResourceManagerError::InvalidNonFungibleDataFieldValue(self.field.clone()),
+            ))?;
+
+        // TODO: Figure out how to move this access check into more appropriate place
+        {
+            let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+            let handle = api.lock_substate(self.node_id, offset, LockFlags::read_only())?;
+            let access_rule = {
+                let substate_ref = api.get_ref(handle)?;
+                let access_rules = &substate_ref.access_rules_chain().access_rules_chain[0];
+                access_rules
+                    .get_all_grouped_auth()
+                    .get(&non_fungible_data_field_group(&self.field))
+                    .unwrap_or_else(|| {
+                        access_rules.get(&AccessRuleKey::Native(NativeFn::ResourceManager(
+                            ResourceManagerFn::UpdateNonFungibleData,
+                        )))
+                    })
+                    .clone()
+            };
+            api.drop_lock(handle)?;
+
+            let node_ids = api.get_visible_nodes()?;
+            let auth_zone_id = node_ids
+                .into_iter()
+                .find(|n| matches!(n, RENodeId::AuthZoneStack(..)))
+                .expect("AuthZone does not exist");
+            api.invoke(AuthZoneAssertAccessRuleInvocation {
+                receiver: auth_zone_id.into(),
+                access_rule,
+            })?;
+        }
+
+        let node_id = RENodeId::NonFungibleStore(nf_store_id);
+        let offset =
+            SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(self.id.clone()));
+        let non_fungible_handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+        let mut substate_mut = api.get_ref_mut(non_fungible_handle)?;
+        let non_fungible_mut = substate_mut.non_fungible();
+        if let Some(ref mut non_fungible) = non_fungible_mut.0 {
+            let mutable_data = match scrypto_decode::<ScryptoValue>(&non_fungible.mutable_data()) {
+                Ok(ScryptoValue::Tuple { mut fields }) if field_index < fields.len() => {
+                    fields[field_index] = field_value;
+                    scrypto_encode(&ScryptoValue::Tuple { fields })
+                        .expect("Failed to encode non-fungible data")
+                }
+                _ => {
+                    return Err(RuntimeError::ApplicationError(
+                        ApplicationError::ResourceManagerError(
+                            ResourceManagerError::InvalidNonFungibleDataFieldValue(self.field),
+                        ),
+                    ));
+                }
+            };
+            non_fungible.set_mutable_data(mutable_data);
+        } else {
+            let non_fungible_global_id = NonFungibleGlobalId::new(resource_address, self.id);
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ResourceManagerError(ResourceManagerError::NonFungibleNotFound(
+                    non_fungible_global_id,
+                )),
+            ));
+        }
+
+        api.drop_lock(non_fungible_handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+impl ExecutableInvocation for ResourceManagerFreezeVaultInvocation {
+    type Exec = ResourceManagerFreezeVaultExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        call_frame_update
+            .node_refs_to_copy
+            .insert(RENodeId::Vault(self.vault_id));
+        let actor = ResolvedActor::method(
+            NativeFn::ResourceManager(ResourceManagerFn::FreezeVault),
+            resolved_receiver,
+        );
+        let executor = ResourceManagerFreezeVaultExecutable {
+            node_id: resolved_receiver.receiver,
+            vault_id: self.vault_id,
+            frozen: true,
+        };
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl ExecutableInvocation for ResourceManagerUnfreezeVaultInvocation {
+    type Exec = ResourceManagerFreezeVaultExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        call_frame_update
+            .node_refs_to_copy
+            .insert(RENodeId::Vault(self.vault_id));
+        let actor = ResolvedActor::method(
+            NativeFn::ResourceManager(ResourceManagerFn::UnfreezeVault),
+            resolved_receiver,
+        );
+        let executor = ResourceManagerFreezeVaultExecutable {
+            node_id: resolved_receiver.receiver,
+            vault_id: self.vault_id,
+            frozen: false,
+        };
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerFreezeVaultExecutable {
+    node_id: RENodeId,
+    vault_id: VaultId,
+    frozen: bool,
+}
+
+impl Executor for ResourceManagerFreezeVaultExecutable {
+    type Output = ();
+
+    fn execute<'a, Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+        let resman_handle = api.lock_substate(self.node_id, offset, LockFlags::read_only())?;
+        let resource_address = {
+            let substate_ref = api.get_ref(resman_handle)?;
+            substate_ref.resource_manager().resource_address
+        };
+        api.drop_lock(resman_handle)?;
+
+        // The frozen flag lives on the vault, so that withdraws and deposits needn't look it up
+        let offset = SubstateOffset::Vault(VaultOffset::Vault);
+        let vault_handle =
+            api.lock_substate(RENodeId::Vault(self.vault_id), offset, LockFlags::MUTABLE)?;
+        {
+            let mut substate_mut = api.get_ref_mut(vault_handle)?;
+            let vault = substate_mut.vault();
+            if vault.resource_address() != resource_address {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::ResourceManagerError(
+                        ResourceManagerError::MismatchingVaultResource,
+                    ),
+                ));
+            }
+            vault.set_frozen(self.frozen);
+        }
+        api.drop_lock(vault_handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+impl ExecutableInvocation for ResourceManagerSetMaxSupplyInvocation {
+    type Exec = ResourceManagerSetMaxSupplyExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = ResolvedActor::method(
+            NativeFn::ResourceManager(ResourceManagerFn::SetMaxSupply),
+            resolved_receiver,
+        );
+        let executor =
+            ResourceManagerSetMaxSupplyExecutable(resolved_receiver.receiver, self.max_supply);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerSetMaxSupplyExecutable(RENodeId, Option<Decimal>);
+
+impl Executor for ResourceManagerSetMaxSupplyExecutable {
+    type Output = ();
+
+    fn execute<'a, Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+        let resman_handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        {
+            let mut substate_mut = api.get_ref_mut(resman_handle)?;
+            let resource_manager = substate_mut.resource_manager();
+            ResourceManagerSubstate::check_supply_limit(self.1)?;
+            if let Some(max_supply) = self.1 {
+                if max_supply < resource_manager.total_supply {
+                    return Err(RuntimeError::ApplicationError(
+                        ApplicationError::ResourceManagerError(
+                            ResourceManagerError::MaxSupplyBelowTotalSupply,
+                        ),
+                    ));
+                }
+            }
+            resource_manager.max_supply = self.1;
+        }
+        api.drop_lock(resman_handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+impl ExecutableInvocation for ResourceManagerSetMintLimitPerEpochInvocation {
+    type Exec = ResourceManagerSetMintLimitPerEpochExecutable;
+
+    fn resolve<D: ResolverApi>(
+        self,
+        api: &mut D,
+    ) -> Result<(ResolvedActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = ResolvedActor::method(
+            NativeFn::ResourceManager(ResourceManagerFn::SetMintLimitPerEpoch),
+            resolved_receiver,
+        );
+        let executor = ResourceManagerSetMintLimitPerEpochExecutable(
+            resolved_receiver.receiver,
+            self.mint_limit_per_epoch,
+        );
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerSetMintLimitPerEpochExecutable(RENodeId, Option<Decimal>);
+
+impl Executor for ResourceManagerSetMintLimitPerEpochExecutable {
+    type Output = ();
+
+    fn execute<'a, Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+        let resman_handle = api.lock_substate(self.0, offset, LockFlags::MUTABLE)?;
+        {
+            let mut substate_mut = api.get_ref_mut(resman_handle)?;
+            let resource_manager = substate_mut.resource_manager();
+            ResourceManagerSubstate::check_supply_limit(self.1)?;
+            // Anything already minted this epoch still counts against the new limit
+            resource_manager.mint_limit_per_epoch = self.1;
+        }
+        api.drop_lock(resman_handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
 impl ExecutableInvocation for ResourceManagerNonFungibleExistsInvocation {
     type Exec = ResourceManagerNonFungibleExistsExecutable;
 
@@ -3,7 +3,9 @@ use crate::engine::{
     ResolvedActor, ResolvedReceiver, ResolverApi, RuntimeError, SystemApi,
 };
 use crate::fee::FeeReserveError;
-use crate::model::{BucketSubstate, ProofError, ResourceContainerId, ResourceOperationError};
+use crate::model::{
+    BucketSubstate, ProofError, Resource, ResourceContainerId, ResourceOperationError,
+};
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::types::{
@@ -22,10 +24,51 @@ pub enum VaultError {
     LockFeeNotRadixToken,
     LockFeeInsufficientBalance,
     LockFeeRepayFailure(FeeReserveError),
+    VaultFrozen(VaultId),
+}
+
+/// Fails if the resource manager of the given vault has frozen it.
+fn check_not_frozen<Y>(
+    vault_id: VaultId,
+    vault_handle: LockHandle,
+    api: &mut Y,
+) -> Result<(), RuntimeError>
+where
+    Y: SystemApi,
+{
+    let frozen = {
+        let substate_ref = api.get_ref(vault_handle)?;
+        substate_ref.vault().is_frozen()
+    };
+
+    if frozen {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::VaultError(VaultError::VaultFrozen(vault_id)),
+        ));
+    }
+
+    Ok(())
+}
+
+fn create_bucket<Y>(
+    container: Resource,
+    api: &mut Y,
+) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+where
+    Y: SystemApi,
+{
+    let node_id = api.allocate_node_id(RENodeType::Bucket)?;
+    api.create_node(node_id, RENodeInit::Bucket(BucketSubstate::new(container)))?;
+    let bucket_id = node_id.into();
+
+    Ok((
+        Bucket(bucket_id),
+        CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
+    ))
 }
 
 impl ExecutableInvocation for VaultRecallInvocation {
-    type Exec = VaultTakeInvocation;
+    type Exec = Self;
 
     fn resolve<D: ResolverApi>(
         self,
@@ -37,11 +80,32 @@ impl ExecutableInvocation for VaultRecallInvocation {
             NativeFn::Vault(VaultFn::Recall),
             ResolvedReceiver::new(receiver),
         );
-        let executor = VaultTakeInvocation {
-            receiver: self.receiver,
-            amount: self.amount,
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for VaultRecallInvocation {
+    type Output = Bucket;
+
+    fn execute<'a, Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        // Recalling is allowed even if the vault is frozen
+        let offset = SubstateOffset::Vault(VaultOffset::Vault);
+        let vault_handle =
+            api.lock_substate(RENodeId::Vault(self.receiver), offset, LockFlags::MUTABLE)?;
+
+        let container = {
+            let mut substate_mut = api.get_ref_mut(vault_handle)?;
+            let vault = substate_mut.vault();
+            vault.take(self.amount)?
         };
-        Ok((actor, call_frame_update, executor))
+
+        create_bucket(container, api)
     }
 }
 
@@ -75,6 +139,7 @@ impl Executor for VaultTakeInvocation {
         let offset = SubstateOffset::Vault(VaultOffset::Vault);
         let vault_handle =
             api.lock_substate(RENodeId::Vault(self.receiver), offset, LockFlags::MUTABLE)?;
+        check_not_frozen(self.receiver, vault_handle, api)?;
 
         let container = {
             let mut substate_mut = api.get_ref_mut(vault_handle)?;
@@ -82,14 +147,7 @@ impl Executor for VaultTakeInvocation {
             vault.take(self.amount)?
         };
 
-        let node_id = api.allocate_node_id(RENodeType::Bucket)?;
-        api.create_node(node_id, RENodeInit::Bucket(BucketSubstate::new(container)))?;
-        let bucket_id = node_id.into();
-
-        Ok((
-            Bucket(bucket_id),
-            CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
-        ))
+        create_bucket(container, api)
     }
 }
 
@@ -126,6 +184,7 @@ impl Executor for VaultPutInvocation {
         let node_id = RENodeId::Vault(self.receiver);
         let offset = SubstateOffset::Vault(VaultOffset::Vault);
         let vault_handle = system_api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+        check_not_frozen(self.receiver, vault_handle, system_api)?;
 
         let bucket = system_api
             .drop_node(RENodeId::Bucket(self.bucket.0))?
@@ -177,6 +236,7 @@ impl Executor for VaultLockFeeInvocation {
             offset,
             LockFlags::MUTABLE | LockFlags::UNMODIFIED_BASE | LockFlags::FORCE_WRITE,
         )?;
+        check_not_frozen(self.receiver, vault_handle, system_api)?;
 
         let fee = {
             let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
@@ -215,7 +275,7 @@ impl Executor for VaultLockFeeInvocation {
 }
 
 impl ExecutableInvocation for VaultRecallNonFungiblesInvocation {
-    type Exec = VaultTakeNonFungiblesInvocation;
+    type Exec = Self;
 
     fn resolve<D: ResolverApi>(
         self,
@@ -227,11 +287,32 @@ impl ExecutableInvocation for VaultRecallNonFungiblesInvocation {
             NativeFn::Vault(VaultFn::RecallNonFungibles),
             ResolvedReceiver::new(receiver),
         );
-        let executor = VaultTakeNonFungiblesInvocation {
-            receiver: self.receiver,
-            non_fungible_local_ids: self.non_fungible_local_ids,
+        Ok((actor, call_frame_update, self))
+    }
+}
+
+impl Executor for VaultRecallNonFungiblesInvocation {
+    type Output = Bucket;
+
+    fn execute<'a, Y, W: WasmEngine>(
+        self,
+        api: &mut Y,
+    ) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        // Recalling is allowed even if the vault is frozen
+        let node_id = RENodeId::Vault(self.receiver);
+        let offset = SubstateOffset::Vault(VaultOffset::Vault);
+        let vault_handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+
+        let container = {
+            let mut substate_mut = api.get_ref_mut(vault_handle)?;
+            let vault = substate_mut.vault();
+            vault.take_non_fungibles(&self.non_fungible_local_ids)?
         };
-        Ok((actor, call_frame_update, executor))
+
+        create_bucket(container, api)
     }
 }
 
@@ -265,6 +346,7 @@ impl Executor for VaultTakeNonFungiblesInvocation {
         let node_id = RENodeId::Vault(self.receiver);
         let offset = SubstateOffset::Vault(VaultOffset::Vault);
         let vault_handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+        check_not_frozen(self.receiver, vault_handle, api)?;
 
         let container = {
             let mut substate_mut = api.get_ref_mut(vault_handle)?;
@@ -272,14 +354,7 @@ impl Executor for VaultTakeNonFungiblesInvocation {
             vault.take_non_fungibles(&self.non_fungible_local_ids)?
         };
 
-        let node_id = api.allocate_node_id(RENodeType::Bucket)?;
-        api.create_node(node_id, RENodeInit::Bucket(BucketSubstate::new(container)))?;
-        let bucket_id = node_id.into();
-
-        Ok((
-            Bucket(bucket_id),
-            CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
-        ))
+        create_bucket(container, api)
     }
 }
 
@@ -10,22 +10,64 @@ pub struct ResourceManagerSubstate {
     pub resource_type: ResourceType,
     pub total_supply: Decimal,
     pub nf_store_id: Option<NonFungibleStoreId>,
+    /// The schema of the mutable part of the non-fungible data, if known.
+    pub mutable_data_schema: Option<Type>,
+    /// The maximum total supply, if capped.
+    pub max_supply: Option<Decimal>,
+    /// The maximum amount which can be minted in a single epoch, if limited.
+    pub mint_limit_per_epoch: Option<Decimal>,
+    /// The epoch of the last limited mint, and how much was minted in that epoch.
+    pub minted_in_epoch: (u64, Decimal),
 }
 
 impl ResourceManagerSubstate {
     pub fn new(
         resource_type: ResourceType,
         nf_store_id: Option<NonFungibleStoreId>,
+        mutable_data_schema: Option<Type>,
+        max_supply: Option<Decimal>,
+        mint_limit_per_epoch: Option<Decimal>,
         resource_address: ResourceAddress,
     ) -> ResourceManagerSubstate {
         Self {
             resource_type,
             total_supply: 0.into(),
             nf_store_id,
+            mutable_data_schema,
+            max_supply,
+            mint_limit_per_epoch,
+            minted_in_epoch: (0, Decimal::zero()),
             resource_address,
         }
     }
 
+    /// Looks up a mutable field of the non-fungible data, returning its index and type.
+    pub fn mutable_data_field(
+        &self,
+        field: &str,
+    ) -> Result<(usize, Type), InvokeError<ResourceManagerError>> {
+        let schema = self
+            .mutable_data_schema
+            .as_ref()
+            .ok_or(InvokeError::SelfError(
+                ResourceManagerError::NonFungibleDataSchemaMissing,
+            ))?;
+
+        if let Type::Struct {
+            fields: Fields::Named { named },
+            ..
+        } = schema
+        {
+            if let Some(index) = named.iter().position(|(name, _)| name == field) {
+                return Ok((index, named[index].1.clone()));
+            }
+        }
+
+        Err(InvokeError::SelfError(
+            ResourceManagerError::NonFungibleDataFieldNotFound(field.to_string()),
+        ))
+    }
+
     pub fn check_fungible_amount(
         &self,
         amount: Decimal,
@@ -45,6 +87,62 @@ impl ResourceManagerSubstate {
         }
     }
 
+    /// Checks that a max supply or per-epoch mint limit, if set, is positive.
+    pub fn check_supply_limit(
+        limit: Option<Decimal>,
+    ) -> Result<(), InvokeError<ResourceManagerError>> {
+        match limit {
+            Some(limit) if limit <= Decimal::zero() => Err(InvokeError::SelfError(
+                ResourceManagerError::InvalidSupplyLimit(limit),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn check_max_supply(
+        &self,
+        amount: Decimal,
+    ) -> Result<(), InvokeError<ResourceManagerError>> {
+        if let Some(max_supply) = self.max_supply {
+            if self.total_supply + amount > max_supply {
+                return Err(InvokeError::SelfError(
+                    ResourceManagerError::MaxSupplyExceeded,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks a mint of `amount` against the supply caps, and adds it to the total supply.
+    ///
+    /// The current epoch is only needed if the resource has a per-epoch mint limit.
+    pub fn record_mint(
+        &mut self,
+        amount: Decimal,
+        current_epoch: Option<u64>,
+    ) -> Result<(), InvokeError<ResourceManagerError>> {
+        self.check_max_supply(amount)?;
+
+        if let (Some(limit), Some(current_epoch)) = (self.mint_limit_per_epoch, current_epoch) {
+            let (epoch, minted) = self.minted_in_epoch;
+            let minted = if epoch == current_epoch {
+                minted
+            } else {
+                Decimal::zero()
+            };
+            if minted + amount > limit {
+                return Err(InvokeError::SelfError(
+                    ResourceManagerError::MintLimitPerEpochExceeded,
+                ));
+            }
+            self.minted_in_epoch = (current_epoch, minted + amount);
+        }
+
+        self.total_supply += amount;
+        Ok(())
+    }
+
     pub fn burn(&mut self, amount: Decimal) {
         self.total_supply -= amount;
     }
@@ -52,6 +150,7 @@ impl ResourceManagerSubstate {
     pub fn mint_fungible(
         &mut self,
         amount: Decimal,
+        current_epoch: Option<u64>,
         self_address: ResourceAddress,
     ) -> Result<Resource, InvokeError<ResourceManagerError>> {
         if let ResourceType::Fungible { divisibility } = self.resource_type {
@@ -65,7 +164,7 @@ impl ResourceManagerSubstate {
                 ));
             }
 
-            self.total_supply += amount;
+            self.record_mint(amount, current_epoch)?;
 
             Ok(Resource::new_fungible(self_address, divisibility, amount))
         } else {
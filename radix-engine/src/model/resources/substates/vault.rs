@@ -7,17 +7,22 @@ use crate::types::*;
 use sbor::rust::ops::Deref;
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
-pub struct VaultSubstate(pub Resource);
+pub struct VaultSubstate {
+    pub resource: Resource,
+    /// Whether withdraws and deposits are blocked until the resource manager unfreezes the vault.
+    pub frozen: bool,
+}
 
 impl VaultSubstate {
     pub fn resource_address(&self) -> ResourceAddress {
-        self.0.resource_address()
+        self.resource.resource_address()
     }
 }
 
 #[derive(Debug)]
 pub struct VaultRuntimeSubstate {
     resource: Rc<RefCell<LockableResource>>,
+    frozen: bool,
 }
 
 impl VaultRuntimeSubstate {
@@ -52,23 +57,43 @@ impl VaultRuntimeSubstate {
             },
         };
 
-        VaultSubstate(resource)
+        VaultSubstate {
+            resource,
+            frozen: self.frozen,
+        }
     }
 
     pub fn to_persisted(self) -> Result<VaultSubstate, ResourceOperationError> {
+        let frozen = self.frozen;
         Rc::try_unwrap(self.resource)
             .map_err(|_| ResourceOperationError::ResourceLocked)
             .map(|c| c.into_inner())
             .map(Into::into)
-            .map(|r| VaultSubstate(r))
+            .map(|resource| VaultSubstate { resource, frozen })
     }
 
     pub fn new(resource: Resource) -> Self {
         Self {
             resource: Rc::new(RefCell::new(resource.into())),
+            frozen: false,
+        }
+    }
+
+    pub fn from_persisted(substate: VaultSubstate) -> Self {
+        Self {
+            resource: Rc::new(RefCell::new(substate.resource.into())),
+            frozen: substate.frozen,
         }
     }
 
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    pub fn set_frozen(&mut self, frozen: bool) {
+        self.frozen = frozen;
+    }
+
     pub fn put(&mut self, other: BucketSubstate) -> Result<(), ResourceOperationError> {
         self.borrow_resource_mut().put(other.resource()?)
     }
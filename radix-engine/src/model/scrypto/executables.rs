@@ -1,4 +1,5 @@
 use radix_engine_interface::data::*;
+use sbor::validate_value_against_schema;
 
 use crate::engine::*;
 use crate::model::TransactionProcessorError;
@@ -145,21 +146,23 @@ impl ExecutableInvocation for ScryptoInvocation {
                 ));
             }
 
-            if !match_schema_with_value(&fn_abi.input, args.as_value()) {
-                return Err(RuntimeError::InterpreterError(
-                    InterpreterError::InvalidScryptoInvocation(
+            let fn_abi = fn_abi.clone();
+            api.drop_lock(handle)?;
+
+            let schemas = api.schema_cache().fn_schemas(self.package_address, &fn_abi);
+            let (input_type, input_schema) = &schemas.input;
+            validate_value_against_schema(args.as_value(), input_schema, *input_type).map_err(
+                |e| {
+                    RuntimeError::InterpreterError(InterpreterError::InvalidScryptoInvocation(
                         self.package_address,
                         self.blueprint_name.clone(),
                         self.fn_name.clone(),
-                        ScryptoFnResolvingError::InvalidInput,
-                    ),
-                ));
-            }
-
-            let export_name = fn_abi.export_name.clone();
-            api.drop_lock(handle)?;
+                        ScryptoFnResolvingError::InvalidInput(e),
+                    ))
+                },
+            )?;
 
-            export_name
+            fn_abi.export_name
         };
 
         let executor = ScryptoExecutor {
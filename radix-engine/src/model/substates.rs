@@ -12,6 +12,7 @@ pub enum PersistedSubstate {
     Global(GlobalAddressSubstate),
     EpochManager(EpochManagerSubstate),
     ValidatorSet(ValidatorSetSubstate),
+    ValidatorLiveness(ValidatorLivenessSubstate),
     Validator(ValidatorSubstate),
     CurrentTimeRoundedToMinutes(CurrentTimeRoundedToMinutesSubstate),
     ResourceManager(ResourceManagerSubstate),
@@ -96,6 +97,9 @@ impl PersistedSubstate {
             PersistedSubstate::Global(value) => RuntimeSubstate::Global(value),
             PersistedSubstate::EpochManager(value) => RuntimeSubstate::EpochManager(value),
             PersistedSubstate::ValidatorSet(value) => RuntimeSubstate::ValidatorSet(value),
+            PersistedSubstate::ValidatorLiveness(value) => {
+                RuntimeSubstate::ValidatorLiveness(value)
+            }
             PersistedSubstate::Validator(value) => RuntimeSubstate::Validator(value),
             PersistedSubstate::CurrentTimeRoundedToMinutes(value) => {
                 RuntimeSubstate::CurrentTimeRoundedToMinutes(value)
@@ -119,7 +123,7 @@ impl PersistedSubstate {
                 RuntimeSubstate::PackageRoyaltyAccumulator(value)
             }
             PersistedSubstate::Vault(value) => {
-                RuntimeSubstate::Vault(VaultRuntimeSubstate::new(value.0))
+                RuntimeSubstate::Vault(VaultRuntimeSubstate::from_persisted(value))
             }
             PersistedSubstate::NonFungible(value) => RuntimeSubstate::NonFungible(value),
             PersistedSubstate::KeyValueStoreEntry(value) => {
@@ -139,6 +143,7 @@ pub enum RuntimeSubstate {
     Global(GlobalAddressSubstate),
     EpochManager(EpochManagerSubstate),
     ValidatorSet(ValidatorSetSubstate),
+    ValidatorLiveness(ValidatorLivenessSubstate),
     Validator(ValidatorSubstate),
     CurrentTimeRoundedToMinutes(CurrentTimeRoundedToMinutesSubstate),
     ResourceManager(ResourceManagerSubstate),
@@ -170,6 +175,9 @@ impl RuntimeSubstate {
             RuntimeSubstate::Global(value) => PersistedSubstate::Global(value.clone()),
             RuntimeSubstate::EpochManager(value) => PersistedSubstate::EpochManager(value.clone()),
             RuntimeSubstate::ValidatorSet(value) => PersistedSubstate::ValidatorSet(value.clone()),
+            RuntimeSubstate::ValidatorLiveness(value) => {
+                PersistedSubstate::ValidatorLiveness(value.clone())
+            }
             RuntimeSubstate::Validator(value) => PersistedSubstate::Validator(value.clone()),
             RuntimeSubstate::AccessRulesChain(value) => {
                 PersistedSubstate::AccessRulesChain(value.clone())
@@ -228,6 +236,9 @@ impl RuntimeSubstate {
             RuntimeSubstate::Global(value) => PersistedSubstate::Global(value),
             RuntimeSubstate::EpochManager(value) => PersistedSubstate::EpochManager(value),
             RuntimeSubstate::ValidatorSet(value) => PersistedSubstate::ValidatorSet(value),
+            RuntimeSubstate::ValidatorLiveness(value) => {
+                PersistedSubstate::ValidatorLiveness(value)
+            }
             RuntimeSubstate::Validator(value) => PersistedSubstate::Validator(value),
             RuntimeSubstate::AccessRulesChain(value) => PersistedSubstate::AccessRulesChain(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
@@ -308,6 +319,7 @@ impl RuntimeSubstate {
             RuntimeSubstate::Global(value) => SubstateRefMut::Global(value),
             RuntimeSubstate::EpochManager(value) => SubstateRefMut::EpochManager(value),
             RuntimeSubstate::ValidatorSet(value) => SubstateRefMut::ValidatorSet(value),
+            RuntimeSubstate::ValidatorLiveness(value) => SubstateRefMut::ValidatorLiveness(value),
             RuntimeSubstate::Validator(value) => SubstateRefMut::Validator(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 SubstateRefMut::CurrentTimeRoundedToMinutes(value)
@@ -349,6 +361,7 @@ impl RuntimeSubstate {
             RuntimeSubstate::Global(value) => SubstateRef::Global(value),
             RuntimeSubstate::EpochManager(value) => SubstateRef::EpochManager(value),
             RuntimeSubstate::ValidatorSet(value) => SubstateRef::ValidatorSet(value),
+            RuntimeSubstate::ValidatorLiveness(value) => SubstateRef::ValidatorLiveness(value),
             RuntimeSubstate::Validator(value) => SubstateRef::Validator(value),
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 SubstateRef::CurrentTimeRoundedToMinutes(value)
@@ -472,6 +485,14 @@ impl RuntimeSubstate {
             panic!("Not a validator set");
         }
     }
+
+    pub fn validator_liveness(&self) -> &ValidatorLivenessSubstate {
+        if let RuntimeSubstate::ValidatorLiveness(validator_liveness) = self {
+            validator_liveness
+        } else {
+            panic!("Not validator liveness");
+        }
+    }
 }
 
 impl Into<RuntimeSubstate> for AccessRulesChainSubstate {
@@ -498,6 +519,12 @@ impl Into<RuntimeSubstate> for ValidatorSetSubstate {
     }
 }
 
+impl Into<RuntimeSubstate> for ValidatorLivenessSubstate {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::ValidatorLiveness(self)
+    }
+}
+
 impl Into<RuntimeSubstate> for ValidatorSubstate {
     fn into(self) -> RuntimeSubstate {
         RuntimeSubstate::Validator(self)
@@ -794,6 +821,16 @@ impl Into<ValidatorSetSubstate> for RuntimeSubstate {
     }
 }
 
+impl Into<ValidatorLivenessSubstate> for RuntimeSubstate {
+    fn into(self) -> ValidatorLivenessSubstate {
+        if let RuntimeSubstate::ValidatorLiveness(substate) = self {
+            substate
+        } else {
+            panic!("Not validator liveness");
+        }
+    }
+}
+
 pub enum SubstateRef<'a> {
     AuthZoneStack(&'a AuthZoneStackSubstate),
     Worktop(&'a WorktopSubstate),
@@ -814,6 +851,7 @@ pub enum SubstateRef<'a> {
     ResourceManager(&'a ResourceManagerSubstate),
     EpochManager(&'a EpochManagerSubstate),
     ValidatorSet(&'a ValidatorSetSubstate),
+    ValidatorLiveness(&'a ValidatorLivenessSubstate),
     Validator(&'a ValidatorSubstate),
     CurrentTimeRoundedToMinutes(&'a CurrentTimeRoundedToMinutesSubstate),
     AccessRulesChain(&'a AccessRulesChainSubstate),
@@ -1083,12 +1121,21 @@ impl<'a> SubstateRef<'a> {
                 let mut references = HashSet::new();
                 let mut owned_nodes = HashSet::new();
                 references.insert(GlobalAddress::Component(substate.manager));
+                references.insert(GlobalAddress::Component(substate.address));
                 references.insert(GlobalAddress::Resource(substate.unstake_nft));
                 references.insert(GlobalAddress::Resource(substate.liquidity_token));
                 owned_nodes.insert(RENodeId::Vault(substate.stake_xrd_vault_id));
                 owned_nodes.insert(RENodeId::Vault(substate.pending_xrd_withdraw_vault_id));
+                owned_nodes.insert(RENodeId::Vault(substate.owner_fee_xrd_vault_id));
                 (references, owned_nodes)
             }
+            SubstateRef::ValidatorSet(substate) => {
+                let mut references = HashSet::new();
+                for validator_address in substate.validator_set.keys() {
+                    references.insert(GlobalAddress::Component(*validator_address));
+                }
+                (references, HashSet::new())
+            }
             SubstateRef::AccessController(substate) => {
                 let mut owned_nodes = HashSet::new();
                 owned_nodes.insert(RENodeId::Vault(substate.controlled_asset));
@@ -1164,6 +1211,7 @@ pub enum SubstateRefMut<'a> {
     ResourceManager(&'a mut ResourceManagerSubstate),
     EpochManager(&'a mut EpochManagerSubstate),
     ValidatorSet(&'a mut ValidatorSetSubstate),
+    ValidatorLiveness(&'a mut ValidatorLivenessSubstate),
     Validator(&'a mut ValidatorSubstate),
     CurrentTimeRoundedToMinutes(&'a mut CurrentTimeRoundedToMinutesSubstate),
     AccessRulesChain(&'a mut AccessRulesChainSubstate),
@@ -1307,6 +1355,13 @@ impl<'a> SubstateRefMut<'a> {
         }
     }
 
+    pub fn validator_liveness(&mut self) -> &mut ValidatorLivenessSubstate {
+        match self {
+            SubstateRefMut::ValidatorLiveness(value) => *value,
+            _ => panic!("Not validator liveness"),
+        }
+    }
+
     pub fn current_time_rounded_to_minutes(&mut self) -> &mut CurrentTimeRoundedToMinutesSubstate {
         match self {
             SubstateRefMut::CurrentTimeRoundedToMinutes(value) => *value,
@@ -118,6 +118,8 @@ fn instruction_get_update(instruction: &Instruction, update: &mut CallFrameUpdat
                 }
             }
             BasicInstruction::SetMetadata { entity_address, .. }
+            | BasicInstruction::RemoveMetadata { entity_address, .. }
+            | BasicInstruction::LockMetadata { entity_address, .. }
             | BasicInstruction::SetMethodAccessRule { entity_address, .. } => {
                 update.add_ref(RENodeId::Global(*entity_address));
             }
@@ -184,6 +186,17 @@ fn instruction_get_update(instruction: &Instruction, update: &mut CallFrameUpdat
             } => {
                 update.add_ref(RENodeId::Global(GlobalAddress::Resource(*resource_address)));
             }
+            BasicInstruction::FreezeVault {
+                resource_address,
+                vault_id,
+            }
+            | BasicInstruction::UnfreezeVault {
+                resource_address,
+                vault_id,
+            } => {
+                update.add_ref(RENodeId::Global(GlobalAddress::Resource(*resource_address)));
+                update.add_ref(RENodeId::Vault(*vault_id));
+            }
             BasicInstruction::ReturnToWorktop { .. }
             | BasicInstruction::PopFromAuthZone { .. }
             | BasicInstruction::PushToAuthZone { .. }
@@ -221,6 +234,14 @@ fn slice_to_global_references(slice: &[u8]) -> Vec<RENodeId> {
         .collect()
 }
 
+/// Manifests create entities with string metadata, which becomes string metadata entries.
+fn metadata_entries(metadata: &BTreeMap<String, String>) -> BTreeMap<String, MetadataEntry> {
+    metadata
+        .iter()
+        .map(|(key, value)| (key.clone(), MetadataEntry::from(value.clone())))
+        .collect()
+}
+
 impl<'a> ExecutableInvocation for TransactionProcessorRunInvocation<'a> {
     type Exec = Self;
 
@@ -487,7 +508,7 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
                         code: code.clone().clone(),
                         abi: abi.clone().clone(),
                         royalty_config: royalty_config.clone(),
-                        metadata: metadata.clone(),
+                        metadata: metadata_entries(metadata),
                         access_rules: access_rules.clone(),
                     })?;
 
@@ -536,8 +557,10 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
                             api.invoke(ResourceManagerCreateFungibleWithInitialSupplyInvocation {
                                 resource_address: None,
                                 divisibility: *divisibility,
-                                metadata: metadata.clone(),
+                                metadata: metadata_entries(metadata),
                                 access_rules: access_rules.clone(),
+                                max_supply: None,
+                                mint_limit_per_epoch: None,
                                 initial_supply: *amount,
                             })?;
 
@@ -547,8 +570,10 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
                     } else {
                         let rtn = api.invoke(ResourceManagerCreateFungibleInvocation {
                             divisibility: *divisibility,
-                            metadata: metadata.clone(),
+                            metadata: metadata_entries(metadata),
                             access_rules: access_rules.clone(),
+                            max_supply: None,
+                            mint_limit_per_epoch: None,
                         })?;
 
                         InstructionOutput::Native(Box::new(rtn))
@@ -565,8 +590,10 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
                             api.invoke(ResourceManagerCreateFungibleWithInitialSupplyInvocation {
                                 resource_address: None,
                                 divisibility: *divisibility,
-                                metadata: metadata.clone(),
+                                metadata: metadata_entries(metadata),
                                 access_rules: resource_access_rules_from_owner_badge(owner_badge),
+                                max_supply: None,
+                                mint_limit_per_epoch: None,
                                 initial_supply: *amount,
                             })?;
 
@@ -576,8 +603,10 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
                     } else {
                         let rtn = api.invoke(ResourceManagerCreateFungibleInvocation {
                             divisibility: *divisibility,
-                            metadata: metadata.clone(),
+                            metadata: metadata_entries(metadata),
                             access_rules: resource_access_rules_from_owner_badge(owner_badge),
+                            max_supply: None,
+                            mint_limit_per_epoch: None,
                         })?;
 
                         InstructionOutput::Native(Box::new(rtn))
@@ -593,8 +622,11 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
                         let rtn = api.invoke(
                             ResourceManagerCreateNonFungibleWithInitialSupplyInvocation {
                                 id_type: *id_type,
-                                metadata: metadata.clone(),
+                                metadata: metadata_entries(metadata),
                                 access_rules: access_rules.clone(),
+                                max_supply: None,
+                                mint_limit_per_epoch: None,
+                                mutable_data_schema: None,
                                 entries: ids.clone(),
                             },
                         )?;
@@ -606,8 +638,11 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
                         let rtn = api.invoke(ResourceManagerCreateNonFungibleInvocation {
                             resource_address: None,
                             id_type: *id_type,
-                            metadata: metadata.clone(),
+                            metadata: metadata_entries(metadata),
                             access_rules: access_rules.clone(),
+                            max_supply: None,
+                            mint_limit_per_epoch: None,
+                            mutable_data_schema: None,
                         })?;
 
                         InstructionOutput::Native(Box::new(rtn))
@@ -623,8 +658,11 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
                         let rtn = api.invoke(
                             ResourceManagerCreateNonFungibleWithInitialSupplyInvocation {
                                 id_type: *id_type,
-                                metadata: metadata.clone(),
+                                metadata: metadata_entries(metadata),
                                 access_rules: resource_access_rules_from_owner_badge(owner_badge),
+                                max_supply: None,
+                                mint_limit_per_epoch: None,
+                                mutable_data_schema: None,
                                 entries: ids.clone(),
                             },
                         )?;
@@ -636,8 +674,11 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
                         let rtn = api.invoke(ResourceManagerCreateNonFungibleInvocation {
                             resource_address: None,
                             id_type: *id_type,
-                            metadata: metadata.clone(),
+                            metadata: metadata_entries(metadata),
                             access_rules: resource_access_rules_from_owner_badge(owner_badge),
+                            max_supply: None,
+                            mint_limit_per_epoch: None,
+                            mutable_data_schema: None,
                         })?;
 
                         InstructionOutput::Native(Box::new(rtn))
@@ -695,6 +736,28 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
 
                     InstructionOutput::Native(Box::new(rtn))
                 }
+                Instruction::Basic(BasicInstruction::FreezeVault {
+                    resource_address,
+                    vault_id,
+                }) => {
+                    let rtn = api.invoke(ResourceManagerFreezeVaultInvocation {
+                        receiver: resource_address.clone(),
+                        vault_id: vault_id.clone(),
+                    })?;
+
+                    InstructionOutput::Native(Box::new(rtn))
+                }
+                Instruction::Basic(BasicInstruction::UnfreezeVault {
+                    resource_address,
+                    vault_id,
+                }) => {
+                    let rtn = api.invoke(ResourceManagerUnfreezeVaultInvocation {
+                        receiver: resource_address.clone(),
+                        vault_id: vault_id.clone(),
+                    })?;
+
+                    InstructionOutput::Native(Box::new(rtn))
+                }
                 Instruction::Basic(BasicInstruction::SetMetadata {
                     entity_address,
                     key,
@@ -708,6 +771,28 @@ impl<'a> Executor for TransactionProcessorRunInvocation<'a> {
 
                     InstructionOutput::Native(Box::new(rtn))
                 }
+                Instruction::Basic(BasicInstruction::RemoveMetadata {
+                    entity_address,
+                    key,
+                }) => {
+                    let rtn = api.invoke(MetadataRemoveInvocation {
+                        receiver: RENodeId::Global(entity_address.clone()),
+                        key: key.clone(),
+                    })?;
+
+                    InstructionOutput::Native(Box::new(rtn))
+                }
+                Instruction::Basic(BasicInstruction::LockMetadata {
+                    entity_address,
+                    key,
+                }) => {
+                    let rtn = api.invoke(MetadataLockInvocation {
+                        receiver: RENodeId::Global(entity_address.clone()),
+                        key: key.clone(),
+                    })?;
+
+                    InstructionOutput::Native(Box::new(rtn))
+                }
                 Instruction::Basic(BasicInstruction::SetPackageRoyaltyConfig {
                     package_address,
                     royalty_config,
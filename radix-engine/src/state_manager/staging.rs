@@ -412,11 +412,14 @@ mod tests {
 
     fn build_dummy_output_value(version: u32) -> OutputValue {
         OutputValue {
-            substate: PersistedSubstate::Vault(VaultSubstate(Resource::Fungible {
-                resource_address: ResourceAddress::Normal([2u8; 26]),
-                divisibility: 56,
-                amount: Decimal::one(),
-            })),
+            substate: PersistedSubstate::Vault(VaultSubstate {
+                resource: Resource::Fungible {
+                    resource_address: ResourceAddress::Normal([2u8; 26]),
+                    divisibility: 56,
+                    amount: Decimal::one(),
+                },
+                frozen: false,
+            }),
             version,
         }
     }
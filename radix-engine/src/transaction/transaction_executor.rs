@@ -1,6 +1,6 @@
 use crate::engine::Track;
 use crate::engine::*;
-use crate::fee::{FeeReserve, FeeTable, SystemLoanFeeReserve};
+use crate::fee::{u128_to_decimal, FeeReserve, FeeTable, SystemLoanFeeReserve};
 use crate::ledger::{ReadableSubstateStore, WriteableSubstateStore};
 use crate::model::*;
 use crate::transaction::*;
@@ -31,6 +31,13 @@ impl FeeReserveConfig {
             system_loan: DEFAULT_SYSTEM_LOAN,
         }
     }
+
+    /// The most XRD a transaction with the given cost unit limit and tip can be charged.
+    pub fn max_fee(&self, cost_unit_limit: u32, tip_percentage: u16) -> Decimal {
+        let effective_price =
+            self.cost_unit_price + self.cost_unit_price * tip_percentage as u128 / 100;
+        u128_to_decimal(effective_price * cost_unit_limit as u128)
+    }
 }
 
 pub struct ExecutionConfig {
@@ -121,6 +128,11 @@ where
             FeePayment::User {
                 cost_unit_limit,
                 tip_percentage,
+            }
+            | FeePayment::Sponsored {
+                cost_unit_limit,
+                tip_percentage,
+                ..
             } => SystemLoanFeeReserve::new(
                 fee_reserve_config.cost_unit_price,
                 *tip_percentage,
@@ -131,7 +143,16 @@ where
             FeePayment::NoFee => SystemLoanFeeReserve::no_fee(),
         };
 
-        self.execute_with_fee_reserve(transaction, execution_config, fee_reserve)
+        let sponsored_fee = match transaction.fee_payment() {
+            FeePayment::Sponsored {
+                cost_unit_limit,
+                tip_percentage,
+                ..
+            } => Some(fee_reserve_config.max_fee(*cost_unit_limit, *tip_percentage)),
+            FeePayment::User { .. } | FeePayment::NoFee => None,
+        };
+
+        self.execute_with_fee_reserve(transaction, execution_config, fee_reserve, sponsored_fee)
     }
 
     fn execute_with_fee_reserve<R: FeeReserve>(
@@ -139,6 +160,7 @@ where
         transaction: &Executable,
         execution_config: &ExecutionConfig,
         fee_reserve: R,
+        sponsored_fee: Option<Decimal>,
     ) -> TransactionReceipt {
         let transaction_hash = transaction.transaction_hash();
         let auth_zone_params = transaction.auth_zone_params();
@@ -151,6 +173,7 @@ where
             println!("{:-^80}", "Transaction Metadata");
             println!("Transaction hash: {}", transaction_hash);
             println!("Transaction auth zone params: {:?}", auth_zone_params);
+            println!("Transaction fee payment: {:?}", transaction.fee_payment());
             println!("Number of unique blobs: {}", blobs.len());
 
             println!("{:-^80}", "Engine Execution Log");
@@ -184,25 +207,67 @@ where
             let mut id_allocator =
                 IdAllocator::new(transaction_hash.clone(), pre_allocated_ids.clone());
 
-            let mut kernel = Kernel::new(
-                auth_zone_params.clone(),
-                &mut id_allocator,
-                &mut track,
-                self.scrypto_interpreter,
-                &mut module,
-            );
+            // The sponsor's fee is locked in a root frame of its own, so that the fee payer's
+            // proofs never reach the auth zone of the manifest.
+            let fee_payer_result = match (transaction.fee_payment(), sponsored_fee) {
+                (
+                    FeePayment::Sponsored {
+                        fee_payer,
+                        fee_payer_proofs,
+                        ..
+                    },
+                    Some(amount),
+                ) => {
+                    let mut kernel = Kernel::new(
+                        AuthZoneParams {
+                            initial_proofs: fee_payer_proofs.clone(),
+                            virtualizable_proofs_resource_addresses: BTreeSet::new(),
+                        },
+                        &mut id_allocator,
+                        &mut track,
+                        self.scrypto_interpreter,
+                        &mut module,
+                    );
 
-            let invoke_result = kernel.invoke(TransactionProcessorRunInvocation {
-                transaction_hash: transaction_hash.clone(),
-                runtime_validations: Cow::Borrowed(transaction.runtime_validations()),
-                instructions: match instructions {
-                    InstructionList::Basic(instructions) => {
-                        Cow::Owned(instructions.iter().map(|e| e.clone().into()).collect())
-                    }
-                    InstructionList::Any(instructions) => Cow::Borrowed(instructions),
-                    InstructionList::AnyOwned(instructions) => Cow::Borrowed(instructions),
-                },
-                blobs: Cow::Borrowed(blobs),
+                    kernel
+                        .invoke(TransactionProcessorRunInvocation {
+                            transaction_hash: transaction_hash.clone(),
+                            runtime_validations: Cow::Owned(vec![]),
+                            instructions: Cow::Owned(vec![Instruction::Basic(
+                                BasicInstruction::CallMethod {
+                                    component_address: *fee_payer,
+                                    method_name: "lock_fee".to_string(),
+                                    args: args!(amount),
+                                },
+                            )]),
+                            blobs: Cow::Owned(vec![]),
+                        })
+                        .map(|_| ())
+                }
+                _ => Ok(()),
+            };
+
+            let invoke_result = fee_payer_result.and_then(|()| {
+                let mut kernel = Kernel::new(
+                    auth_zone_params.clone(),
+                    &mut id_allocator,
+                    &mut track,
+                    self.scrypto_interpreter,
+                    &mut module,
+                );
+
+                kernel.invoke(TransactionProcessorRunInvocation {
+                    transaction_hash: transaction_hash.clone(),
+                    runtime_validations: Cow::Borrowed(transaction.runtime_validations()),
+                    instructions: match instructions {
+                        InstructionList::Basic(instructions) => {
+                            Cow::Owned(instructions.iter().map(|e| e.clone().into()).collect())
+                        }
+                        InstructionList::Any(instructions) => Cow::Borrowed(instructions),
+                        InstructionList::AnyOwned(instructions) => Cow::Borrowed(instructions),
+                    },
+                    blobs: Cow::Borrowed(blobs),
+                })
             });
 
             let events = module.collect_events();
@@ -212,7 +212,7 @@ fn test_arg(method_name: &str, args: Vec<u8>, expected_result: ExpectedResult) {
                         _,
                         _,
                         _,
-                        ScryptoFnResolvingError::InvalidInput
+                        ScryptoFnResolvingError::InvalidInput(..)
                     ))
                 )
             });
@@ -4,6 +4,7 @@ use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::model::FromPublicKey;
 use radix_engine_interface::rule;
+use radix_engine_interface::time::Instant;
 use scrypto::component::ComponentAccessRules;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -627,3 +628,146 @@ enum Call {
     //Method,
     Function,
 }
+
+#[test]
+fn assert_access_rule_with_epoch_conditions_through_manifest() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().without_trace().build();
+    let (public_key, _, _account_component) = test_runner.new_account(false);
+    test_runner.set_current_epoch(10);
+
+    // Act
+    let after_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .assert_access_rule(rule!(after_epoch(10)))
+            .build(),
+        [NonFungibleGlobalId::from_public_key(&public_key)].into(),
+    );
+    let before_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .assert_access_rule(rule!(before_epoch(10)))
+            .build(),
+        [NonFungibleGlobalId::from_public_key(&public_key)].into(),
+    );
+
+    // Assert
+    after_receipt.expect_commit_success();
+    before_receipt.expect_specific_failure(|error: &RuntimeError| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn assert_access_rule_with_time_conditions_through_manifest() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().without_trace().build();
+    let (public_key, _, _account_component) = test_runner.new_account(false);
+    test_runner.set_current_time(1_000_000_000_000);
+    let now = Instant::new(1_000_000_000);
+
+    // Act
+    let after_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .assert_access_rule(rule!(after_time(now.add_minutes(-1).unwrap())))
+            .build(),
+        [NonFungibleGlobalId::from_public_key(&public_key)].into(),
+    );
+    let before_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .assert_access_rule(rule!(before_time(now.add_minutes(-1).unwrap())))
+            .build(),
+        [NonFungibleGlobalId::from_public_key(&public_key)].into(),
+    );
+
+    // Assert
+    after_receipt.expect_commit_success();
+    before_receipt.expect_specific_failure(|error: &RuntimeError| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn after_time_rule_is_not_satisfied_before_the_instant_within_the_same_minute() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().without_trace().build();
+    let (public_key, _, _account_component) = test_runner.new_account(false);
+    let minute = Instant::new(999_999_960);
+    test_runner.set_current_time((minute.seconds_since_unix_epoch + 10) * 1000);
+
+    // Act
+    let at_minute_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .assert_access_rule(rule!(after_time(minute)))
+            .build(),
+        [NonFungibleGlobalId::from_public_key(&public_key)].into(),
+    );
+    let later_in_minute_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .assert_access_rule(rule!(after_time(Instant::new(
+                minute.seconds_since_unix_epoch + 30
+            ))))
+            .build(),
+        [NonFungibleGlobalId::from_public_key(&public_key)].into(),
+    );
+
+    // Assert
+    at_minute_receipt.expect_commit_success();
+    later_in_minute_receipt.expect_specific_failure(|error: &RuntimeError| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn assert_access_rule_with_weighted_threshold_through_manifest() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().without_trace().build();
+    let (public_key, _, account_component) = test_runner.new_account(false);
+    let badge = test_runner.create_fungible_resource(dec!("1"), 0, account_component);
+    let rule = rule!(require_weighted_threshold(
+        3,
+        vec![(RADIX_TOKEN, 2), (badge, 1)]
+    ));
+
+    // Act
+    let partial_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .create_proof_from_account(account_component, RADIX_TOKEN)
+            .assert_access_rule(rule.clone())
+            .build(),
+        [NonFungibleGlobalId::from_public_key(&public_key)].into(),
+    );
+    let full_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .create_proof_from_account(account_component, RADIX_TOKEN)
+            .create_proof_from_account(account_component, badge)
+            .assert_access_rule(rule)
+            .build(),
+        [NonFungibleGlobalId::from_public_key(&public_key)].into(),
+    );
+
+    // Assert
+    partial_receipt.expect_specific_failure(|error: &RuntimeError| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(..)
+            ))
+        )
+    });
+    full_receipt.expect_commit_success();
+}
@@ -1,3 +1,5 @@
+use radix_engine::engine::{AuthError, ModuleError, RuntimeError};
+use radix_engine::model::{AuthRuleNode, ConsideredProofKind, HardResourceOrNonFungible};
 use radix_engine::types::*;
 use radix_engine_interface::modules::auth::AuthAddresses;
 use radix_engine_interface::rule;
@@ -315,3 +317,105 @@ fn cannot_withdraw_from_my_any_xrd_auth_account_with_less_than_amount_of_proof()
     // Assert
     receipt.expect_specific_failure(is_auth_error)
 }
+
+#[test]
+fn unauthorized_withdraw_explains_which_rule_failed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (pk0, _, auth0) = test_runner.new_key_pair_with_auth_address();
+    let (_, _, auth1) = test_runner.new_key_pair_with_auth_address();
+    let auth_rule = rule!(require(auth0.clone()) && require(auth1.clone()));
+    let account = test_runner.new_account_with_auth_rule(&auth_rule);
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(account, RADIX_TOKEN)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, AuthAddresses::signer_set(&[pk0.into()]));
+
+    // Assert
+    let explanation = match receipt.expect_commit_failure() {
+        RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
+            explanation,
+            ..
+        })) => explanation.clone(),
+        e => panic!("Expected an auth error but got {:?}", e),
+    };
+    assert_eq!(explanation.node, AuthRuleNode::AllOf);
+    assert!(!explanation.satisfied);
+    assert_eq!(explanation.children.len(), 2);
+    assert!(explanation.children[0].satisfied);
+    assert!(!explanation.children[1].satisfied);
+
+    let failed_leaves = explanation.failed_leaves();
+    assert_eq!(failed_leaves.len(), 1);
+    assert_eq!(
+        failed_leaves[0].node,
+        AuthRuleNode::Require(HardResourceOrNonFungible::NonFungible(auth1))
+    );
+    assert!(failed_leaves[0]
+        .considered_proofs
+        .iter()
+        .any(|p| p.kind == ConsideredProofKind::VirtualNonFungible(auth0.clone()) && !p.matched));
+
+    let rendered = test_runner.explain_auth_failure(&receipt).unwrap();
+    assert!(rendered.starts_with("[FAIL] AllOf"));
+    assert!(rendered.contains("[PASS] Require("));
+    assert!(rendered.contains("[FAIL] Require("));
+}
+
+#[test]
+fn unauthorized_withdraw_explains_insufficient_proof_amount() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let xrd_auth = rule!(require_amount(Decimal::from(1), RADIX_TOKEN));
+    let account = test_runner.new_account_with_auth_rule(&xrd_auth);
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .take_from_worktop_by_amount(Decimal::from("0.9"), RADIX_TOKEN, |builder, bucket_id| {
+            builder.create_proof_from_bucket(&bucket_id, |builder, proof_id| {
+                builder.push_to_auth_zone(proof_id);
+                builder.withdraw_from_account(account, RADIX_TOKEN);
+                builder.pop_from_auth_zone(|builder, proof_id| builder.drop_proof(proof_id));
+                builder
+            });
+            builder.return_to_worktop(bucket_id);
+            builder
+        })
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let explanation = match receipt.expect_commit_failure() {
+        RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
+            explanation,
+            ..
+        })) => explanation.clone(),
+        e => panic!("Expected an auth error but got {:?}", e),
+    };
+    assert!(!explanation.satisfied);
+    assert!(explanation.considered_proofs.iter().any(|p| match &p.kind {
+        ConsideredProofKind::Proof {
+            resource_address,
+            amount,
+            ..
+        } => *resource_address == RADIX_TOKEN && *amount == Decimal::from("0.9") && !p.matched,
+        _ => false,
+    }));
+}
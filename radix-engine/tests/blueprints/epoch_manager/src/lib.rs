@@ -16,6 +16,8 @@ mod epoch_manager_test {
                 .invoke(EpochManagerNextRoundInvocation {
                     receiver: epoch_manager,
                     round,
+                    leader: None,
+                    missed_leaders: Vec::new(),
                 })
                 .unwrap();
         }
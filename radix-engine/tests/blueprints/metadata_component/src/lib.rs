@@ -10,5 +10,29 @@ mod metadata_component {
             component.metadata("key", "value");
             component.globalize()
         }
+
+        pub fn remove_metadata() -> ComponentAddress {
+            let mut component = MetadataComponent {}.instantiate();
+            component.metadata("key", "value");
+            component.remove_metadata("key");
+            assert_eq!(component.get_metadata::<_, String>("key"), None);
+            component.globalize()
+        }
+
+        pub fn set_locked_metadata() -> ComponentAddress {
+            let mut component = MetadataComponent {}.instantiate();
+            component.metadata("key", "value");
+            component.lock_metadata("key");
+            component.metadata("key", "other value");
+            component.globalize()
+        }
+
+        pub fn remove_locked_metadata() -> ComponentAddress {
+            let mut component = MetadataComponent {}.instantiate();
+            component.metadata("key", "value");
+            component.lock_metadata("key");
+            component.remove_metadata("key");
+            component.globalize()
+        }
     }
 }
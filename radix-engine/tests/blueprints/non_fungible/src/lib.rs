@@ -150,6 +150,127 @@ mod non_fungible_test {
             (mint_badge, bucket)
         }
 
+        pub fn create_non_fungible_with_mutable_fields() -> (Bucket, ResourceAddress, Bucket) {
+            let mint_badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .mint_initial_supply(1);
+
+            let resource_address = ResourceBuilder::new_integer_non_fungible()
+                .metadata("name", "Katz's Sandwiches")
+                .mintable(
+                    rule!(require(mint_badge.resource_address())),
+                    rule!(deny_all),
+                )
+                .updateable_non_fungible_data(
+                    rule!(require(mint_badge.resource_address())),
+                    rule!(require(mint_badge.resource_address())),
+                )
+                .non_fungible_data_schema::<Sandwich>()
+                .create_with_no_initial_supply();
+
+            let non_fungible = mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).mint_non_fungible(
+                    &NonFungibleLocalId::integer(0),
+                    Sandwich {
+                        name: "Test".to_owned(),
+                        available: false,
+                    },
+                )
+            });
+
+            (mint_badge, resource_address, non_fungible)
+        }
+
+        pub fn update_non_fungible_data_field() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, bucket) =
+                Self::create_non_fungible_with_mutable_fields();
+            mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).update_non_fungible_data_field(
+                    &NonFungibleLocalId::integer(0),
+                    "available",
+                    true,
+                );
+            });
+
+            let data: Sandwich = borrow_resource_manager!(resource_address)
+                .get_non_fungible_data(&NonFungibleLocalId::integer(0));
+            assert_eq!(data.name, "Test");
+            assert_eq!(data.available, true);
+            (mint_badge, bucket)
+        }
+
+        pub fn update_non_fungible_data_field_with_wrong_type() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, bucket) =
+                Self::create_non_fungible_with_mutable_fields();
+            mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).update_non_fungible_data_field(
+                    &NonFungibleLocalId::integer(0),
+                    "available",
+                    "yes".to_owned(),
+                );
+            });
+            (mint_badge, bucket)
+        }
+
+        pub fn update_immutable_non_fungible_data_field() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, bucket) =
+                Self::create_non_fungible_with_mutable_fields();
+            mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).update_non_fungible_data_field(
+                    &NonFungibleLocalId::integer(0),
+                    "name",
+                    "Other".to_owned(),
+                );
+            });
+            (mint_badge, bucket)
+        }
+
+        pub fn update_non_fungible_data_field_without_badge() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, bucket) =
+                Self::create_non_fungible_with_mutable_fields();
+            borrow_resource_manager!(resource_address).update_non_fungible_data_field(
+                &NonFungibleLocalId::integer(0),
+                "available",
+                true,
+            );
+            (mint_badge, bucket)
+        }
+
+        pub fn update_non_fungible_data_field_with_field_rule() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, bucket) =
+                Self::create_non_fungible_with_mutable_fields();
+            mint_badge.authorize(|| {
+                borrow_resource_manager!(resource_address)
+                    .set_updateable_non_fungible_data_field("available", rule!(allow_all));
+            });
+
+            borrow_resource_manager!(resource_address).update_non_fungible_data_field(
+                &NonFungibleLocalId::integer(0),
+                "available",
+                true,
+            );
+
+            let data: Sandwich = borrow_resource_manager!(resource_address)
+                .get_non_fungible_data(&NonFungibleLocalId::integer(0));
+            assert_eq!(data.available, true);
+            (mint_badge, bucket)
+        }
+
+        pub fn update_non_fungible_data_field_after_revoking_data_rule() -> (Bucket, Bucket) {
+            let (mint_badge, resource_address, bucket) =
+                Self::create_non_fungible_with_mutable_fields();
+            mint_badge.authorize(|| {
+                let resource_manager = borrow_resource_manager!(resource_address);
+                resource_manager.set_updateable_non_fungible_data(rule!(deny_all));
+                resource_manager.update_non_fungible_data_field(
+                    &NonFungibleLocalId::integer(0),
+                    "available",
+                    true,
+                );
+            });
+            (mint_badge, bucket)
+        }
+
         pub fn take_and_put_bucket() -> Bucket {
             let mut bucket = Self::create_non_fungible_fixed();
             assert_eq!(bucket.amount(), 3.into());
@@ -307,6 +428,9 @@ mod non_fungible_test {
                         id_type: NonFungibleIdType::UUID,
                         metadata: BTreeMap::new(),
                         access_rules: BTreeMap::new(),
+                        max_supply: None,
+                        mint_limit_per_epoch: None,
+                        mutable_data_schema: None,
                         entries: encoded,
                     },
                 )
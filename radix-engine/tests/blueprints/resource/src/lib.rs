@@ -123,13 +123,149 @@ mod resource_test {
             badge.authorize(|| {
                 let token_resource_manager = borrow_resource_manager!(resource_address);
                 token_resource_manager.set_metadata("a".to_owned(), "b".to_owned());
+                token_resource_manager
+                    .set_metadata("icon_url", Url::of("https://example.com/icon.png"));
+                token_resource_manager.set_metadata("tags", vec![1u32, 2u32]);
+                let a: String = token_resource_manager.get_metadata("a").unwrap();
+                assert_eq!(a, "b".to_owned());
                 assert_eq!(
-                    token_resource_manager.get_metadata("a".to_owned()).unwrap(),
-                    "b".to_owned()
+                    token_resource_manager.get_metadata::<_, Url>("icon_url"),
+                    Some(Url::of("https://example.com/icon.png"))
                 );
+                assert_eq!(
+                    token_resource_manager.get_metadata::<_, String>("icon_url"),
+                    None
+                );
+                assert_eq!(
+                    token_resource_manager.get_metadata::<_, Vec<u32>>("tags"),
+                    Some(vec![1u32, 2u32])
+                );
+                token_resource_manager.remove_metadata("tags");
+                assert_eq!(
+                    token_resource_manager.get_metadata::<_, Vec<u32>>("tags"),
+                    None
+                );
+                token_resource_manager.lock_metadata("a");
+            });
+
+            badge
+        }
+
+        pub fn set_locked_resource_metadata() -> Bucket {
+            let (badge, resource_address) = Self::create_fungible_with_locked_metadata();
+            badge.authorize(|| {
+                borrow_resource_manager!(resource_address).set_metadata("a", "c");
             });
+            badge
+        }
 
+        pub fn remove_locked_resource_metadata() -> Bucket {
+            let (badge, resource_address) = Self::create_fungible_with_locked_metadata();
+            badge.authorize(|| {
+                borrow_resource_manager!(resource_address).remove_metadata("a");
+            });
             badge
         }
+
+        pub fn set_resource_metadata_key_with_its_own_rule() -> (Bucket, Bucket) {
+            let (admin_badge, key_badge, resource_address) =
+                Self::create_fungible_with_metadata_key_rule();
+            key_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).set_metadata("a", "c");
+            });
+            admin_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).set_metadata("b", "c");
+            });
+            (admin_badge, key_badge)
+        }
+
+        pub fn set_other_resource_metadata_key_with_key_rule() -> (Bucket, Bucket) {
+            let (admin_badge, key_badge, resource_address) =
+                Self::create_fungible_with_metadata_key_rule();
+            key_badge.authorize(|| {
+                borrow_resource_manager!(resource_address).set_metadata("b", "c");
+            });
+            (admin_badge, key_badge)
+        }
+
+        fn create_fungible_with_metadata_key_rule() -> (Bucket, Bucket, ResourceAddress) {
+            let admin_badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .mint_initial_supply(1);
+            let key_badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .mint_initial_supply(1);
+            let resource_address = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata("a", "b")
+                .updateable_metadata(
+                    rule!(require(admin_badge.resource_address())),
+                    MUTABLE(rule!(require(admin_badge.resource_address()))),
+                )
+                .create_with_no_initial_supply();
+            admin_badge.authorize(|| {
+                borrow_resource_manager!(resource_address)
+                    .set_updateable_metadata_key("a", rule!(require(key_badge.resource_address())));
+            });
+            (admin_badge, key_badge, resource_address)
+        }
+
+        fn create_fungible_with_locked_metadata() -> (Bucket, ResourceAddress) {
+            let badge = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_NONE)
+                .mint_initial_supply(1);
+            let resource_address = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata("a", "b")
+                .updateable_metadata(rule!(require(badge.resource_address())), LOCKED)
+                .create_with_no_initial_supply();
+            badge.authorize(|| {
+                borrow_resource_manager!(resource_address).lock_metadata("a");
+            });
+            (badge, resource_address)
+        }
+
+        pub fn create_fungible_with_supply_limits(
+            max_supply: Option<Decimal>,
+            mint_limit_per_epoch: Option<Decimal>,
+        ) -> ResourceAddress {
+            let mut builder = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata("name", "TestToken");
+            if let Some(max_supply) = max_supply {
+                builder = builder.max_supply(max_supply);
+            }
+            if let Some(mint_limit_per_epoch) = mint_limit_per_epoch {
+                builder = builder.mint_limit_per_epoch(mint_limit_per_epoch);
+            }
+            builder
+                .mintable(rule!(allow_all), LOCKED)
+                .create_with_no_initial_supply()
+        }
+
+        pub fn create_fungible_with_initial_supply_above_max_supply() -> Bucket {
+            ResourceBuilder::new_fungible()
+                .max_supply(100)
+                .mint_initial_supply(101)
+        }
+
+        pub fn set_max_supply_below_total_supply() -> Bucket {
+            let bucket = ResourceBuilder::new_fungible()
+                .max_supply(200)
+                .updateable_max_supply(rule!(allow_all), LOCKED)
+                .mint_initial_supply(100);
+            borrow_resource_manager!(bucket.resource_address()).set_max_supply(Some(dec!("50")));
+            bucket
+        }
+
+        pub fn set_mint_limit_per_epoch_to_zero() -> Bucket {
+            let bucket = ResourceBuilder::new_fungible()
+                .mint_limit_per_epoch(100)
+                .updateable_mint_limit_per_epoch(rule!(allow_all), LOCKED)
+                .mint_initial_supply(100);
+            borrow_resource_manager!(bucket.resource_address())
+                .set_mint_limit_per_epoch(Some(Decimal::zero()));
+            bucket
+        }
     }
 }
@@ -1,6 +1,6 @@
 use radix_engine::engine::{ApplicationError, AuthError, ModuleError, RuntimeError};
-use radix_engine::ledger::create_genesis;
-use radix_engine::model::{Validator, ValidatorError};
+use radix_engine::ledger::{create_genesis, GenesisConfig};
+use radix_engine::model::{EpochManager, EpochManagerError, Validator, ValidatorError};
 use radix_engine::types::*;
 use radix_engine_interface::modules::auth::AuthAddresses;
 use scrypto_unit::*;
@@ -57,13 +57,11 @@ fn next_round_with_validator_auth_succeeds() {
     // Arrange
     let rounds_per_epoch = 5u64;
     let num_unstake_epochs = 1u64;
-    let genesis = create_genesis(
-        BTreeMap::new(),
-        BTreeMap::new(),
-        1u64,
+    let genesis = create_genesis(GenesisConfig {
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
     // Act
@@ -71,6 +69,8 @@ fn next_round_with_validator_auth_succeeds() {
         EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
             receiver: EPOCH_MANAGER,
             round: rounds_per_epoch - 1,
+            leader: None,
+            missed_leaders: vec![],
         }),
     ))];
     let receipt = test_runner.execute_transaction(
@@ -95,13 +95,12 @@ fn next_epoch_with_validator_auth_succeeds() {
     let initial_epoch = 5u64;
     let rounds_per_epoch = 2u64;
     let num_unstake_epochs = 1u64;
-    let genesis = create_genesis(
-        BTreeMap::new(),
-        BTreeMap::new(),
+    let genesis = create_genesis(GenesisConfig {
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
     // Act
@@ -109,6 +108,8 @@ fn next_epoch_with_validator_auth_succeeds() {
         EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
             receiver: EPOCH_MANAGER,
             round: rounds_per_epoch,
+            leader: None,
+            missed_leaders: vec![],
         }),
     ))];
     let receipt = test_runner.execute_transaction(
@@ -144,13 +145,13 @@ fn register_validator_with_auth_succeeds() {
     let mut validator_set_and_stake_owners = BTreeMap::new();
     let validator_account_address = ComponentAddress::virtual_account_from_public_key(&pub_key);
     validator_set_and_stake_owners.insert(pub_key, (Decimal::one(), validator_account_address));
-    let genesis = create_genesis(
+    let genesis = create_genesis(GenesisConfig {
         validator_set_and_stake_owners,
-        BTreeMap::new(),
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
     // Act
@@ -186,13 +187,13 @@ fn register_validator_without_auth_fails() {
             ComponentAddress::virtual_account_from_public_key(&pub_key),
         ),
     );
-    let genesis = create_genesis(
+    let genesis = create_genesis(GenesisConfig {
         validator_set_and_stake_owners,
-        BTreeMap::new(),
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
     // Act
@@ -221,13 +222,13 @@ fn unregister_validator_with_auth_succeeds() {
     let validator_account_address = ComponentAddress::virtual_account_from_public_key(&pub_key);
     let mut validator_set_and_stake_owners = BTreeMap::new();
     validator_set_and_stake_owners.insert(pub_key, (Decimal::one(), validator_account_address));
-    let genesis = create_genesis(
+    let genesis = create_genesis(GenesisConfig {
         validator_set_and_stake_owners,
-        BTreeMap::new(),
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
     // Act
@@ -263,13 +264,13 @@ fn unregister_validator_without_auth_fails() {
             ComponentAddress::virtual_account_from_public_key(&pub_key),
         ),
     );
-    let genesis = create_genesis(
+    let genesis = create_genesis(GenesisConfig {
         validator_set_and_stake_owners,
-        BTreeMap::new(),
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
 
     // Act
@@ -297,13 +298,13 @@ fn test_disabled_delegated_stake(owner: bool, expect_success: bool) {
     let validator_account_address = ComponentAddress::virtual_account_from_public_key(&pub_key);
     let mut validator_set_and_stake_owners = BTreeMap::new();
     validator_set_and_stake_owners.insert(pub_key, (Decimal::one(), validator_account_address));
-    let genesis = create_genesis(
+    let genesis = create_genesis(GenesisConfig {
         validator_set_and_stake_owners,
-        BTreeMap::new(),
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&pub_key);
     let manifest = ManifestBuilder::new()
@@ -374,13 +375,12 @@ fn registered_validator_with_no_stake_does_not_become_part_of_validator_on_epoch
     let initial_epoch = 5u64;
     let rounds_per_epoch = 2u64;
     let num_unstake_epochs = 1u64;
-    let genesis = create_genesis(
-        BTreeMap::new(),
-        BTreeMap::new(),
+    let genesis = create_genesis(GenesisConfig {
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let (pub_key, validator_address) = test_runner.new_validator();
     let manifest = ManifestBuilder::new()
@@ -398,6 +398,8 @@ fn registered_validator_with_no_stake_does_not_become_part_of_validator_on_epoch
         EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
             receiver: EPOCH_MANAGER,
             round: rounds_per_epoch,
+            leader: None,
+            missed_leaders: vec![],
         }),
     ))];
     let receipt = test_runner.execute_transaction(
@@ -424,13 +426,12 @@ fn registered_validator_with_stake_does_become_part_of_validator_on_epoch_change
     let initial_epoch = 5u64;
     let rounds_per_epoch = 2u64;
     let num_unstake_epochs = 1u64;
-    let genesis = create_genesis(
-        BTreeMap::new(),
-        BTreeMap::new(),
+    let genesis = create_genesis(GenesisConfig {
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let (pub_key, _, account_address) = test_runner.new_account(false);
     let validator_address = test_runner.new_validator_with_pub_key(pub_key, rule!(allow_all));
@@ -458,6 +459,8 @@ fn registered_validator_with_stake_does_become_part_of_validator_on_epoch_change
         EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
             receiver: EPOCH_MANAGER,
             round: rounds_per_epoch,
+            leader: None,
+            missed_leaders: vec![],
         }),
     ))];
     let receipt = test_runner.execute_transaction(
@@ -500,13 +503,13 @@ fn unregistered_validator_gets_removed_on_epoch_change() {
         validator_pub_key,
         (Decimal::one(), validator_account_address),
     );
-    let genesis = create_genesis(
+    let genesis = create_genesis(GenesisConfig {
         validator_set_and_stake_owners,
-        BTreeMap::new(),
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
     let manifest = ManifestBuilder::new()
@@ -525,6 +528,8 @@ fn unregistered_validator_gets_removed_on_epoch_change() {
         EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
             receiver: EPOCH_MANAGER,
             round: rounds_per_epoch,
+            leader: None,
+            missed_leaders: vec![],
         }),
     ))];
     let receipt = test_runner.execute_transaction(
@@ -561,13 +566,13 @@ fn updated_validator_keys_gets_updated_on_epoch_change() {
         validator_pub_key,
         (Decimal::one(), validator_account_address),
     );
-    let genesis = create_genesis(
+    let genesis = create_genesis(GenesisConfig {
         validator_set_and_stake_owners,
-        BTreeMap::new(),
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
     let next_validator_pub_key = EcdsaSecp256k1PrivateKey::from_u64(3u64)
@@ -593,6 +598,8 @@ fn updated_validator_keys_gets_updated_on_epoch_change() {
         EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
             receiver: EPOCH_MANAGER,
             round: rounds_per_epoch,
+            leader: None,
+            missed_leaders: vec![],
         }),
     ))];
     let receipt = test_runner.execute_transaction(
@@ -631,13 +638,13 @@ fn cannot_claim_unstake_immediately() {
     let mut validator_set_and_stake_owners = BTreeMap::new();
     let account_with_lp = ComponentAddress::virtual_account_from_public_key(&account_pub_key);
     validator_set_and_stake_owners.insert(validator_pub_key, (Decimal::from(10), account_with_lp));
-    let genesis = create_genesis(
+    let genesis = create_genesis(GenesisConfig {
         validator_set_and_stake_owners,
-        BTreeMap::new(),
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
     let validator_substate = test_runner.get_validator_info(validator_address);
@@ -688,13 +695,13 @@ fn can_claim_unstake_after_epochs() {
     let account_with_lp = ComponentAddress::virtual_account_from_public_key(&account_pub_key);
     let mut validator_set_and_stake_owners = BTreeMap::new();
     validator_set_and_stake_owners.insert(validator_pub_key, (Decimal::from(10), account_with_lp));
-    let genesis = create_genesis(
+    let genesis = create_genesis(GenesisConfig {
         validator_set_and_stake_owners,
-        BTreeMap::new(),
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
     let validator_substate = test_runner.get_validator_info(validator_address);
@@ -754,13 +761,13 @@ fn unstaked_validator_gets_less_stake_on_epoch_change() {
     let account_with_lp = ComponentAddress::virtual_account_from_public_key(&account_pub_key);
     let mut validator_set_and_stake_owners = BTreeMap::new();
     validator_set_and_stake_owners.insert(validator_pub_key, (Decimal::from(10), account_with_lp));
-    let genesis = create_genesis(
+    let genesis = create_genesis(GenesisConfig {
         validator_set_and_stake_owners,
-        BTreeMap::new(),
         initial_epoch,
         rounds_per_epoch,
         num_unstake_epochs,
-    );
+        ..Default::default()
+    });
     let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
     let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
     let validator_substate = test_runner.get_validator_info(validator_address);
@@ -791,6 +798,8 @@ fn unstaked_validator_gets_less_stake_on_epoch_change() {
         EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
             receiver: EPOCH_MANAGER,
             round: rounds_per_epoch,
+            leader: None,
+            missed_leaders: vec![],
         }),
     ))];
     let receipt = test_runner.execute_transaction(
@@ -836,6 +845,10 @@ fn epoch_manager_create_should_fail_with_supervisor_privilege() {
             initial_epoch: 1u64,
             rounds_per_epoch: 1u64,
             num_unstake_epochs: 1u64,
+            emission_xrd_per_epoch: Decimal::zero(),
+            max_validators: 100u32,
+            min_validator_stake: Decimal::zero(),
+            min_validator_reliability: Decimal::zero(),
         }),
     ))];
     let blobs = vec![];
@@ -874,6 +887,10 @@ fn epoch_manager_create_should_succeed_with_system_privilege() {
             initial_epoch: 1u64,
             rounds_per_epoch: 1u64,
             num_unstake_epochs: 1u64,
+            emission_xrd_per_epoch: Decimal::zero(),
+            max_validators: 100u32,
+            min_validator_stake: Decimal::zero(),
+            min_validator_reliability: Decimal::zero(),
         }),
     ))];
     let blobs = vec![];
@@ -890,3 +907,541 @@ fn epoch_manager_create_should_succeed_with_system_privilege() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn epoch_change_distributes_emission_pro_rata_by_stake() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let emission_xrd_per_epoch = Decimal::from(100);
+    let validator1_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let validator2_pub_key = EcdsaSecp256k1PrivateKey::from_u64(3u64)
+        .unwrap()
+        .public_key();
+    let staker_account_address = ComponentAddress::virtual_account_from_public_key(
+        &EcdsaSecp256k1PrivateKey::from_u64(1u64)
+            .unwrap()
+            .public_key(),
+    );
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    validator_set_and_stake_owners.insert(
+        validator1_pub_key,
+        (Decimal::from(10), staker_account_address),
+    );
+    validator_set_and_stake_owners.insert(
+        validator2_pub_key,
+        (Decimal::from(30), staker_account_address),
+    );
+    let genesis = create_genesis(GenesisConfig {
+        validator_set_and_stake_owners,
+        initial_epoch,
+        rounds_per_epoch,
+        num_unstake_epochs,
+        emission_xrd_per_epoch,
+        ..Default::default()
+    });
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator1_address = test_runner.get_validator_with_key(&validator1_pub_key);
+    let validator2_address = test_runner.get_validator_with_key(&validator2_pub_key);
+
+    // Act
+    let instructions = vec![Instruction::System(NativeInvocation::EpochManager(
+        EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
+            receiver: EPOCH_MANAGER,
+            round: rounds_per_epoch,
+            leader: None,
+            missed_leaders: vec![],
+        }),
+    ))];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let result = receipt.expect_commit();
+    let next_epoch = result.next_epoch.as_ref().expect("Should have next epoch");
+    assert_eq!(
+        next_epoch.0.get(&validator1_address).unwrap().stake,
+        Decimal::from(35)
+    );
+    assert_eq!(
+        next_epoch.0.get(&validator2_address).unwrap().stake,
+        Decimal::from(105)
+    );
+    let validator1_substate = test_runner.get_validator_info(validator1_address);
+    assert_eq!(
+        test_runner.inspect_vault_balance(validator1_substate.stake_xrd_vault_id),
+        Some(Decimal::from(35))
+    );
+}
+
+#[test]
+fn validator_fee_is_credited_to_owner_and_rest_is_staked() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let emission_xrd_per_epoch = Decimal::from(100);
+    let validator_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let validator_account_address =
+        ComponentAddress::virtual_account_from_public_key(&validator_pub_key);
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    validator_set_and_stake_owners.insert(
+        validator_pub_key,
+        (Decimal::from(10), validator_account_address),
+    );
+    let genesis = create_genesis(GenesisConfig {
+        validator_set_and_stake_owners,
+        initial_epoch,
+        rounds_per_epoch,
+        num_unstake_epochs,
+        emission_xrd_per_epoch,
+        ..Default::default()
+    });
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator_address = test_runner.get_validator_with_key(&validator_pub_key);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account(validator_account_address, OLYMPIA_VALIDATOR_TOKEN)
+        .call_method(validator_address, "update_fee", args!(dec!("0.1")))
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&validator_pub_key)],
+    );
+    receipt.expect_commit_success();
+
+    // Act
+    test_runner.set_current_epoch(initial_epoch + 2);
+
+    // Assert
+    let validator_substate = test_runner.get_validator_info(validator_address);
+    assert_eq!(validator_substate.fee_factor, dec!("0.1"));
+    assert_eq!(
+        test_runner.inspect_vault_balance(validator_substate.owner_fee_xrd_vault_id),
+        Some(Decimal::from(20))
+    );
+    assert_eq!(
+        test_runner.inspect_vault_balance(validator_substate.stake_xrd_vault_id),
+        Some(Decimal::from(190))
+    );
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account(validator_account_address, OLYMPIA_VALIDATOR_TOKEN)
+        .call_method(validator_address, "claim_fees", args!())
+        .call_method(
+            validator_account_address,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&validator_pub_key)],
+    );
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.inspect_vault_balance(validator_substate.owner_fee_xrd_vault_id),
+        Some(Decimal::zero())
+    );
+}
+
+#[test]
+fn update_fee_with_fee_factor_over_one_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (pub_key, _, _) = test_runner.new_account(false);
+    let validator_address = test_runner.new_validator_with_pub_key(pub_key, rule!(allow_all));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(validator_address, "update_fee", args!(dec!("1.5")))
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&pub_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ValidatorError(
+                ValidatorError::InvalidFeeFactor(..)
+            ))
+        )
+    });
+}
+
+fn next_epoch_validator_set_with_genesis_stakes(
+    stakes: Vec<u64>,
+    max_validators: u32,
+    min_validator_stake: Decimal,
+) -> (
+    BTreeMap<ComponentAddress, Validator>,
+    Vec<EcdsaSecp256k1PublicKey>,
+) {
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let staker_account_address = ComponentAddress::virtual_account_from_public_key(
+        &EcdsaSecp256k1PrivateKey::from_u64(1u64)
+            .unwrap()
+            .public_key(),
+    );
+    let mut validator_pub_keys = Vec::new();
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    for (i, stake) in stakes.into_iter().enumerate() {
+        let pub_key = EcdsaSecp256k1PrivateKey::from_u64(i as u64 + 2)
+            .unwrap()
+            .public_key();
+        validator_set_and_stake_owners
+            .insert(pub_key, (Decimal::from(stake), staker_account_address));
+        validator_pub_keys.push(pub_key);
+    }
+    let genesis = create_genesis(GenesisConfig {
+        validator_set_and_stake_owners,
+        initial_epoch,
+        rounds_per_epoch,
+        num_unstake_epochs,
+        max_validators,
+        min_validator_stake,
+        ..Default::default()
+    });
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+
+    let instructions = vec![Instruction::System(NativeInvocation::EpochManager(
+        EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
+            receiver: EPOCH_MANAGER,
+            round: rounds_per_epoch,
+            leader: None,
+            missed_leaders: vec![],
+        }),
+    ))];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    receipt.expect_commit_success();
+    let result = receipt.expect_commit();
+    let next_epoch = result.next_epoch.as_ref().expect("Should have next epoch");
+    (next_epoch.0.clone(), validator_pub_keys)
+}
+
+#[test]
+fn next_epoch_validator_set_is_bounded_to_validators_with_most_stake() {
+    // Act
+    let (validator_set, pub_keys) =
+        next_epoch_validator_set_with_genesis_stakes(vec![10, 30, 20], 2, Decimal::zero());
+
+    // Assert
+    let keys: BTreeSet<EcdsaSecp256k1PublicKey> = validator_set
+        .values()
+        .map(|validator| validator.key)
+        .collect();
+    assert_eq!(keys, BTreeSet::from([pub_keys[1], pub_keys[2]]));
+}
+
+#[test]
+fn next_epoch_validator_set_excludes_validators_below_minimum_stake() {
+    // Act
+    let (validator_set, pub_keys) =
+        next_epoch_validator_set_with_genesis_stakes(vec![10, 30, 20], 100, Decimal::from(15));
+
+    // Assert
+    let keys: BTreeSet<EcdsaSecp256k1PublicKey> = validator_set
+        .values()
+        .map(|validator| validator.key)
+        .collect();
+    assert_eq!(keys, BTreeSet::from([pub_keys[1], pub_keys[2]]));
+}
+
+#[test]
+fn active_validator_selection_breaks_ties_in_stake_by_address() {
+    // Arrange
+    let registered_validators = (0..3u8)
+        .rev()
+        .map(|i| {
+            let pub_key = EcdsaSecp256k1PrivateKey::from_u64(i as u64 + 2)
+                .unwrap()
+                .public_key();
+            (
+                ComponentAddress::Validator([i; 26]),
+                Validator {
+                    key: pub_key,
+                    stake: Decimal::from(10),
+                },
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    // Act
+    let selected =
+        EpochManager::select_active_validators(&registered_validators, 2, Decimal::zero());
+
+    // Assert
+    assert_eq!(
+        selected.keys().cloned().collect::<Vec<_>>(),
+        vec![
+            ComponentAddress::Validator([0u8; 26]),
+            ComponentAddress::Validator([1u8; 26])
+        ]
+    );
+}
+
+#[test]
+fn missed_proposals_reduce_validator_emission() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let emission_xrd_per_epoch = Decimal::from(100);
+    let validator1_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let validator2_pub_key = EcdsaSecp256k1PrivateKey::from_u64(3u64)
+        .unwrap()
+        .public_key();
+    let staker_account_address = ComponentAddress::virtual_account_from_public_key(
+        &EcdsaSecp256k1PrivateKey::from_u64(1u64)
+            .unwrap()
+            .public_key(),
+    );
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    validator_set_and_stake_owners.insert(
+        validator1_pub_key,
+        (Decimal::from(10), staker_account_address),
+    );
+    validator_set_and_stake_owners.insert(
+        validator2_pub_key,
+        (Decimal::from(10), staker_account_address),
+    );
+    let genesis = create_genesis(GenesisConfig {
+        validator_set_and_stake_owners,
+        initial_epoch,
+        rounds_per_epoch,
+        num_unstake_epochs,
+        emission_xrd_per_epoch,
+        ..Default::default()
+    });
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator1_address = test_runner.get_validator_with_key(&validator1_pub_key);
+    let validator2_address = test_runner.get_validator_with_key(&validator2_pub_key);
+    let instructions = vec![Instruction::System(NativeInvocation::EpochManager(
+        EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
+            receiver: EPOCH_MANAGER,
+            round: 1,
+            leader: Some(validator2_address),
+            missed_leaders: vec![],
+        }),
+    ))];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+    receipt.expect_commit_success();
+
+    // Act
+    let instructions = vec![Instruction::System(NativeInvocation::EpochManager(
+        EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
+            receiver: EPOCH_MANAGER,
+            round: rounds_per_epoch,
+            leader: Some(validator1_address),
+            missed_leaders: vec![validator2_address],
+        }),
+    ))];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 1,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let result = receipt.expect_commit();
+    let next_epoch = result.next_epoch.as_ref().expect("Should have next epoch");
+    assert_eq!(
+        next_epoch.0.get(&validator1_address).unwrap().stake,
+        Decimal::from(60)
+    );
+    assert_eq!(
+        next_epoch.0.get(&validator2_address).unwrap().stake,
+        Decimal::from(35)
+    );
+}
+
+#[test]
+fn validator_below_minimum_reliability_is_excluded_at_epoch_change() {
+    // Arrange
+    let initial_epoch = 5u64;
+    let rounds_per_epoch = 2u64;
+    let num_unstake_epochs = 1u64;
+    let emission_xrd_per_epoch = Decimal::from(100);
+    let validator1_pub_key = EcdsaSecp256k1PrivateKey::from_u64(2u64)
+        .unwrap()
+        .public_key();
+    let validator2_pub_key = EcdsaSecp256k1PrivateKey::from_u64(3u64)
+        .unwrap()
+        .public_key();
+    let staker_account_address = ComponentAddress::virtual_account_from_public_key(
+        &EcdsaSecp256k1PrivateKey::from_u64(1u64)
+            .unwrap()
+            .public_key(),
+    );
+    let mut validator_set_and_stake_owners = BTreeMap::new();
+    validator_set_and_stake_owners.insert(
+        validator1_pub_key,
+        (Decimal::from(10), staker_account_address),
+    );
+    validator_set_and_stake_owners.insert(
+        validator2_pub_key,
+        (Decimal::from(10), staker_account_address),
+    );
+    let genesis = create_genesis(GenesisConfig {
+        validator_set_and_stake_owners,
+        initial_epoch,
+        rounds_per_epoch,
+        num_unstake_epochs,
+        emission_xrd_per_epoch,
+        min_validator_reliability: dec!("0.5"),
+        ..Default::default()
+    });
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let validator1_address = test_runner.get_validator_with_key(&validator1_pub_key);
+    let validator2_address = test_runner.get_validator_with_key(&validator2_pub_key);
+
+    // Act
+    let instructions = vec![Instruction::System(NativeInvocation::EpochManager(
+        EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
+            receiver: EPOCH_MANAGER,
+            round: rounds_per_epoch,
+            leader: Some(validator1_address),
+            missed_leaders: vec![validator2_address],
+        }),
+    ))];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let result = receipt.expect_commit();
+    let next_epoch = result.next_epoch.as_ref().expect("Should have next epoch");
+    assert_eq!(
+        next_epoch.0.get(&validator1_address).unwrap().stake,
+        Decimal::from(60)
+    );
+    assert!(!next_epoch.0.contains_key(&validator2_address));
+}
+
+#[test]
+fn next_round_with_leader_outside_validator_set_fails() {
+    // Arrange
+    let rounds_per_epoch = 5u64;
+    let genesis = create_genesis(GenesisConfig {
+        rounds_per_epoch,
+        ..Default::default()
+    });
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+
+    // Act
+    let instructions = vec![Instruction::System(NativeInvocation::EpochManager(
+        EpochManagerInvocation::NextRound(EpochManagerNextRoundInvocation {
+            receiver: EPOCH_MANAGER,
+            round: 1,
+            leader: Some(FAUCET_COMPONENT),
+            missed_leaders: vec![],
+        }),
+    ))];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::validator_role()]),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::InvalidLeader(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn set_epoch_with_overflowing_emission_fails() {
+    // Arrange
+    let genesis = create_genesis(GenesisConfig {
+        emission_xrd_per_epoch: Decimal::MAX / 2,
+        ..Default::default()
+    });
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+
+    // Act
+    let instructions = vec![Instruction::System(NativeInvocation::EpochManager(
+        EpochManagerInvocation::SetEpoch(EpochManagerSetEpochInvocation {
+            receiver: EPOCH_MANAGER,
+            epoch: 4,
+        }),
+    ))];
+    let receipt = test_runner.execute_transaction(
+        SystemTransaction {
+            instructions,
+            blobs: vec![],
+            nonce: 0,
+            pre_allocated_ids: BTreeSet::new(),
+        }
+        .get_executable(vec![AuthAddresses::system_role()]),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::EpochManagerError(
+                EpochManagerError::EmissionOverflow { skipped_epochs: 3 }
+            ))
+        )
+    });
+}
@@ -0,0 +1,244 @@
+use radix_engine::engine::{
+    ApplicationError, AuthError, ModuleError, ResolvedActor, ResolvedReceiver, RuntimeError,
+};
+use radix_engine::model::{MethodAuthorizationError, ResourceManagerError, VaultError};
+use radix_engine::types::*;
+use radix_engine_interface::api::types::RENodeId;
+use radix_engine_interface::model::FromPublicKey;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+fn freeze_vault(
+    test_runner: &mut TestRunner,
+    resource_address: ResourceAddress,
+    vault_id: VaultId,
+) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .freeze_vault(resource_address, vault_id)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+}
+
+#[test]
+fn cannot_freeze_vault_of_non_freezable_resource() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    let resource_address = test_runner.create_fungible_resource(10u32.into(), 0u8, account);
+    let vaults = test_runner.get_component_vaults(account, resource_address);
+    let vault_id = vaults[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .freeze_vault(resource_address, vault_id)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
+                actor: ResolvedActor {
+                    identifier: FnIdentifier::Native(NativeFn::ResourceManager(
+                        ResourceManagerFn::FreezeVault
+                    )),
+                    receiver: Some(ResolvedReceiver {
+                        receiver: RENodeId::ResourceManager(..),
+                        ..
+                    })
+                },
+                error: MethodAuthorizationError::NotAuthorized,
+                ..
+            },))
+        )
+    });
+}
+
+#[test]
+fn cannot_withdraw_from_frozen_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    let freezable_token = test_runner.create_freezable_token(account);
+    let vaults = test_runner.get_component_vaults(account, freezable_token);
+    let vault_id = vaults[0];
+    freeze_vault(&mut test_runner, freezable_token, vault_id);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .withdraw_from_account_by_amount(account, Decimal::one(), freezable_token)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::VaultFrozen(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn cannot_deposit_into_frozen_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    let freezable_token = test_runner.create_freezable_token(account);
+    let vaults = test_runner.get_component_vaults(account, freezable_token);
+    let vault_id = vaults[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .withdraw_from_account_by_amount(account, Decimal::one(), freezable_token)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+    let other_vault_id = test_runner.get_component_vaults(other_account, freezable_token)[0];
+    freeze_vault(&mut test_runner, freezable_token, vault_id);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .recall(other_vault_id, Decimal::one())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::VaultFrozen(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn can_recall_from_frozen_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    let freezable_token = test_runner.create_freezable_token(account);
+    let vaults = test_runner.get_component_vaults(account, freezable_token);
+    let vault_id = vaults[0];
+    freeze_vault(&mut test_runner, freezable_token, vault_id);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .recall(vault_id, Decimal::one())
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn can_withdraw_from_unfrozen_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    let freezable_token = test_runner.create_freezable_token(account);
+    let vaults = test_runner.get_component_vaults(account, freezable_token);
+    let vault_id = vaults[0];
+    freeze_vault(&mut test_runner, freezable_token, vault_id);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .unfreeze_vault(freezable_token, vault_id)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .withdraw_from_account_by_amount(account, Decimal::one(), freezable_token)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_freeze_vault_of_another_resource() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    let freezable_token = test_runner.create_freezable_token(account);
+    let vaults = test_runner.get_component_vaults(account, RADIX_TOKEN);
+    let vault_id = vaults[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .freeze_vault(freezable_token, vault_id)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::MismatchingVaultResource
+            ))
+        )
+    });
+}
@@ -1,4 +1,4 @@
-use radix_engine::engine::ScryptoInterpreter;
+use radix_engine::engine::{SchemaCache, ScryptoInterpreter};
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::transaction::{
     execute_and_commit_transaction, ExecutionConfig, FeeReserveConfig,
@@ -28,6 +28,7 @@ fn execute_single_transaction(transaction: NotarizedTransaction) {
     let mut scrypto_interpreter = ScryptoInterpreter {
         wasm_engine: DefaultWasmEngine::default(),
         wasm_instrumenter: WasmInstrumenter::default(),
+        schema_cache: SchemaCache::default(),
         wasm_metering_config: WasmMeteringConfig::V0,
     };
     let mut store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
@@ -1,3 +1,5 @@
+use radix_engine::engine::{ApplicationError, RuntimeError};
+use radix_engine::model::MetadataError;
 use radix_engine::types::*;
 use radix_engine_interface::args;
 use scrypto_unit::*;
@@ -19,3 +21,51 @@ fn can_set_component_metadata() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn can_remove_component_metadata() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/metadata_component");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "remove_metadata",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_set_or_remove_locked_component_metadata() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/metadata_component");
+
+    for function in ["set_locked_metadata", "remove_locked_metadata"] {
+        // Act
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_function(package_address, "MetadataComponent", function, args!())
+            .build();
+        let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+        // Assert
+        receipt.expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::ApplicationError(ApplicationError::MetadataError(
+                    MetadataError::KeyLocked(..)
+                ))
+            )
+        });
+    }
+}
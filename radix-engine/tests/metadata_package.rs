@@ -1,4 +1,5 @@
-use radix_engine::engine::{AuthError, ModuleError, RuntimeError};
+use radix_engine::engine::{ApplicationError, AuthError, ModuleError, RuntimeError};
+use radix_engine::model::MetadataError;
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -27,7 +28,7 @@ fn cannot_set_package_metadata_with_no_owner() {
         .set_metadata(
             GlobalAddress::Package(package_address),
             "name".to_string(),
-            "best package ever!".to_string(),
+            MetadataEntry::from("best package ever!"),
         )
         .build();
     let receipt = test_runner.execute_manifest(manifest, vec![]);
@@ -71,7 +72,7 @@ fn can_set_package_metadata_with_owner() {
         .set_metadata(
             GlobalAddress::Package(package_address),
             "name".to_string(),
-            "best package ever!".to_string(),
+            MetadataEntry::from("best package ever!"),
         )
         .build();
     let receipt = test_runner.execute_manifest(
@@ -82,7 +83,66 @@ fn can_set_package_metadata_with_owner() {
     // Assert
     receipt.expect_commit_success();
     let metadata = test_runner.get_metadata(GlobalAddress::Package(package_address));
-    assert_eq!(metadata.get("name").unwrap(), "best package ever!");
+    assert_eq!(
+        metadata.get("name").unwrap(),
+        &MetadataEntry::from("best package ever!")
+    );
+}
+
+#[test]
+fn can_set_typed_package_metadata_with_owner() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let code = wat2wasm(include_str!("wasm/basic_package.wat"));
+    let (public_key, _, account) = test_runner.new_account(false);
+    let owner_badge_resource = test_runner.create_non_fungible_resource(account);
+    let owner_badge_addr =
+        NonFungibleGlobalId::new(owner_badge_resource, NonFungibleLocalId::integer(1));
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .publish_package_with_owner(
+            code,
+            generate_single_function_abi("Test", "f", Type::Any),
+            owner_badge_addr,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    let package_address = receipt.expect_commit().entity_changes.new_package_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account(account, owner_badge_resource)
+        .set_metadata(
+            GlobalAddress::Package(package_address),
+            "icon_url".to_string(),
+            Url::of("https://example.com/icon.png").into(),
+        )
+        .set_metadata(
+            GlobalAddress::Package(package_address),
+            "linked_resources".to_string(),
+            vec![GlobalAddress::Resource(owner_badge_resource)].into(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let metadata = test_runner.get_metadata(GlobalAddress::Package(package_address));
+    assert_eq!(
+        Url::from_metadata_entry(metadata.get("icon_url").unwrap().clone()),
+        Some(Url::of("https://example.com/icon.png"))
+    );
+    assert_eq!(
+        Vec::<GlobalAddress>::from_metadata_entry(
+            metadata.get("linked_resources").unwrap().clone()
+        ),
+        Some(vec![GlobalAddress::Resource(owner_badge_resource)])
+    );
 }
 
 #[test]
@@ -130,7 +190,7 @@ fn can_lock_package_metadata_with_owner() {
         .set_metadata(
             GlobalAddress::Package(package_address),
             "name".to_string(),
-            "best package ever!".to_string(),
+            MetadataEntry::from("best package ever!"),
         )
         .build();
     let receipt = test_runner.execute_manifest(
@@ -148,3 +208,167 @@ fn can_lock_package_metadata_with_owner() {
     let metadata = test_runner.get_metadata(GlobalAddress::Package(package_address));
     assert!(metadata.get("name").is_none());
 }
+
+#[test]
+fn can_remove_package_metadata_with_owner() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let code = wat2wasm(include_str!("wasm/basic_package.wat"));
+    let (public_key, _, account) = test_runner.new_account(false);
+    let owner_badge_resource = test_runner.create_non_fungible_resource(account);
+    let owner_badge_addr =
+        NonFungibleGlobalId::new(owner_badge_resource, NonFungibleLocalId::integer(1));
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .publish_package_with_owner(
+            code,
+            generate_single_function_abi("Test", "f", Type::Any),
+            owner_badge_addr,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    let package_address = receipt.expect_commit().entity_changes.new_package_addresses[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account(account, owner_badge_resource)
+        .set_metadata(
+            GlobalAddress::Package(package_address),
+            "name".to_string(),
+            MetadataEntry::from("best package ever!"),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account(account, owner_badge_resource)
+        .remove_metadata(GlobalAddress::Package(package_address), "name".to_string())
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let metadata = test_runner.get_metadata(GlobalAddress::Package(package_address));
+    assert!(metadata.get("name").is_none());
+}
+
+#[test]
+fn cannot_lock_package_metadata_key_with_no_owner() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let code = wat2wasm(include_str!("wasm/basic_package.wat"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .publish_package(
+            code,
+            generate_single_function_abi("Test", "f", Type::Any),
+            BTreeMap::new(),
+            BTreeMap::new(),
+            AccessRules::new(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let package_address = receipt.expect_commit().entity_changes.new_package_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .lock_metadata(GlobalAddress::Package(package_address), "name".to_string())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
+#[test]
+fn cannot_set_or_remove_locked_package_metadata_key() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let code = wat2wasm(include_str!("wasm/basic_package.wat"));
+    let (public_key, _, account) = test_runner.new_account(false);
+    let owner_badge_resource = test_runner.create_non_fungible_resource(account);
+    let owner_badge_addr =
+        NonFungibleGlobalId::new(owner_badge_resource, NonFungibleLocalId::integer(1));
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .publish_package_with_owner(
+            code,
+            generate_single_function_abi("Test", "f", Type::Any),
+            owner_badge_addr,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    let package_address = receipt.expect_commit().entity_changes.new_package_addresses[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account(account, owner_badge_resource)
+        .set_metadata(
+            GlobalAddress::Package(package_address),
+            "name".to_string(),
+            MetadataEntry::from("best package ever!"),
+        )
+        .lock_metadata(GlobalAddress::Package(package_address), "name".to_string())
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+
+    // Act
+    let set_manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account(account, owner_badge_resource)
+        .set_metadata(
+            GlobalAddress::Package(package_address),
+            "name".to_string(),
+            MetadataEntry::from("worst package ever!"),
+        )
+        .build();
+    let set_receipt = test_runner.execute_manifest(
+        set_manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let remove_manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account(account, owner_badge_resource)
+        .remove_metadata(GlobalAddress::Package(package_address), "name".to_string())
+        .build();
+    let remove_receipt = test_runner.execute_manifest(
+        remove_manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    for receipt in [set_receipt, remove_receipt] {
+        receipt.expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::ApplicationError(ApplicationError::MetadataError(
+                    MetadataError::KeyLocked(..)
+                ))
+            )
+        });
+    }
+    let metadata = test_runner.get_metadata(GlobalAddress::Package(package_address));
+    assert_eq!(
+        metadata.get("name").unwrap(),
+        &MetadataEntry::from("best package ever!")
+    );
+}
@@ -41,7 +41,7 @@ fn can_set_validator_metadata_with_owner() {
         .set_metadata(
             GlobalAddress::Component(component_address),
             "name".to_string(),
-            "best package ever!".to_string(),
+            MetadataEntry::from("best package ever!"),
         )
         .build();
     let receipt = test_runner.execute_manifest(manifest, vec![owner_id]);
@@ -49,7 +49,10 @@ fn can_set_validator_metadata_with_owner() {
     // Assert
     receipt.expect_commit_success();
     let metadata = test_runner.get_metadata(GlobalAddress::Component(component_address));
-    assert_eq!(metadata.get("name").unwrap(), "best package ever!");
+    assert_eq!(
+        metadata.get("name").unwrap(),
+        &MetadataEntry::from("best package ever!")
+    );
 }
 
 #[test]
@@ -67,7 +70,7 @@ fn cannot_set_validator_metadata_without_owner() {
         .set_metadata(
             GlobalAddress::Component(component_address),
             "name".to_string(),
-            "best package ever!".to_string(),
+            MetadataEntry::from("best package ever!"),
         )
         .build();
     let receipt = test_runner.execute_manifest(manifest, vec![]);
@@ -1,3 +1,6 @@
+use radix_engine::engine::{ApplicationError, RuntimeError};
+use radix_engine::model::{AuthZoneError, ResourceManagerError};
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::model::FromPublicKey;
 use scrypto::NonFungibleData;
@@ -516,3 +519,110 @@ fn can_mint_uuid_non_fungible_in_manifest() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn call_non_fungible_data_field_function(function_name: &str) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "NonFungibleTest", function_name, args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn can_update_mutable_non_fungible_data_field() {
+    // Act
+    let receipt = call_non_fungible_data_field_function("update_non_fungible_data_field");
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_update_non_fungible_data_field_with_wrong_type() {
+    // Act
+    let receipt =
+        call_non_fungible_data_field_function("update_non_fungible_data_field_with_wrong_type");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::InvalidNonFungibleDataFieldValue(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_update_immutable_non_fungible_data_field() {
+    // Act
+    let receipt = call_non_fungible_data_field_function("update_immutable_non_fungible_data_field");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::NonFungibleDataFieldNotFound(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_update_non_fungible_data_field_without_badge() {
+    // Act
+    let receipt =
+        call_non_fungible_data_field_function("update_non_fungible_data_field_without_badge");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_update_non_fungible_data_field_after_revoking_data_rule() {
+    // Act
+    let receipt = call_non_fungible_data_field_function(
+        "update_non_fungible_data_field_after_revoking_data_rule",
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn can_update_non_fungible_data_field_with_its_own_rule() {
+    // Act
+    let receipt =
+        call_non_fungible_data_field_function("update_non_fungible_data_field_with_field_rule");
+
+    // Assert
+    receipt.expect_commit_success();
+}
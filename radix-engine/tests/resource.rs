@@ -1,7 +1,9 @@
-use radix_engine::engine::{ApplicationError, RuntimeError};
-use radix_engine::model::ResourceManagerError;
+use radix_engine::engine::{ApplicationError, AuthError, ModuleError, RuntimeError};
+use radix_engine::model::{MetadataError, ResourceManagerError};
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::model::FromPublicKey;
+use radix_engine_interface::rule;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
 
@@ -65,6 +67,139 @@ fn test_resource_manager() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn set_or_remove_locked_resource_metadata_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+
+    for function in [
+        "set_locked_resource_metadata",
+        "remove_locked_resource_metadata",
+    ] {
+        // Act
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_function(package_address, "ResourceTest", function, args!())
+            .call_method(
+                account,
+                "deposit_batch",
+                args!(ManifestExpression::EntireWorktop),
+            )
+            .build();
+        let receipt = test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        );
+
+        // Assert
+        receipt.expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::ApplicationError(ApplicationError::MetadataError(
+                    MetadataError::KeyLocked(..)
+                ))
+            )
+        });
+    }
+}
+
+fn call_resource_metadata_key_function(function_name: &str) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "ResourceTest", function_name, args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn can_set_resource_metadata_key_with_its_own_rule() {
+    // Act
+    let receipt =
+        call_resource_metadata_key_function("set_resource_metadata_key_with_its_own_rule");
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_set_other_resource_metadata_key_with_key_rule() {
+    // Act
+    let receipt =
+        call_resource_metadata_key_function("set_other_resource_metadata_key_with_key_rule");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
+#[test]
+fn set_metadata_access_rule_of_resource_applies_to_set_metadata() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let mut access_rules = BTreeMap::new();
+    access_rules.insert(
+        ResourceMethodAuthKey::UpdateMetadata,
+        (rule!(allow_all), rule!(allow_all)),
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_fungible_resource(0, BTreeMap::new(), access_rules, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let resource_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .set_method_access_rule(
+            GlobalAddress::Resource(resource_address),
+            0,
+            AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Set)),
+            rule!(deny_all),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .set_metadata(
+            GlobalAddress::Resource(resource_address),
+            "name".to_string(),
+            MetadataEntry::from("TestToken"),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
 #[test]
 fn mint_with_bad_granularity_should_fail() {
     // Arrange
@@ -142,3 +277,229 @@ fn mint_too_much_should_fail() {
         )
     })
 }
+
+fn create_fungible_with_supply_limits(
+    test_runner: &mut TestRunner,
+    max_supply: Option<Decimal>,
+    mint_limit_per_epoch: Option<Decimal>,
+) -> ResourceAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "ResourceTest",
+            "create_fungible_with_supply_limits",
+            args!(max_supply, mint_limit_per_epoch),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0]
+}
+
+fn mint(
+    test_runner: &mut TestRunner,
+    resource_address: ResourceAddress,
+    amount: Decimal,
+    account: ComponentAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .mint_fungible(resource_address, amount)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn mint_beyond_max_supply_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        create_fungible_with_supply_limits(&mut test_runner, Some(dec!("100")), None);
+    mint(&mut test_runner, resource_address, dec!("60"), account).expect_commit_success();
+
+    // Act
+    let receipt = mint(&mut test_runner, resource_address, dec!("60"), account);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::MaxSupplyExceeded
+            ))
+        )
+    })
+}
+
+#[test]
+fn initial_supply_beyond_max_supply_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "ResourceTest",
+            "create_fungible_with_initial_supply_above_max_supply",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::MaxSupplyExceeded
+            ))
+        )
+    })
+}
+
+#[test]
+fn set_max_supply_below_total_supply_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "ResourceTest",
+            "set_max_supply_below_total_supply",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::MaxSupplyBelowTotalSupply
+            ))
+        )
+    })
+}
+
+#[test]
+fn create_with_non_positive_supply_limit_should_fail() {
+    for (max_supply, mint_limit_per_epoch, invalid_limit) in [
+        (Some(Decimal::zero()), None, Decimal::zero()),
+        (None, Some(dec!("-1")), dec!("-1")),
+    ] {
+        // Arrange
+        let mut test_runner = TestRunner::builder().build();
+        let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+
+        // Act
+        let manifest = ManifestBuilder::new()
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_function(
+                package_address,
+                "ResourceTest",
+                "create_fungible_with_supply_limits",
+                args!(max_supply, mint_limit_per_epoch),
+            )
+            .build();
+        let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+        // Assert
+        receipt.expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                    ResourceManagerError::InvalidSupplyLimit(limit)
+                )) if *limit == invalid_limit
+            )
+        })
+    }
+}
+
+#[test]
+fn set_mint_limit_per_epoch_to_zero_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "ResourceTest",
+            "set_mint_limit_per_epoch_to_zero",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::InvalidSupplyLimit(..)
+            ))
+        )
+    })
+}
+
+#[test]
+fn mint_beyond_mint_limit_per_epoch_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        create_fungible_with_supply_limits(&mut test_runner, None, Some(dec!("100")));
+    mint(&mut test_runner, resource_address, dec!("60"), account).expect_commit_success();
+
+    // Act
+    let receipt = mint(&mut test_runner, resource_address, dec!("60"), account);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::MintLimitPerEpochExceeded
+            ))
+        )
+    })
+}
+
+#[test]
+fn can_mint_up_to_mint_limit_again_in_next_epoch() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        create_fungible_with_supply_limits(&mut test_runner, None, Some(dec!("100")));
+    mint(&mut test_runner, resource_address, dec!("100"), account).expect_commit_success();
+    let epoch = test_runner.get_current_epoch();
+    test_runner.set_current_epoch(epoch + 1);
+
+    // Act
+    let receipt = mint(&mut test_runner, resource_address, dec!("100"), account);
+
+    // Assert
+    receipt.expect_commit_success();
+}
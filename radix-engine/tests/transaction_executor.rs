@@ -1,5 +1,5 @@
 use radix_engine::engine::RejectionError;
-use radix_engine::engine::ScryptoInterpreter;
+use radix_engine::engine::{SchemaCache, ScryptoInterpreter};
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::transaction::execute_and_commit_transaction;
 use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
@@ -117,6 +117,7 @@ fn test_normal_transaction_flow() {
     let mut scrypto_interpreter = ScryptoInterpreter {
         wasm_engine: DefaultWasmEngine::default(),
         wasm_instrumenter: WasmInstrumenter::default(),
+        schema_cache: SchemaCache::default(),
         wasm_metering_config: WasmMeteringConfig::V0,
     };
     let mut substate_store = TypedInMemorySubstateStore::with_bootstrap(&scrypto_interpreter);
@@ -154,6 +155,102 @@ fn test_normal_transaction_flow() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn sponsored_transaction_pays_fee_from_fee_payer_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, fee_payer_private_key, fee_payer_account) = test_runner.new_account(true);
+    let balance_before = test_runner.get_component_resources(fee_payer_account)[&RADIX_TOKEN];
+    let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .header(create_header(&sk_notary))
+        .manifest(ManifestBuilder::new().clear_auth_zone().build())
+        .fee_payer(fee_payer_account, &fee_payer_private_key)
+        .notarize(&sk_notary)
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_transaction(get_executable(&transaction).unwrap());
+
+    // Assert
+    receipt.expect_commit_success();
+    let balance_after = test_runner.get_component_resources(fee_payer_account)[&RADIX_TOKEN];
+    assert_eq!(
+        balance_before - balance_after,
+        receipt.execution.fee_summary.total_execution_cost_xrd
+            + receipt.execution.fee_summary.total_royalty_cost_xrd
+    );
+}
+
+#[test]
+fn fee_payer_proofs_are_not_visible_to_manifest() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, fee_payer_private_key, fee_payer_account) = test_runner.new_account(true);
+    let (_, _, other_account) = test_runner.new_account(true);
+    let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .header(create_header(&sk_notary))
+        .manifest(
+            ManifestBuilder::new()
+                .withdraw_from_account(fee_payer_account, RADIX_TOKEN)
+                .call_method(
+                    other_account,
+                    "deposit_batch",
+                    args!(ManifestExpression::EntireWorktop),
+                )
+                .build(),
+        )
+        .fee_payer(fee_payer_account, &fee_payer_private_key)
+        .notarize(&sk_notary)
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_transaction(get_executable(&transaction).unwrap());
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn sponsored_transaction_with_insufficient_fee_payer_balance_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (fee_payer_public_key, fee_payer_private_key) = test_runner.new_key_pair();
+    let fee_payer_account =
+        ComponentAddress::virtual_account_from_public_key(&fee_payer_public_key);
+    let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+
+    let transaction = TransactionBuilder::new()
+        .header(create_header(&sk_notary))
+        .manifest(ManifestBuilder::new().clear_auth_zone().build())
+        .fee_payer(fee_payer_account, &fee_payer_private_key)
+        .notarize(&sk_notary)
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_transaction(get_executable(&transaction).unwrap());
+
+    // Assert
+    receipt.expect_rejection();
+}
+
+fn create_header(sk_notary: &EcdsaSecp256k1PrivateKey) -> TransactionHeader {
+    TransactionHeader {
+        version: 1,
+        network_id: NetworkDefinition::simulator().id,
+        start_epoch_inclusive: 0,
+        end_epoch_exclusive: 10,
+        nonce: 5,
+        notary_public_key: sk_notary.public_key().into(),
+        notary_as_signatory: false,
+        cost_unit_limit: DEFAULT_COST_UNIT_LIMIT,
+        tip_percentage: 5,
+    }
+}
+
 fn get_executable<'a>(
     transaction: &'a NotarizedTransaction,
 ) -> Result<Executable<'a>, TransactionValidationError> {
@@ -28,6 +28,28 @@ pub fn handle_categorize(
         build_custom_categorize_generic(&generics, &attrs, context_custom_value_kind)?;
 
     let output = match data {
+        Data::Struct(s) if is_transparent(&attrs) => {
+            let (field, _) = get_transparent_field(&ident, &s.fields, is_encoding_skipped)?;
+            let field_type = &field.ty;
+            let where_clause = add_where_predicate(
+                where_clause,
+                parse_quote!(#field_type: ::sbor::Categorize<#sbor_cvk>),
+            );
+            quote! {
+                impl #impl_generics ::sbor::Categorize <#sbor_cvk> for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn value_kind() -> ::sbor::ValueKind <#sbor_cvk> {
+                        <#field_type as ::sbor::Categorize<#sbor_cvk>>::value_kind()
+                    }
+                }
+            }
+        }
+        _ if is_transparent(&attrs) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Only structs can be transparent",
+            ));
+        }
         Data::Struct(_) => quote! {
             impl #impl_generics ::sbor::Categorize <#sbor_cvk> for #ident #ty_generics #where_clause {
                 #[inline]
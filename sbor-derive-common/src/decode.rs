@@ -28,6 +28,47 @@ pub fn handle_decode(
         build_decode_generics(&generics, &attrs, context_custom_value_kind)?;
 
     let output = match data {
+        Data::Struct(s) if is_transparent(&attrs) => {
+            let (field, _) = get_transparent_field(&ident, &s.fields, is_decoding_skipped)?;
+            let field_type = &field.ty;
+            let where_clause = add_where_predicate(
+                where_clause,
+                parse_quote!(#field_type: ::sbor::Decode<#custom_value_kind_generic, #decoder_generic>),
+            );
+            let mut fields = Vec::<TokenStream>::new();
+            for (i, f) in s.fields.iter().enumerate() {
+                let value: Expr = if is_decoding_skipped(f) {
+                    get_skipped_field_default(f)?
+                } else {
+                    parse_quote! {
+                        <#field_type as ::sbor::Decode<#custom_value_kind_generic, #decoder_generic>>::decode_body_with_value_kind(decoder, value_kind)?
+                    }
+                };
+                fields.push(match &f.ident {
+                    Some(ident) => quote! { #ident: #value },
+                    None => {
+                        let index = Index::from(i);
+                        quote! { #index: #value }
+                    }
+                });
+            }
+            quote! {
+                impl #impl_generics ::sbor::Decode <#custom_value_kind_generic, #decoder_generic> for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn decode_body_with_value_kind(decoder: &mut #decoder_generic, value_kind: ::sbor::ValueKind<#custom_value_kind_generic>) -> Result<Self, ::sbor::DecodeError> {
+                        Ok(Self {
+                            #(#fields,)*
+                        })
+                    }
+                }
+            }
+        }
+        _ if is_transparent(&attrs) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Only structs can be transparent",
+            ));
+        }
         Data::Struct(s) => match s.fields {
             syn::Fields::Named(FieldsNamed { named, .. }) => {
                 // ns: not skipped, s: skipped
@@ -37,7 +78,10 @@ pub fn handle_decode(
                 let ns_types = ns.iter().map(|f| &f.ty);
                 let s: Vec<&Field> = named.iter().filter(|f| is_decoding_skipped(f)).collect();
                 let s_ids = s.iter().map(|f| &f.ident);
-                let s_types = s.iter().map(|f| &f.ty);
+                let s_defaults = s
+                    .iter()
+                    .map(|f| get_skipped_field_default(f))
+                    .collect::<Result<Vec<_>>>()?;
                 quote! {
                     impl #impl_generics ::sbor::Decode <#custom_value_kind_generic, #decoder_generic> for #ident #ty_generics #where_clause {
                         #[inline]
@@ -47,7 +91,7 @@ pub fn handle_decode(
                             decoder.read_and_check_size(#ns_len)?;
                             Ok(Self {
                                 #(#ns_ids: decoder.decode::<#ns_types>()?,)*
-                                #(#s_ids: <#s_types>::default()),*
+                                #(#s_ids: #s_defaults),*
                             })
                         }
                     }
@@ -58,7 +102,7 @@ pub fn handle_decode(
                 for f in &unnamed {
                     let ty = &f.ty;
                     if is_decoding_skipped(f) {
-                        fields.push(parse_quote! {<#ty>::default()})
+                        fields.push(get_skipped_field_default(f)?)
                     } else {
                         fields.push(parse_quote! {decoder.decode::<#ty>()?})
                     }
@@ -93,63 +137,69 @@ pub fn handle_decode(
             }
         },
         Data::Enum(DataEnum { variants, .. }) => {
-            let match_arms = variants.iter().enumerate().map(|(i, v)| {
-                let v_id = &v.ident;
-                let i: u8 = i.try_into().expect("Too many variants found in enum");
-                let discriminator: Expr = parse_quote! { #i };
+            let discriminators = get_variant_discriminators(&variants)?;
+            let match_arms = variants
+                .iter()
+                .zip(discriminators)
+                .map(|(v, discriminator)| {
+                    let v_id = &v.ident;
+                    let discriminator: Expr = parse_quote! { #discriminator };
 
-                match &v.fields {
-                    syn::Fields::Named(FieldsNamed { named, .. }) => {
-                        let ns: Vec<&Field> =
-                            named.iter().filter(|f| !is_decoding_skipped(f)).collect();
-                        let ns_len = Index::from(ns.len());
-                        let ns_ids = ns.iter().map(|f| &f.ident);
-                        let ns_types = ns.iter().map(|f| &f.ty);
-                        let s: Vec<&Field> =
-                            named.iter().filter(|f| is_decoding_skipped(f)).collect();
-                        let s_ids = s.iter().map(|f| &f.ident);
-                        let s_types = s.iter().map(|f| &f.ty);
-                        quote! {
-                            #discriminator => {
-                                decoder.read_and_check_size(#ns_len)?;
-                                Ok(Self::#v_id {
-                                    #(#ns_ids: decoder.decode::<#ns_types>()?,)*
-                                    #(#s_ids: <#s_types>::default(),)*
-                                })
-                            }
-                        }
-                    }
-                    syn::Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
-                        let mut fields = Vec::<Expr>::new();
-                        for f in unnamed {
-                            let ty = &f.ty;
-                            if is_decoding_skipped(f) {
-                                fields.push(parse_quote! {<#ty>::default()})
-                            } else {
-                                fields.push(parse_quote! {decoder.decode::<#ty>()?})
-                            }
+                    match &v.fields {
+                        syn::Fields::Named(FieldsNamed { named, .. }) => {
+                            let ns: Vec<&Field> =
+                                named.iter().filter(|f| !is_decoding_skipped(f)).collect();
+                            let ns_len = Index::from(ns.len());
+                            let ns_ids = ns.iter().map(|f| &f.ident);
+                            let ns_types = ns.iter().map(|f| &f.ty);
+                            let s: Vec<&Field> =
+                                named.iter().filter(|f| is_decoding_skipped(f)).collect();
+                            let s_ids = s.iter().map(|f| &f.ident);
+                            let s_defaults = s
+                                .iter()
+                                .map(|f| get_skipped_field_default(f))
+                                .collect::<Result<Vec<_>>>()?;
+                            Ok(quote! {
+                                #discriminator => {
+                                    decoder.read_and_check_size(#ns_len)?;
+                                    Ok(Self::#v_id {
+                                        #(#ns_ids: decoder.decode::<#ns_types>()?,)*
+                                        #(#s_ids: #s_defaults,)*
+                                    })
+                                }
+                            })
                         }
-                        let ns_len =
-                            Index::from(unnamed.iter().filter(|f| !is_decoding_skipped(f)).count());
-                        quote! {
-                            #discriminator => {
-                                decoder.read_and_check_size(#ns_len)?;
-                                Ok(Self::#v_id (
-                                    #(#fields),*
-                                ))
+                        syn::Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
+                            let mut fields = Vec::<Expr>::new();
+                            for f in unnamed {
+                                let ty = &f.ty;
+                                if is_decoding_skipped(f) {
+                                    fields.push(get_skipped_field_default(f)?)
+                                } else {
+                                    fields.push(parse_quote! {decoder.decode::<#ty>()?})
+                                }
                             }
+                            let ns_len = Index::from(
+                                unnamed.iter().filter(|f| !is_decoding_skipped(f)).count(),
+                            );
+                            Ok(quote! {
+                                #discriminator => {
+                                    decoder.read_and_check_size(#ns_len)?;
+                                    Ok(Self::#v_id (
+                                        #(#fields),*
+                                    ))
+                                }
+                            })
                         }
-                    }
-                    syn::Fields::Unit => {
-                        quote! {
+                        syn::Fields::Unit => Ok(quote! {
                             #discriminator => {
                                 decoder.read_and_check_size(0)?;
                                 Ok(Self::#v_id)
                             }
-                        }
+                        }),
                     }
-                }
-            });
+                })
+                .collect::<Result<Vec<_>>>()?;
 
             quote! {
                 impl #impl_generics ::sbor::Decode <#custom_value_kind_generic, #decoder_generic> for #ident #ty_generics #where_clause {
@@ -35,6 +35,33 @@ pub fn handle_describe(
         .collect::<Vec<_>>();
 
     let output = match data {
+        Data::Struct(s) if is_transparent(&attrs) => {
+            let (field, _) = get_transparent_field(&ident, &s.fields, is_encoding_skipped)?;
+            let field_type = &field.ty;
+            let where_clause = add_where_predicate(
+                where_clause,
+                parse_quote!(#field_type: ::sbor::Describe<#custom_type_kind_generic>),
+            );
+            quote! {
+                impl #impl_generics ::sbor::Describe <#custom_type_kind_generic> for #ident #ty_generics #where_clause {
+                    const TYPE_ID: ::sbor::GlobalTypeId = <#field_type as ::sbor::Describe<#custom_type_kind_generic>>::TYPE_ID;
+
+                    fn type_data() -> Option<::sbor::TypeData<#custom_type_kind_generic, ::sbor::GlobalTypeId>> {
+                        <#field_type as ::sbor::Describe<#custom_type_kind_generic>>::type_data()
+                    }
+
+                    fn add_all_dependencies(aggregator: &mut ::sbor::TypeAggregator<#custom_type_kind_generic>) {
+                        <#field_type as ::sbor::Describe<#custom_type_kind_generic>>::add_all_dependencies(aggregator)
+                    }
+                }
+            }
+        }
+        _ if is_transparent(&attrs) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Only structs can be transparent",
+            ));
+        }
         Data::Struct(s) => match s.fields {
             syn::Fields::Named(FieldsNamed { named, .. }) => {
                 let unskipped_fields: Vec<&Field> =
@@ -139,11 +166,7 @@ pub fn handle_describe(
             }
         },
         Data::Enum(DataEnum { variants, .. }) => {
-            let n: u8 = variants
-                .len()
-                .try_into()
-                .expect("Too many variants in enum");
-            let variant_indices: Vec<u8> = (0..n).into_iter().collect();
+            let variant_indices = get_variant_discriminators(&variants)?;
             let mut all_field_types = Vec::new();
 
             let variant_type_data: Vec<_> = {
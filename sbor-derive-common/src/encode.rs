@@ -28,6 +28,35 @@ pub fn handle_encode(
         build_encode_generics(&generics, &attrs, context_custom_value_kind)?;
 
     let output = match data {
+        Data::Struct(s) if is_transparent(&attrs) => {
+            let (field, member) = get_transparent_field(&ident, &s.fields, is_encoding_skipped)?;
+            let field_type = &field.ty;
+            let where_clause = add_where_predicate(
+                where_clause,
+                parse_quote!(#field_type: ::sbor::Encode<#custom_value_kind_generic, #encoder_generic>),
+            );
+            quote! {
+                impl #impl_generics ::sbor::Encode <#custom_value_kind_generic, #encoder_generic> for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn encode_value_kind(&self, encoder: &mut #encoder_generic) -> Result<(), ::sbor::EncodeError> {
+                        use ::sbor::{self, Encode};
+                        self.#member.encode_value_kind(encoder)
+                    }
+
+                    #[inline]
+                    fn encode_body(&self, encoder: &mut #encoder_generic) -> Result<(), ::sbor::EncodeError> {
+                        use ::sbor::{self, Encode};
+                        self.#member.encode_body(encoder)
+                    }
+                }
+            }
+        }
+        _ if is_transparent(&attrs) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Only structs can be transparent",
+            ));
+        }
         Data::Struct(s) => match s.fields {
             syn::Fields::Named(FieldsNamed { named, .. }) => {
                 // ns: not skipped
@@ -93,9 +122,10 @@ pub fn handle_encode(
             }
         },
         Data::Enum(DataEnum { variants, .. }) => {
+            let discriminators = get_variant_discriminators(&variants)?;
             let match_arms = variants.iter().enumerate().map(|(i, v)| {
                 let v_id = &v.ident;
-                let i: u8 = i.try_into().expect("Too many variants found in enum");
+                let i: u8 = discriminators[i];
                 let discriminator: Expr = parse_quote! { #i };
 
                 match &v.fields {
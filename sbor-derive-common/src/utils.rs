@@ -53,8 +53,17 @@ pub fn extract_attributes(
                     NestedMeta::Meta(m) => match m {
                         Meta::NameValue(name_value) => {
                             if let Some(ident) = name_value.path.get_ident() {
-                                if let Lit::Str(s) = name_value.lit {
-                                    fields.insert(ident.to_string(), Some(s.value()));
+                                match name_value.lit {
+                                    Lit::Str(s) => {
+                                        fields.insert(ident.to_string(), Some(s.value()));
+                                    }
+                                    Lit::Int(i) => {
+                                        fields.insert(
+                                            ident.to_string(),
+                                            Some(i.base10_digits().to_owned()),
+                                        );
+                                    }
+                                    _ => {}
                                 }
                             }
                         }
@@ -99,6 +108,111 @@ pub fn is_describing_skipped(f: &Field) -> bool {
     }
 }
 
+/// The value of a skipped field when decoding, from `#[sbor(default = "path::to::fn")]` if given,
+/// or else from its `Default` implementation.
+pub fn get_skipped_field_default(f: &Field) -> Result<Expr> {
+    let ty = &f.ty;
+    let default = extract_attributes(&f.attrs, "sbor")
+        .and_then(|fields| fields.get("default").cloned())
+        .flatten();
+    match default {
+        Some(path) => {
+            let path: Path = parse_str(&path)?;
+            Ok(parse_quote! { #path() })
+        }
+        None => Ok(parse_quote! { <#ty>::default() }),
+    }
+}
+
+/// Whether the type has `#[sbor(transparent)]`, so that it's encoded as its only unskipped field.
+pub fn is_transparent(attributes: &[Attribute]) -> bool {
+    if let Some(fields) = extract_attributes(attributes, "sbor") {
+        fields.contains_key("transparent")
+    } else {
+        false
+    }
+}
+
+/// The field which a `#[sbor(transparent)]` struct is encoded as, along with how to access it on
+/// `self`, eg `self.inner` or `self.0`.
+pub fn get_transparent_field<'a>(
+    ident: &Ident,
+    fields: &'a Fields,
+    is_skipped: impl Fn(&Field) -> bool,
+) -> Result<(&'a Field, Member)> {
+    let mut unskipped = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| !is_skipped(f))
+        .map(|(i, f)| {
+            let member = match &f.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(i)),
+            };
+            (f, member)
+        });
+    match (unskipped.next(), unskipped.next()) {
+        (Some(field), None) => Ok(field),
+        _ => Err(Error::new(
+            ident.span(),
+            "A transparent struct must have exactly one unskipped field",
+        )),
+    }
+}
+
+/// Adds a bound to the where clause of an impl. The field of a transparent struct needs a bound of
+/// its own, as its type may be generic.
+pub fn add_where_predicate(
+    where_clause: Option<&WhereClause>,
+    predicate: WherePredicate,
+) -> WhereClause {
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| WhereClause {
+        where_token: Default::default(),
+        predicates: Default::default(),
+    });
+    where_clause.predicates.push(predicate);
+    where_clause
+}
+
+/// The discriminators of the variants of an enum, in order.
+///
+/// A variant's discriminator is given by `#[sbor(discriminator = N)]`, or else is one more than the
+/// discriminator of the previous variant, starting from zero, like the discriminants of Rust enums.
+pub fn get_variant_discriminators<'a>(
+    variants: impl IntoIterator<Item = &'a Variant>,
+) -> Result<Vec<u8>> {
+    let mut discriminators = Vec::<u8>::new();
+    let mut next: Option<u8> = Some(0);
+    for variant in variants {
+        let explicit = extract_attributes(&variant.attrs, "sbor")
+            .and_then(|fields| fields.get("discriminator").cloned())
+            .flatten();
+        let discriminator = match explicit {
+            Some(value) => value.parse::<u8>().map_err(|_| {
+                Error::new(
+                    variant.ident.span(),
+                    format!("The discriminator {} is not a u8", value),
+                )
+            })?,
+            None => next.ok_or_else(|| {
+                Error::new(variant.ident.span(), "Too many variants found in enum")
+            })?,
+        };
+        if discriminators.contains(&discriminator) {
+            return Err(Error::new(
+                variant.ident.span(),
+                format!(
+                    "The discriminator {} is used by another variant",
+                    discriminator
+                ),
+            ));
+        }
+        discriminators.push(discriminator);
+        next = discriminator.checked_add(1);
+    }
+    Ok(discriminators)
+}
+
 pub fn get_custom_value_kind(attributes: &[Attribute]) -> Option<String> {
     if let Some(fields) = extract_attributes(attributes, "sbor") {
         fields.get("custom_value_kind").cloned().unwrap_or_default()
@@ -442,6 +556,35 @@ mod tests {
         assert_eq!(extract_attributes(&[attr], "mutable"), None);
     }
 
+    #[test]
+    fn test_extract_attribute_integer_values() {
+        let attr: Attribute = parse_quote! {
+            #[sbor(discriminator = 5)]
+        };
+        assert_eq!(
+            extract_attributes(&[attr], "sbor"),
+            Some(HashMap::from([(
+                "discriminator".to_owned(),
+                Some("5".to_owned())
+            )]))
+        );
+    }
+
+    #[test]
+    fn test_variant_discriminators() {
+        let input: ItemEnum = parse_str(
+            "enum Test { A, #[sbor(discriminator = 5)] B, C, #[sbor(discriminator = 1)] D }",
+        )
+        .unwrap();
+        assert_eq!(
+            get_variant_discriminators(&input.variants).unwrap(),
+            vec![0, 5, 6, 1]
+        );
+
+        let input: ItemEnum = parse_str("enum Test { A, #[sbor(discriminator = 0)] B }").unwrap();
+        assert!(get_variant_discriminators(&input.variants).is_err());
+    }
+
     #[test]
     fn test_extract_attribute_path() {
         let attr: Attribute = parse_quote! {
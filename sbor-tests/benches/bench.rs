@@ -1,7 +1,7 @@
 #[macro_use]
 extern crate bencher;
 use bencher::Bencher;
-use sbor::{basic_decode, basic_encode};
+use sbor::{basic_decode, basic_encode, BasicDecoder, BASIC_SBOR_V1_PAYLOAD_PREFIX};
 
 mod adapter;
 mod data;
@@ -41,6 +41,20 @@ fn decode_simple_sbor(b: &mut Bencher) {
     b.iter(|| basic_decode::<data::simple::SimpleStruct>(&bytes));
 }
 
+fn decode_simple_sbor_owned_fields(b: &mut Bencher) {
+    let t = data::get_simple_dataset(SIMPLE_REAPT);
+    let bytes = basic_encode(&(&t.string, &t.vector1)).unwrap();
+    b.iter(|| basic_decode::<(String, Vec<u8>)>(&bytes));
+}
+
+fn decode_simple_sbor_borrowed_fields(b: &mut Bencher) {
+    let t = data::get_simple_dataset(SIMPLE_REAPT);
+    let bytes = basic_encode(&(&t.string, &t.vector1)).unwrap();
+    b.iter(|| {
+        BasicDecoder::new(&bytes).decode_payload::<(&str, &[u8])>(BASIC_SBOR_V1_PAYLOAD_PREFIX)
+    });
+}
+
 benchmark_group!(
     encode_simple,
     encode_simple_json,
@@ -52,5 +66,7 @@ benchmark_group!(
     decode_simple_json,
     decode_simple_bincode,
     decode_simple_sbor,
+    decode_simple_sbor_owned_fields,
+    decode_simple_sbor_borrowed_fields,
 );
 benchmark_main!(encode_simple, decode_simple);
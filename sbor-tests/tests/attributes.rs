@@ -0,0 +1,182 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+#[derive(Debug, PartialEq, Categorize, Encode, Decode, Describe)]
+pub enum TestEnumWithDiscriminators {
+    A,
+    #[sbor(discriminator = 5)]
+    B(u32),
+    C {
+        x: u8,
+    },
+    #[sbor(discriminator = 1)]
+    D,
+}
+
+fn default_x() -> u32 {
+    7
+}
+
+#[derive(Debug, PartialEq, Categorize, Encode, Decode, Describe)]
+pub struct TestStructWithDefault {
+    #[sbor(skip, default = "default_x")]
+    pub x: u32,
+    pub y: u32,
+}
+
+#[derive(Debug, PartialEq, Categorize, Encode, Decode, Describe)]
+#[sbor(transparent)]
+pub struct TestTransparentNamed {
+    pub inner: u32,
+    #[sbor(skip)]
+    pub cached: u8,
+}
+
+#[derive(Debug, PartialEq, Categorize, Encode, Decode, Describe)]
+#[sbor(transparent)]
+pub struct TestTransparentUnnamed(Vec<u8>);
+
+// `Vec<T>` is only `Encode`, `Decode` and `Describe` if `T` is `Categorize` too
+#[derive(Debug, PartialEq, Categorize, Encode, Decode, Describe)]
+#[sbor(transparent)]
+pub struct TestTransparentGeneric<T> {
+    pub inner: Vec<T>,
+}
+
+#[test]
+fn test_enum_with_discriminators() {
+    let a = TestEnumWithDiscriminators::A;
+    let b = TestEnumWithDiscriminators::B(1);
+    let c = TestEnumWithDiscriminators::C { x: 2 };
+    let d = TestEnumWithDiscriminators::D;
+
+    let mut bytes = Vec::with_capacity(512);
+    let mut encoder = BasicEncoder::new(&mut bytes);
+    encoder.encode(&a).unwrap();
+    encoder.encode(&b).unwrap();
+    encoder.encode(&c).unwrap();
+    encoder.encode(&d).unwrap();
+
+    #[rustfmt::skip]
+    assert_eq!(
+        vec![
+            34, // enum type
+            0, // "A"
+            0, // number of fields
+
+            34, // enum type
+            5, // "B"
+            1, // number of fields
+            9, 1, 0, 0, 0, // field value
+
+            34, // enum type
+            6, // "C"
+            1, // number of fields
+            7, 2, // field value
+
+            34, // enum type
+            1, // "D"
+            0, // number of fields
+        ],
+        bytes
+    );
+
+    let mut decoder = BasicDecoder::new(&bytes);
+    assert_eq!(a, decoder.decode::<TestEnumWithDiscriminators>().unwrap());
+    assert_eq!(b, decoder.decode::<TestEnumWithDiscriminators>().unwrap());
+    assert_eq!(c, decoder.decode::<TestEnumWithDiscriminators>().unwrap());
+    assert_eq!(d, decoder.decode::<TestEnumWithDiscriminators>().unwrap());
+}
+
+#[test]
+fn test_enum_schema_uses_discriminators() {
+    let (type_ref, schema) =
+        generate_full_schema_from_single_type::<TestEnumWithDiscriminators, NoCustomTypeExtension>(
+        );
+
+    let type_data = schema.resolve(type_ref).unwrap();
+    match type_data.kind.into_owned() {
+        TypeKind::Enum { variants } => {
+            assert_eq!(
+                variants.keys().cloned().collect::<Vec<u8>>(),
+                vec![0, 1, 5, 6]
+            );
+        }
+        _ => panic!("Expected an enum type kind"),
+    }
+}
+
+#[test]
+fn test_struct_with_skip_default() {
+    let a = TestStructWithDefault { x: 1, y: 2 };
+
+    let bytes = basic_encode(&a).unwrap();
+    assert_eq!(
+        vec![
+            91, // payload prefix
+            33, // tuple type
+            1,  // number of fields
+            9, 2, 0, 0, 0, // field value
+        ],
+        bytes
+    );
+
+    assert_eq!(
+        TestStructWithDefault { x: 7, y: 2 },
+        basic_decode::<TestStructWithDefault>(&bytes).unwrap()
+    );
+}
+
+#[test]
+fn test_transparent_struct() {
+    let a = TestTransparentNamed {
+        inner: 5,
+        cached: 1,
+    };
+    let b = TestTransparentUnnamed(vec![1, 2, 3]);
+
+    let bytes_a = basic_encode(&a).unwrap();
+    let bytes_b = basic_encode(&b).unwrap();
+    assert_eq!(basic_encode(&5u32).unwrap(), bytes_a);
+    assert_eq!(basic_encode(&vec![1u8, 2, 3]).unwrap(), bytes_b);
+
+    assert_eq!(
+        TestTransparentNamed {
+            inner: 5,
+            cached: 0
+        },
+        basic_decode::<TestTransparentNamed>(&bytes_a).unwrap()
+    );
+    assert_eq!(b, basic_decode::<TestTransparentUnnamed>(&bytes_b).unwrap());
+
+    assert_eq!(
+        <TestTransparentNamed as Describe<NoCustomTypeKind>>::TYPE_ID,
+        <u32 as Describe<NoCustomTypeKind>>::TYPE_ID
+    );
+    assert_eq!(
+        <TestTransparentUnnamed as Describe<NoCustomTypeKind>>::TYPE_ID,
+        <Vec<u8> as Describe<NoCustomTypeKind>>::TYPE_ID
+    );
+}
+
+#[test]
+fn test_transparent_generic_struct() {
+    let a = TestTransparentGeneric {
+        inner: vec![1u32, 2],
+    };
+
+    let bytes = basic_encode(&a).unwrap();
+    assert_eq!(basic_encode(&vec![1u32, 2]).unwrap(), bytes);
+    assert_eq!(
+        a,
+        basic_decode::<TestTransparentGeneric<u32>>(&bytes).unwrap()
+    );
+
+    assert_eq!(
+        <TestTransparentGeneric<u32> as Describe<NoCustomTypeKind>>::TYPE_ID,
+        <Vec<u32> as Describe<NoCustomTypeKind>>::TYPE_ID
+    );
+}
@@ -0,0 +1,125 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sbor::rust::collections::BTreeMap;
+use sbor::rust::string::{String, ToString};
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+#[derive(Categorize, Encode, Decode, Describe)]
+pub struct Order {
+    pub items: Vec<Item>,
+    pub note: Option<String>,
+}
+
+#[derive(Categorize, Encode, Decode, Describe)]
+pub struct Item {
+    pub sku: [u8; 2],
+    pub quantity: u32,
+}
+
+#[derive(Categorize, Encode, Decode)]
+pub struct BadItem {
+    pub sku: Vec<u8>,
+    pub quantity: u32,
+}
+
+#[derive(Categorize, Encode, Decode)]
+pub struct BadOrder {
+    pub items: Vec<BadItem>,
+    pub note: Option<u32>,
+}
+
+fn validate<T: BasicEncode>(value: &T) -> Result<(), PayloadValidationError> {
+    let (index, schema) = generate_full_schema_from_single_type::<Order, NoCustomTypeExtension>();
+    validate_payload_against_schema(&basic_encode(value).unwrap(), &schema, index)
+}
+
+#[test]
+fn valid_payload_passes_validation() {
+    let order = Order {
+        items: vec![Item {
+            sku: [1, 2],
+            quantity: 3,
+        }],
+        note: Some("hello".to_string()),
+    };
+    assert_eq!(validate(&order), Ok(()));
+}
+
+#[test]
+fn length_validation_is_enforced() {
+    let order = BadOrder {
+        items: vec![
+            BadItem {
+                sku: vec![1, 2],
+                quantity: 3,
+            },
+            BadItem {
+                sku: vec![1, 2, 3],
+                quantity: 3,
+            },
+        ],
+        note: None,
+    };
+    assert_eq!(
+        validate(&order),
+        Err(PayloadValidationError {
+            path: "$.items[1].sku".to_string(),
+            error: PayloadValidationErrorKind::LengthValidationFailed {
+                type_name: "Bytes".to_string(),
+                length: 3
+            }
+        })
+    );
+}
+
+#[test]
+fn mismatching_value_kind_is_reported_with_path() {
+    let order = BadOrder {
+        items: vec![],
+        note: Some(5),
+    };
+    assert_eq!(
+        validate(&order),
+        Err(PayloadValidationError {
+            path: "$.note::Some.0".to_string(),
+            error: PayloadValidationErrorKind::MismatchingValueKind {
+                type_name: "String".to_string(),
+                value_kind: VALUE_KIND_U32,
+            }
+        })
+    );
+}
+
+#[test]
+fn structural_errors_are_reported() {
+    assert!(matches!(
+        validate(&(vec![0u8; 0],)),
+        Err(PayloadValidationError {
+            error: PayloadValidationErrorKind::MismatchingFieldCount {
+                expected: 2,
+                actual: 1
+            },
+            ..
+        })
+    ));
+    assert!(matches!(
+        validate(&BTreeMap::<u8, u8>::new()),
+        Err(PayloadValidationError {
+            error: PayloadValidationErrorKind::MismatchingValueKind { .. },
+            ..
+        })
+    ));
+    assert!(matches!(
+        validate_payload_against_schema(
+            &[0x00],
+            &generate_full_schema_from_single_type::<Order, NoCustomTypeExtension>().1,
+            LocalTypeIndex::SchemaLocalIndex(0)
+        ),
+        Err(PayloadValidationError {
+            error: PayloadValidationErrorKind::DecodeError(_),
+            ..
+        })
+    ));
+}
@@ -0,0 +1,188 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sbor::rust::string::{String, ToString};
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+#[derive(Categorize, Encode, Decode, Describe)]
+pub struct Order {
+    pub items: Vec<Item>,
+    pub status: Status,
+}
+
+#[derive(Categorize, Encode, Decode, Describe)]
+pub struct Item {
+    pub sku: [u8; 2],
+    pub quantity: u32,
+}
+
+#[derive(Categorize, Encode, Decode, Describe)]
+pub enum Status {
+    Pending,
+    Shipped(u64),
+}
+
+#[derive(Categorize, Encode, Decode, Describe)]
+pub struct Exchange {
+    pub returned: Item,
+    pub replacement: Item,
+}
+
+mod v2 {
+    use super::*;
+
+    #[derive(Categorize, Encode, Decode, Describe)]
+    pub struct Order {
+        pub items: Vec<Item>,
+        pub status: Status,
+    }
+
+    #[derive(Categorize, Encode, Decode, Describe)]
+    pub struct Item {
+        pub sku: Vec<u8>,
+        pub amount: u32,
+    }
+
+    #[derive(Categorize, Encode, Decode, Describe)]
+    pub enum Status {
+        Pending,
+        Shipped(u64),
+        Cancelled { reason: String },
+    }
+}
+
+mod v3 {
+    use super::*;
+
+    #[derive(Categorize, Encode, Decode, Describe)]
+    pub struct Order {
+        pub items: Vec<Item>,
+        pub status: Status,
+    }
+
+    #[derive(Categorize, Encode, Decode, Describe)]
+    pub struct Item {
+        pub sku: [u8; 2],
+        pub quantity: String,
+    }
+
+    #[derive(Categorize, Encode, Decode, Describe)]
+    pub enum Status {
+        Pending,
+    }
+
+    #[derive(Categorize, Encode, Decode, Describe)]
+    pub struct Exchange {
+        pub returned: Item,
+        pub replacement: Item,
+    }
+}
+
+fn compare<Old: Describe<NoCustomTypeKind>, New: Describe<NoCustomTypeKind>>() -> SchemaComparison {
+    let (old_index, old_schema) =
+        generate_full_schema_from_single_type::<Old, NoCustomTypeExtension>();
+    let (new_index, new_schema) =
+        generate_full_schema_from_single_type::<New, NoCustomTypeExtension>();
+    compare_schema_types(&old_schema, old_index, &new_schema, new_index)
+}
+
+#[test]
+fn identical_types_have_no_changes() {
+    let comparison = compare::<Order, Order>();
+    assert_eq!(comparison.changes, vec![]);
+    assert_eq!(comparison.compatibility(), SchemaCompatibility::Identical);
+}
+
+#[test]
+fn added_variant_renamed_field_and_relaxed_validation_are_compatible() {
+    let comparison = compare::<Order, v2::Order>();
+    assert_eq!(
+        comparison.changes,
+        vec![
+            SchemaChange {
+                path: "$.items[].sku".to_string(),
+                kind: SchemaChangeKind::ValidationRelaxed,
+            },
+            SchemaChange {
+                path: "$.items[].amount".to_string(),
+                kind: SchemaChangeKind::FieldRenamed {
+                    old: "quantity".to_string(),
+                    new: "amount".to_string(),
+                },
+            },
+            SchemaChange {
+                path: "$.status".to_string(),
+                kind: SchemaChangeKind::VariantAdded { discriminator: 2 },
+            },
+        ]
+    );
+    assert_eq!(
+        comparison.compatibility(),
+        SchemaCompatibility::BackwardCompatible
+    );
+}
+
+#[test]
+fn reverse_direction_is_breaking() {
+    let comparison = compare::<v2::Order, Order>();
+    assert_eq!(comparison.compatibility(), SchemaCompatibility::Breaking);
+    assert_eq!(
+        comparison.breaking_changes().cloned().collect::<Vec<_>>(),
+        vec![
+            SchemaChange {
+                path: "$.items[].sku".to_string(),
+                kind: SchemaChangeKind::ValidationTightened,
+            },
+            SchemaChange {
+                path: "$.status".to_string(),
+                kind: SchemaChangeKind::VariantRemoved { discriminator: 2 },
+            },
+        ]
+    );
+}
+
+#[test]
+fn changed_field_type_and_removed_variant_are_breaking() {
+    let comparison = compare::<Order, v3::Order>();
+    assert_eq!(
+        comparison.breaking_changes().cloned().collect::<Vec<_>>(),
+        vec![
+            SchemaChange {
+                path: "$.items[].quantity".to_string(),
+                kind: SchemaChangeKind::TypeKindChanged {
+                    old: "U32".to_string(),
+                    new: "String".to_string(),
+                },
+            },
+            SchemaChange {
+                path: "$.status".to_string(),
+                kind: SchemaChangeKind::VariantRemoved { discriminator: 1 },
+            },
+        ]
+    );
+}
+
+#[test]
+fn changes_to_shared_types_are_reported_at_every_path() {
+    let comparison = compare::<Exchange, v3::Exchange>();
+    assert_eq!(
+        comparison.changes,
+        vec![
+            SchemaChange {
+                path: "$.returned.quantity".to_string(),
+                kind: SchemaChangeKind::TypeKindChanged {
+                    old: "U32".to_string(),
+                    new: "String".to_string(),
+                },
+            },
+            SchemaChange {
+                path: "$.replacement.quantity".to_string(),
+                kind: SchemaChangeKind::TypeKindChanged {
+                    old: "U32".to_string(),
+                    new: "String".to_string(),
+                },
+            },
+        ]
+    );
+}
@@ -22,6 +22,7 @@ pub type BasicEncoder<'a> = VecEncoder<'a, NoCustomValueKind, DEFAULT_BASIC_MAX_
 pub type BasicDecoder<'a> = VecDecoder<'a, NoCustomValueKind, DEFAULT_BASIC_MAX_DEPTH>;
 pub type BasicValue = Value<NoCustomValueKind, NoCustomValue>;
 pub type BasicValueKind = ValueKind<NoCustomValueKind>;
+pub type BasicRawPayload<'de> = RawPayload<'de, NoCustomValueKind, NoCustomValue>;
 
 // 5b for (basic) [5b]or - (90 in decimal)
 pub const BASIC_SBOR_V1_PAYLOAD_PREFIX: u8 = 0x5b;
@@ -129,6 +130,45 @@ mod schema {
         }
     }
 
+    impl ValidatableCustomTypeExtension for NoCustomTypeExtension {
+        type CustomValue = NoCustomValue;
+
+        fn decode_payload(payload: &[u8]) -> Result<BasicValue, DecodeError> {
+            basic_decode(payload)
+        }
+
+        fn custom_value_kind(_: &Self::CustomValue) -> Self::CustomValueKind {
+            unreachable!("No custom values exist")
+        }
+
+        fn custom_type_kind_matches_value_kind<L: SchemaTypeLink>(
+            _: &Self::CustomTypeKind<L>,
+            _: BasicValueKind,
+        ) -> bool {
+            unreachable!("No custom type kinds exist")
+        }
+
+        fn custom_validation_is_satisfied(_: &Self::CustomTypeValidation, _: &BasicValue) -> bool {
+            unreachable!("No custom type validations exist")
+        }
+    }
+
+    impl ComparableCustomTypeExtension for NoCustomTypeExtension {
+        fn matching_custom_type_kind_children(
+            _: &Self::CustomTypeKind<LocalTypeIndex>,
+            _: &Self::CustomTypeKind<LocalTypeIndex>,
+        ) -> Option<Vec<(&'static str, LocalTypeIndex, LocalTypeIndex)>> {
+            unreachable!("No custom type kinds exist")
+        }
+
+        fn custom_validation_is_relaxed_by(
+            _: &Self::CustomTypeValidation,
+            _: &Self::CustomTypeValidation,
+        ) -> bool {
+            unreachable!("No custom type validations exist")
+        }
+    }
+
     pub type BasicTypeKind<L> = TypeKind<NoCustomValueKind, NoCustomTypeKind, L>;
     pub type BasicSchema = Schema<NoCustomTypeExtension>;
 }
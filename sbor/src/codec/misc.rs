@@ -105,6 +105,11 @@ impl<X: CustomValueKind, E: Encoder<X>, T: Encode<X, E>> Encode<X, E> for RefCel
     }
 }
 
+/// A `Cow` always decodes as `Cow::Owned`, with any decoder.
+///
+/// Borrowing would tie `'a` to the lifetime of the payload, which would break decoding types
+/// such as `Cow<'static, str>` (used throughout schemas) from short-lived buffers. Use `&'de str`,
+/// `&'de [u8]` or `RawPayload` with a `BorrowingDecoder` for zero-copy decoding instead.
 impl<
         'a,
         X: CustomValueKind,
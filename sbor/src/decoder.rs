@@ -1,4 +1,11 @@
+#[cfg(feature = "std")]
+use crate::rust::cell::RefCell;
 use crate::rust::marker::PhantomData;
+use crate::rust::string::String;
+#[cfg(feature = "std")]
+use crate::rust::string::ToString;
+#[cfg(feature = "std")]
+use crate::rust::vec::Vec;
 use crate::value_kind::*;
 use crate::*;
 
@@ -30,6 +37,8 @@ pub enum DecodeError {
     MaxDepthExceeded(u8),
 
     InvalidCustomValue, // TODO: generify custom error codes
+
+    IoError(String), // The underlying stream failed, other than by running out of bytes
 }
 
 pub trait Decoder<X: CustomValueKind>: Sized {
@@ -168,6 +177,22 @@ pub trait Decoder<X: CustomValueKind>: Sized {
     fn read_slice(&mut self, n: usize) -> Result<&[u8], DecodeError>;
 }
 
+/// A `Decoder` over an in-memory payload, which can hand out slices of the payload that outlive
+/// the decoder itself. This allows zero-copy decoding of `&'de str`, `&'de [u8]` and `RawPayload`
+/// (but not `Cow`, which always decodes as owned).
+pub trait BorrowingDecoder<'de, X: CustomValueKind>: Decoder<X> {
+    fn read_slice_from_payload(&mut self, n: usize) -> Result<&'de [u8], DecodeError>;
+
+    /// The offset of the next byte to be read, from the start of the payload
+    fn get_offset(&self) -> usize;
+
+    /// The depth of the value currently being decoded, counting the root value as 1
+    fn get_stack_depth(&self) -> u8;
+
+    /// The bytes of the payload between the given offsets
+    fn get_slice_from_payload(&self, start: usize, end: usize) -> &'de [u8];
+}
+
 /// A `Decoder` abstracts the logic for decoding basic types.
 pub struct VecDecoder<'de, X: CustomValueKind, const MAX_DEPTH: u8> {
     input: &'de [u8],
@@ -178,10 +203,16 @@ pub struct VecDecoder<'de, X: CustomValueKind, const MAX_DEPTH: u8> {
 
 impl<'de, X: CustomValueKind, const MAX_DEPTH: u8> VecDecoder<'de, X, MAX_DEPTH> {
     pub fn new(input: &'de [u8]) -> Self {
+        Self::new_at_depth(input, 0)
+    }
+
+    /// Creates a decoder for a value nested at the given depth within a larger payload, so that
+    /// the depth limit applies to it as it would to the whole payload.
+    pub fn new_at_depth(input: &'de [u8], stack_depth: u8) -> Self {
         Self {
             input,
             offset: 0,
-            stack_depth: 0,
+            stack_depth,
             phantom: PhantomData,
         }
     }
@@ -257,6 +288,136 @@ impl<'de, X: CustomValueKind, const MAX_DEPTH: u8> Decoder<X> for VecDecoder<'de
     }
 }
 
+impl<'de, X: CustomValueKind, const MAX_DEPTH: u8> BorrowingDecoder<'de, X>
+    for VecDecoder<'de, X, MAX_DEPTH>
+{
+    #[inline]
+    fn read_slice_from_payload(&mut self, n: usize) -> Result<&'de [u8], DecodeError> {
+        self.require_remaining(n)?;
+        let slice = &self.input[self.offset..self.offset + n];
+        self.offset += n;
+        Ok(slice)
+    }
+
+    #[inline]
+    fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    fn get_stack_depth(&self) -> u8 {
+        self.stack_depth
+    }
+
+    #[inline]
+    fn get_slice_from_payload(&self, start: usize, end: usize) -> &'de [u8] {
+        &self.input[start..end]
+    }
+}
+
+/// A `Decoder` which pulls bytes from a [`std::io::Read`] on demand, so that large payloads
+/// needn't be loaded into memory up front.
+///
+/// Slices returned by `read_slice` are only valid until the next read.
+///
+/// Bytes are read in small chunks, so unbuffered readers such as files and sockets should be
+/// wrapped in a [`std::io::BufReader`]. As the end of the stream is detected by reading a
+/// single byte, trailing bytes are always reported as `ExtraTrailingBytes(1)`.
+#[cfg(feature = "std")]
+pub struct StreamDecoder<R: std::io::Read, X: CustomValueKind, const MAX_DEPTH: u8> {
+    // The reader is only mutably borrowed from `check_end`, which takes `&self`
+    input: RefCell<R>,
+    buffer: Vec<u8>,
+    stack_depth: u8,
+    phantom: PhantomData<X>,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read, X: CustomValueKind, const MAX_DEPTH: u8> StreamDecoder<R, X, MAX_DEPTH> {
+    pub fn new(input: R) -> Self {
+        Self {
+            input: RefCell::new(input),
+            buffer: Vec::new(),
+            stack_depth: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.input.into_inner()
+    }
+
+    #[inline]
+    fn track_stack_depth_increase(&mut self) -> Result<(), DecodeError> {
+        self.stack_depth += 1;
+        if self.stack_depth > MAX_DEPTH {
+            return Err(DecodeError::MaxDepthExceeded(MAX_DEPTH));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn track_stack_depth_decrease(&mut self) -> Result<(), DecodeError> {
+        self.stack_depth -= 1;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read, X: CustomValueKind, const MAX_DEPTH: u8> Decoder<X>
+    for StreamDecoder<R, X, MAX_DEPTH>
+{
+    fn decode_deeper_body_with_value_kind<T: Decode<X, Self>>(
+        &mut self,
+        value_kind: ValueKind<X>,
+    ) -> Result<T, DecodeError> {
+        self.track_stack_depth_increase()?;
+        let decoded = T::decode_body_with_value_kind(self, value_kind)?;
+        self.track_stack_depth_decrease()?;
+        Ok(decoded)
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.read_slice(1)?[0])
+    }
+
+    fn read_slice(&mut self, n: usize) -> Result<&[u8], DecodeError> {
+        use std::io::Read;
+
+        // Read incrementally rather than allocating `n` bytes up front, as `n` comes from the
+        // (untrusted) payload
+        self.buffer.clear();
+        self.input
+            .get_mut()
+            .take(n as u64)
+            .read_to_end(&mut self.buffer)
+            .map_err(|e| DecodeError::IoError(e.to_string()))?;
+        if self.buffer.len() < n {
+            return Err(DecodeError::BufferUnderflow {
+                required: n,
+                remaining: self.buffer.len(),
+            });
+        }
+        Ok(&self.buffer)
+    }
+
+    fn check_end(&self) -> Result<(), DecodeError> {
+        use std::io::ErrorKind;
+
+        // Only peek a single byte, as draining the rest of the stream could take forever
+        let mut byte = [0u8; 1];
+        loop {
+            match self.input.borrow_mut().read(&mut byte) {
+                Ok(0) => return Ok(()),
+                Ok(_) => return Err(DecodeError::ExtraTrailingBytes(1)),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(DecodeError::IoError(e.to_string())),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -420,4 +581,47 @@ mod tests {
         let value2 = decoder.decode::<[NFA; 2]>().unwrap();
         assert_eq!(value1, value2);
     }
+
+    #[cfg(feature = "std")]
+    type BasicStreamDecoder<'a> =
+        StreamDecoder<&'a [u8], NoCustomValueKind, DEFAULT_BASIC_MAX_DEPTH>;
+
+    #[cfg(feature = "std")]
+    #[test]
+    pub fn test_stream_decoding() {
+        let value = (
+            "hello".to_owned(),
+            vec![1u32, 2, 3],
+            BTreeMap::from([(1u8, Some(2u64))]),
+        );
+        let bytes = basic_encode(&value).unwrap();
+
+        let decoder = BasicStreamDecoder::new(&bytes[..]);
+        assert_eq!(
+            decoder.decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX),
+            Ok(value)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    pub fn test_stream_decoding_reports_truncation_and_trailing_bytes() {
+        let mut bytes = basic_encode(&"hello".to_owned()).unwrap();
+
+        let decoder = BasicStreamDecoder::new(&bytes[..bytes.len() - 2]);
+        assert_eq!(
+            decoder.decode_payload::<String>(BASIC_SBOR_V1_PAYLOAD_PREFIX),
+            Err(DecodeError::BufferUnderflow {
+                required: 5,
+                remaining: 3
+            })
+        );
+
+        bytes.extend([0, 0]);
+        let decoder = BasicStreamDecoder::new(&bytes[..]);
+        assert_eq!(
+            decoder.decode_payload::<String>(BASIC_SBOR_V1_PAYLOAD_PREFIX),
+            Err(DecodeError::ExtraTrailingBytes(1))
+        );
+    }
 }
@@ -23,8 +23,12 @@ pub mod encode;
 pub mod encoder;
 /// SBOR paths.
 pub mod path;
+/// SBOR sub-values captured without decoding.
+pub mod raw_payload;
 /// A facade of Rust types.
 pub mod rust;
+/// SBOR streaming traversal.
+pub mod traversal;
 
 /// SBOR Schema
 pub mod schema;
@@ -38,12 +42,16 @@ pub use categorize::Categorize;
 pub(crate) use categorize::{categorize_generic, categorize_simple};
 pub use constants::*;
 pub use decode::Decode;
-pub use decoder::{DecodeError, Decoder, VecDecoder};
+#[cfg(feature = "std")]
+pub use decoder::StreamDecoder;
+pub use decoder::{BorrowingDecoder, DecodeError, Decoder, VecDecoder};
 pub use encode::Encode;
 pub use encoder::{EncodeError, Encoder, VecEncoder};
 pub use path::{SborPath, SborPathBuf};
+pub use raw_payload::RawPayload;
 
 pub use schema::*;
+pub use traversal::*;
 pub use value::*;
 pub use value_kind::*;
 
@@ -0,0 +1,264 @@
+use crate::rust::marker::PhantomData;
+use crate::value_kind::*;
+use crate::*;
+
+/// A sub-value captured as its encoded bytes, without being decoded.
+///
+/// Decoding a `RawPayload` only walks the value to find where it ends - nothing is allocated, and
+/// the bytes are borrowed from the decoder's payload. Encoding it writes the captured bytes back
+/// unchanged, so a `RawPayload` can be used to pass through parts of a value which aren't needed.
+///
+/// `Y` is the custom value type, which is needed to find the end of any custom values.
+pub struct RawPayload<'de, X: CustomValueKind, Y> {
+    value_kind: ValueKind<X>,
+    body: &'de [u8],
+    /// The depth of the value within the payload it was captured from
+    depth: u8,
+    phantom: PhantomData<Y>,
+}
+
+impl<'de, X: CustomValueKind, Y> RawPayload<'de, X, Y> {
+    pub fn value_kind(&self) -> ValueKind<X> {
+        self.value_kind
+    }
+
+    /// The encoded body of the value, excluding its value kind
+    pub fn body(&self) -> &'de [u8] {
+        self.body
+    }
+
+    /// Decodes the captured value.
+    ///
+    /// The value is decoded at the depth it was captured at, so the depth limit applies as if
+    /// the whole original payload was being decoded.
+    pub fn decode<T: for<'a> Decode<X, VecDecoder<'a, X, MAX_DEPTH>>, const MAX_DEPTH: u8>(
+        &self,
+    ) -> Result<T, DecodeError> {
+        let mut decoder = VecDecoder::<X, MAX_DEPTH>::new_at_depth(self.body, self.depth);
+        let value = T::decode_body_with_value_kind(&mut decoder, self.value_kind)?;
+        decoder.check_end()?;
+        Ok(value)
+    }
+}
+
+// Manual impls, so as not to require `Y: Clone` etc
+impl<'de, X: CustomValueKind, Y> Clone for RawPayload<'de, X, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            value_kind: self.value_kind,
+            body: self.body,
+            depth: self.depth,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'de, X: CustomValueKind, Y> Copy for RawPayload<'de, X, Y> {}
+
+impl<'de, X: CustomValueKind, Y> PartialEq for RawPayload<'de, X, Y> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value_kind == other.value_kind && self.body == other.body
+    }
+}
+
+impl<'de, X: CustomValueKind, Y> Eq for RawPayload<'de, X, Y> {}
+
+impl<'de, X: CustomValueKind, Y> crate::rust::fmt::Debug for RawPayload<'de, X, Y> {
+    fn fmt(&self, f: &mut crate::rust::fmt::Formatter<'_>) -> crate::rust::fmt::Result {
+        f.debug_struct("RawPayload")
+            .field("value_kind", &self.value_kind)
+            .field("body", &self.body)
+            .field("depth", &self.depth)
+            .finish()
+    }
+}
+
+impl<'de, X: CustomValueKind, E: Encoder<X>, Y> Encode<X, E> for RawPayload<'de, X, Y> {
+    #[inline]
+    fn encode_value_kind(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.write_value_kind(self.value_kind)
+    }
+
+    #[inline]
+    fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.write_slice(self.body)
+    }
+}
+
+impl<'de, X: CustomValueKind, Y: Decode<X, D>, D: BorrowingDecoder<'de, X>> Decode<X, D>
+    for RawPayload<'de, X, Y>
+{
+    fn decode_body_with_value_kind(
+        decoder: &mut D,
+        value_kind: ValueKind<X>,
+    ) -> Result<Self, DecodeError> {
+        let start = decoder.get_offset();
+        SkippedValue::<Y>::decode_body_with_value_kind(decoder, value_kind)?;
+        let end = decoder.get_offset();
+        Ok(Self {
+            value_kind,
+            body: decoder.get_slice_from_payload(start, end),
+            depth: decoder.get_stack_depth(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+/// Reads past a value without keeping any of it. Children are read through the decoder, so that
+/// depth limits are enforced exactly as when decoding into a [`Value`].
+struct SkippedValue<Y>(PhantomData<Y>);
+
+impl<X: CustomValueKind, D: Decoder<X>, Y: Decode<X, D>> Decode<X, D> for SkippedValue<Y> {
+    fn decode_body_with_value_kind(
+        decoder: &mut D,
+        value_kind: ValueKind<X>,
+    ) -> Result<Self, DecodeError> {
+        match value_kind {
+            ValueKind::Bool => {
+                bool::decode_body_with_value_kind(decoder, value_kind)?;
+            }
+            ValueKind::I8 | ValueKind::U8 => {
+                decoder.read_slice(1)?;
+            }
+            ValueKind::I16 | ValueKind::U16 => {
+                decoder.read_slice(2)?;
+            }
+            ValueKind::I32 | ValueKind::U32 => {
+                decoder.read_slice(4)?;
+            }
+            ValueKind::I64 | ValueKind::U64 => {
+                decoder.read_slice(8)?;
+            }
+            ValueKind::I128 | ValueKind::U128 => {
+                decoder.read_slice(16)?;
+            }
+            ValueKind::String => {
+                let length = decoder.read_size()?;
+                let slice = decoder.read_slice(length)?;
+                crate::rust::str::from_utf8(slice).map_err(|_| DecodeError::InvalidUtf8)?;
+            }
+            ValueKind::Tuple => {
+                let length = decoder.read_size()?;
+                for _ in 0..length {
+                    decoder.decode::<Self>()?;
+                }
+            }
+            ValueKind::Enum => {
+                decoder.read_discriminator()?;
+                let length = decoder.read_size()?;
+                for _ in 0..length {
+                    decoder.decode::<Self>()?;
+                }
+            }
+            ValueKind::Array => {
+                let element_value_kind = decoder.read_value_kind()?;
+                let length = decoder.read_size()?;
+                for _ in 0..length {
+                    decoder.decode_deeper_body_with_value_kind::<Self>(element_value_kind)?;
+                }
+            }
+            ValueKind::Map => {
+                let key_value_kind = decoder.read_value_kind()?;
+                let value_value_kind = decoder.read_value_kind()?;
+                let length = decoder.read_size()?;
+                for _ in 0..length {
+                    decoder.decode_deeper_body_with_value_kind::<Self>(key_value_kind)?;
+                    decoder.decode_deeper_body_with_value_kind::<Self>(value_value_kind)?;
+                }
+            }
+            ValueKind::Custom(_) => {
+                Y::decode_body_with_value_kind(decoder, value_kind)?;
+            }
+        }
+        Ok(Self(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rust::borrow::ToOwned;
+    use crate::rust::string::String;
+    use crate::rust::vec;
+    use crate::rust::vec::Vec;
+
+    #[test]
+    pub fn test_borrowed_decoding() {
+        let bytes = basic_encode(&("hello".to_owned(), vec![1u8, 2, 3])).unwrap();
+
+        let decoded = BasicDecoder::new(&bytes)
+            .decode_payload::<(&str, &[u8])>(BASIC_SBOR_V1_PAYLOAD_PREFIX)
+            .unwrap();
+        assert_eq!(decoded, ("hello", &[1u8, 2, 3][..]));
+        // The decoded values point into the payload rather than being copied
+        assert!(bytes.as_ptr_range().contains(&decoded.0.as_ptr()));
+        assert!(bytes.as_ptr_range().contains(&decoded.1.as_ptr()));
+    }
+
+    #[test]
+    pub fn test_raw_payload_round_trip() {
+        let inner = (1u32, vec!["a".to_owned()], Some(-5i64));
+        let bytes = basic_encode(&(inner.clone(), 7u8)).unwrap();
+
+        let (raw, last) = BasicDecoder::new(&bytes)
+            .decode_payload::<(BasicRawPayload, u8)>(BASIC_SBOR_V1_PAYLOAD_PREFIX)
+            .unwrap();
+        assert_eq!(last, 7);
+        assert_eq!(raw.value_kind(), ValueKind::Tuple);
+        assert_eq!(
+            raw.decode::<(u32, Vec<String>, Option<i64>), DEFAULT_BASIC_MAX_DEPTH>(),
+            Ok(inner)
+        );
+        assert_eq!(basic_encode(&(raw, last)).unwrap(), bytes);
+    }
+
+    #[test]
+    pub fn test_raw_payload_keeps_its_depth() {
+        fn nested(depth: u8) -> BasicValue {
+            if depth == 1 {
+                Value::U8 { value: 1 }
+            } else {
+                Value::Tuple {
+                    fields: vec![nested(depth - 1)],
+                }
+            }
+        }
+
+        // The raw payload is captured at depth 2, so its body reaches the maximum depth
+        let inner = nested(DEFAULT_BASIC_MAX_DEPTH - 1);
+        let bytes = basic_encode(&BasicValue::Tuple {
+            fields: vec![inner.clone()],
+        })
+        .unwrap();
+        let (raw,) = BasicDecoder::new(&bytes)
+            .decode_payload::<(BasicRawPayload,)>(BASIC_SBOR_V1_PAYLOAD_PREFIX)
+            .unwrap();
+
+        assert_eq!(
+            raw.decode::<BasicValue, DEFAULT_BASIC_MAX_DEPTH>(),
+            Ok(inner)
+        );
+        assert_eq!(
+            raw.decode::<BasicValue, { DEFAULT_BASIC_MAX_DEPTH - 1 }>(),
+            Err(DecodeError::MaxDepthExceeded(DEFAULT_BASIC_MAX_DEPTH - 1))
+        );
+    }
+
+    #[test]
+    pub fn test_raw_payload_rejects_invalid_values() {
+        // A string with invalid UTF-8 inside a tuple
+        let bytes = vec![
+            BASIC_SBOR_V1_PAYLOAD_PREFIX,
+            VALUE_KIND_TUPLE,
+            1,
+            VALUE_KIND_STRING,
+            1,
+            0xff,
+        ];
+        assert_eq!(
+            BasicDecoder::new(&bytes)
+                .decode_payload::<BasicRawPayload>(BASIC_SBOR_V1_PAYLOAD_PREFIX),
+            Err(DecodeError::InvalidUtf8)
+        );
+    }
+}
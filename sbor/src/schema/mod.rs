@@ -1,7 +1,9 @@
 mod custom_traits;
 mod describe;
 mod macros;
+mod payload_validation;
 mod schema;
+mod schema_comparison;
 mod type_aggregator;
 mod type_data;
 mod type_link;
@@ -10,7 +12,9 @@ mod well_known_types;
 pub use custom_traits::*;
 pub use describe::*;
 pub(crate) use macros::*;
+pub use payload_validation::*;
 pub use schema::*;
+pub use schema_comparison::*;
 pub use type_aggregator::*;
 pub use type_data::*;
 pub use type_link::*;
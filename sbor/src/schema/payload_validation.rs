@@ -0,0 +1,339 @@
+use super::*;
+use crate::rust::borrow::Cow;
+use crate::rust::fmt::Debug;
+use crate::rust::format;
+use crate::rust::string::{String, ToString};
+use crate::rust::vec;
+use crate::rust::vec::Vec;
+use crate::*;
+
+/// A [`CustomTypeExtension`] whose payloads can be validated against a [`Schema`].
+pub trait ValidatableCustomTypeExtension: CustomTypeExtension {
+    type CustomValue: Debug;
+
+    /// Decodes a full payload, including its prefix byte.
+    fn decode_payload(
+        payload: &[u8],
+    ) -> Result<Value<Self::CustomValueKind, Self::CustomValue>, DecodeError>;
+
+    fn custom_value_kind(custom_value: &Self::CustomValue) -> Self::CustomValueKind;
+
+    fn custom_type_kind_matches_value_kind<L: SchemaTypeLink>(
+        custom_type_kind: &Self::CustomTypeKind<L>,
+        value_kind: ValueKind<Self::CustomValueKind>,
+    ) -> bool;
+
+    fn custom_validation_is_satisfied(
+        custom_validation: &Self::CustomTypeValidation,
+        value: &Value<Self::CustomValueKind, Self::CustomValue>,
+    ) -> bool;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Categorize, Encode, Decode)]
+pub struct PayloadValidationError {
+    /// The location of the offending value, e.g. `$.vaults[2]` or `$::Some.0`.
+    pub path: String,
+    pub error: PayloadValidationErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Categorize, Encode, Decode)]
+pub enum PayloadValidationErrorKind {
+    DecodeError(DecodeError),
+    TypeNotFound(LocalTypeIndex),
+    MismatchingValueKind {
+        type_name: String,
+        value_kind: u8,
+    },
+    MismatchingFieldCount {
+        expected: usize,
+        actual: usize,
+    },
+    UnknownEnumVariant {
+        type_name: String,
+        discriminator: u8,
+    },
+    NumericValidationFailed {
+        type_name: String,
+    },
+    LengthValidationFailed {
+        type_name: String,
+        length: usize,
+    },
+    CustomValidationFailed {
+        type_name: String,
+    },
+}
+
+/// Validates that a payload is a value of the given type of the schema.
+///
+/// Unlike decoding into a Rust type, this enforces the [`TypeValidation`]s of the schema, such as
+/// the length of fixed-size arrays, and reports where in the payload the first violation is.
+pub fn validate_payload_against_schema<E: ValidatableCustomTypeExtension>(
+    payload: &[u8],
+    schema: &Schema<E>,
+    index: LocalTypeIndex,
+) -> Result<(), PayloadValidationError> {
+    let value = E::decode_payload(payload).map_err(|e| PayloadValidationError {
+        path: "$".to_string(),
+        error: PayloadValidationErrorKind::DecodeError(e),
+    })?;
+    validate_value_against_schema(&value, schema, index)
+}
+
+/// Validates an already decoded payload, see [`validate_payload_against_schema`].
+pub fn validate_value_against_schema<E: ValidatableCustomTypeExtension>(
+    value: &Value<E::CustomValueKind, E::CustomValue>,
+    schema: &Schema<E>,
+    index: LocalTypeIndex,
+) -> Result<(), PayloadValidationError> {
+    let mut validator = PayloadValidator {
+        schema,
+        path: vec!["$".to_string()],
+    };
+    validator
+        .validate(value, index)
+        .map_err(|error| PayloadValidationError {
+            path: validator.path.concat(),
+            error,
+        })
+}
+
+struct PayloadValidator<'s, E: ValidatableCustomTypeExtension> {
+    schema: &'s Schema<E>,
+    path: Vec<String>,
+}
+
+impl<'s, E: ValidatableCustomTypeExtension> PayloadValidator<'s, E> {
+    fn validate(
+        &mut self,
+        value: &Value<E::CustomValueKind, E::CustomValue>,
+        index: LocalTypeIndex,
+    ) -> Result<(), PayloadValidationErrorKind> {
+        let type_data = self.resolve_with_value_kind(index, value_kind_of::<E>(value))?;
+        let type_name = || type_data.metadata.type_name.to_string();
+
+        if !validation_is_satisfied::<E>(&type_data.validation, value) {
+            return Err(match type_data.validation.as_ref() {
+                TypeValidation::String { .. } | TypeValidation::Array { .. } => {
+                    PayloadValidationErrorKind::LengthValidationFailed {
+                        type_name: type_name(),
+                        length: match value {
+                            Value::String { value } => value.len(),
+                            Value::Array { elements, .. } => elements.len(),
+                            _ => 0,
+                        },
+                    }
+                }
+                TypeValidation::Custom(_) => PayloadValidationErrorKind::CustomValidationFailed {
+                    type_name: type_name(),
+                },
+                _ => PayloadValidationErrorKind::NumericValidationFailed {
+                    type_name: type_name(),
+                },
+            });
+        }
+
+        match (type_data.kind.as_ref(), value) {
+            (
+                TypeKind::Array { element_type },
+                Value::Array {
+                    element_value_kind,
+                    elements,
+                },
+            ) => {
+                // Also checks the element value kind of empty arrays
+                self.resolve_with_value_kind(*element_type, *element_value_kind)?;
+                for (i, element) in elements.iter().enumerate() {
+                    self.path.push(format!("[{}]", i));
+                    self.validate(element, *element_type)?;
+                    self.path.pop();
+                }
+            }
+            (TypeKind::Tuple { field_types }, Value::Tuple { fields }) => {
+                let field_names = match &type_data.metadata.child_names {
+                    ChildNames::FieldNames(names) => Some(names),
+                    _ => None,
+                };
+                self.validate_fields(fields, field_types, field_names)?;
+            }
+            (
+                TypeKind::Enum { variants },
+                Value::Enum {
+                    discriminator,
+                    fields,
+                },
+            ) => {
+                let field_types = variants.get(discriminator).ok_or_else(|| {
+                    PayloadValidationErrorKind::UnknownEnumVariant {
+                        type_name: type_name(),
+                        discriminator: *discriminator,
+                    }
+                })?;
+                let variant_metadata = match &type_data.metadata.child_names {
+                    ChildNames::VariantNames(variants) => variants.get(discriminator),
+                    _ => None,
+                };
+                self.path.push(match variant_metadata {
+                    Some(metadata) => format!("::{}", metadata.type_name),
+                    None => format!("::{}", discriminator),
+                });
+                let field_names =
+                    variant_metadata.and_then(|metadata| match &metadata.child_names {
+                        ChildNames::FieldNames(names) => Some(names),
+                        _ => None,
+                    });
+                self.validate_fields(fields, field_types, field_names)?;
+                self.path.pop();
+            }
+            (
+                TypeKind::Map {
+                    key_type,
+                    value_type,
+                },
+                Value::Map {
+                    key_value_kind,
+                    value_value_kind,
+                    entries,
+                },
+            ) => {
+                self.resolve_with_value_kind(*key_type, *key_value_kind)?;
+                self.resolve_with_value_kind(*value_type, *value_value_kind)?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    self.path.push(format!("[{}].key", i));
+                    self.validate(key, *key_type)?;
+                    self.path.pop();
+                    self.path.push(format!("[{}].value", i));
+                    self.validate(value, *value_type)?;
+                    self.path.pop();
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn resolve_with_value_kind(
+        &self,
+        index: LocalTypeIndex,
+        value_kind: ValueKind<E::CustomValueKind>,
+    ) -> Result<ResolvedTypeData<'s, E>, PayloadValidationErrorKind> {
+        let type_data = self
+            .schema
+            .resolve(index)
+            .ok_or(PayloadValidationErrorKind::TypeNotFound(index))?;
+        if !type_kind_matches_value_kind::<E>(&type_data.kind, value_kind) {
+            return Err(PayloadValidationErrorKind::MismatchingValueKind {
+                type_name: type_data.metadata.type_name.to_string(),
+                value_kind: value_kind.as_u8(),
+            });
+        }
+        Ok(type_data)
+    }
+
+    fn validate_fields(
+        &mut self,
+        fields: &[Value<E::CustomValueKind, E::CustomValue>],
+        field_types: &[LocalTypeIndex],
+        field_names: Option<&Vec<Cow<'static, str>>>,
+    ) -> Result<(), PayloadValidationErrorKind> {
+        if fields.len() != field_types.len() {
+            return Err(PayloadValidationErrorKind::MismatchingFieldCount {
+                expected: field_types.len(),
+                actual: fields.len(),
+            });
+        }
+        for (i, (field, field_type)) in fields.iter().zip(field_types).enumerate() {
+            self.path
+                .push(match field_names.and_then(|names| names.get(i)) {
+                    Some(name) => format!(".{}", name),
+                    None => format!(".{}", i),
+                });
+            self.validate(field, *field_type)?;
+            self.path.pop();
+        }
+        Ok(())
+    }
+}
+
+fn value_kind_of<E: ValidatableCustomTypeExtension>(
+    value: &Value<E::CustomValueKind, E::CustomValue>,
+) -> ValueKind<E::CustomValueKind> {
+    match value {
+        Value::Bool { .. } => ValueKind::Bool,
+        Value::I8 { .. } => ValueKind::I8,
+        Value::I16 { .. } => ValueKind::I16,
+        Value::I32 { .. } => ValueKind::I32,
+        Value::I64 { .. } => ValueKind::I64,
+        Value::I128 { .. } => ValueKind::I128,
+        Value::U8 { .. } => ValueKind::U8,
+        Value::U16 { .. } => ValueKind::U16,
+        Value::U32 { .. } => ValueKind::U32,
+        Value::U64 { .. } => ValueKind::U64,
+        Value::U128 { .. } => ValueKind::U128,
+        Value::String { .. } => ValueKind::String,
+        Value::Enum { .. } => ValueKind::Enum,
+        Value::Array { .. } => ValueKind::Array,
+        Value::Tuple { .. } => ValueKind::Tuple,
+        Value::Map { .. } => ValueKind::Map,
+        Value::Custom { value } => ValueKind::Custom(E::custom_value_kind(value)),
+    }
+}
+
+fn type_kind_matches_value_kind<E: ValidatableCustomTypeExtension>(
+    type_kind: &TypeKind<E::CustomValueKind, E::CustomTypeKind<LocalTypeIndex>, LocalTypeIndex>,
+    value_kind: ValueKind<E::CustomValueKind>,
+) -> bool {
+    match type_kind {
+        TypeKind::Any => true,
+        TypeKind::Bool => value_kind == ValueKind::Bool,
+        TypeKind::I8 => value_kind == ValueKind::I8,
+        TypeKind::I16 => value_kind == ValueKind::I16,
+        TypeKind::I32 => value_kind == ValueKind::I32,
+        TypeKind::I64 => value_kind == ValueKind::I64,
+        TypeKind::I128 => value_kind == ValueKind::I128,
+        TypeKind::U8 => value_kind == ValueKind::U8,
+        TypeKind::U16 => value_kind == ValueKind::U16,
+        TypeKind::U32 => value_kind == ValueKind::U32,
+        TypeKind::U64 => value_kind == ValueKind::U64,
+        TypeKind::U128 => value_kind == ValueKind::U128,
+        TypeKind::String => value_kind == ValueKind::String,
+        TypeKind::Array { .. } => value_kind == ValueKind::Array,
+        TypeKind::Tuple { .. } => value_kind == ValueKind::Tuple,
+        TypeKind::Enum { .. } => value_kind == ValueKind::Enum,
+        TypeKind::Map { .. } => value_kind == ValueKind::Map,
+        TypeKind::Custom(custom_type_kind) => {
+            E::custom_type_kind_matches_value_kind(custom_type_kind, value_kind)
+        }
+    }
+}
+
+fn validation_is_satisfied<E: ValidatableCustomTypeExtension>(
+    validation: &TypeValidation<E::CustomTypeValidation>,
+    value: &Value<E::CustomValueKind, E::CustomValue>,
+) -> bool {
+    match (validation, value) {
+        (TypeValidation::None, _) => true,
+        (TypeValidation::I8(v), Value::I8 { value }) => v.is_satisfied_by(value),
+        (TypeValidation::I16(v), Value::I16 { value }) => v.is_satisfied_by(value),
+        (TypeValidation::I32(v), Value::I32 { value }) => v.is_satisfied_by(value),
+        (TypeValidation::I64(v), Value::I64 { value }) => v.is_satisfied_by(value),
+        (TypeValidation::I128(v), Value::I128 { value }) => v.is_satisfied_by(value),
+        (TypeValidation::U8(v), Value::U8 { value }) => v.is_satisfied_by(value),
+        (TypeValidation::U16(v), Value::U16 { value }) => v.is_satisfied_by(value),
+        (TypeValidation::U32(v), Value::U32 { value }) => v.is_satisfied_by(value),
+        (TypeValidation::U64(v), Value::U64 { value }) => v.is_satisfied_by(value),
+        (TypeValidation::U128(v), Value::U128 { value }) => v.is_satisfied_by(value),
+        (TypeValidation::String { length_validation }, Value::String { value }) => {
+            length_validation.is_satisfied_by(value.len())
+        }
+        (TypeValidation::Array { length_validation }, Value::Array { elements, .. }) => {
+            length_validation.is_satisfied_by(elements.len())
+        }
+        (TypeValidation::Custom(custom_validation), value) => {
+            E::custom_validation_is_satisfied(custom_validation, value)
+        }
+        // A validation which doesn't apply to the value kind can't be satisfied
+        _ => false,
+    }
+}
@@ -8,6 +8,7 @@ pub struct Schema<C: CustomTypeExtension> {
     pub type_kinds:
         Vec<TypeKind<C::CustomValueKind, C::CustomTypeKind<LocalTypeIndex>, LocalTypeIndex>>,
     pub type_metadata: Vec<NovelTypeMetadata>,
+    pub type_validations: Vec<TypeValidation<C::CustomTypeValidation>>,
 }
 
 // TODO: Could get rid of the Cow by using some per-custom type once_cell to cache basic well-known-types,
@@ -16,6 +17,7 @@ pub struct ResolvedTypeData<'a, C: CustomTypeExtension> {
     pub kind:
         Cow<'a, TypeKind<C::CustomValueKind, C::CustomTypeKind<LocalTypeIndex>, LocalTypeIndex>>,
     pub metadata: Cow<'a, TypeMetadata>,
+    pub validation: Cow<'a, TypeValidation<C::CustomTypeValidation>>,
 }
 
 impl<E: CustomTypeExtension> Schema<E> {
@@ -25,16 +27,24 @@ impl<E: CustomTypeExtension> Schema<E> {
                 resolve_well_known_type::<E>(index).map(|local_type_data| ResolvedTypeData {
                     kind: Cow::Owned(local_type_data.kind),
                     metadata: Cow::Owned(local_type_data.metadata),
+                    validation: Cow::Owned(local_type_data.validation),
                 })
             }
             LocalTypeIndex::SchemaLocalIndex(index) => {
-                match (self.type_kinds.get(index), self.type_metadata.get(index)) {
-                    (Some(schema), Some(novel_metadata)) => Some(ResolvedTypeData {
-                        kind: Cow::Borrowed(schema),
-                        metadata: Cow::Borrowed(&novel_metadata.type_metadata),
-                    }),
-                    (None, None) => None,
-                    _ => panic!("Index existed in exactly one of schema and naming"),
+                match (
+                    self.type_kinds.get(index),
+                    self.type_metadata.get(index),
+                    self.type_validations.get(index),
+                ) {
+                    (Some(schema), Some(novel_metadata), Some(validation)) => {
+                        Some(ResolvedTypeData {
+                            kind: Cow::Borrowed(schema),
+                            metadata: Cow::Borrowed(&novel_metadata.type_metadata),
+                            validation: Cow::Borrowed(validation),
+                        })
+                    }
+                    (None, None, None) => None,
+                    _ => panic!("Index existed in only some of schema, naming and validations"),
                 }
             }
         }
@@ -0,0 +1,430 @@
+use super::*;
+use crate::rust::borrow::Cow;
+use crate::rust::collections::HashSet;
+use crate::rust::format;
+use crate::rust::mem::discriminant;
+use crate::rust::string::{String, ToString};
+use crate::rust::vec;
+use crate::rust::vec::Vec;
+use crate::*;
+
+/// A [`CustomTypeExtension`] whose schemas can be compared with [`compare_schema_types`].
+pub trait ComparableCustomTypeExtension: CustomTypeExtension {
+    /// If the two custom type kinds have the same encoding, returns the pairs of child types
+    /// which must also be compared, with the path segment to report them under.
+    /// Returns `None` if values of one can't be decoded as the other.
+    fn matching_custom_type_kind_children(
+        old: &Self::CustomTypeKind<LocalTypeIndex>,
+        new: &Self::CustomTypeKind<LocalTypeIndex>,
+    ) -> Option<Vec<(&'static str, LocalTypeIndex, LocalTypeIndex)>>;
+
+    /// Whether every value accepted by the old validation is accepted by the new one.
+    fn custom_validation_is_relaxed_by(
+        old: &Self::CustomTypeValidation,
+        new: &Self::CustomTypeValidation,
+    ) -> bool;
+}
+
+/// How a type has changed, from the point of view of values encoded under the old type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SchemaCompatibility {
+    Identical,
+    /// Every value of the old type is also a valid value of the new type.
+    BackwardCompatible,
+    /// Some values of the old type can't be decoded as, or fail validation for, the new type.
+    Breaking,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    /// The location of the change, e.g. `$.vaults[]` or `$::Some.0`.
+    pub path: String,
+    pub kind: SchemaChangeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaChangeKind {
+    // Backward compatible
+    TypeRenamed { old: String, new: String },
+    FieldRenamed { old: String, new: String },
+    VariantRenamed { old: String, new: String },
+    VariantAdded { discriminator: u8 },
+    ValidationRelaxed,
+    GeneralizedToAny { old: String },
+
+    // Breaking
+    TypeNotFound(LocalTypeIndex),
+    TypeKindChanged { old: String, new: String },
+    FieldCountChanged { old: usize, new: usize },
+    VariantRemoved { discriminator: u8 },
+    ValidationTightened,
+}
+
+impl SchemaChangeKind {
+    pub fn compatibility(&self) -> SchemaCompatibility {
+        match self {
+            SchemaChangeKind::TypeRenamed { .. }
+            | SchemaChangeKind::FieldRenamed { .. }
+            | SchemaChangeKind::VariantRenamed { .. }
+            | SchemaChangeKind::VariantAdded { .. }
+            | SchemaChangeKind::ValidationRelaxed
+            | SchemaChangeKind::GeneralizedToAny { .. } => SchemaCompatibility::BackwardCompatible,
+            SchemaChangeKind::TypeNotFound(_)
+            | SchemaChangeKind::TypeKindChanged { .. }
+            | SchemaChangeKind::FieldCountChanged { .. }
+            | SchemaChangeKind::VariantRemoved { .. }
+            | SchemaChangeKind::ValidationTightened => SchemaCompatibility::Breaking,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaComparison {
+    pub changes: Vec<SchemaChange>,
+}
+
+impl SchemaComparison {
+    pub fn compatibility(&self) -> SchemaCompatibility {
+        self.changes
+            .iter()
+            .map(|change| change.kind.compatibility())
+            .max()
+            .unwrap_or(SchemaCompatibility::Identical)
+    }
+
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &SchemaChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.kind.compatibility() == SchemaCompatibility::Breaking)
+    }
+}
+
+/// Compares a type in an old schema with a type in a new schema, eg before and after a blueprint
+/// upgrade, and lists every difference along with the path at which it occurs.
+///
+/// The comparison is directional: adding an enum variant or relaxing a validation is compatible,
+/// as existing values still decode, whereas removing a variant or tightening a validation is
+/// breaking. To check that readers of the old type can read values of the new type, swap the
+/// arguments.
+pub fn compare_schema_types<E: ComparableCustomTypeExtension>(
+    old_schema: &Schema<E>,
+    old_index: LocalTypeIndex,
+    new_schema: &Schema<E>,
+    new_index: LocalTypeIndex,
+) -> SchemaComparison {
+    let mut comparer = SchemaComparer {
+        old_schema,
+        new_schema,
+        path: vec!["$".to_string()],
+        in_progress: HashSet::new(),
+        changes: Vec::new(),
+    };
+    comparer.compare(old_index, new_index);
+    SchemaComparison {
+        changes: comparer.changes,
+    }
+}
+
+struct SchemaComparer<'s, E: ComparableCustomTypeExtension> {
+    old_schema: &'s Schema<E>,
+    new_schema: &'s Schema<E>,
+    path: Vec<String>,
+    /// The pairs of types being compared on the current path, to detect recursion
+    in_progress: HashSet<(LocalTypeIndex, LocalTypeIndex)>,
+    changes: Vec<SchemaChange>,
+}
+
+impl<'s, E: ComparableCustomTypeExtension> SchemaComparer<'s, E> {
+    fn report(&mut self, kind: SchemaChangeKind) {
+        self.changes.push(SchemaChange {
+            path: self.path.concat(),
+            kind,
+        });
+    }
+
+    fn compare_child(&mut self, segment: String, old: LocalTypeIndex, new: LocalTypeIndex) {
+        self.path.push(segment);
+        self.compare(old, new);
+        self.path.pop();
+    }
+
+    fn compare(&mut self, old_index: LocalTypeIndex, new_index: LocalTypeIndex) {
+        // Well known types are the same in every schema
+        if old_index == new_index && matches!(old_index, LocalTypeIndex::WellKnown(_)) {
+            return;
+        }
+        // Recursive types would otherwise be compared forever. Types shared between several
+        // paths are still compared at each of them, so that every affected path is reported.
+        if !self.in_progress.insert((old_index, new_index)) {
+            return;
+        }
+        self.compare_types(old_index, new_index);
+        self.in_progress.remove(&(old_index, new_index));
+    }
+
+    fn compare_types(&mut self, old_index: LocalTypeIndex, new_index: LocalTypeIndex) {
+        let Some(old) = self.old_schema.resolve(old_index) else {
+            return self.report(SchemaChangeKind::TypeNotFound(old_index));
+        };
+        let Some(new) = self.new_schema.resolve(new_index) else {
+            return self.report(SchemaChangeKind::TypeNotFound(new_index));
+        };
+
+        let old_name = old.metadata.type_name.to_string();
+        let new_name = new.metadata.type_name.to_string();
+        match (old.kind.as_ref(), new.kind.as_ref()) {
+            (TypeKind::Any, TypeKind::Any) => {}
+            (_, TypeKind::Any) => {
+                return self.report(SchemaChangeKind::GeneralizedToAny { old: old_name })
+            }
+            (
+                TypeKind::Array {
+                    element_type: old_element,
+                },
+                TypeKind::Array {
+                    element_type: new_element,
+                },
+            ) => {
+                self.compare_child("[]".to_string(), *old_element, *new_element);
+            }
+            (
+                TypeKind::Tuple {
+                    field_types: old_fields,
+                },
+                TypeKind::Tuple {
+                    field_types: new_fields,
+                },
+            ) => {
+                self.compare_fields(&old.metadata, old_fields, &new.metadata, new_fields);
+            }
+            (
+                TypeKind::Enum {
+                    variants: old_variants,
+                },
+                TypeKind::Enum {
+                    variants: new_variants,
+                },
+            ) => {
+                for (discriminator, old_fields) in old_variants {
+                    let old_variant = variant_metadata(&old.metadata, *discriminator);
+                    self.path.push(match old_variant {
+                        Some(metadata) => format!("::{}", metadata.type_name),
+                        None => format!("::{}", discriminator),
+                    });
+                    match new_variants.get(discriminator) {
+                        Some(new_fields) => {
+                            let new_variant = variant_metadata(&new.metadata, *discriminator);
+                            if let (Some(old_variant), Some(new_variant)) =
+                                (old_variant, new_variant)
+                            {
+                                if old_variant.type_name != new_variant.type_name {
+                                    self.report(SchemaChangeKind::VariantRenamed {
+                                        old: old_variant.type_name.to_string(),
+                                        new: new_variant.type_name.to_string(),
+                                    });
+                                }
+                            }
+                            self.compare_fields(
+                                old_variant.unwrap_or(&TypeMetadata::default()),
+                                old_fields,
+                                new_variant.unwrap_or(&TypeMetadata::default()),
+                                new_fields,
+                            );
+                        }
+                        None => self.report(SchemaChangeKind::VariantRemoved {
+                            discriminator: *discriminator,
+                        }),
+                    }
+                    self.path.pop();
+                }
+                for discriminator in new_variants.keys() {
+                    if !old_variants.contains_key(discriminator) {
+                        self.report(SchemaChangeKind::VariantAdded {
+                            discriminator: *discriminator,
+                        });
+                    }
+                }
+            }
+            (
+                TypeKind::Map {
+                    key_type: old_key,
+                    value_type: old_value,
+                },
+                TypeKind::Map {
+                    key_type: new_key,
+                    value_type: new_value,
+                },
+            ) => {
+                self.compare_child("[].key".to_string(), *old_key, *new_key);
+                self.compare_child("[].value".to_string(), *old_value, *new_value);
+            }
+            (TypeKind::Custom(old_custom), TypeKind::Custom(new_custom)) => {
+                match E::matching_custom_type_kind_children(old_custom, new_custom) {
+                    Some(children) => {
+                        for (segment, old_child, new_child) in children {
+                            self.compare_child(segment.to_string(), old_child, new_child);
+                        }
+                    }
+                    None => {
+                        return self.report(SchemaChangeKind::TypeKindChanged {
+                            old: old_name,
+                            new: new_name,
+                        })
+                    }
+                }
+            }
+            // The remaining kinds have no children, so are the same if they're the same variant
+            (old_kind, new_kind)
+                if is_leaf_kind(old_kind) && discriminant(old_kind) == discriminant(new_kind) => {}
+            _ => {
+                return self.report(SchemaChangeKind::TypeKindChanged {
+                    old: old_name,
+                    new: new_name,
+                })
+            }
+        }
+
+        if old_name != new_name {
+            self.report(SchemaChangeKind::TypeRenamed {
+                old: old_name,
+                new: new_name,
+            });
+        }
+        if let Some(change) = compare_validations::<E>(&old.validation, &new.validation) {
+            self.report(change);
+        }
+    }
+
+    fn compare_fields(
+        &mut self,
+        old_metadata: &TypeMetadata,
+        old_fields: &[LocalTypeIndex],
+        new_metadata: &TypeMetadata,
+        new_fields: &[LocalTypeIndex],
+    ) {
+        if old_fields.len() != new_fields.len() {
+            return self.report(SchemaChangeKind::FieldCountChanged {
+                old: old_fields.len(),
+                new: new_fields.len(),
+            });
+        }
+        let old_names = field_names(old_metadata);
+        let new_names = field_names(new_metadata);
+        for (i, (old_field, new_field)) in old_fields.iter().zip(new_fields).enumerate() {
+            let old_name = old_names.and_then(|names| names.get(i));
+            let new_name = new_names.and_then(|names| names.get(i));
+            self.path.push(match new_name.or(old_name) {
+                Some(name) => format!(".{}", name),
+                None => format!(".{}", i),
+            });
+            if let (Some(old_name), Some(new_name)) = (old_name, new_name) {
+                if old_name != new_name {
+                    self.report(SchemaChangeKind::FieldRenamed {
+                        old: old_name.to_string(),
+                        new: new_name.to_string(),
+                    });
+                }
+            }
+            self.compare(*old_field, *new_field);
+            self.path.pop();
+        }
+    }
+}
+
+fn is_leaf_kind<
+    X: CustomValueKind,
+    K: CustomTypeKind<L, CustomValueKind = X>,
+    L: SchemaTypeLink,
+>(
+    kind: &TypeKind<X, K, L>,
+) -> bool {
+    !matches!(
+        kind,
+        TypeKind::Any
+            | TypeKind::Array { .. }
+            | TypeKind::Tuple { .. }
+            | TypeKind::Enum { .. }
+            | TypeKind::Map { .. }
+            | TypeKind::Custom(_)
+    )
+}
+
+fn field_names(metadata: &TypeMetadata) -> Option<&[Cow<'static, str>]> {
+    match &metadata.child_names {
+        ChildNames::FieldNames(names) => Some(names),
+        _ => None,
+    }
+}
+
+fn variant_metadata(metadata: &TypeMetadata, discriminator: u8) -> Option<&TypeMetadata> {
+    match &metadata.child_names {
+        ChildNames::VariantNames(variants) => variants.get(&discriminator),
+        _ => None,
+    }
+}
+
+fn compare_validations<E: ComparableCustomTypeExtension>(
+    old: &TypeValidation<E::CustomTypeValidation>,
+    new: &TypeValidation<E::CustomTypeValidation>,
+) -> Option<SchemaChangeKind> {
+    if old == new {
+        return None;
+    }
+    let relaxed = match (old, new) {
+        (_, TypeValidation::None) => true,
+        (TypeValidation::I8(old), TypeValidation::I8(new)) => numeric_is_relaxed(old, new),
+        (TypeValidation::I16(old), TypeValidation::I16(new)) => numeric_is_relaxed(old, new),
+        (TypeValidation::I32(old), TypeValidation::I32(new)) => numeric_is_relaxed(old, new),
+        (TypeValidation::I64(old), TypeValidation::I64(new)) => numeric_is_relaxed(old, new),
+        (TypeValidation::I128(old), TypeValidation::I128(new)) => numeric_is_relaxed(old, new),
+        (TypeValidation::U8(old), TypeValidation::U8(new)) => numeric_is_relaxed(old, new),
+        (TypeValidation::U16(old), TypeValidation::U16(new)) => numeric_is_relaxed(old, new),
+        (TypeValidation::U32(old), TypeValidation::U32(new)) => numeric_is_relaxed(old, new),
+        (TypeValidation::U64(old), TypeValidation::U64(new)) => numeric_is_relaxed(old, new),
+        (TypeValidation::U128(old), TypeValidation::U128(new)) => numeric_is_relaxed(old, new),
+        (
+            TypeValidation::String {
+                length_validation: old,
+            },
+            TypeValidation::String {
+                length_validation: new,
+            },
+        )
+        | (
+            TypeValidation::Array {
+                length_validation: old,
+            },
+            TypeValidation::Array {
+                length_validation: new,
+            },
+        ) => {
+            bound_is_relaxed(&old.min, &new.min, true)
+                && bound_is_relaxed(&old.max, &new.max, false)
+        }
+        (TypeValidation::Custom(old), TypeValidation::Custom(new)) => {
+            E::custom_validation_is_relaxed_by(old, new)
+        }
+        _ => false,
+    };
+    Some(if relaxed {
+        SchemaChangeKind::ValidationRelaxed
+    } else {
+        SchemaChangeKind::ValidationTightened
+    })
+}
+
+fn numeric_is_relaxed<T: PartialOrd>(
+    old: &NumericValidation<T>,
+    new: &NumericValidation<T>,
+) -> bool {
+    bound_is_relaxed(&old.min, &new.min, true) && bound_is_relaxed(&old.max, &new.max, false)
+}
+
+fn bound_is_relaxed<T: PartialOrd>(old: &Option<T>, new: &Option<T>, is_lower_bound: bool) -> bool {
+    match (old, new) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(old), Some(new)) if is_lower_bound => new <= old,
+        (Some(old), Some(new)) => new >= old,
+    }
+}
@@ -1,5 +1,6 @@
 use super::*;
 use sbor::rust::collections::*;
+use sbor::rust::vec::Vec;
 
 pub fn generate_full_schema_from_single_type<
     T: Describe<E::CustomTypeKind<GlobalTypeId>>,
@@ -22,14 +23,20 @@ pub fn generate_full_schema<C: CustomTypeKind<GlobalTypeId>>(
     for (type_hash, (type_data, type_index)) in aggregator.types {
         let kind = linearize::<C::CustomTypeExtension>(type_data.kind, &type_indices);
         let metadata = type_data.metadata.with_type_hash(type_hash);
-        sorted_types.insert(type_index, (kind, metadata));
+        sorted_types.insert(type_index, (kind, metadata, type_data.validation));
     }
 
-    let mapped = sorted_types.into_iter().map(|(_, v)| v).unzip();
-    Schema {
-        type_kinds: mapped.0,
-        type_metadata: mapped.1,
+    let mut schema = Schema {
+        type_kinds: Vec::with_capacity(sorted_types.len()),
+        type_metadata: Vec::with_capacity(sorted_types.len()),
+        type_validations: Vec::with_capacity(sorted_types.len()),
+    };
+    for (_, (kind, metadata, validation)) in sorted_types {
+        schema.type_kinds.push(kind);
+        schema.type_metadata.push(metadata);
+        schema.type_validations.push(validation);
     }
+    schema
 }
 
 fn linearize<E: CustomTypeExtension>(
@@ -37,6 +37,11 @@ impl LengthValidation {
             max: None,
         }
     }
+
+    pub fn is_satisfied_by(&self, length: usize) -> bool {
+        self.min.map_or(true, |min| length >= min as usize)
+            && self.max.map_or(true, |max| length <= max as usize)
+    }
 }
 
 /// Represents additional validation that should be performed on the numeric value.
@@ -54,3 +59,10 @@ impl<T> NumericValidation<T> {
         }
     }
 }
+
+impl<T: PartialOrd> NumericValidation<T> {
+    pub fn is_satisfied_by(&self, value: &T) -> bool {
+        self.min.as_ref().map_or(true, |min| value >= min)
+            && self.max.as_ref().map_or(true, |max| value <= max)
+    }
+}
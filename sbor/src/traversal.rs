@@ -0,0 +1,499 @@
+use crate::rust::marker::PhantomData;
+use crate::rust::string::String;
+use crate::rust::vec::Vec;
+use crate::value_kind::*;
+use crate::*;
+
+/// An event yielded by a [`Traverser`], in the order the values appear in the payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraversalEvent<X: CustomValueKind, Y> {
+    ContainerStart(ContainerHeader<X>),
+    ContainerEnd(ContainerHeader<X>),
+    TerminalValue(TerminalValue<X, Y>),
+    /// The whole value has been traversed, and the input has been checked to be fully consumed.
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerHeader<X: CustomValueKind> {
+    Tuple {
+        length: usize,
+    },
+    EnumVariant {
+        discriminator: u8,
+        length: usize,
+    },
+    Array {
+        element_value_kind: ValueKind<X>,
+        length: usize,
+    },
+    Map {
+        key_value_kind: ValueKind<X>,
+        value_value_kind: ValueKind<X>,
+        length: usize,
+    },
+}
+
+impl<X: CustomValueKind> ContainerHeader<X> {
+    pub fn value_kind(&self) -> ValueKind<X> {
+        match self {
+            ContainerHeader::Tuple { .. } => ValueKind::Tuple,
+            ContainerHeader::EnumVariant { .. } => ValueKind::Enum,
+            ContainerHeader::Array { .. } => ValueKind::Array,
+            ContainerHeader::Map { .. } => ValueKind::Map,
+        }
+    }
+
+    /// The number of child values, counting each map entry as two (key and value).
+    fn child_count(&self) -> usize {
+        match self {
+            ContainerHeader::Tuple { length }
+            | ContainerHeader::EnumVariant { length, .. }
+            | ContainerHeader::Array { length, .. } => *length,
+            ContainerHeader::Map { length, .. } => *length * 2,
+        }
+    }
+
+    /// The value kind of the given child, if it's fixed by the header.
+    fn child_value_kind(&self, index: usize) -> Option<ValueKind<X>> {
+        match self {
+            ContainerHeader::Tuple { .. } | ContainerHeader::EnumVariant { .. } => None,
+            ContainerHeader::Array {
+                element_value_kind, ..
+            } => Some(*element_value_kind),
+            ContainerHeader::Map {
+                key_value_kind,
+                value_value_kind,
+                ..
+            } => Some(if index % 2 == 0 {
+                *key_value_kind
+            } else {
+                *value_value_kind
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalValue<X: CustomValueKind, Y> {
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    String(String),
+    Custom { value_kind: X, value: Y },
+}
+
+impl<X: CustomValueKind, Y> TerminalValue<X, Y> {
+    pub fn value_kind(&self) -> ValueKind<X> {
+        match self {
+            TerminalValue::Bool(_) => ValueKind::Bool,
+            TerminalValue::I8(_) => ValueKind::I8,
+            TerminalValue::I16(_) => ValueKind::I16,
+            TerminalValue::I32(_) => ValueKind::I32,
+            TerminalValue::I64(_) => ValueKind::I64,
+            TerminalValue::I128(_) => ValueKind::I128,
+            TerminalValue::U8(_) => ValueKind::U8,
+            TerminalValue::U16(_) => ValueKind::U16,
+            TerminalValue::U32(_) => ValueKind::U32,
+            TerminalValue::U64(_) => ValueKind::U64,
+            TerminalValue::U128(_) => ValueKind::U128,
+            TerminalValue::String(_) => ValueKind::String,
+            TerminalValue::Custom { value_kind, .. } => ValueKind::Custom(*value_kind),
+        }
+    }
+}
+
+struct ContainerState<X: CustomValueKind> {
+    header: ContainerHeader<X>,
+    children_started: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TraversalStatus {
+    NotStarted,
+    InProgress,
+    Finished,
+}
+
+/// A pull-based walk over a single SBOR value, which reads from the decoder only as far as is
+/// needed to produce the next event.
+///
+/// Unlike decoding into a [`Value`], memory use is bounded by the depth of the value rather than
+/// its size, so when used with a [`StreamDecoder`](crate::decoder::StreamDecoder) over a
+/// [`std::io::BufReader`] it can scan payloads which don't fit in memory.
+///
+/// Depth is counted in the same way as the [`Value`] codec: every value, including leaves, is one
+/// level deeper than its parent, and the root is at depth 1.
+pub struct Traverser<X: CustomValueKind, Y, D: Decoder<X>, const MAX_DEPTH: u8> {
+    decoder: D,
+    payload_prefix: Option<u8>,
+    stack: Vec<ContainerState<X>>,
+    status: TraversalStatus,
+    phantom: PhantomData<Y>,
+}
+
+impl<X: CustomValueKind, Y: Decode<X, D>, D: Decoder<X>, const MAX_DEPTH: u8>
+    Traverser<X, Y, D, MAX_DEPTH>
+{
+    /// Creates a traverser for a full payload, starting with the given prefix byte.
+    pub fn new_payload(decoder: D, expected_prefix: u8) -> Self {
+        Self {
+            decoder,
+            payload_prefix: Some(expected_prefix),
+            stack: Vec::new(),
+            status: TraversalStatus::NotStarted,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a traverser for a value without a payload prefix.
+    pub fn new(decoder: D) -> Self {
+        Self {
+            decoder,
+            payload_prefix: None,
+            stack: Vec::new(),
+            status: TraversalStatus::NotStarted,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The number of containers which the traverser is currently inside.
+    pub fn container_depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// The path to the value which the last event relates to, in the same format as
+    /// [`traverse_any`] - where map entries are addressed by entry index, then 0 for the key or
+    /// 1 for the value.
+    pub fn current_path(&self) -> SborPath {
+        let mut path = Vec::new();
+        for state in &self.stack {
+            // A container whose children haven't started yet is the value itself
+            if state.children_started == 0 {
+                break;
+            }
+            let index = state.children_started - 1;
+            match state.header {
+                ContainerHeader::Map { .. } => {
+                    path.push(index / 2);
+                    path.push(index % 2);
+                }
+                _ => path.push(index),
+            }
+        }
+        SborPath::new(path)
+    }
+
+    pub fn next_event(&mut self) -> Result<TraversalEvent<X, Y>, DecodeError> {
+        let value_kind = match self.stack.last_mut() {
+            Some(state) => {
+                if state.children_started == state.header.child_count() {
+                    let header = state.header;
+                    self.stack.pop();
+                    return Ok(TraversalEvent::ContainerEnd(header));
+                }
+                let child_value_kind = state.header.child_value_kind(state.children_started);
+                state.children_started += 1;
+                match child_value_kind {
+                    Some(value_kind) => value_kind,
+                    None => self.decoder.read_value_kind()?,
+                }
+            }
+            None => match self.status {
+                TraversalStatus::NotStarted => {
+                    if let Some(prefix) = self.payload_prefix {
+                        self.decoder.read_and_check_payload_prefix(prefix)?;
+                    }
+                    self.status = TraversalStatus::InProgress;
+                    self.decoder.read_value_kind()?
+                }
+                TraversalStatus::InProgress => {
+                    self.decoder.check_end()?;
+                    self.status = TraversalStatus::Finished;
+                    return Ok(TraversalEvent::End);
+                }
+                TraversalStatus::Finished => return Ok(TraversalEvent::End),
+            },
+        };
+
+        if self.stack.len() >= MAX_DEPTH as usize {
+            return Err(DecodeError::MaxDepthExceeded(MAX_DEPTH));
+        }
+        self.read_value_body(value_kind)
+    }
+
+    fn read_value_body(
+        &mut self,
+        value_kind: ValueKind<X>,
+    ) -> Result<TraversalEvent<X, Y>, DecodeError> {
+        let decoder = &mut self.decoder;
+        let terminal_value = match value_kind {
+            ValueKind::Bool => {
+                TerminalValue::Bool(bool::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::I8 => {
+                TerminalValue::I8(i8::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::I16 => {
+                TerminalValue::I16(i16::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::I32 => {
+                TerminalValue::I32(i32::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::I64 => {
+                TerminalValue::I64(i64::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::I128 => {
+                TerminalValue::I128(i128::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::U8 => {
+                TerminalValue::U8(u8::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::U16 => {
+                TerminalValue::U16(u16::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::U32 => {
+                TerminalValue::U32(u32::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::U64 => {
+                TerminalValue::U64(u64::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::U128 => {
+                TerminalValue::U128(u128::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::String => {
+                TerminalValue::String(String::decode_body_with_value_kind(decoder, value_kind)?)
+            }
+            ValueKind::Custom(custom_value_kind) => TerminalValue::Custom {
+                value_kind: custom_value_kind,
+                value: Y::decode_body_with_value_kind(decoder, value_kind)?,
+            },
+            ValueKind::Tuple => {
+                let length = decoder.read_size()?;
+                return Ok(self.start_container(ContainerHeader::Tuple { length }));
+            }
+            ValueKind::Enum => {
+                let discriminator = decoder.read_discriminator()?;
+                let length = decoder.read_size()?;
+                return Ok(self.start_container(ContainerHeader::EnumVariant {
+                    discriminator,
+                    length,
+                }));
+            }
+            ValueKind::Array => {
+                let element_value_kind = decoder.read_value_kind()?;
+                let length = decoder.read_size()?;
+                return Ok(self.start_container(ContainerHeader::Array {
+                    element_value_kind,
+                    length,
+                }));
+            }
+            ValueKind::Map => {
+                let key_value_kind = decoder.read_value_kind()?;
+                let value_value_kind = decoder.read_value_kind()?;
+                let length = decoder.read_size()?;
+                return Ok(self.start_container(ContainerHeader::Map {
+                    key_value_kind,
+                    value_value_kind,
+                    length,
+                }));
+            }
+        };
+        Ok(TraversalEvent::TerminalValue(terminal_value))
+    }
+
+    fn start_container(&mut self, header: ContainerHeader<X>) -> TraversalEvent<X, Y> {
+        self.stack.push(ContainerState {
+            header,
+            children_started: 0,
+        });
+        TraversalEvent::ContainerStart(header)
+    }
+
+    pub fn into_decoder(self) -> D {
+        self.decoder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rust::borrow::ToOwned;
+    use crate::rust::collections::*;
+    use crate::rust::vec;
+
+    type BasicTraverser<'a> =
+        Traverser<NoCustomValueKind, NoCustomValue, BasicDecoder<'a>, DEFAULT_BASIC_MAX_DEPTH>;
+
+    fn collect_events(
+        payload: &[u8],
+    ) -> Result<Vec<(SborPath, TraversalEvent<NoCustomValueKind, NoCustomValue>)>, DecodeError>
+    {
+        let mut traverser =
+            BasicTraverser::new_payload(BasicDecoder::new(payload), BASIC_SBOR_V1_PAYLOAD_PREFIX);
+        let mut events = Vec::new();
+        loop {
+            let event = traverser.next_event()?;
+            if event == TraversalEvent::End {
+                return Ok(events);
+            }
+            events.push((traverser.current_path(), event));
+        }
+    }
+
+    #[test]
+    pub fn test_traversal_events() {
+        let payload = basic_encode(&(
+            "hi".to_owned(),
+            vec![1u16],
+            BTreeMap::from([(2u8, Some(true))]),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            collect_events(&payload).unwrap(),
+            vec![
+                (
+                    SborPath::new(vec![]),
+                    TraversalEvent::ContainerStart(ContainerHeader::Tuple { length: 3 })
+                ),
+                (
+                    SborPath::new(vec![0]),
+                    TraversalEvent::TerminalValue(TerminalValue::String("hi".to_owned()))
+                ),
+                (
+                    SborPath::new(vec![1]),
+                    TraversalEvent::ContainerStart(ContainerHeader::Array {
+                        element_value_kind: ValueKind::U16,
+                        length: 1
+                    })
+                ),
+                (
+                    SborPath::new(vec![1, 0]),
+                    TraversalEvent::TerminalValue(TerminalValue::U16(1))
+                ),
+                (
+                    SborPath::new(vec![1]),
+                    TraversalEvent::ContainerEnd(ContainerHeader::Array {
+                        element_value_kind: ValueKind::U16,
+                        length: 1
+                    })
+                ),
+                (
+                    SborPath::new(vec![2]),
+                    TraversalEvent::ContainerStart(ContainerHeader::Map {
+                        key_value_kind: ValueKind::U8,
+                        value_value_kind: ValueKind::Enum,
+                        length: 1
+                    })
+                ),
+                (
+                    SborPath::new(vec![2, 0, 0]),
+                    TraversalEvent::TerminalValue(TerminalValue::U8(2))
+                ),
+                (
+                    SborPath::new(vec![2, 0, 1]),
+                    TraversalEvent::ContainerStart(ContainerHeader::EnumVariant {
+                        discriminator: 1,
+                        length: 1
+                    })
+                ),
+                (
+                    SborPath::new(vec![2, 0, 1, 0]),
+                    TraversalEvent::TerminalValue(TerminalValue::Bool(true))
+                ),
+                (
+                    SborPath::new(vec![2, 0, 1]),
+                    TraversalEvent::ContainerEnd(ContainerHeader::EnumVariant {
+                        discriminator: 1,
+                        length: 1
+                    })
+                ),
+                (
+                    SborPath::new(vec![2]),
+                    TraversalEvent::ContainerEnd(ContainerHeader::Map {
+                        key_value_kind: ValueKind::U8,
+                        value_value_kind: ValueKind::Enum,
+                        length: 1
+                    })
+                ),
+                (
+                    SborPath::new(vec![]),
+                    TraversalEvent::ContainerEnd(ContainerHeader::Tuple { length: 3 })
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_traversal_checks_trailing_bytes() {
+        let mut payload = basic_encode(&1u8).unwrap();
+        payload.push(0);
+        assert_eq!(
+            collect_events(&payload),
+            Err(DecodeError::ExtraTrailingBytes(1))
+        );
+    }
+
+    #[test]
+    pub fn test_traversal_depth_matches_value_codec() {
+        fn nested_vecs(depth: usize) -> BasicValue {
+            let mut value = BasicValue::U8 { value: 0 };
+            let mut value_kind = ValueKind::U8;
+            for _ in 1..depth {
+                value = BasicValue::Array {
+                    element_value_kind: value_kind,
+                    elements: vec![value],
+                };
+                value_kind = ValueKind::Array;
+            }
+            value
+        }
+
+        let max_depth = DEFAULT_BASIC_MAX_DEPTH as usize;
+        let mut payload = Vec::new();
+        VecEncoder::<NoCustomValueKind, 255>::new(&mut payload)
+            .encode_payload(&nested_vecs(max_depth), BASIC_SBOR_V1_PAYLOAD_PREFIX)
+            .unwrap();
+        assert!(basic_decode::<BasicValue>(&payload).is_ok());
+        assert!(collect_events(&payload).is_ok());
+
+        let mut payload = Vec::new();
+        VecEncoder::<NoCustomValueKind, 255>::new(&mut payload)
+            .encode_payload(&nested_vecs(max_depth + 1), BASIC_SBOR_V1_PAYLOAD_PREFIX)
+            .unwrap();
+        let expected = Err(DecodeError::MaxDepthExceeded(DEFAULT_BASIC_MAX_DEPTH));
+        assert_eq!(basic_decode::<BasicValue>(&payload).map(|_| ()), expected);
+        assert_eq!(collect_events(&payload).map(|_| ()), expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    pub fn test_traversal_over_stream() {
+        let payload = basic_encode(&(vec![1u32, 2, 3], "stream".to_owned())).unwrap();
+
+        let mut traverser =
+            Traverser::<NoCustomValueKind, NoCustomValue, _, DEFAULT_BASIC_MAX_DEPTH>::new_payload(
+                StreamDecoder::<_, NoCustomValueKind, DEFAULT_BASIC_MAX_DEPTH>::new(&payload[..]),
+                BASIC_SBOR_V1_PAYLOAD_PREFIX,
+            );
+        let mut stream_events = Vec::new();
+        loop {
+            let event = traverser.next_event().unwrap();
+            if event == TraversalEvent::End {
+                break;
+            }
+            stream_events.push((traverser.current_path(), event));
+        }
+
+        assert_eq!(stream_events, collect_events(&payload).unwrap());
+    }
+}
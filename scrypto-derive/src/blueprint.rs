@@ -519,7 +519,7 @@ fn generate_stubs(
             fn blueprint_name(&self) -> String {
                 self.component.blueprint_name()
             }
-            fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+            fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataEntry>>(&mut self, name: K, value: V) -> &mut Self {
                 self.component.metadata(name, value);
                 self
             }
@@ -565,7 +565,7 @@ fn generate_stubs(
             fn blueprint_name(&self) -> String {
                 self.component.blueprint_name()
             }
-            fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+            fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataEntry>>(&mut self, name: K, value: V) -> &mut Self {
                 self.component.metadata(name, value);
                 self
             }
@@ -766,7 +766,7 @@ mod tests {
                     fn blueprint_name(&self) -> String {
                         self.component.blueprint_name()
                     }
-                    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+                    fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataEntry>>(&mut self, name: K, value: V) -> &mut Self {
                         self.component.metadata(name, value);
                         self
                     }
@@ -816,7 +816,7 @@ mod tests {
                     fn blueprint_name(&self) -> String {
                         self.component.blueprint_name()
                     }
-                    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+                    fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataEntry>>(&mut self, name: K, value: V) -> &mut Self {
                         self.component.metadata(name, value);
                         self
                     }
@@ -909,7 +909,7 @@ mod tests {
                     fn blueprint_name(&self) -> String {
                         self.component.blueprint_name()
                     }
-                    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+                    fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataEntry>>(&mut self, name: K, value: V) -> &mut Self {
                         self.component.metadata(name, value);
                         self
                     }
@@ -952,7 +952,7 @@ mod tests {
                     fn blueprint_name(&self) -> String {
                         self.component.blueprint_name()
                     }
-                    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+                    fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataEntry>>(&mut self, name: K, value: V) -> &mut Self {
                         self.component.metadata(name, value);
                         self
                     }
@@ -1046,7 +1046,7 @@ mod tests {
                     fn blueprint_name(&self) -> String {
                         self.component.blueprint_name()
                     }
-                    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+                    fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataEntry>>(&mut self, name: K, value: V) -> &mut Self {
                         self.component.metadata(name, value);
                         self
                     }
@@ -1089,7 +1089,7 @@ mod tests {
                     fn blueprint_name(&self) -> String {
                         self.component.blueprint_name()
                     }
-                    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+                    fn metadata<K: AsRef<str>, V: Into<::scrypto::model::MetadataEntry>>(&mut self, name: K, value: V) -> &mut Self {
                         self.component.metadata(name, value);
                         self
                     }
@@ -1,5 +1,7 @@
 use bech32::{self, ToBase32, Variant};
 use scrypto::radix_engine_interface::address::*;
+use scrypto::radix_engine_interface::api::types::GlobalAddress;
+use scrypto::radix_engine_interface::crypto::*;
 use scrypto::radix_engine_interface::model::*;
 use scrypto::radix_engine_interface::node::NetworkDefinition;
 
@@ -75,10 +77,7 @@ fn decode_truncated_checksum_address_fails() {
     );
 
     // Assert
-    assert!(matches!(
-        decoded_resource_address,
-        Err(AddressError::Bech32mDecodingError(_))
-    ));
+    assert_eq!(decoded_resource_address, Err(AddressError::InvalidChecksum));
 }
 
 #[test]
@@ -97,10 +96,7 @@ fn decode_modified_checksum_address_fails() {
         bech32_decoder.validate_and_decode_resource_address(&encoded_resource_address);
 
     // Assert
-    assert!(matches!(
-        decoded_resource_address,
-        Err(AddressError::Bech32mDecodingError(_))
-    ));
+    assert_eq!(decoded_resource_address, Err(AddressError::InvalidChecksum));
 }
 
 /// Tests if the decoding fails when the address is encoded in Bech32 and not Bech32m
@@ -306,3 +302,209 @@ fn decode_invalid_network_specifier_fails() {
     // Assert
     assert!(matches!(decoded_resource_address, Err(_)));
 }
+
+#[test]
+fn parse_any_decodes_addresses_of_every_entity_type() {
+    // Arrange
+    let bech32_encoder = Bech32Encoder::for_simulator();
+    let bech32_decoder = Bech32Decoder::for_simulator();
+    let addresses = [
+        GlobalAddress::Package(PackageAddress::Normal([1u8; 26])),
+        GlobalAddress::Resource(ResourceAddress::Normal([2u8; 26])),
+        GlobalAddress::Component(ComponentAddress::EcdsaSecp256k1VirtualAccount([3u8; 26])),
+        GlobalAddress::Component(ComponentAddress::EddsaEd25519VirtualIdentity([4u8; 26])),
+        GlobalAddress::Component(ComponentAddress::AccessController([5u8; 26])),
+    ];
+
+    for address in addresses {
+        // Act
+        let encoded = match &address {
+            GlobalAddress::Package(address) => {
+                bech32_encoder.encode_package_address_to_string(address)
+            }
+            GlobalAddress::Resource(address) => {
+                bech32_encoder.encode_resource_address_to_string(address)
+            }
+            GlobalAddress::Component(address) => {
+                bech32_encoder.encode_component_address_to_string(address)
+            }
+        };
+        let decoded = bech32_decoder.parse_any(&encoded);
+
+        // Assert
+        assert_eq!(decoded, Ok(address));
+    }
+}
+
+#[test]
+fn parse_any_classifies_entity_type() {
+    // Arrange
+    let bech32_encoder = Bech32Encoder::for_simulator();
+    let bech32_decoder = Bech32Decoder::for_simulator();
+    let encoded = bech32_encoder.encode_component_address_to_string(
+        &ComponentAddress::EddsaEd25519VirtualAccount([0u8; 26]),
+    );
+
+    // Act
+    let entity_type = EntityType::global_address(&bech32_decoder.parse_any(&encoded).unwrap());
+
+    // Assert
+    assert_eq!(entity_type, EntityType::EddsaEd25519VirtualAccountComponent);
+    assert!(entity_type.is_component());
+    assert!(entity_type.is_account());
+    assert!(entity_type.is_virtual());
+    assert!(!entity_type.is_identity());
+}
+
+#[test]
+fn parse_any_with_address_of_other_network_fails() {
+    // Arrange
+    let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::mainnet());
+    let bech32_decoder = Bech32Decoder::for_simulator();
+    let encoded =
+        bech32_encoder.encode_resource_address_to_string(&ResourceAddress::Normal([0u8; 26]));
+
+    // Act
+    let decoded = bech32_decoder.parse_any(&encoded);
+
+    // Assert
+    assert_eq!(
+        decoded,
+        Err(AddressError::WrongNetwork {
+            expected_hrp_suffix: "sim".to_string(),
+            actual_hrp_suffix: "rdx".to_string(),
+        })
+    );
+}
+
+#[test]
+fn parse_any_with_unknown_entity_byte_fails() {
+    // Arrange
+    let bech32_encoder = Bech32Encoder::for_simulator();
+    let bech32_decoder = Bech32Decoder::for_simulator();
+    let encoded = bech32::encode(
+        bech32_encoder
+            .hrp_set
+            .get_entity_hrp(&EntityType::NormalComponent),
+        generate_u8_array(0xff).to_base32(),
+        Variant::Bech32m,
+    )
+    .unwrap();
+
+    // Act
+    let decoded = bech32_decoder.parse_any(&encoded);
+
+    // Assert
+    assert_eq!(decoded, Err(AddressError::InvalidEntityTypeId(0xff)));
+}
+
+#[test]
+fn parse_any_with_unknown_hrp_fails() {
+    // Arrange
+    let bech32_decoder = Bech32Decoder::for_simulator();
+    let encoded = bech32::encode(
+        "vault_sim",
+        generate_u8_array(NORMAL_COMPONENT_ADDRESS_ENTITY_ID).to_base32(),
+        Variant::Bech32m,
+    )
+    .unwrap();
+
+    // Act
+    let decoded = bech32_decoder.parse_any(&encoded);
+
+    // Assert
+    assert_eq!(
+        decoded,
+        Err(AddressError::UnknownHrp("vault_sim".to_string()))
+    );
+}
+
+#[test]
+fn detect_network_from_hrp_succeeds() {
+    // Arrange
+    let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::adapanet());
+    let encoded = bech32_encoder.encode_component_address_to_string(
+        &ComponentAddress::EcdsaSecp256k1VirtualIdentity([0u8; 26]),
+    );
+
+    let networks = [
+        NetworkDefinition::simulator(),
+        NetworkDefinition::adapanet(),
+    ];
+
+    // Act
+    let network = Bech32Decoder::detect_network(&encoded, &networks);
+
+    // Assert
+    assert_eq!(network, Ok(&NetworkDefinition::adapanet()));
+}
+
+#[test]
+fn detect_network_from_hrp_of_unlisted_network_fails() {
+    // Arrange
+    let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::nebunet());
+    let encoded =
+        bech32_encoder.encode_resource_address_to_string(&ResourceAddress::Normal([0u8; 26]));
+    let networks = [
+        NetworkDefinition::simulator(),
+        NetworkDefinition::adapanet(),
+    ];
+
+    // Act
+    let network = Bech32Decoder::detect_network(&encoded, &networks);
+
+    // Assert
+    assert_eq!(
+        network,
+        Err(AddressError::UnknownHrp("resource_tdx_b_".to_string()))
+    );
+}
+
+#[test]
+fn derive_virtual_addresses_matches_public_key_derivation() {
+    // Arrange
+    let bech32_encoder = Bech32Encoder::for_simulator();
+    let public_keys = [
+        PublicKey::EcdsaSecp256k1(EcdsaSecp256k1PublicKey(
+            [2u8; EcdsaSecp256k1PublicKey::LENGTH],
+        )),
+        PublicKey::EddsaEd25519(EddsaEd25519PublicKey([3u8; EddsaEd25519PublicKey::LENGTH])),
+    ];
+
+    // Act
+    let accounts =
+        derive_virtual_addresses(&public_keys, VirtualEntityKind::Account, &bech32_encoder);
+    let identities =
+        derive_virtual_addresses(&public_keys, VirtualEntityKind::Identity, &bech32_encoder);
+
+    // Assert
+    for (i, public_key) in public_keys.iter().enumerate() {
+        let account = ComponentAddress::virtual_account_from_public_key(public_key);
+        let identity = ComponentAddress::virtual_identity_from_public_key(public_key);
+        assert_eq!(
+            accounts[i],
+            bech32_encoder.encode_component_address_to_string(&account)
+        );
+        assert_eq!(
+            identities[i],
+            bech32_encoder.encode_component_address_to_string(&identity)
+        );
+        assert_eq!(
+            EntityType::component(&account),
+            VirtualEntityKind::Account.entity_type(public_key)
+        );
+        assert_eq!(
+            EntityType::component(&identity),
+            VirtualEntityKind::Identity.entity_type(public_key)
+        );
+    }
+    assert!(accounts[0].starts_with("account_sim1"));
+    assert!(identities[1].starts_with("identity_sim1"));
+}
+
+#[test]
+fn has_vanity_prefix_skips_entity_characters() {
+    assert!(has_vanity_prefix("account_sim1qwabc", "abc"));
+    assert!(!has_vanity_prefix("account_sim1abcde", "abc"));
+    assert!(!has_vanity_prefix("account_sim1qwabc", "b1"));
+}
@@ -5,15 +5,15 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use radix_engine::engine::RuntimeError;
-use radix_engine::engine::{KernelError, ModuleError, ScryptoInterpreter};
+use radix_engine::engine::{AuthError, KernelError, ModuleError, SchemaCache, ScryptoInterpreter};
 use radix_engine::ledger::*;
 use radix_engine::model::{
     export_abi, export_abi_by_component, extract_abi, GlobalAddressSubstate, MetadataSubstate,
     ValidatorSetSubstate, ValidatorSubstate,
 };
 use radix_engine::transaction::{
-    execute_preview, execute_transaction, ExecutionConfig, FeeReserveConfig, PreviewError,
-    PreviewResult, TransactionReceipt, TransactionResult,
+    execute_preview, execute_transaction, CommitResult, ExecutionConfig, FeeReserveConfig,
+    PreviewError, PreviewResult, TransactionOutcome, TransactionReceipt, TransactionResult,
 };
 use radix_engine::types::*;
 use radix_engine::wasm::{DefaultWasmEngine, WasmInstrumenter, WasmMeteringConfig};
@@ -39,6 +39,7 @@ use transaction::model::{Executable, Instruction, SystemTransaction, Transaction
 use transaction::model::{PreviewIntent, TestTransaction};
 use transaction::signing::EcdsaSecp256k1PrivateKey;
 use transaction::validation::TestIntentHashManager;
+use utils::ContextualDisplay;
 
 pub struct Compile;
 
@@ -132,6 +133,7 @@ impl TestRunnerBuilder {
                 wasm_metering_config: WasmMeteringConfig::V0,
                 wasm_engine: DefaultWasmEngine::default(),
                 wasm_instrumenter: WasmInstrumenter::default(),
+                schema_cache: SchemaCache::default(),
             },
             substate_store: TypedInMemorySubstateStore::new(),
             state_hash_support: Some(self.state_hashing)
@@ -144,7 +146,7 @@ impl TestRunnerBuilder {
         };
         let genesis = self
             .custom_genesis
-            .unwrap_or_else(|| create_genesis(BTreeMap::new(), BTreeMap::new(), 1u64, 1u64, 1u64));
+            .unwrap_or_else(|| create_genesis(GenesisConfig::default()));
         let receipt = runner.execute_transaction_with_config(
             genesis.get_executable(vec![AuthAddresses::system_role()]),
             &FeeReserveConfig::default(),
@@ -214,7 +216,7 @@ impl TestRunner {
         )
     }
 
-    pub fn get_metadata(&mut self, address: GlobalAddress) -> BTreeMap<String, String> {
+    pub fn get_metadata(&mut self, address: GlobalAddress) -> BTreeMap<String, MetadataEntry> {
         let node_id = RENodeId::Global(address);
         let global = self
             .substate_store
@@ -285,7 +287,7 @@ impl TestRunner {
                     RENodeId::Vault(royalty_vault.vault_id()),
                     SubstateOffset::Vault(VaultOffset::Vault),
                 ))
-                .map(|output| output.substate.vault().0.amount())
+                .map(|output| output.substate.vault().resource.amount())
         } else {
             None
         }
@@ -309,7 +311,7 @@ impl TestRunner {
                     RENodeId::Vault(royalty_vault.vault_id()),
                     SubstateOffset::Vault(VaultOffset::Vault),
                 ))
-                .map(|output| output.substate.vault().0.amount())
+                .map(|output| output.substate.vault().resource.amount())
         } else {
             None
         }
@@ -331,13 +333,22 @@ impl TestRunner {
         vault_finder.to_vaults()
     }
 
+    pub fn inspect_vault_balance(&mut self, vault_id: VaultId) -> Option<Decimal> {
+        self.substate_store()
+            .get_substate(&SubstateId(
+                RENodeId::Vault(vault_id),
+                SubstateOffset::Vault(VaultOffset::Vault),
+            ))
+            .map(|output| output.substate.vault().resource.amount())
+    }
+
     pub fn inspect_nft_vault(&mut self, vault_id: VaultId) -> Option<BTreeSet<NonFungibleLocalId>> {
         self.substate_store()
             .get_substate(&SubstateId(
                 RENodeId::Vault(vault_id),
                 SubstateOffset::Vault(VaultOffset::Vault),
             ))
-            .map(|output| output.substate.vault().0.ids().clone())
+            .map(|output| output.substate.vault().resource.ids().clone())
     }
 
     pub fn get_component_resources(
@@ -642,6 +653,25 @@ impl TestRunner {
         transaction_receipt
     }
 
+    /// Pretty-prints which access rule nodes failed, and which proofs they were checked against,
+    /// if the transaction failed on an authorization check.
+    pub fn explain_auth_failure(&self, receipt: &TransactionReceipt) -> Option<String> {
+        match &receipt.result {
+            TransactionResult::Commit(CommitResult {
+                outcome:
+                    TransactionOutcome::Failure(RuntimeError::ModuleError(ModuleError::AuthError(
+                        AuthError::Unauthorized { explanation, .. },
+                    ))),
+                ..
+            }) => Some(
+                explanation
+                    .display(&Bech32Encoder::for_simulator())
+                    .to_string(),
+            ),
+            _ => None,
+        }
+    }
+
     pub fn preview(
         &mut self,
         preview_intent: PreviewIntent,
@@ -822,6 +852,16 @@ impl TestRunner {
         self.create_fungible_resource_and_deposit(access_rules, account)
     }
 
+    pub fn create_freezable_token(&mut self, account: ComponentAddress) -> ResourceAddress {
+        let mut access_rules = BTreeMap::new();
+        access_rules.insert(ResourceMethodAuthKey::Withdraw, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Deposit, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Recall, (rule!(allow_all), LOCKED));
+        access_rules.insert(ResourceMethodAuthKey::Freeze, (rule!(allow_all), LOCKED));
+
+        self.create_fungible_resource_and_deposit(access_rules, account)
+    }
+
     pub fn create_restricted_burn_token(
         &mut self,
         account: ComponentAddress,
@@ -32,7 +32,7 @@ pub trait ComponentState<C: LocalComponent>: ScryptoEncode + ScryptoDecode {
 pub trait GlobalComponent {
     fn package_address(&self) -> PackageAddress;
     fn blueprint_name(&self) -> String;
-    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self;
+    fn metadata<K: AsRef<str>, V: Into<MetadataEntry>>(&mut self, name: K, value: V) -> &mut Self;
     fn add_access_check(&mut self, access_rules: AccessRules) -> &mut Self;
     fn set_royalty_config(&mut self, royalty_config: RoyaltyConfig) -> &mut Self;
     fn claim_royalty(&self) -> Bucket;
@@ -44,7 +44,7 @@ pub trait GlobalComponent {
 pub trait LocalComponent {
     fn package_address(&self) -> PackageAddress;
     fn blueprint_name(&self) -> String;
-    fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self;
+    fn metadata<K: AsRef<str>, V: Into<MetadataEntry>>(&mut self, name: K, value: V) -> &mut Self;
     fn add_access_check(&mut self, access_rules: AccessRules) -> &mut Self;
     fn set_royalty_config(&mut self, royalty_config: RoyaltyConfig) -> &mut Self;
     fn globalize(self) -> ComponentAddress;
@@ -121,12 +121,49 @@ impl Component {
         self
     }
 
-    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+    pub fn metadata<K: AsRef<str>, V: Into<MetadataEntry>>(
+        &mut self,
+        name: K,
+        value: V,
+    ) -> &mut Self {
         ScryptoEnv
             .invoke(MetadataSetInvocation {
                 receiver: RENodeId::Component(self.0),
                 key: name.as_ref().to_owned(),
-                value: value.as_ref().to_owned(),
+                value: value.into(),
+            })
+            .unwrap();
+        self
+    }
+
+    /// Returns the metadata value under the given key, or `None` if there is no value or it is
+    /// not of the requested type.
+    pub fn get_metadata<K: AsRef<str>, V: FromMetadataEntry>(&self, name: K) -> Option<V> {
+        ScryptoEnv
+            .invoke(MetadataGetInvocation {
+                receiver: RENodeId::Component(self.0),
+                key: name.as_ref().to_owned(),
+            })
+            .unwrap()
+            .and_then(V::from_metadata_entry)
+    }
+
+    pub fn remove_metadata<K: AsRef<str>>(&mut self, name: K) -> &mut Self {
+        ScryptoEnv
+            .invoke(MetadataRemoveInvocation {
+                receiver: RENodeId::Component(self.0),
+                key: name.as_ref().to_owned(),
+            })
+            .unwrap();
+        self
+    }
+
+    /// Locks the metadata under the given key, so that it can no longer be set or removed.
+    pub fn lock_metadata<K: AsRef<str>>(&mut self, name: K) -> &mut Self {
+        ScryptoEnv
+            .invoke(MetadataLockInvocation {
+                receiver: RENodeId::Component(self.0),
+                key: name.as_ref().to_owned(),
             })
             .unwrap();
         self
@@ -178,12 +215,49 @@ impl GlobalComponentRef {
         scrypto_decode(&output).unwrap()
     }
 
-    pub fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self {
+    pub fn metadata<K: AsRef<str>, V: Into<MetadataEntry>>(
+        &mut self,
+        name: K,
+        value: V,
+    ) -> &mut Self {
         ScryptoEnv
             .invoke(MetadataSetInvocation {
                 receiver: RENodeId::Global(GlobalAddress::Component(self.0)),
                 key: name.as_ref().to_owned(),
-                value: value.as_ref().to_owned(),
+                value: value.into(),
+            })
+            .unwrap();
+        self
+    }
+
+    /// Returns the metadata value under the given key, or `None` if there is no value or it is
+    /// not of the requested type.
+    pub fn get_metadata<K: AsRef<str>, V: FromMetadataEntry>(&self, name: K) -> Option<V> {
+        ScryptoEnv
+            .invoke(MetadataGetInvocation {
+                receiver: RENodeId::Global(GlobalAddress::Component(self.0)),
+                key: name.as_ref().to_owned(),
+            })
+            .unwrap()
+            .and_then(V::from_metadata_entry)
+    }
+
+    pub fn remove_metadata<K: AsRef<str>>(&mut self, name: K) -> &mut Self {
+        ScryptoEnv
+            .invoke(MetadataRemoveInvocation {
+                receiver: RENodeId::Global(GlobalAddress::Component(self.0)),
+                key: name.as_ref().to_owned(),
+            })
+            .unwrap();
+        self
+    }
+
+    /// Locks the metadata under the given key, so that it can no longer be set or removed.
+    pub fn lock_metadata<K: AsRef<str>>(&mut self, name: K) -> &mut Self {
+        ScryptoEnv
+            .invoke(MetadataLockInvocation {
+                receiver: RENodeId::Global(GlobalAddress::Component(self.0)),
+                key: name.as_ref().to_owned(),
             })
             .unwrap();
         self
@@ -1,5 +1,6 @@
 use crate::engine::scrypto_env::ScryptoEnv;
 use crate::radix_engine_interface::api::Invokable;
+use radix_engine_interface::abi::Type;
 use radix_engine_interface::api::wasm::SerializableInvocation;
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::resource_access_rules_from_owner_badge;
@@ -80,7 +81,7 @@ impl ResourceBuilder {
 #[must_use]
 pub struct InProgressResourceBuilder<T: ResourceType, A: ConfiguredAuth> {
     resource_type: T,
-    metadata: BTreeMap<String, String>,
+    metadata: BTreeMap<String, MetadataEntry>,
     auth: A,
 }
 
@@ -135,11 +136,17 @@ impl Default for FungibleResourceType {
     }
 }
 
-pub struct NonFungibleResourceType<T: IsNonFungibleLocalId>(PhantomData<T>);
+pub struct NonFungibleResourceType<T: IsNonFungibleLocalId> {
+    mutable_data_schema: Option<Type>,
+    id_type: PhantomData<T>,
+}
 impl<T: IsNonFungibleLocalId> ResourceType for NonFungibleResourceType<T> {}
 impl<T: IsNonFungibleLocalId> Default for NonFungibleResourceType<T> {
     fn default() -> Self {
-        Self(PhantomData)
+        Self {
+            mutable_data_schema: None,
+            id_type: PhantomData,
+        }
     }
 }
 
@@ -162,7 +169,11 @@ pub trait UpdateMetadataBuilder: private::CanAddMetadata {
     /// Adds a resource metadata.
     ///
     /// If a previous attribute with the same name has been set, it will be overwritten.
-    fn metadata<K: Into<String>, V: Into<String>>(self, name: K, value: V) -> Self::OutputBuilder {
+    fn metadata<K: Into<String>, V: Into<MetadataEntry>>(
+        self,
+        name: K,
+        value: V,
+    ) -> Self::OutputBuilder {
         self.add_metadata(name.into(), value.into())
     }
 }
@@ -253,6 +264,34 @@ pub trait UpdateAuthBuilder: private::CanAddAuth {
         self.add_auth(Recall, method_auth, mutability.into())
     }
 
+    /// Sets the resource to allow individual vaults to be frozen, blocking withdraws and deposits.
+    ///
+    /// * The first parameter is the access rule which allows freezing and unfreezing of vaults.
+    /// * The second parameter is the mutability / access rule which controls if and how the access rule can be updated.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let resource_address = RADIX_TOKEN;
+    /// // Sets the resource to be freezable with a proof of a specific resource, and this is locked forever.
+    /// ResourceBuilder::new_fungible()
+    ///    .freezable(rule!(require(resource_address)), LOCKED);
+    ///
+    /// # let resource_address = RADIX_TOKEN;
+    /// // Sets the resource to not be freezable, but this is can be changed in future by the second rule
+    /// ResourceBuilder::new_fungible()
+    ///    .freezable(rule!(deny_all), MUTABLE(rule!(require(resource_address))));
+    /// ```
+    fn freezable<R: Into<AccessRule>>(
+        self,
+        method_auth: AccessRule,
+        mutability: R,
+    ) -> Self::OutputBuilder {
+        self.add_auth(Freeze, method_auth, mutability.into())
+    }
+
     /// Sets the resource to not be freely withdrawable from a vault.
     ///
     /// * The first parameter is the access rule which allows withdrawing from a vault.
@@ -336,6 +375,62 @@ pub trait UpdateAuthBuilder: private::CanAddAuth {
     ) -> Self::OutputBuilder {
         self.add_auth(UpdateMetadata, method_auth, mutability.into())
     }
+
+    /// Sets how the resource's maximum supply can be updated.
+    ///
+    /// * The first parameter is the access rule which allows updating the maximum supply of the resource.
+    /// * The second parameter is the mutability / access rule which controls if and how the access rule can be updated.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let resource_address = RADIX_TOKEN;
+    /// // Sets the resource to allow its maximum supply to be updated with a proof of a specific resource, and this is locked forever.
+    /// ResourceBuilder::new_fungible()
+    ///    .updateable_max_supply(rule!(require(resource_address)), LOCKED);
+    ///
+    /// # let resource_address = RADIX_TOKEN;
+    /// // Sets the resource to not allow its maximum supply to be updated, but this is can be changed in future by the second rule.
+    /// ResourceBuilder::new_fungible()
+    ///    .updateable_max_supply(rule!(deny_all), MUTABLE(rule!(require(resource_address))));
+    /// ```
+    fn updateable_max_supply<R: Into<AccessRule>>(
+        self,
+        method_auth: AccessRule,
+        mutability: R,
+    ) -> Self::OutputBuilder {
+        self.add_auth(UpdateMaxSupply, method_auth, mutability.into())
+    }
+
+    /// Sets how the resource's per-epoch mint limit can be updated.
+    ///
+    /// * The first parameter is the access rule which allows updating the mint limit of the resource.
+    /// * The second parameter is the mutability / access rule which controls if and how the access rule can be updated.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let resource_address = RADIX_TOKEN;
+    /// // Sets the resource to allow its mint limit to be updated with a proof of a specific resource, and this is locked forever.
+    /// ResourceBuilder::new_fungible()
+    ///    .updateable_mint_limit_per_epoch(rule!(require(resource_address)), LOCKED);
+    ///
+    /// # let resource_address = RADIX_TOKEN;
+    /// // Sets the resource to not allow its mint limit to be updated, but this is can be changed in future by the second rule.
+    /// ResourceBuilder::new_fungible()
+    ///    .updateable_mint_limit_per_epoch(rule!(deny_all), MUTABLE(rule!(require(resource_address))));
+    /// ```
+    fn updateable_mint_limit_per_epoch<R: Into<AccessRule>>(
+        self,
+        method_auth: AccessRule,
+        mutability: R,
+    ) -> Self::OutputBuilder {
+        self.add_auth(UpdateMintLimitPerEpoch, method_auth, mutability.into())
+    }
 }
 impl<B: private::CanAddAuth> UpdateAuthBuilder for B {}
 
@@ -393,6 +488,44 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
 }
 impl<B: private::CanCreateWithNoSupply> CreateWithNoSupplyBuilder for B {}
 
+impl<T: ResourceType, A: ConfiguredAuth> InProgressResourceBuilder<T, A> {
+    /// Caps the total supply of the resource, including any initial supply. Minting beyond it fails.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let resource_address = RADIX_TOKEN;
+    /// ResourceBuilder::new_fungible()
+    ///    .max_supply(21_000_000)
+    ///    .mintable(rule!(require(resource_address)), LOCKED)
+    ///    .create_with_no_initial_supply();
+    /// ```
+    pub fn max_supply<D: Into<Decimal>>(mut self, amount: D) -> Self {
+        self.max_supply = Some(amount.into());
+        self
+    }
+
+    /// Limits how much of the resource can be minted within a single epoch.
+    ///
+    /// Any initial supply doesn't count towards the limit.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let resource_address = RADIX_TOKEN;
+    /// ResourceBuilder::new_fungible()
+    ///    .mint_limit_per_epoch(1000)
+    ///    .mintable(rule!(require(resource_address)), LOCKED)
+    ///    .create_with_no_initial_supply();
+    /// ```
+    pub fn mint_limit_per_epoch<D: Into<Decimal>>(mut self, amount: D) -> Self {
+        self.mint_limit_per_epoch = Some(amount.into());
+        self
+    }
+}
+
 impl<A: ConfiguredAuth> InProgressResourceBuilder<FungibleResourceType, A> {
     /// Set the resource's divisibility: the number of digits of precision after the decimal point in its balances.
     ///
@@ -435,11 +568,43 @@ impl<A: ConfiguredAuth> InProgressResourceBuilder<FungibleResourceType, A> {
             divisibility: self.resource_type.divisibility,
             metadata: self.metadata,
             access_rules: self.auth.into_access_rules(),
+            max_supply: self.max_supply,
+            mint_limit_per_epoch: self.mint_limit_per_epoch,
             initial_supply: amount.into(),
         })
     }
 }
 
+impl<A: ConfiguredAuth, Y: IsNonFungibleLocalId>
+    InProgressResourceBuilder<NonFungibleResourceType<Y>, A>
+{
+    /// Records the schema of the non-fungible data type on the resource, so that individual
+    /// `#[mutable]` fields can later be updated with `ResourceManager::update_non_fungible_data_field`.
+    ///
+    /// This is only needed with `create_with_no_initial_supply()`, as `mint_initial_supply(..)`
+    /// records the schema of the data it is given.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// #[derive(NonFungibleData)]
+    /// struct NFData {
+    ///     pub name: String,
+    ///     #[mutable]
+    ///     pub flag: bool,
+    /// }
+    ///
+    /// let resource_address = ResourceBuilder::new_integer_non_fungible()
+    ///     .non_fungible_data_schema::<NFData>()
+    ///     .create_with_no_initial_supply();
+    /// ```
+    pub fn non_fungible_data_schema<V: NonFungibleData>(mut self) -> Self {
+        self.resource_type.mutable_data_schema = Some(V::mutable_data_schema());
+        self
+    }
+}
+
 impl<A: ConfiguredAuth>
     InProgressResourceBuilder<NonFungibleResourceType<StringNonFungibleLocalId>, A>
 {
@@ -472,6 +637,9 @@ impl<A: ConfiguredAuth>
                 id_type: StringNonFungibleLocalId::id_type(),
                 metadata: self.metadata,
                 access_rules: self.auth.into_access_rules(),
+                max_supply: self.max_supply,
+                mint_limit_per_epoch: self.mint_limit_per_epoch,
+                mutable_data_schema: Some(V::mutable_data_schema()),
                 entries: map_entries(entries),
             },
         )
@@ -510,6 +678,9 @@ impl<A: ConfiguredAuth>
                 id_type: IntegerNonFungibleLocalId::id_type(),
                 metadata: self.metadata,
                 access_rules: self.auth.into_access_rules(),
+                max_supply: self.max_supply,
+                mint_limit_per_epoch: self.mint_limit_per_epoch,
+                mutable_data_schema: Some(V::mutable_data_schema()),
                 entries: map_entries(entries),
             },
         )
@@ -548,6 +719,9 @@ impl<A: ConfiguredAuth>
                 id_type: BytesNonFungibleLocalId::id_type(),
                 metadata: self.metadata,
                 access_rules: self.auth.into_access_rules(),
+                max_supply: self.max_supply,
+                mint_limit_per_epoch: self.mint_limit_per_epoch,
+                mutable_data_schema: Some(V::mutable_data_schema()),
                 entries: map_entries(entries),
             },
         )
@@ -588,6 +762,9 @@ impl<A: ConfiguredAuth>
             ResourceManagerCreateUuidNonFungibleWithInitialSupplyInvocation {
                 metadata: self.metadata,
                 access_rules: self.auth.into_access_rules(),
+                max_supply: self.max_supply,
+                mint_limit_per_epoch: self.mint_limit_per_epoch,
+                mutable_data_schema: Some(V::mutable_data_schema()),
                 entries: entries
                     .into_iter()
                     .map(|data| (data.immutable_data().unwrap(), data.mutable_data().unwrap()))
@@ -629,7 +806,7 @@ impl<T: ResourceType, A: ConfiguredAuth> private::CanAddMetadata
 {
     type OutputBuilder = Self;
 
-    fn add_metadata(mut self, key: String, value: String) -> Self::OutputBuilder {
+    fn add_metadata(mut self, key: String, value: MetadataEntry) -> Self::OutputBuilder {
         self.metadata.insert(key, value);
         self
     }
@@ -688,6 +865,8 @@ impl<A: ConfiguredAuth> private::CanCreateWithNoSupply
             divisibility: self.resource_type.divisibility,
             metadata: self.metadata,
             access_rules: self.auth.into_access_rules(),
+            max_supply: self.max_supply,
+            mint_limit_per_epoch: self.mint_limit_per_epoch,
         }
     }
 }
@@ -703,6 +882,9 @@ impl<A: ConfiguredAuth, Y: IsNonFungibleLocalId> private::CanCreateWithNoSupply
             id_type: Y::id_type(),
             metadata: self.metadata,
             access_rules: self.auth.into_access_rules(),
+            max_supply: self.max_supply,
+            mint_limit_per_epoch: self.mint_limit_per_epoch,
+            mutable_data_schema: self.resource_type.mutable_data_schema,
         }
     }
 }
@@ -729,7 +911,7 @@ mod private {
     pub trait CanAddMetadata: Sized {
         type OutputBuilder;
 
-        fn add_metadata(self, key: String, value: String) -> Self::OutputBuilder;
+        fn add_metadata(self, key: String, value: MetadataEntry) -> Self::OutputBuilder;
     }
 
     pub trait CanAddAuth: Sized {
@@ -1,11 +1,13 @@
 use radix_engine_interface::api::types::{
-    GlobalAddress, MetadataFn, NativeFn, RENodeId, ResourceManagerFn,
+    GlobalAddress, MetadataFn, NativeFn, RENodeId, ResourceManagerFn, VaultId,
 };
 use radix_engine_interface::api::Invokable;
+use radix_engine_interface::data::{scrypto_encode, ScryptoEncode};
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::VaultMethodAuthKey::{Deposit, Recall, Withdraw};
 use radix_engine_interface::model::*;
 
+use sbor::rust::borrow::ToOwned;
 use sbor::rust::collections::BTreeMap;
 use sbor::rust::string::String;
 use sbor::rust::string::ToString;
@@ -20,21 +22,43 @@ use crate::*;
 pub struct ResourceManager(pub(crate) ResourceAddress);
 
 impl ResourceManager {
-    pub fn set_metadata(&mut self, key: String, value: String) {
+    pub fn set_metadata<K: AsRef<str>, V: Into<MetadataEntry>>(&mut self, key: K, value: V) {
         let mut env = ScryptoEnv;
         env.invoke(MetadataSetInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
-            key,
-            value,
+            key: key.as_ref().to_owned(),
+            value: value.into(),
         })
         .unwrap()
     }
 
-    pub fn get_metadata(&mut self, key: String) -> Option<String> {
+    /// Returns the metadata value under the given key, or `None` if there is no value or it is
+    /// not of the requested type.
+    pub fn get_metadata<K: AsRef<str>, V: FromMetadataEntry>(&mut self, key: K) -> Option<V> {
         let mut env = ScryptoEnv;
         env.invoke(MetadataGetInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
-            key,
+            key: key.as_ref().to_owned(),
+        })
+        .unwrap()
+        .and_then(V::from_metadata_entry)
+    }
+
+    pub fn remove_metadata<K: AsRef<str>>(&mut self, key: K) {
+        let mut env = ScryptoEnv;
+        env.invoke(MetadataRemoveInvocation {
+            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+            key: key.as_ref().to_owned(),
+        })
+        .unwrap()
+    }
+
+    /// Locks the metadata under the given key, so that it can no longer be set or removed.
+    pub fn lock_metadata<K: AsRef<str>>(&mut self, key: K) {
+        let mut env = ScryptoEnv;
+        env.invoke(MetadataLockInvocation {
+            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+            key: key.as_ref().to_owned(),
         })
         .unwrap()
     }
@@ -91,17 +115,67 @@ impl ResourceManager {
         .unwrap()
     }
 
-    pub fn set_updateable_metadata(&self, access_rule: AccessRule) {
+    pub fn set_freezable(&mut self, access_rule: AccessRule) {
+        let mut env = ScryptoEnv;
+        env.invoke(AccessRulesSetGroupAccessRuleInvocation {
+            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+            index: 0,
+            name: "freeze".to_string(),
+            rule: access_rule,
+        })
+        .unwrap();
+    }
+
+    pub fn set_updateable_max_supply(&mut self, access_rule: AccessRule) {
+        let mut env = ScryptoEnv;
+        env.invoke(AccessRulesSetMethodAccessRuleInvocation {
+            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+            index: 0,
+            key: AccessRuleKey::Native(NativeFn::ResourceManager(ResourceManagerFn::SetMaxSupply)),
+            rule: AccessRuleEntry::AccessRule(access_rule),
+        })
+        .unwrap();
+    }
+
+    pub fn set_updateable_mint_limit_per_epoch(&mut self, access_rule: AccessRule) {
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodAccessRuleInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
             index: 0,
-            key: AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Set)),
+            key: AccessRuleKey::Native(NativeFn::ResourceManager(
+                ResourceManagerFn::SetMintLimitPerEpoch,
+            )),
             rule: AccessRuleEntry::AccessRule(access_rule),
         })
         .unwrap();
     }
 
+    pub fn set_updateable_metadata(&self, access_rule: AccessRule) {
+        let mut env = ScryptoEnv;
+        for metadata_fn in [MetadataFn::Set, MetadataFn::Remove, MetadataFn::Lock] {
+            env.invoke(AccessRulesSetMethodAccessRuleInvocation {
+                receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+                index: 0,
+                key: AccessRuleKey::Native(NativeFn::Metadata(metadata_fn)),
+                rule: AccessRuleEntry::AccessRule(access_rule.clone()),
+            })
+            .unwrap();
+        }
+    }
+
+    /// Sets the access rule for setting or removing the metadata under a single key.
+    /// Until then, the key is guarded by the rule for updating the metadata.
+    pub fn set_updateable_metadata_key<K: AsRef<str>>(&self, key: K, access_rule: AccessRule) {
+        let mut env = ScryptoEnv;
+        env.invoke(AccessRulesSetGroupAccessRuleInvocation {
+            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+            index: 0,
+            name: metadata_key_group(key.as_ref()),
+            rule: access_rule,
+        })
+        .unwrap();
+    }
+
     pub fn set_updateable_non_fungible_data(&self, access_rule: AccessRule) {
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodAccessRuleInvocation {
@@ -115,6 +189,23 @@ impl ResourceManager {
         .unwrap();
     }
 
+    /// Sets the access rule for updating a single mutable field of the non-fungible data.
+    /// Until then, the field is guarded by the rule for updating the whole data.
+    pub fn set_updateable_non_fungible_data_field<F: AsRef<str>>(
+        &self,
+        field: F,
+        access_rule: AccessRule,
+    ) {
+        let mut env = ScryptoEnv;
+        env.invoke(AccessRulesSetGroupAccessRuleInvocation {
+            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+            index: 0,
+            name: non_fungible_data_field_group(field.as_ref()),
+            rule: access_rule,
+        })
+        .unwrap();
+    }
+
     pub fn lock_mintable(&mut self) {
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetGroupMutabilityInvocation {
@@ -137,17 +228,54 @@ impl ResourceManager {
         .unwrap()
     }
 
-    pub fn lock_updateable_metadata(&mut self) {
+    pub fn lock_freezable(&mut self) {
+        let mut env = ScryptoEnv;
+        env.invoke(AccessRulesSetGroupMutabilityInvocation {
+            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+            index: 0,
+            name: "freeze".to_string(),
+            mutability: AccessRule::DenyAll,
+        })
+        .unwrap()
+    }
+
+    pub fn lock_updateable_max_supply(&mut self) {
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodMutabilityInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
             index: 0,
-            key: AccessRuleKey::Native(NativeFn::Metadata(MetadataFn::Set)),
+            key: AccessRuleKey::Native(NativeFn::ResourceManager(ResourceManagerFn::SetMaxSupply)),
             mutability: AccessRule::DenyAll,
         })
         .unwrap()
     }
 
+    pub fn lock_updateable_mint_limit_per_epoch(&mut self) {
+        let mut env = ScryptoEnv;
+        env.invoke(AccessRulesSetMethodMutabilityInvocation {
+            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+            index: 0,
+            key: AccessRuleKey::Native(NativeFn::ResourceManager(
+                ResourceManagerFn::SetMintLimitPerEpoch,
+            )),
+            mutability: AccessRule::DenyAll,
+        })
+        .unwrap()
+    }
+
+    pub fn lock_updateable_metadata(&mut self) {
+        let mut env = ScryptoEnv;
+        for metadata_fn in [MetadataFn::Set, MetadataFn::Remove, MetadataFn::Lock] {
+            env.invoke(AccessRulesSetMethodMutabilityInvocation {
+                receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+                index: 0,
+                key: AccessRuleKey::Native(NativeFn::Metadata(metadata_fn)),
+                mutability: AccessRule::DenyAll,
+            })
+            .unwrap();
+        }
+    }
+
     pub fn lock_updateable_non_fungible_data(&mut self) {
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodMutabilityInvocation {
@@ -161,6 +289,17 @@ impl ResourceManager {
         .unwrap()
     }
 
+    pub fn lock_updateable_non_fungible_data_field<F: AsRef<str>>(&mut self, field: F) {
+        let mut env = ScryptoEnv;
+        env.invoke(AccessRulesSetGroupMutabilityInvocation {
+            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
+            index: 0,
+            name: non_fungible_data_field_group(field.as_ref()),
+            mutability: AccessRule::DenyAll,
+        })
+        .unwrap()
+    }
+
     pub fn lock_withdrawable(&mut self) {
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerSetVaultAuthMutabilityInvocation {
@@ -233,6 +372,30 @@ impl ResourceManager {
                 bucket: Bucket(bucket.0),
             }
         }
+        pub fn freeze_vault(&mut self, vault_id: VaultId) -> () {
+            ResourceManagerFreezeVaultInvocation {
+                receiver: self.0,
+                vault_id,
+            }
+        }
+        pub fn unfreeze_vault(&mut self, vault_id: VaultId) -> () {
+            ResourceManagerUnfreezeVaultInvocation {
+                receiver: self.0,
+                vault_id,
+            }
+        }
+        pub fn set_max_supply(&mut self, max_supply: Option<Decimal>) -> () {
+            ResourceManagerSetMaxSupplyInvocation {
+                receiver: self.0,
+                max_supply,
+            }
+        }
+        pub fn set_mint_limit_per_epoch(&mut self, mint_limit_per_epoch: Option<Decimal>) -> () {
+            ResourceManagerSetMintLimitPerEpochInvocation {
+                receiver: self.0,
+                mint_limit_per_epoch,
+            }
+        }
     }
 
     /// Mints fungible resources
@@ -296,4 +459,25 @@ impl ResourceManager {
     ) {
         self.update_non_fungible_data_internal(id.clone(), new_data.mutable_data().unwrap())
     }
+
+    /// Updates a single `#[mutable]` field of a non-fungible unit.
+    ///
+    /// # Panics
+    /// Panics if the resource has no such mutable field, the value does not match the field's
+    /// type, or the specified non-fungible is not found.
+    pub fn update_non_fungible_data_field<F: AsRef<str>, V: ScryptoEncode>(
+        &mut self,
+        id: &NonFungibleLocalId,
+        field: F,
+        value: V,
+    ) {
+        let mut env = ScryptoEnv;
+        env.invoke(ResourceManagerUpdateNonFungibleDataFieldInvocation {
+            receiver: self.0,
+            id: id.clone(),
+            field: field.as_ref().to_owned(),
+            value: scrypto_encode(&value).unwrap(),
+        })
+        .unwrap()
+    }
 }
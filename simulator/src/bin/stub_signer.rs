@@ -0,0 +1,23 @@
+//! A minimal external signer, serving signing requests for a single in-memory key over stdio.
+//!
+//! Usage: `stub-signer <private key hex>`
+
+use radix_engine::types::PublicKey;
+use std::io::{self, BufReader};
+use transaction::signing::{serve_external_signer, EcdsaSecp256k1PrivateKey, ExternalSignerError};
+
+pub fn main() -> Result<(), ExternalSignerError> {
+    let private_key = std::env::args()
+        .nth(1)
+        .and_then(|key| hex::decode(key).ok())
+        .and_then(|bytes| EcdsaSecp256k1PrivateKey::from_bytes(&bytes).ok())
+        .expect("Usage: stub-signer <private key hex>");
+    let public_key: PublicKey = private_key.public_key().into();
+
+    serve_external_signer(
+        &private_key,
+        public_key,
+        BufReader::new(io::stdin()),
+        io::stdout(),
+    )
+}
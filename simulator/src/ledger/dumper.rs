@@ -239,8 +239,8 @@ fn dump_resources<T: ReadableSubstateStore, O: std::io::Write>(
             .map(|s| s.substate)
             .map(|s| s.into())
             .unwrap();
-        let amount = vault.0.amount();
-        let resource_address = vault.0.resource_address();
+        let amount = vault.resource.amount();
+        let resource_address = vault.resource.resource_address();
         let global: Option<GlobalAddressSubstate> = substate_store
             .get_substate(&SubstateId(
                 RENodeId::Global(GlobalAddress::Resource(resource_address)),
@@ -278,19 +278,19 @@ fn dump_resources<T: ReadableSubstateStore, O: std::io::Write>(
             metadata
                 .metadata
                 .get("name")
-                .map(|name| format!(", name: \"{}\"", name))
+                .map(|name| format!(", name: \"{}\"", name.display(&bech32_encoder)))
                 .unwrap_or(String::new()),
             metadata
                 .metadata
                 .get("symbol")
-                .map(|symbol| format!(", symbol: \"{}\"", symbol))
+                .map(|symbol| format!(", symbol: \"{}\"", symbol.display(&bech32_encoder)))
                 .unwrap_or(String::new()),
         );
         if matches!(
             resource_manager.resource_type,
             ResourceType::NonFungible { .. }
         ) {
-            let ids = vault.0.ids();
+            let ids = vault.resource.ids();
             for (inner_last, id) in ids.iter().identify_last() {
                 let non_fungible: NonFungibleSubstate = substate_store
                     .get_substate(&SubstateId(
@@ -330,6 +330,8 @@ pub fn dump_resource_manager<T: ReadableSubstateStore, O: std::io::Write>(
     substate_store: &T,
     output: &mut O,
 ) -> Result<(), DisplayError> {
+    let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::simulator());
+
     let global: Option<GlobalAddressSubstate> = substate_store
         .get_substate(&SubstateId(
             RENodeId::Global(GlobalAddress::Resource(resource_address)),
@@ -376,7 +378,7 @@ pub fn dump_resource_manager<T: ReadableSubstateStore, O: std::io::Write>(
             "{} {}: {}",
             list_item_prefix(last),
             e.0.green().bold(),
-            e.1
+            e.1.display(&bech32_encoder)
         );
     }
     writeln!(
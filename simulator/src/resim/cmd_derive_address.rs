@@ -0,0 +1,85 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::types::*;
+use radix_engine_interface::address::{
+    derive_virtual_addresses, has_vanity_prefix, VirtualEntityKind,
+};
+use rand::Rng;
+use transaction::signing::EcdsaSecp256k1PrivateKey;
+
+use crate::resim::*;
+
+/// Derive the addresses of the virtual accounts or identities of public keys, without a ledger
+#[derive(Parser, Debug)]
+pub struct DeriveAddress {
+    /// The public keys to derive the addresses of, separated by comma
+    #[clap(long)]
+    pub public_key: Option<String>,
+
+    /// The kind of virtual component, [account | identity]
+    #[clap(long, default_value = "account")]
+    pub kind: String,
+
+    /// The network to derive the addresses on, [simulator | adapanet | nebunet | mainnet]
+    #[clap(short, long)]
+    pub network: Option<String>,
+
+    /// Instead of deriving from public keys, generate secp256k1 key pairs until one's address
+    /// starts with these Bech32 characters
+    #[clap(long, conflicts_with = "public-key")]
+    pub vanity: Option<String>,
+
+    /// The maximum number of key pairs to try when searching for a vanity address
+    #[clap(long, default_value = "1000000")]
+    pub max_attempts: u64,
+}
+
+impl DeriveAddress {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let kind = VirtualEntityKind::from_str(&self.kind).map_err(|_| Error::InvalidKind)?;
+        let network = match &self.network {
+            Some(n) => NetworkDefinition::from_str(n).map_err(Error::ParseNetworkError)?,
+            None => NetworkDefinition::simulator(),
+        };
+        let bech32_encoder = Bech32Encoder::new(&network);
+
+        if let Some(pattern) = &self.vanity {
+            for _ in 0..self.max_attempts {
+                let private_key =
+                    EcdsaSecp256k1PrivateKey::from_bytes(&rand::thread_rng().gen::<[u8; 32]>())
+                        .map_err(|_| Error::InvalidPrivateKey)?;
+                let public_key = PublicKey::from(private_key.public_key());
+                let address =
+                    derive_virtual_addresses(&[public_key], kind, &bech32_encoder).remove(0);
+                if has_vanity_prefix(&address, pattern) {
+                    writeln!(out, "Address: {}", address.green()).map_err(Error::IOError)?;
+                    writeln!(
+                        out,
+                        "Public key: {}",
+                        format_public_key(&public_key).green()
+                    )
+                    .map_err(Error::IOError)?;
+                    writeln!(
+                        out,
+                        "Private key: {}",
+                        hex::encode(private_key.to_bytes()).green()
+                    )
+                    .map_err(Error::IOError)?;
+                    return Ok(());
+                }
+            }
+            return Err(Error::VanityAddressNotFound);
+        }
+
+        let public_keys = parse_public_keys(self.public_key.as_deref().unwrap_or_default())?;
+        if public_keys.is_empty() {
+            return Err(Error::InvalidPublicKey);
+        }
+        let addresses = derive_virtual_addresses(&public_keys, kind, &bech32_encoder);
+        for (public_key, address) in public_keys.iter().zip(addresses) {
+            writeln!(out, "{} {}", format_public_key(public_key), address.green())
+                .map_err(Error::IOError)?;
+        }
+        Ok(())
+    }
+}
@@ -2,18 +2,40 @@ use clap::Parser;
 use colored::*;
 use radix_engine::types::*;
 use rand::Rng;
-use transaction::signing::EcdsaSecp256k1PrivateKey;
+use transaction::signing::Mnemonic;
 
 use crate::resim::*;
 
-/// Generate a key pair
+/// Generate a key pair, derived from a new or an existing BIP-39 mnemonic
 #[derive(Parser, Debug)]
-pub struct GenerateKeyPair {}
+pub struct GenerateKeyPair {
+    /// The mnemonic to derive the key pair from, a new one is generated if not provided
+    #[clap(long)]
+    pub mnemonic: Option<String>,
+
+    /// The BIP-39 passphrase of the mnemonic
+    #[clap(long)]
+    pub passphrase: Option<String>,
+
+    /// The index of the key derived from the mnemonic
+    #[clap(long, default_value = "0")]
+    pub index: u32,
+}
 
 impl GenerateKeyPair {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let secret = rand::thread_rng().gen::<[u8; 32]>();
-        let private_key = EcdsaSecp256k1PrivateKey::from_bytes(&secret).unwrap();
+        let mnemonic = match &self.mnemonic {
+            Some(mnemonic) => mnemonic.clone(),
+            None => {
+                let entropy = rand::thread_rng().gen::<[u8; 32]>();
+                let mnemonic = Mnemonic::from_entropy(&entropy)
+                    .map_err(Error::KeyDerivationError)?
+                    .to_phrase();
+                writeln!(out, "Mnemonic: {}", mnemonic.green()).map_err(Error::IOError)?;
+                mnemonic
+            }
+        };
+        let private_key = derive_private_key(&mnemonic, &self.passphrase, self.index)?;
         let public_key = private_key.public_key();
         writeln!(out, "Public key: {}", public_key.to_string().green()).map_err(Error::IOError)?;
         writeln!(
@@ -0,0 +1,43 @@
+use clap::Parser;
+use colored::*;
+use std::path::PathBuf;
+
+use crate::resim::*;
+
+/// Merge the signatures collected in multiple copies of a partially signed transaction
+#[derive(Parser, Debug)]
+pub struct MergePartialTransactions {
+    /// The paths to the partially signed transaction files
+    #[clap(required = true, min_values = 2)]
+    pub paths: Vec<PathBuf>,
+
+    /// The path to write the merged partially signed transaction to
+    #[clap(short, long)]
+    pub output: PathBuf,
+}
+
+impl MergePartialTransactions {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut transaction = read_partial_transaction(&self.paths[0])?;
+        for path in &self.paths[1..] {
+            transaction
+                .merge(read_partial_transaction(path)?)
+                .map_err(Error::PartialTransactionError)?;
+        }
+        write_partial_transaction(&self.output, &transaction)?;
+
+        writeln!(
+            out,
+            "Signatures: {}",
+            transaction.intent_signatures.len().to_string().green()
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "Missing signatures: {}",
+            transaction.missing_signers().len()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -23,12 +23,29 @@ pub struct NewAccount {
     /// Turn on tracing
     #[clap(short, long)]
     trace: bool,
+
+    /// Derive the account key from a BIP-39 mnemonic, instead of generating a random one
+    #[clap(long)]
+    mnemonic: Option<String>,
+
+    /// The BIP-39 passphrase of the mnemonic
+    #[clap(long)]
+    passphrase: Option<String>,
+
+    /// The index of the account key derived from the mnemonic
+    #[clap(long, default_value = "0")]
+    index: u32,
 }
 
 impl NewAccount {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let secret = rand::thread_rng().gen::<[u8; 32]>();
-        let private_key = EcdsaSecp256k1PrivateKey::from_bytes(&secret).unwrap();
+        let private_key = match &self.mnemonic {
+            Some(mnemonic) => derive_private_key(mnemonic, &self.passphrase, self.index)?,
+            None => {
+                let secret = rand::thread_rng().gen::<[u8; 32]>();
+                EcdsaSecp256k1PrivateKey::from_bytes(&secret).unwrap()
+            }
+        };
         let public_key = private_key.public_key();
         let auth_global_id = NonFungibleGlobalId::from_public_key(&public_key);
         let withdraw_auth = rule!(require(auth_global_id));
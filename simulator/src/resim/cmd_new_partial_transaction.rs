@@ -0,0 +1,108 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::types::*;
+use std::path::PathBuf;
+use transaction::model::{PartiallySignedTransaction, TransactionHeader, TransactionIntent};
+
+use crate::resim::*;
+
+/// Create an unsigned transaction, to be signed by multiple parties before notarization
+#[derive(Parser, Debug)]
+pub struct NewPartialTransaction {
+    /// The path to a transaction manifest file
+    pub path: PathBuf,
+
+    /// The path to write the partially signed transaction to
+    #[clap(short, long)]
+    pub output: PathBuf,
+
+    /// The public keys of the required signers, separated by comma
+    #[clap(long)]
+    pub signers: String,
+
+    /// The public key of the notary, defaults to the public key of the default account
+    #[clap(long)]
+    pub notary_public_key: Option<String>,
+
+    /// The network to use, [simulator | adapanet | nebunet | mainnet]
+    #[clap(short, long)]
+    pub network: Option<String>,
+
+    /// The paths to blobs
+    #[clap(short, long, multiple = true)]
+    pub blobs: Option<Vec<String>>,
+
+    /// The first epoch in which the transaction can be committed
+    #[clap(long, default_value = "0")]
+    pub start_epoch: u64,
+
+    /// The epoch from which the transaction can no longer be committed
+    #[clap(long, default_value = "100")]
+    pub end_epoch: u64,
+
+    /// The transaction nonce, defaults to the simulator nonce
+    #[clap(long)]
+    pub nonce: Option<u64>,
+
+    /// The cost unit limit
+    #[clap(long, default_value = "100000000")]
+    pub cost_unit_limit: u32,
+
+    /// The tip percentage
+    #[clap(long, default_value = "0")]
+    pub tip_percentage: u16,
+}
+
+impl NewPartialTransaction {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let network = match &self.network {
+            Some(n) => NetworkDefinition::from_str(n).map_err(Error::ParseNetworkError)?,
+            None => NetworkDefinition::simulator(),
+        };
+        let manifest = std::fs::read_to_string(&self.path).map_err(Error::IOError)?;
+        let pre_processed_manifest = Run::pre_process_manifest(&manifest);
+        let mut blobs = Vec::new();
+        if let Some(paths) = &self.blobs {
+            for path in paths {
+                blobs.push(std::fs::read(path).map_err(Error::IOError)?);
+            }
+        }
+        let compiled_manifest =
+            transaction::manifest::compile(&pre_processed_manifest, &network, blobs)
+                .map_err(Error::CompileError)?;
+
+        let notary_public_key = match &self.notary_public_key {
+            Some(public_key) => parse_public_key(public_key)?,
+            None => get_default_private_key()?.public_key().into(),
+        };
+        let intent = TransactionIntent {
+            header: TransactionHeader {
+                version: 1,
+                network_id: network.id,
+                start_epoch_inclusive: self.start_epoch,
+                end_epoch_exclusive: self.end_epoch,
+                nonce: match self.nonce {
+                    Some(nonce) => nonce,
+                    None => get_nonce()?,
+                },
+                notary_public_key,
+                notary_as_signatory: false,
+                cost_unit_limit: self.cost_unit_limit,
+                tip_percentage: self.tip_percentage,
+            },
+            manifest: compiled_manifest,
+            message: None,
+        };
+        let required_signers = parse_public_keys(&self.signers)?;
+        let transaction = PartiallySignedTransaction::new(intent, required_signers);
+        write_partial_transaction(&self.output, &transaction)?;
+
+        writeln!(
+            out,
+            "Intent hash: {}",
+            transaction.intent.hash().unwrap().to_string().green()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
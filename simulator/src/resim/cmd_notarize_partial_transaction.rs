@@ -0,0 +1,53 @@
+use clap::Parser;
+use colored::*;
+use std::path::PathBuf;
+
+use crate::resim::*;
+
+/// Notarize a fully signed transaction, producing a transaction ready for submission
+#[derive(Parser, Debug)]
+pub struct NotarizePartialTransaction {
+    /// The path to a partially signed transaction file
+    pub path: PathBuf,
+
+    /// The path to write the notarized transaction to
+    #[clap(short, long)]
+    pub output: PathBuf,
+
+    /// The private key of the notary, defaults to the private key of the default account
+    #[clap(long)]
+    pub notary_private_key: Option<String>,
+
+    /// The command of an external signer to notarize with, instead of the notary private key
+    #[clap(long)]
+    pub external_signer: Option<String>,
+}
+
+impl NotarizePartialTransaction {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let transaction = read_partial_transaction(&self.path)?;
+        let notarized_transaction = if let Some(command) = &self.external_signer {
+            transaction.notarize(&spawn_external_signer(command)?)
+        } else {
+            let notary = match &self.notary_private_key {
+                Some(private_key) => hex::decode(private_key)
+                    .ok()
+                    .and_then(|bytes| EcdsaSecp256k1PrivateKey::from_bytes(&bytes).ok())
+                    .ok_or(Error::InvalidPrivateKey)?,
+                None => get_default_private_key()?,
+            };
+            transaction.notarize(&notary)
+        }
+        .map_err(Error::PartialTransactionError)?;
+        std::fs::write(&self.output, notarized_transaction.to_bytes().unwrap())
+            .map_err(Error::IOError)?;
+
+        writeln!(
+            out,
+            "Transaction hash: {}",
+            notarized_transaction.hash().unwrap().to_string().green()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::types::*;
+use std::path::PathBuf;
+use transaction::manifest::decompile;
+use utils::ContextualDisplay;
+
+use crate::resim::*;
+use crate::utils::*;
+
+/// Show the manifest and the collected and missing signatures of a partially signed transaction
+#[derive(Parser, Debug)]
+pub struct ShowPartialTransaction {
+    /// The path to a partially signed transaction file
+    pub path: PathBuf,
+
+    /// The network to use when displaying the manifest, [simulator | adapanet | nebunet | mainnet]
+    #[clap(short, long)]
+    pub network: Option<String>,
+}
+
+impl ShowPartialTransaction {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let transaction = read_partial_transaction(&self.path)?;
+        let network = match &self.network {
+            Some(n) => NetworkDefinition::from_str(n).map_err(Error::ParseNetworkError)?,
+            None => NetworkDefinition::simulator(),
+        };
+        let manifest = decompile(&transaction.intent.manifest.instructions, &network)
+            .map_err(Error::DecompileError)?;
+        let signers = transaction.signers();
+
+        writeln!(
+            out,
+            "{}: {}",
+            "Intent hash".green().bold(),
+            transaction.intent.hash().unwrap()
+        )
+        .map_err(Error::IOError)?;
+        writeln!(out, "{}:\n{}", "Manifest".green().bold(), manifest).map_err(Error::IOError)?;
+        writeln!(out, "{}:", "Required signers".green().bold()).map_err(Error::IOError)?;
+        for (last, public_key) in transaction.required_signers.iter().identify_last() {
+            let status = if signers.contains(public_key) {
+                "signed".green()
+            } else {
+                "missing".red()
+            };
+            writeln!(
+                out,
+                "{} {} ({})",
+                list_item_prefix(last),
+                format_public_key(public_key),
+                status
+            )
+            .map_err(Error::IOError)?;
+        }
+        writeln!(
+            out,
+            "{}: {}",
+            "Fee payer".green().bold(),
+            match &transaction.fee_payer {
+                Some(fee_payer) => fee_payer
+                    .account
+                    .display(&Bech32Encoder::new(&network))
+                    .to_string(),
+                None => "None".to_string(),
+            }
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "{}: {}",
+            "Ready to notarize".green().bold(),
+            transaction.is_fully_signed()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
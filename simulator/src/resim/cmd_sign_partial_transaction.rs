@@ -0,0 +1,59 @@
+use clap::Parser;
+use colored::*;
+use std::path::PathBuf;
+
+use crate::resim::*;
+
+/// Add signatures to a partially signed transaction
+#[derive(Parser, Debug)]
+pub struct SignPartialTransaction {
+    /// The path to a partially signed transaction file, which is updated in place
+    pub path: PathBuf,
+
+    /// The private keys used for signing, separated by comma
+    #[clap(short, long)]
+    pub signing_keys: Option<String>,
+
+    /// The command of an external signer to sign with, instead of the signing keys
+    #[clap(long)]
+    pub external_signer: Option<String>,
+}
+
+impl SignPartialTransaction {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut transaction = read_partial_transaction(&self.path)?;
+        let mut signers = Vec::new();
+        if let Some(command) = &self.external_signer {
+            let signer = spawn_external_signer(command)?;
+            let signature = signer
+                .try_sign(&transaction.intent.to_bytes().unwrap())
+                .map_err(Error::ExternalSignerError)?;
+            signers.push(
+                transaction
+                    .add_signature(signature)
+                    .map_err(Error::PartialTransactionError)?,
+            );
+        } else {
+            for private_key in get_signing_keys(&self.signing_keys)? {
+                signers.push(
+                    transaction
+                        .sign(&private_key)
+                        .map_err(Error::PartialTransactionError)?,
+                );
+            }
+        }
+        for public_key in signers {
+            writeln!(out, "Signed by: {}", format_public_key(&public_key).green())
+                .map_err(Error::IOError)?;
+        }
+        write_partial_transaction(&self.path, &transaction)?;
+
+        writeln!(
+            out,
+            "Missing signatures: {}",
+            transaction.missing_signers().len()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
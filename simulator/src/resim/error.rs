@@ -9,6 +9,7 @@ use radix_engine::wasm::PrepareError;
 use radix_engine_interface::node::ParseNetworkError;
 use sbor::*;
 use transaction::errors::*;
+use transaction::signing::ExternalSignerError;
 
 use crate::ledger::*;
 use crate::utils::*;
@@ -62,6 +63,20 @@ pub enum Error {
 
     InvalidPrivateKey,
 
+    InvalidPublicKey,
+
+    InvalidKind,
+
+    VanityAddressNotFound,
+
+    KeyDerivationError(KeyDerivationError),
+
+    PartialTransactionError(PartialTransactionError),
+
+    InvalidExternalSignerCommand,
+
+    ExternalSignerError(ExternalSignerError),
+
     AddressError(AddressError),
 
     NonFungibleGlobalIdError(ParseNonFungibleGlobalIdError),
@@ -1,15 +1,19 @@
 mod addressing;
 mod cmd_call_function;
 mod cmd_call_method;
+mod cmd_derive_address;
 mod cmd_export_abi;
 mod cmd_generate_key_pair;
+mod cmd_merge_partial_transactions;
 mod cmd_mint;
 mod cmd_new_account;
 mod cmd_new_badge_fixed;
 mod cmd_new_badge_mutable;
+mod cmd_new_partial_transaction;
 mod cmd_new_simple_badge;
 mod cmd_new_token_fixed;
 mod cmd_new_token_mutable;
+mod cmd_notarize_partial_transaction;
 mod cmd_publish;
 mod cmd_reset;
 mod cmd_run;
@@ -19,6 +23,8 @@ mod cmd_set_default_account;
 mod cmd_show;
 mod cmd_show_configs;
 mod cmd_show_ledger;
+mod cmd_show_partial_transaction;
+mod cmd_sign_partial_transaction;
 mod cmd_transfer;
 mod config;
 mod error;
@@ -26,15 +32,19 @@ mod error;
 pub use addressing::*;
 pub use cmd_call_function::*;
 pub use cmd_call_method::*;
+pub use cmd_derive_address::*;
 pub use cmd_export_abi::*;
 pub use cmd_generate_key_pair::*;
+pub use cmd_merge_partial_transactions::*;
 pub use cmd_mint::*;
 pub use cmd_new_account::*;
 pub use cmd_new_badge_fixed::*;
 pub use cmd_new_badge_mutable::*;
+pub use cmd_new_partial_transaction::*;
 pub use cmd_new_simple_badge::*;
 pub use cmd_new_token_fixed::*;
 pub use cmd_new_token_mutable::*;
+pub use cmd_notarize_partial_transaction::*;
 pub use cmd_publish::*;
 pub use cmd_reset::*;
 pub use cmd_run::*;
@@ -44,6 +54,8 @@ pub use cmd_set_default_account::*;
 pub use cmd_show::*;
 pub use cmd_show_configs::*;
 pub use cmd_show_ledger::*;
+pub use cmd_show_partial_transaction::*;
+pub use cmd_sign_partial_transaction::*;
 pub use cmd_transfer::*;
 pub use config::*;
 pub use error::*;
@@ -71,14 +83,17 @@ use radix_engine_interface::node::NetworkDefinition;
 use radix_engine_stores::rocks_db::RadixEngineDB;
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use transaction::builder::ManifestBuilder;
 use transaction::manifest::decompile;
 use transaction::model::Instruction;
+use transaction::model::PartiallySignedTransaction;
 use transaction::model::SystemTransaction;
 use transaction::model::TestTransaction;
 use transaction::model::TransactionManifest;
 use transaction::signing::EcdsaSecp256k1PrivateKey;
+use transaction::signing::ExternalSigner;
+use transaction::signing::{derive_ecdsa_secp256k1_private_key, DerivationPath, Mnemonic};
 use utils::ContextualDisplay;
 
 /// Build fast, reward everyone, and scale without friction
@@ -99,15 +114,19 @@ impl ResimCli {
 pub enum Command {
     CallFunction(CallFunction),
     CallMethod(CallMethod),
+    DeriveAddress(DeriveAddress),
     ExportAbi(ExportAbi),
     GenerateKeyPair(GenerateKeyPair),
+    MergePartialTransactions(MergePartialTransactions),
     Mint(crate::resim::cmd_mint::Mint),
     NewAccount(NewAccount),
+    NewPartialTransaction(NewPartialTransaction),
     NewSimpleBadge(NewSimpleBadge),
     NewBadgeFixed(NewBadgeFixed),
     NewBadgeMutable(NewBadgeMutable),
     NewTokenFixed(NewTokenFixed),
     NewTokenMutable(NewTokenMutable),
+    NotarizePartialTransaction(NotarizePartialTransaction),
     Publish(Publish),
     Reset(Reset),
     Run(Run),
@@ -117,6 +136,8 @@ pub enum Command {
     ShowConfigs(ShowConfigs),
     ShowLedger(ShowLedger),
     Show(Show),
+    ShowPartialTransaction(ShowPartialTransaction),
+    SignPartialTransaction(SignPartialTransaction),
     Transfer(Transfer),
 }
 
@@ -128,15 +149,19 @@ pub fn run() -> Result<(), Error> {
     match cli.command {
         Command::CallFunction(cmd) => cmd.run(&mut out),
         Command::CallMethod(cmd) => cmd.run(&mut out),
+        Command::DeriveAddress(cmd) => cmd.run(&mut out),
         Command::ExportAbi(cmd) => cmd.run(&mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
+        Command::MergePartialTransactions(cmd) => cmd.run(&mut out),
         Command::Mint(cmd) => cmd.run(&mut out),
         Command::NewAccount(cmd) => cmd.run(&mut out),
+        Command::NewPartialTransaction(cmd) => cmd.run(&mut out),
         Command::NewSimpleBadge(cmd) => cmd.run(&mut out).map(|_| ()),
         Command::NewBadgeFixed(cmd) => cmd.run(&mut out),
         Command::NewBadgeMutable(cmd) => cmd.run(&mut out),
         Command::NewTokenFixed(cmd) => cmd.run(&mut out),
         Command::NewTokenMutable(cmd) => cmd.run(&mut out),
+        Command::NotarizePartialTransaction(cmd) => cmd.run(&mut out),
         Command::Publish(cmd) => cmd.run(&mut out),
         Command::Reset(cmd) => cmd.run(&mut out),
         Command::Run(cmd) => cmd.run(&mut out),
@@ -146,6 +171,8 @@ pub fn run() -> Result<(), Error> {
         Command::ShowConfigs(cmd) => cmd.run(&mut out),
         Command::ShowLedger(cmd) => cmd.run(&mut out),
         Command::Show(cmd) => cmd.run(&mut out),
+        Command::ShowPartialTransaction(cmd) => cmd.run(&mut out),
+        Command::SignPartialTransaction(cmd) => cmd.run(&mut out),
         Command::Transfer(cmd) => cmd.run(&mut out),
     }
 }
@@ -299,6 +326,65 @@ pub fn get_signing_keys(
     Ok(private_keys)
 }
 
+/// Derives the secp256k1 key with the given account index from a BIP-39 mnemonic.
+pub fn derive_private_key(
+    mnemonic: &str,
+    passphrase: &Option<String>,
+    index: u32,
+) -> Result<EcdsaSecp256k1PrivateKey, Error> {
+    let seed = Mnemonic::from_phrase(mnemonic)
+        .map_err(Error::KeyDerivationError)?
+        .to_seed(passphrase.as_deref().unwrap_or_default());
+    let path = DerivationPath::account(index).map_err(Error::KeyDerivationError)?;
+    derive_ecdsa_secp256k1_private_key(&seed, &path).map_err(Error::KeyDerivationError)
+}
+
+pub fn parse_public_key(public_key: &str) -> Result<PublicKey, Error> {
+    if let Ok(public_key) = EcdsaSecp256k1PublicKey::from_str(public_key) {
+        return Ok(public_key.into());
+    }
+    EddsaEd25519PublicKey::from_str(public_key)
+        .map(Into::into)
+        .map_err(|_| Error::InvalidPublicKey)
+}
+
+pub fn parse_public_keys(public_keys: &str) -> Result<Vec<PublicKey>, Error> {
+    public_keys
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(parse_public_key)
+        .collect()
+}
+
+pub fn format_public_key(public_key: &PublicKey) -> String {
+    match public_key {
+        PublicKey::EcdsaSecp256k1(public_key) => public_key.to_string(),
+        PublicKey::EddsaEd25519(public_key) => public_key.to_string(),
+    }
+}
+
+/// Spawns an external signer from a command line, e.g. `my-signer --key-id 1`.
+pub fn spawn_external_signer(command: &str) -> Result<ExternalSigner, Error> {
+    let mut parts = command.split_whitespace();
+    let program = parts.next().ok_or(Error::InvalidExternalSignerCommand)?;
+    ExternalSigner::spawn(std::process::Command::new(program).args(parts))
+        .map_err(Error::ExternalSignerError)
+}
+
+pub fn read_partial_transaction(path: &Path) -> Result<PartiallySignedTransaction, Error> {
+    let bytes = fs::read(path).map_err(|err| Error::IOErrorAtPath(err, path.to_path_buf()))?;
+    PartiallySignedTransaction::from_slice(&bytes).map_err(Error::DataError)
+}
+
+pub fn write_partial_transaction(
+    path: &Path,
+    transaction: &PartiallySignedTransaction,
+) -> Result<(), Error> {
+    fs::write(path, transaction.to_bytes().unwrap())
+        .map_err(|err| Error::IOErrorAtPath(err, path.to_path_buf()))
+}
+
 pub fn export_abi(
     package_address: PackageAddress,
     blueprint_name: &str,
@@ -0,0 +1,53 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::types::*;
+use sbor::SchemaCompatibility;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::scrypto::*;
+use crate::utils::*;
+
+/// Check that a new version of a package ABI is compatible with an old one
+#[derive(Parser, Debug)]
+pub struct CheckCompat {
+    /// The path to the old `.abi` file
+    old: PathBuf,
+
+    /// The path to the new `.abi` file
+    new: PathBuf,
+}
+
+impl CheckCompat {
+    pub fn run(&self) -> Result<(), Error> {
+        let old = read_package_abi(&self.old)?;
+        let new = read_package_abi(&self.new)?;
+
+        let changes = compare_package_abis(&old, &new);
+        let mut breaking = 0;
+        for change in &changes {
+            match change.compatibility() {
+                SchemaCompatibility::Breaking => {
+                    breaking += 1;
+                    println!("{} {}", "breaking:".red(), change);
+                }
+                _ => println!("{} {}", "compatible:".green(), change),
+            }
+        }
+
+        if breaking > 0 {
+            Err(Error::IncompatibleAbi(breaking))
+        } else {
+            if changes.is_empty() {
+                println!("The ABIs are identical");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Reads a package ABI, as written by `scrypto build`
+pub fn read_package_abi(path: &PathBuf) -> Result<BTreeMap<String, BlueprintAbi>, Error> {
+    let bytes = fs::read(path).map_err(Error::IOError)?;
+    scrypto_decode(&bytes).map_err(Error::AbiDecodeError)
+}
@@ -0,0 +1,36 @@
+use clap::Parser;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::scrypto::*;
+use crate::utils::*;
+
+/// Generate client bindings from a package ABI
+#[derive(Parser, Debug)]
+pub struct GenerateBindings {
+    /// The path to the `.abi` file
+    abi: PathBuf,
+
+    /// The language to generate bindings for
+    #[clap(long, arg_enum)]
+    lang: BindingLanguage,
+
+    /// The file to write the bindings to, instead of stdout
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+}
+
+impl GenerateBindings {
+    pub fn run(&self) -> Result<(), Error> {
+        let abis = read_package_abi(&self.abi)?;
+        let bindings = generate_bindings(&abis, self.lang).map_err(Error::BindingsError)?;
+
+        match &self.output {
+            Some(output) => fs::write(output, bindings).map_err(Error::IOError),
+            None => {
+                print!("{}", bindings);
+                Ok(())
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use radix_engine::types::DecodeError;
 use std::io;
 
 use crate::utils::*;
@@ -13,4 +14,11 @@ pub enum Error {
     FormatError(FormatError),
 
     PackageAlreadyExists,
+
+    AbiDecodeError(DecodeError),
+
+    BindingsError(BindingsError),
+
+    /// The number of breaking changes found
+    IncompatibleAbi(usize),
 }
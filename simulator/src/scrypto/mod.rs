@@ -1,11 +1,15 @@
 mod cmd_build;
+mod cmd_check_compat;
 mod cmd_fmt;
+mod cmd_generate_bindings;
 mod cmd_new_package;
 mod cmd_test;
 mod error;
 
 pub use cmd_build::*;
+pub use cmd_check_compat::*;
 pub use cmd_fmt::*;
+pub use cmd_generate_bindings::*;
 pub use cmd_new_package::*;
 pub use cmd_test::*;
 pub use error::*;
@@ -23,7 +27,9 @@ pub struct ScryptoCli {
 #[derive(Subcommand, Debug)]
 pub enum Command {
     Build(Build),
+    CheckCompat(CheckCompat),
     Fmt(Fmt),
+    GenerateBindings(GenerateBindings),
     NewPackage(NewPackage),
     Test(Test),
 }
@@ -33,7 +39,9 @@ pub fn run() -> Result<(), Error> {
 
     match cli.command {
         Command::Build(cmd) => cmd.run(),
+        Command::CheckCompat(cmd) => cmd.run(),
         Command::Fmt(cmd) => cmd.run(),
+        Command::GenerateBindings(cmd) => cmd.run(),
         Command::NewPackage(cmd) => cmd.run(),
         Command::Test(cmd) => cmd.run(),
     }
@@ -0,0 +1,422 @@
+use radix_engine::types::*;
+use radix_engine_interface::data::{generate_schema_from_abi_type, ScryptoCustomTypeExtension};
+use sbor::{compare_schema_types, SchemaChange, SchemaChangeKind, SchemaCompatibility};
+
+/// A difference between two versions of a package ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiChange {
+    BlueprintRemoved(String),
+    BlueprintAdded(String),
+    FunctionRemoved {
+        blueprint: String,
+        function: String,
+    },
+    FunctionAdded {
+        blueprint: String,
+        function: String,
+    },
+    Type {
+        blueprint: String,
+        /// Either `state`, or `<function>(input)` / `<function>(output)`
+        location: String,
+        /// The change, labelled from the old to the new version
+        change: SchemaChange,
+        /// Whether existing users are affected, which for outputs doesn't follow from the label
+        compatibility: SchemaCompatibility,
+    },
+}
+
+impl AbiChange {
+    pub fn compatibility(&self) -> SchemaCompatibility {
+        match self {
+            AbiChange::BlueprintAdded(_) | AbiChange::FunctionAdded { .. } => {
+                SchemaCompatibility::BackwardCompatible
+            }
+            AbiChange::BlueprintRemoved(_) | AbiChange::FunctionRemoved { .. } => {
+                SchemaCompatibility::Breaking
+            }
+            AbiChange::Type { compatibility, .. } => *compatibility,
+        }
+    }
+}
+
+impl fmt::Display for AbiChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiChange::BlueprintRemoved(blueprint) => {
+                write!(f, "blueprint {} was removed", blueprint)
+            }
+            AbiChange::BlueprintAdded(blueprint) => write!(f, "blueprint {} was added", blueprint),
+            AbiChange::FunctionRemoved {
+                blueprint,
+                function,
+            } => write!(f, "{}::{} was removed", blueprint, function),
+            AbiChange::FunctionAdded {
+                blueprint,
+                function,
+            } => write!(f, "{}::{} was added", blueprint, function),
+            AbiChange::Type {
+                blueprint,
+                location,
+                change,
+                ..
+            } => write!(
+                f,
+                "{} {} at {}: {:?}",
+                blueprint, location, change.path, change.kind
+            ),
+        }
+    }
+}
+
+/// Compares two versions of a package ABI, as written by `scrypto build`.
+///
+/// Component state and function inputs must accept everything the old version accepted. Function
+/// outputs are compared the other way round, as it's the existing callers which decode them.
+pub fn compare_package_abis(
+    old: &BTreeMap<String, BlueprintAbi>,
+    new: &BTreeMap<String, BlueprintAbi>,
+) -> Vec<AbiChange> {
+    let mut changes = Vec::new();
+    for (blueprint, old_abi) in old {
+        let new_abi = match new.get(blueprint) {
+            Some(new_abi) => new_abi,
+            None => {
+                changes.push(AbiChange::BlueprintRemoved(blueprint.clone()));
+                continue;
+            }
+        };
+
+        compare_abi_types(
+            &mut changes,
+            blueprint,
+            "state",
+            &old_abi.structure,
+            &new_abi.structure,
+        );
+        for old_fn in &old_abi.fns {
+            match new_abi.fns.iter().find(|f| f.ident == old_fn.ident) {
+                Some(new_fn) => {
+                    compare_abi_types(
+                        &mut changes,
+                        blueprint,
+                        &format!("{}(input)", old_fn.ident),
+                        &old_fn.input,
+                        &new_fn.input,
+                    );
+                    compare_reversed_abi_types(
+                        &mut changes,
+                        blueprint,
+                        &format!("{}(output)", old_fn.ident),
+                        &old_fn.output,
+                        &new_fn.output,
+                    );
+                }
+                None => changes.push(AbiChange::FunctionRemoved {
+                    blueprint: blueprint.clone(),
+                    function: old_fn.ident.clone(),
+                }),
+            }
+        }
+        for new_fn in &new_abi.fns {
+            if !old_abi.fns.iter().any(|f| f.ident == new_fn.ident) {
+                changes.push(AbiChange::FunctionAdded {
+                    blueprint: blueprint.clone(),
+                    function: new_fn.ident.clone(),
+                });
+            }
+        }
+    }
+    for blueprint in new.keys() {
+        if !old.contains_key(blueprint) {
+            changes.push(AbiChange::BlueprintAdded(blueprint.clone()));
+        }
+    }
+    changes
+}
+
+fn compare_abi_types(
+    changes: &mut Vec<AbiChange>,
+    blueprint: &str,
+    location: &str,
+    from: &Type,
+    to: &Type,
+) {
+    let (from_index, from_schema) = generate_schema_from_abi_type(from);
+    let (to_index, to_schema) = generate_schema_from_abi_type(to);
+    let comparison = compare_schema_types::<ScryptoCustomTypeExtension>(
+        &from_schema,
+        from_index,
+        &to_schema,
+        to_index,
+    );
+    changes.extend(
+        comparison
+            .changes
+            .into_iter()
+            .map(|change| AbiChange::Type {
+                blueprint: blueprint.to_owned(),
+                location: location.to_owned(),
+                compatibility: change.kind.compatibility(),
+                change,
+            }),
+    );
+}
+
+/// Checks that values of the new type can be read as the old type, while still labelling the
+/// changes from old to new. The compatibility of each change is kept from the reversed comparison,
+/// so e.g. a variant added to an output is labelled `VariantAdded` but breaking.
+fn compare_reversed_abi_types(
+    changes: &mut Vec<AbiChange>,
+    blueprint: &str,
+    location: &str,
+    old: &Type,
+    new: &Type,
+) {
+    let start = changes.len();
+    compare_abi_types(changes, blueprint, location, new, old);
+    for change in &mut changes[start..] {
+        if let AbiChange::Type { change, .. } = change {
+            change.kind = reverse_label(change.kind.clone());
+        }
+    }
+}
+
+/// Turns the label of a change from new to old into the label of the same change from old to new.
+fn reverse_label(kind: SchemaChangeKind) -> SchemaChangeKind {
+    match kind {
+        SchemaChangeKind::TypeRenamed { old, new } => {
+            SchemaChangeKind::TypeRenamed { old: new, new: old }
+        }
+        SchemaChangeKind::FieldRenamed { old, new } => {
+            SchemaChangeKind::FieldRenamed { old: new, new: old }
+        }
+        SchemaChangeKind::VariantRenamed { old, new } => {
+            SchemaChangeKind::VariantRenamed { old: new, new: old }
+        }
+        SchemaChangeKind::TypeKindChanged { old, new } => {
+            SchemaChangeKind::TypeKindChanged { old: new, new: old }
+        }
+        SchemaChangeKind::FieldCountChanged { old, new } => {
+            SchemaChangeKind::FieldCountChanged { old: new, new: old }
+        }
+        SchemaChangeKind::VariantAdded { discriminator } => {
+            SchemaChangeKind::VariantRemoved { discriminator }
+        }
+        SchemaChangeKind::VariantRemoved { discriminator } => {
+            SchemaChangeKind::VariantAdded { discriminator }
+        }
+        SchemaChangeKind::ValidationRelaxed => SchemaChangeKind::ValidationTightened,
+        SchemaChangeKind::ValidationTightened => SchemaChangeKind::ValidationRelaxed,
+        // The old type was `Any`, and the new one is more specific
+        SchemaChangeKind::GeneralizedToAny { old } => SchemaChangeKind::TypeKindChanged {
+            old: "Any".to_owned(),
+            new: old,
+        },
+        SchemaChangeKind::TypeNotFound(index) => SchemaChangeKind::TypeNotFound(index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blueprint(fields: Vec<(String, Type)>, fns: Vec<Fn>) -> BlueprintAbi {
+        BlueprintAbi {
+            structure: Type::Struct {
+                name: "Counter".to_owned(),
+                fields: Fields::Named { named: fields },
+            },
+            fns,
+        }
+    }
+
+    fn function(ident: &str, input: Type, output: Type) -> Fn {
+        Fn {
+            ident: ident.to_owned(),
+            mutability: None,
+            input,
+            output,
+            export_name: format!("Counter_{}", ident),
+        }
+    }
+
+    #[test]
+    fn test_compare_package_abis() {
+        let old = BTreeMap::from([(
+            "Counter".to_owned(),
+            blueprint(
+                vec![("count".to_owned(), Type::U32)],
+                vec![
+                    function(
+                        "get",
+                        Type::Tuple {
+                            element_types: vec![],
+                        },
+                        Type::U32,
+                    ),
+                    function(
+                        "reset",
+                        Type::Tuple {
+                            element_types: vec![],
+                        },
+                        Type::Tuple {
+                            element_types: vec![],
+                        },
+                    ),
+                ],
+            ),
+        )]);
+        let new = BTreeMap::from([(
+            "Counter".to_owned(),
+            blueprint(
+                vec![("count".to_owned(), Type::U64)],
+                vec![
+                    function(
+                        "get",
+                        Type::Tuple {
+                            element_types: vec![],
+                        },
+                        Type::U32,
+                    ),
+                    function(
+                        "increment",
+                        Type::Tuple {
+                            element_types: vec![],
+                        },
+                        Type::Tuple {
+                            element_types: vec![],
+                        },
+                    ),
+                ],
+            ),
+        )]);
+
+        let changes = compare_package_abis(&old, &new);
+        assert_eq!(
+            changes,
+            vec![
+                AbiChange::Type {
+                    blueprint: "Counter".to_owned(),
+                    location: "state".to_owned(),
+                    change: SchemaChange {
+                        path: "$.count".to_owned(),
+                        kind: SchemaChangeKind::TypeKindChanged {
+                            old: "U32".to_owned(),
+                            new: "U64".to_owned(),
+                        },
+                    },
+                    compatibility: SchemaCompatibility::Breaking,
+                },
+                AbiChange::FunctionRemoved {
+                    blueprint: "Counter".to_owned(),
+                    function: "reset".to_owned(),
+                },
+                AbiChange::FunctionAdded {
+                    blueprint: "Counter".to_owned(),
+                    function: "increment".to_owned(),
+                },
+            ]
+        );
+        assert_eq!(changes[0].compatibility(), SchemaCompatibility::Breaking);
+        assert_eq!(
+            changes[2].compatibility(),
+            SchemaCompatibility::BackwardCompatible
+        );
+    }
+
+    #[test]
+    fn test_output_changes_keep_old_to_new_labels() {
+        let output = |name: &str| Type::Struct {
+            name: name.to_owned(),
+            fields: Fields::Named {
+                named: vec![("count".to_owned(), Type::U32)],
+            },
+        };
+        let abi = |output_name: &str| {
+            BTreeMap::from([(
+                "Counter".to_owned(),
+                blueprint(
+                    vec![("count".to_owned(), Type::U32)],
+                    vec![function(
+                        "get",
+                        Type::Tuple {
+                            element_types: vec![],
+                        },
+                        output(output_name),
+                    )],
+                ),
+            )])
+        };
+
+        assert_eq!(
+            compare_package_abis(&abi("Count"), &abi("CounterState")),
+            vec![AbiChange::Type {
+                blueprint: "Counter".to_owned(),
+                location: "get(output)".to_owned(),
+                change: SchemaChange {
+                    path: "$".to_owned(),
+                    kind: SchemaChangeKind::TypeRenamed {
+                        old: "Count".to_owned(),
+                        new: "CounterState".to_owned(),
+                    },
+                },
+                compatibility: SchemaCompatibility::BackwardCompatible,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_variant_added_to_output_is_breaking() {
+        let variant = |name: &str| Variant {
+            name: name.to_owned(),
+            fields: Fields::Unit,
+        };
+        let abi = |variants: Vec<Variant>| {
+            BTreeMap::from([(
+                "Counter".to_owned(),
+                blueprint(
+                    vec![("count".to_owned(), Type::U32)],
+                    vec![function(
+                        "status",
+                        Type::Tuple {
+                            element_types: vec![],
+                        },
+                        Type::Enum {
+                            name: "Status".to_owned(),
+                            variants,
+                        },
+                    )],
+                ),
+            )])
+        };
+
+        // Existing callers can't decode the new variant
+        let changes = compare_package_abis(
+            &abi(vec![variant("Idle")]),
+            &abi(vec![variant("Idle"), variant("Busy")]),
+        );
+        assert_eq!(
+            changes,
+            vec![AbiChange::Type {
+                blueprint: "Counter".to_owned(),
+                location: "status(output)".to_owned(),
+                change: SchemaChange {
+                    path: "$::Busy".to_owned(),
+                    kind: SchemaChangeKind::VariantAdded { discriminator: 1 },
+                },
+                compatibility: SchemaCompatibility::Breaking,
+            }]
+        );
+
+        // Whereas they'll never see a removed one
+        assert_eq!(
+            compare_package_abis(
+                &abi(vec![variant("Idle"), variant("Busy")]),
+                &abi(vec![variant("Idle")]),
+            )[0]
+            .compatibility(),
+            SchemaCompatibility::BackwardCompatible
+        );
+    }
+}
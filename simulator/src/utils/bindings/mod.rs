@@ -0,0 +1,330 @@
+mod python;
+mod typescript;
+
+use radix_engine::types::*;
+use radix_engine_interface::data::{
+    generate_schema_from_abi_type, ScryptoCustomTypeKind, ScryptoSchema,
+};
+use sbor::{
+    ChildNames, LocalTypeIndex, TypeKind, TypeMetadata, OPTION_VARIANT_SOME, RESULT_VARIANT_ERR,
+    RESULT_VARIANT_OK,
+};
+
+/// The languages which client bindings can be generated for.
+#[derive(clap::ArgEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingLanguage {
+    #[clap(name = "typescript")]
+    TypeScript,
+    Python,
+}
+
+/// Generates client bindings for the blueprints of a package.
+///
+/// For every function and method, the bindings contain the argument and return types, a builder
+/// which writes the `CALL_FUNCTION` or `CALL_METHOD` manifest instruction, and an encoder of the
+/// SBOR arguments. The types of each blueprint are namespaced by the blueprint name.
+pub fn generate_bindings(
+    abis: &BTreeMap<String, BlueprintAbi>,
+    language: BindingLanguage,
+) -> Result<String, BindingsError> {
+    let bindings = Bindings::from_abis(abis)?;
+    Ok(match language {
+        BindingLanguage::TypeScript => typescript::generate(&bindings),
+        BindingLanguage::Python => python::generate(&bindings),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindingsError {
+    /// The input of a function isn't a struct, so it can't be turned into arguments
+    InvalidFunctionInput { blueprint: String, function: String },
+}
+
+/// A type as seen by the client, resolved from the ABI schema of a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BindingType {
+    Bool,
+    /// An integer, with its manifest value kind, eg `U32`
+    Integer(&'static str),
+    String,
+    Bytes,
+    Array(Box<BindingType>),
+    Map(Box<BindingType>, Box<BindingType>),
+    Tuple(Vec<BindingType>),
+    Option(Box<BindingType>),
+    Result(Box<BindingType>, Box<BindingType>),
+    /// A struct or enum, defined in [`BindingBlueprint::types`]
+    Named(String),
+    /// A value written in a manifest as `Kind("...")`, eg addresses and decimals
+    Custom(String),
+    /// A value of any type, passed through as manifest text and SBOR
+    Any,
+}
+
+impl BindingType {
+    /// Whether the type is an integer which doesn't fit into a float
+    fn is_big_integer(&self) -> bool {
+        matches!(self, BindingType::Integer("I64" | "I128" | "U64" | "U128"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BindingFields {
+    Named(Vec<(String, BindingType)>),
+    Unnamed(Vec<BindingType>),
+}
+
+impl BindingFields {
+    fn is_empty(&self) -> bool {
+        match self {
+            BindingFields::Named(fields) => fields.is_empty(),
+            BindingFields::Unnamed(fields) => fields.is_empty(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BindingVariant {
+    discriminator: u8,
+    name: String,
+    fields: BindingFields,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NamedType {
+    Struct(BindingFields),
+    Enum(Vec<BindingVariant>),
+}
+
+#[derive(Debug, Clone)]
+struct BindingFunction {
+    ident: String,
+    is_method: bool,
+    args: Vec<(String, BindingType)>,
+    output: BindingType,
+}
+
+#[derive(Debug, Clone)]
+struct BindingBlueprint {
+    name: String,
+    /// The structs and enums used by any function of the blueprint, by name
+    types: BTreeMap<String, NamedType>,
+    functions: Vec<BindingFunction>,
+}
+
+impl BindingBlueprint {
+    /// The manifest value kind of a type, as used in `Array<Kind>` and `Map<Key, Value>`.
+    /// Returns `None` for [`BindingType::Any`], whose value kind is only known at runtime.
+    fn value_kind(&self, ty: &BindingType) -> Option<String> {
+        let value_kind: &str = match ty {
+            BindingType::Bool => "Bool",
+            BindingType::Integer(value_kind) => value_kind,
+            BindingType::String => "String",
+            BindingType::Bytes | BindingType::Array(_) => "Array",
+            BindingType::Map(..) => "Map",
+            BindingType::Tuple(_) => "Tuple",
+            BindingType::Option(_) | BindingType::Result(..) => "Enum",
+            BindingType::Named(name) => match self.types.get(name) {
+                Some(NamedType::Enum(_)) => "Enum",
+                _ => "Tuple",
+            },
+            BindingType::Custom(value_kind) => value_kind,
+            BindingType::Any => return None,
+        };
+        Some(value_kind.to_owned())
+    }
+}
+
+/// The language-independent content of the bindings.
+#[derive(Debug, Clone)]
+struct Bindings {
+    blueprints: Vec<BindingBlueprint>,
+}
+
+impl Bindings {
+    fn from_abis(abis: &BTreeMap<String, BlueprintAbi>) -> Result<Self, BindingsError> {
+        let mut blueprints = Vec::new();
+        for (name, abi) in abis {
+            let mut types = BTreeMap::new();
+            let mut functions = Vec::new();
+            for function in &abi.fns {
+                let invalid_input = || BindingsError::InvalidFunctionInput {
+                    blueprint: name.clone(),
+                    function: function.ident.clone(),
+                };
+                let args = match resolve_abi_type(&mut types, &function.input) {
+                    BindingType::Named(input_name) => match types.remove(&input_name) {
+                        Some(NamedType::Struct(BindingFields::Named(args))) => args,
+                        Some(NamedType::Struct(BindingFields::Unnamed(args))) => args
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, ty)| (format!("arg{}", i), ty))
+                            .collect(),
+                        _ => return Err(invalid_input()),
+                    },
+                    BindingType::Tuple(args) if args.is_empty() => Vec::new(),
+                    _ => return Err(invalid_input()),
+                };
+                functions.push(BindingFunction {
+                    ident: function.ident.clone(),
+                    is_method: function.mutability.is_some(),
+                    args,
+                    output: resolve_abi_type(&mut types, &function.output),
+                });
+            }
+            blueprints.push(BindingBlueprint {
+                name: name.clone(),
+                types,
+                functions,
+            });
+        }
+        Ok(Self { blueprints })
+    }
+}
+
+fn resolve_abi_type(types: &mut BTreeMap<String, NamedType>, ty: &Type) -> BindingType {
+    let (index, schema) = generate_schema_from_abi_type(ty);
+    resolve_type(types, &schema, index)
+}
+
+fn resolve_type(
+    types: &mut BTreeMap<String, NamedType>,
+    schema: &ScryptoSchema,
+    index: LocalTypeIndex,
+) -> BindingType {
+    let resolved = schema
+        .resolve(index)
+        .expect("ABI schemas contain all their types");
+    let type_name = resolved.metadata.type_name.to_string();
+
+    match resolved.kind.as_ref() {
+        TypeKind::Any => BindingType::Any,
+        TypeKind::Bool => BindingType::Bool,
+        TypeKind::I8 => BindingType::Integer("I8"),
+        TypeKind::I16 => BindingType::Integer("I16"),
+        TypeKind::I32 => BindingType::Integer("I32"),
+        TypeKind::I64 => BindingType::Integer("I64"),
+        TypeKind::I128 => BindingType::Integer("I128"),
+        TypeKind::U8 => BindingType::Integer("U8"),
+        TypeKind::U16 => BindingType::Integer("U16"),
+        TypeKind::U32 => BindingType::Integer("U32"),
+        TypeKind::U64 => BindingType::Integer("U64"),
+        TypeKind::U128 => BindingType::Integer("U128"),
+        TypeKind::String => BindingType::String,
+        TypeKind::Array { element_type } => match resolve_type(types, schema, *element_type) {
+            BindingType::Integer("U8") => BindingType::Bytes,
+            element_type => BindingType::Array(Box::new(element_type)),
+        },
+        TypeKind::Map {
+            key_type,
+            value_type,
+        } => BindingType::Map(
+            Box::new(resolve_type(types, schema, *key_type)),
+            Box::new(resolve_type(types, schema, *value_type)),
+        ),
+        TypeKind::Tuple { field_types } => match type_name.as_str() {
+            "Tuple" => BindingType::Tuple(
+                field_types
+                    .iter()
+                    .map(|field_type| resolve_type(types, schema, *field_type))
+                    .collect(),
+            ),
+            "NonFungibleGlobalId" => BindingType::Custom(type_name),
+            _ => {
+                if !types.contains_key(&type_name) {
+                    let fields = resolve_fields(types, schema, &resolved.metadata, field_types);
+                    types.insert(type_name.clone(), NamedType::Struct(fields));
+                }
+                BindingType::Named(type_name)
+            }
+        },
+        TypeKind::Enum { variants } => match (
+            type_name.as_str(),
+            single_field(variants, OPTION_VARIANT_SOME),
+            single_field(variants, RESULT_VARIANT_OK)
+                .zip(single_field(variants, RESULT_VARIANT_ERR)),
+        ) {
+            // User enums may be named `Option` or `Result` too, so check the variants as well
+            ("Option", Some(some_type), _) if variants.len() == 2 => {
+                BindingType::Option(Box::new(resolve_type(types, schema, some_type)))
+            }
+            ("Result", _, Some((okay_type, err_type))) if variants.len() == 2 => {
+                BindingType::Result(
+                    Box::new(resolve_type(types, schema, okay_type)),
+                    Box::new(resolve_type(types, schema, err_type)),
+                )
+            }
+            _ => {
+                if !types.contains_key(&type_name) {
+                    let variants = variants
+                        .iter()
+                        .map(|(discriminator, field_types)| {
+                            let metadata = match &resolved.metadata.child_names {
+                                ChildNames::VariantNames(names) => names.get(discriminator),
+                                _ => None,
+                            };
+                            BindingVariant {
+                                discriminator: *discriminator,
+                                name: metadata
+                                    .map(|metadata| metadata.type_name.to_string())
+                                    .unwrap_or_else(|| format!("Variant{}", discriminator)),
+                                fields: resolve_fields(
+                                    types,
+                                    schema,
+                                    metadata.unwrap_or(&TypeMetadata::default()),
+                                    field_types,
+                                ),
+                            }
+                        })
+                        .collect();
+                    types.insert(type_name.clone(), NamedType::Enum(variants));
+                }
+                BindingType::Named(type_name)
+            }
+        },
+        TypeKind::Custom(custom_type_kind) => BindingType::Custom(match custom_type_kind {
+            // Only buckets and proofs can be passed in from a manifest by name
+            ScryptoCustomTypeKind::Own if type_name == "Bucket" || type_name == "Proof" => {
+                type_name
+            }
+            ScryptoCustomTypeKind::Own | ScryptoCustomTypeKind::KeyValueStore { .. } => {
+                "Own".to_owned()
+            }
+            _ => type_name,
+        }),
+    }
+}
+
+fn single_field(
+    variants: &BTreeMap<u8, Vec<LocalTypeIndex>>,
+    discriminator: u8,
+) -> Option<LocalTypeIndex> {
+    match variants
+        .get(&discriminator)
+        .map(|field_types| field_types.as_slice())
+    {
+        Some([field_type]) => Some(*field_type),
+        _ => None,
+    }
+}
+
+fn resolve_fields(
+    types: &mut BTreeMap<String, NamedType>,
+    schema: &ScryptoSchema,
+    metadata: &TypeMetadata,
+    field_types: &[LocalTypeIndex],
+) -> BindingFields {
+    let field_types = field_types
+        .iter()
+        .map(|field_type| resolve_type(types, schema, *field_type));
+    match &metadata.child_names {
+        ChildNames::FieldNames(names) => BindingFields::Named(
+            names
+                .iter()
+                .map(|name| name.to_string())
+                .zip(field_types)
+                .collect(),
+        ),
+        _ => BindingFields::Unnamed(field_types.collect()),
+    }
+}
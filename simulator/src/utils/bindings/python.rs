@@ -0,0 +1,638 @@
+use std::fmt::Write;
+
+use super::*;
+
+const PRELUDE: &str = r#"# Generated by `scrypto generate-bindings`. Do not edit.
+
+from __future__ import annotations
+
+import json
+import re
+from dataclasses import dataclass, field
+from typing import Any, Callable, Dict, Generic, List, Optional, Tuple, TypeVar, Union
+
+T = TypeVar("T")
+E = TypeVar("E")
+
+
+@dataclass
+class ManifestValue:
+    """A value of any type, as manifest text along with its value kind"""
+
+    kind: str
+    text: str
+    # The SBOR encoding of the value without its value kind, needed to encode SBOR arguments
+    sbor: Optional[bytes] = None
+
+
+@dataclass
+class Ok(Generic[T]):
+    value: T
+
+
+@dataclass
+class Err(Generic[E]):
+    value: E
+
+
+@dataclass
+class Value:
+    """An encoded value, which can be written as manifest text or as SBOR
+
+    The `kind` is one of `Bool`, `Integer`, `String`, `Bytes`, `Custom`, `Array`, `Map`, `Tuple`,
+    `Enum` and `Any`. The `value_kinds` are the value kind of an integer or custom value, the
+    element kind of an array, or the key and value kinds of a map. The `children` are the
+    elements of an array, the flattened entries of a map, or the fields of a tuple or enum.
+    """
+
+    kind: str
+    value: Any = None
+    value_kinds: List[str] = field(default_factory=list)
+    children: List[Value] = field(default_factory=list)
+
+
+def encode_bool(value: bool) -> Value:
+    return Value("Bool", value)
+
+
+def encode_integer(value: int, value_kind: str) -> Value:
+    return Value("Integer", value, [value_kind])
+
+
+def encode_string(value: str) -> Value:
+    return Value("String", value)
+
+
+def encode_bytes(value: bytes) -> Value:
+    return Value("Bytes", value)
+
+
+def encode_custom(value_kind: str, value: str) -> Value:
+    return Value("Custom", value, [value_kind])
+
+
+def encode_array(element_kind: str, elements: List[Value]) -> Value:
+    return Value("Array", None, [element_kind], elements)
+
+
+def encode_map(key_kind: str, value_kind: str, entries: List[Tuple[Value, Value]]) -> Value:
+    return Value("Map", None, [key_kind, value_kind], [child for entry in entries for child in entry])
+
+
+def encode_tuple(fields: List[Value]) -> Value:
+    return Value("Tuple", None, [], fields)
+
+
+def encode_enum(discriminator: int, fields: List[Value]) -> Value:
+    return Value("Enum", discriminator, [], fields)
+
+
+def encode_option(value: Optional[T], encode_some: Callable[[T], Value]) -> Value:
+    return encode_enum(0, []) if value is None else encode_enum(1, [encode_some(value)])
+
+
+def encode_result(
+    value: Union[Ok[T], Err[E]],
+    encode_ok: Callable[[T], Value],
+    encode_err: Callable[[E], Value],
+) -> Value:
+    if isinstance(value, Ok):
+        return encode_enum(0, [encode_ok(value.value)])
+    return encode_enum(1, [encode_err(value.value)])
+
+
+def encode_any(value: ManifestValue) -> Value:
+    return Value("Any", value)
+
+
+def value_kind_of(values: List[ManifestValue]) -> str:
+    return values[0].kind if values else "Tuple"
+
+
+def to_manifest(value: Value) -> str:
+    """Writes a value as manifest text"""
+    children = [to_manifest(child) for child in value.children]
+    if value.kind == "Bool":
+        return "true" if value.value else "false"
+    if value.kind == "Integer":
+        return f"{value.value}{value.value_kinds[0].lower()}"
+    if value.kind == "String":
+        return json.dumps(value.value)
+    if value.kind == "Bytes":
+        return f'Bytes("{value.value.hex()}")'
+    if value.kind == "Custom":
+        return f"{value.value_kinds[0]}({json.dumps(value.value)})"
+    if value.kind == "Array":
+        return f"Array<{value.value_kinds[0]}>({', '.join(children)})"
+    if value.kind == "Map":
+        return f"Map<{', '.join(value.value_kinds)}>({', '.join(children)})"
+    if value.kind == "Tuple":
+        return f"Tuple({', '.join(children)})"
+    if value.kind == "Enum":
+        return f"Enum({', '.join([f'{value.value}u8', *children])})"
+    return value.value.text
+
+
+SBOR_PAYLOAD_PREFIX = 0x5C
+
+SBOR_VALUE_KINDS = {
+    "Bool": 0x01,
+    "I8": 0x02,
+    "I16": 0x03,
+    "I32": 0x04,
+    "I64": 0x05,
+    "I128": 0x06,
+    "U8": 0x07,
+    "U16": 0x08,
+    "U32": 0x09,
+    "U64": 0x0A,
+    "U128": 0x0B,
+    "String": 0x0C,
+    "Array": 0x20,
+    "Tuple": 0x21,
+    "Enum": 0x22,
+    "Map": 0x23,
+    "PackageAddress": 0x80,
+    "ComponentAddress": 0x81,
+    "ResourceAddress": 0x82,
+    "Own": 0x90,
+    "Bucket": 0xA0,
+    "Proof": 0xA1,
+    "Expression": 0xA2,
+    "Blob": 0xA3,
+    "Hash": 0xB0,
+    "EcdsaSecp256k1PublicKey": 0xB1,
+    "EcdsaSecp256k1Signature": 0xB2,
+    "EddsaEd25519PublicKey": 0xB3,
+    "EddsaEd25519Signature": 0xB4,
+    "Decimal": 0xB5,
+    "PreciseDecimal": 0xB6,
+    "NonFungibleLocalId": 0xB7,
+}
+
+
+def decimal_bytes(value: str, scale: int, size: int) -> bytes:
+    match = re.fullmatch(r"(-?)(\d+)(?:\.(\d+))?", value)
+    if match is None or len(match[3] or "") > scale:
+        raise ValueError(f"Invalid decimal: {value}")
+    units = int(match[2] + (match[3] or "").ljust(scale, "0"))
+    return (-units if match[1] else units).to_bytes(size, "little", signed=True)
+
+
+BECH32_CHARSET = "qpzry9x8gf2tvdw0s3jn54khce6mua7l"
+BECH32M_CONSTANT = 0x2BC830A3
+
+
+def bech32_polymod(values: List[int]) -> int:
+    generators = [0x3B6A57B2, 0x26508E6D, 0x1EA119FA, 0x3D4233DD, 0x2A1462B3]
+    checksum = 1
+    for value in values:
+        top = checksum >> 25
+        checksum = (checksum & 0x1FFFFFF) << 5 ^ value
+        for i, generator in enumerate(generators):
+            if (top >> i) & 1:
+                checksum ^= generator
+    return checksum
+
+
+def address_bytes(address: str) -> bytes:
+    lowercase = address.lower()
+    hrp, _, encoded = lowercase.rpartition("1")
+    data = [BECH32_CHARSET.find(c) for c in encoded]
+    checked = [ord(c) >> 5 for c in hrp] + [0] + [ord(c) & 31 for c in hrp] + data
+    if not hrp or len(data) < 6 or -1 in data or bech32_polymod(checked) != BECH32M_CONSTANT:
+        raise ValueError(f"Invalid address: {address}")
+    accumulator, bits, result = 0, 0, bytearray()
+    for value in data[:-6]:
+        accumulator = ((accumulator << 5) | value) & 0xFFF
+        bits += 5
+        if bits >= 8:
+            bits -= 8
+            result.append((accumulator >> bits) & 0xFF)
+    return bytes(result)
+
+
+def expression_bytes(value: str) -> bytes:
+    expressions = ["ENTIRE_WORKTOP", "ENTIRE_AUTH_ZONE"]
+    if value not in expressions:
+        raise ValueError(f"Invalid expression: {value}")
+    return bytes([expressions.index(value)])
+
+
+# The SBOR encoders of custom values, from their manifest text, by value kind.
+# Buckets and proofs are identified by their numeric id. Other value kinds can't be encoded
+# as SBOR unless an encoder is registered here.
+CUSTOM_SBOR_ENCODERS: Dict[str, Callable[[str], bytes]] = {
+    "PackageAddress": address_bytes,
+    "ComponentAddress": address_bytes,
+    "ResourceAddress": address_bytes,
+    "Bucket": lambda value: int(value).to_bytes(4, "little"),
+    "Proof": lambda value: int(value).to_bytes(4, "little"),
+    "Expression": expression_bytes,
+    "Blob": bytes.fromhex,
+    "Hash": bytes.fromhex,
+    "EcdsaSecp256k1PublicKey": bytes.fromhex,
+    "EcdsaSecp256k1Signature": bytes.fromhex,
+    "EddsaEd25519PublicKey": bytes.fromhex,
+    "EddsaEd25519Signature": bytes.fromhex,
+    "Decimal": lambda value: decimal_bytes(value, 18, 32),
+    "PreciseDecimal": lambda value: decimal_bytes(value, 64, 64),
+}
+
+
+def sbor_value_kind(value_kind: str) -> int:
+    if value_kind not in SBOR_VALUE_KINDS:
+        raise ValueError(f"Unknown value kind: {value_kind}")
+    return SBOR_VALUE_KINDS[value_kind]
+
+
+def write_size(out: bytearray, size: int) -> None:
+    while True:
+        out.append((size & 0x7F) | (0x80 if size > 0x7F else 0))
+        size >>= 7
+        if size == 0:
+            break
+
+
+def write_sbor_value(out: bytearray, value: Value) -> None:
+    if value.kind in ("Integer", "Custom"):
+        value_kind = value.value_kinds[0]
+    elif value.kind == "Bytes":
+        value_kind = "Array"
+    elif value.kind == "Any":
+        value_kind = value.value.kind
+    else:
+        value_kind = value.kind
+    out.append(sbor_value_kind(value_kind))
+    write_sbor_body(out, value)
+
+
+def write_sbor_body(out: bytearray, value: Value) -> None:
+    if value.kind == "Bool":
+        out.append(1 if value.value else 0)
+    elif value.kind == "Integer":
+        value_kind = value.value_kinds[0]
+        out += value.value.to_bytes(int(value_kind[1:]) // 8, "little", signed=value_kind.startswith("I"))
+    elif value.kind == "String":
+        utf8 = value.value.encode("utf-8")
+        write_size(out, len(utf8))
+        out += utf8
+    elif value.kind == "Bytes":
+        out.append(sbor_value_kind("U8"))
+        write_size(out, len(value.value))
+        out += value.value
+    elif value.kind == "Custom":
+        if value.value_kinds[0] not in CUSTOM_SBOR_ENCODERS:
+            raise ValueError(f"{value.value_kinds[0]} values can't be encoded as SBOR")
+        out += CUSTOM_SBOR_ENCODERS[value.value_kinds[0]](value.value)
+    elif value.kind in ("Array", "Map"):
+        out += bytes(sbor_value_kind(value_kind) for value_kind in value.value_kinds)
+        write_size(out, len(value.children) // len(value.value_kinds))
+        for child in value.children:
+            write_sbor_body(out, child)
+    elif value.kind in ("Tuple", "Enum"):
+        if value.kind == "Enum":
+            out.append(value.value)
+        write_size(out, len(value.children))
+        for child in value.children:
+            write_sbor_value(out, child)
+    elif value.value.sbor is None:
+        raise ValueError(f"The SBOR encoding of {value.value.text} is missing")
+    else:
+        out += value.value.sbor
+
+
+def to_sbor(value: Value) -> bytes:
+    """Encodes a value as an SBOR payload"""
+    out = bytearray([SBOR_PAYLOAD_PREFIX])
+    write_sbor_value(out, value)
+    return bytes(out)
+
+
+def call_function(package_address: str, blueprint_name: str, function_name: str, args: List[Value]) -> str:
+    parts = [encode_custom("PackageAddress", package_address), encode_string(blueprint_name)]
+    return " ".join(["CALL_FUNCTION", *map(to_manifest, [*parts, encode_string(function_name), *args])]) + ";"
+
+
+def call_method(component_address: str, method_name: str, args: List[Value]) -> str:
+    parts = [encode_custom("ComponentAddress", component_address), encode_string(method_name)]
+    return " ".join(["CALL_METHOD", *map(to_manifest, [*parts, *args])]) + ";"
+"#;
+
+pub(super) fn generate(bindings: &Bindings) -> String {
+    let mut out = PRELUDE.to_owned();
+
+    for blueprint in &bindings.blueprints {
+        for (name, named_type) in &blueprint.types {
+            let name = qualified_name(blueprint, name);
+            match named_type {
+                NamedType::Struct(fields) => {
+                    write_dataclass(&mut out, blueprint, &name, fields);
+                }
+                NamedType::Enum(variants) => {
+                    for variant in variants {
+                        write_dataclass(
+                            &mut out,
+                            blueprint,
+                            &format!("{}{}", name, variant.name),
+                            &variant.fields,
+                        );
+                    }
+                    let variant_classes = variants
+                        .iter()
+                        .map(|variant| format!("{}{}", name, variant.name))
+                        .collect::<Vec<_>>();
+                    out.push_str("\n\n");
+                    writeln!(out, "{} = Union[{}]", name, variant_classes.join(", ")).unwrap();
+                }
+            }
+
+            out.push_str("\n\n");
+            writeln!(
+                out,
+                "def encode_{}(value: {}) -> Value:",
+                snake_case(&name),
+                name
+            )
+            .unwrap();
+            match named_type {
+                NamedType::Struct(fields) => {
+                    writeln!(
+                        out,
+                        "    return encode_tuple({})",
+                        encode_fields(blueprint, fields, "value")
+                    )
+                    .unwrap();
+                }
+                NamedType::Enum(variants) => {
+                    for variant in variants {
+                        writeln!(out, "    if isinstance(value, {}{}):", name, variant.name)
+                            .unwrap();
+                        writeln!(
+                            out,
+                            "        return encode_enum({}, {})",
+                            variant.discriminator,
+                            encode_fields(blueprint, &variant.fields, "value")
+                        )
+                        .unwrap();
+                    }
+                    writeln!(out, "    raise TypeError(f\"Not a {}: {{value!r}}\")", name).unwrap();
+                }
+            }
+        }
+
+        out.push_str("\n\n");
+        for function in &blueprint.functions {
+            writeln!(
+                out,
+                "{}_{}_Output = {}",
+                blueprint.name,
+                function.ident,
+                type_name(blueprint, &function.output)
+            )
+            .unwrap();
+        }
+
+        out.push_str("\n\n");
+        writeln!(out, "class {}:", blueprint.name).unwrap();
+        for (i, function) in blueprint.functions.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            let address = if function.is_method {
+                "component_address"
+            } else {
+                "package_address"
+            };
+            let params = function
+                .args
+                .iter()
+                .map(|(name, ty)| format!("{}: {}", name, type_name(blueprint, ty)))
+                .collect::<Vec<_>>();
+            let args = function
+                .args
+                .iter()
+                .map(|(name, ty)| encode(blueprint, ty, name, 0))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            writeln!(out, "    @staticmethod").unwrap();
+            writeln!(
+                out,
+                "    def {}({}) -> str:",
+                function.ident,
+                [vec![format!("{}: str", address)], params.clone()]
+                    .concat()
+                    .join(", ")
+            )
+            .unwrap();
+            if function.is_method {
+                writeln!(
+                    out,
+                    "        return call_method({}, \"{}\", [{}])",
+                    address, function.ident, args
+                )
+                .unwrap();
+            } else {
+                writeln!(
+                    out,
+                    "        return call_function({}, \"{}\", \"{}\", [{}])",
+                    address, blueprint.name, function.ident, args
+                )
+                .unwrap();
+            }
+
+            out.push('\n');
+            writeln!(out, "    @staticmethod").unwrap();
+            writeln!(
+                out,
+                "    def {}_args({}) -> bytes:",
+                function.ident,
+                params.join(", ")
+            )
+            .unwrap();
+            writeln!(out, "        return to_sbor(encode_tuple([{}]))", args).unwrap();
+        }
+    }
+
+    out
+}
+
+/// The name of a struct or enum of a blueprint, prefixed with the blueprint name.
+fn qualified_name(blueprint: &BindingBlueprint, name: &str) -> String {
+    format!("{}_{}", blueprint.name, name)
+}
+
+fn write_dataclass(
+    out: &mut String,
+    blueprint: &BindingBlueprint,
+    name: &str,
+    fields: &BindingFields,
+) {
+    out.push_str("\n\n");
+    writeln!(out, "@dataclass").unwrap();
+    writeln!(out, "class {}:", name).unwrap();
+    match fields {
+        fields if fields.is_empty() => {
+            writeln!(out, "    pass").unwrap();
+        }
+        BindingFields::Named(fields) => {
+            for (field_name, ty) in fields {
+                writeln!(out, "    {}: {}", field_name, type_name(blueprint, ty)).unwrap();
+            }
+        }
+        BindingFields::Unnamed(fields) => {
+            for (i, ty) in fields.iter().enumerate() {
+                writeln!(out, "    field_{}: {}", i, type_name(blueprint, ty)).unwrap();
+            }
+        }
+    }
+}
+
+fn type_name(blueprint: &BindingBlueprint, ty: &BindingType) -> String {
+    match ty {
+        BindingType::Bool => "bool".to_owned(),
+        BindingType::Integer(_) => "int".to_owned(),
+        BindingType::String | BindingType::Custom(_) => "str".to_owned(),
+        BindingType::Bytes => "bytes".to_owned(),
+        BindingType::Array(element_type) => format!("List[{}]", type_name(blueprint, element_type)),
+        BindingType::Map(key_type, value_type) => {
+            format!(
+                "Dict[{}, {}]",
+                type_name(blueprint, key_type),
+                type_name(blueprint, value_type)
+            )
+        }
+        BindingType::Tuple(field_types) if field_types.is_empty() => "Tuple[()]".to_owned(),
+        BindingType::Tuple(field_types) => {
+            let field_types = field_types
+                .iter()
+                .map(|ty| type_name(blueprint, ty))
+                .collect::<Vec<_>>();
+            format!("Tuple[{}]", field_types.join(", "))
+        }
+        BindingType::Option(some_type) => format!("Optional[{}]", type_name(blueprint, some_type)),
+        BindingType::Result(okay_type, err_type) => format!(
+            "Union[Ok[{}], Err[{}]]",
+            type_name(blueprint, okay_type),
+            type_name(blueprint, err_type)
+        ),
+        BindingType::Named(name) => qualified_name(blueprint, name),
+        BindingType::Any => "ManifestValue".to_owned(),
+    }
+}
+
+/// The fields of a struct or enum variant, encoded as a list of values.
+fn encode_fields(blueprint: &BindingBlueprint, fields: &BindingFields, value: &str) -> String {
+    let fields = match fields {
+        BindingFields::Named(fields) => fields
+            .iter()
+            .map(|(name, ty)| encode(blueprint, ty, &format!("{}.{}", value, name), 0))
+            .collect::<Vec<_>>(),
+        BindingFields::Unnamed(fields) => fields
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| encode(blueprint, ty, &format!("{}.field_{}", value, i), 0))
+            .collect(),
+    };
+    format!("[{}]", fields.join(", "))
+}
+
+/// An expression which encodes `value` as a value, which can be written as manifest text or SBOR.
+/// The `depth` is used to give the variables of nested comprehensions and lambdas distinct names.
+fn encode(blueprint: &BindingBlueprint, ty: &BindingType, value: &str, depth: usize) -> String {
+    match ty {
+        BindingType::Bool => format!("encode_bool({})", value),
+        BindingType::Integer(value_kind) => {
+            format!("encode_integer({}, \"{}\")", value, value_kind)
+        }
+        BindingType::String => format!("encode_string({})", value),
+        BindingType::Bytes => format!("encode_bytes({})", value),
+        BindingType::Array(element_type) => {
+            let element = format!("v{}", depth);
+            format!(
+                "encode_array({}, [{} for {} in {}])",
+                value_kind(blueprint, element_type, value),
+                encode(blueprint, element_type, &element, depth + 1),
+                element,
+                value
+            )
+        }
+        BindingType::Map(key_type, value_type) => {
+            let key = format!("k{}", depth);
+            let element = format!("v{}", depth);
+            format!(
+                "encode_map({}, {}, [({}, {}) for {}, {} in {}.items()])",
+                value_kind(blueprint, key_type, &format!("list({}.keys())", value)),
+                value_kind(blueprint, value_type, &format!("list({}.values())", value)),
+                encode(blueprint, key_type, &key, depth + 1),
+                encode(blueprint, value_type, &element, depth + 1),
+                key,
+                element,
+                value
+            )
+        }
+        BindingType::Tuple(field_types) => {
+            let fields = field_types
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| encode(blueprint, ty, &format!("{}[{}]", value, i), depth))
+                .collect::<Vec<_>>();
+            format!("encode_tuple([{}])", fields.join(", "))
+        }
+        BindingType::Option(some_type) => {
+            let element = format!("v{}", depth);
+            format!(
+                "encode_option({}, lambda {}: {})",
+                value,
+                element,
+                encode(blueprint, some_type, &element, depth + 1)
+            )
+        }
+        BindingType::Result(okay_type, err_type) => {
+            let element = format!("v{}", depth);
+            format!(
+                "encode_result({}, lambda {}: {}, lambda {}: {})",
+                value,
+                element,
+                encode(blueprint, okay_type, &element, depth + 1),
+                element,
+                encode(blueprint, err_type, &element, depth + 1)
+            )
+        }
+        BindingType::Named(name) => format!(
+            "encode_{}({})",
+            snake_case(&qualified_name(blueprint, name)),
+            value
+        ),
+        BindingType::Custom(value_kind) => {
+            format!("encode_custom(\"{}\", {})", value_kind, value)
+        }
+        BindingType::Any => format!("encode_any({})", value),
+    }
+}
+
+/// An expression for the value kind of the elements of a collection.
+fn value_kind(blueprint: &BindingBlueprint, ty: &BindingType, elements: &str) -> String {
+    match blueprint.value_kind(ty) {
+        Some(value_kind) => format!("\"{}\"", value_kind),
+        None => format!("value_kind_of({})", elements),
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    let mut previous: Option<char> = None;
+    for c in name.chars() {
+        if c.is_uppercase() {
+            if previous.map_or(false, |p| p.is_lowercase() || p.is_ascii_digit()) {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+        previous = Some(c);
+    }
+    snake
+}
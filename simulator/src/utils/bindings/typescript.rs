@@ -0,0 +1,662 @@
+use std::fmt::Write;
+
+use super::*;
+
+const PRELUDE: &str = r#"// Generated by `scrypto generate-bindings`. Do not edit.
+
+/** A value of any type, as manifest text along with its value kind */
+export interface ManifestValue {
+  kind: string;
+  text: string;
+  /** The SBOR encoding of the value without its value kind, needed to encode SBOR arguments */
+  sbor?: Uint8Array;
+}
+
+export type Result<T, E> = { Ok: T } | { Err: E };
+
+/** An encoded value, which can be written as manifest text or as SBOR */
+export type Value =
+  | { kind: "Bool"; value: boolean }
+  | { kind: "Integer"; valueKind: string; value: number | bigint }
+  | { kind: "String"; value: string }
+  | { kind: "Bytes"; value: Uint8Array }
+  | { kind: "Custom"; valueKind: string; value: string }
+  | { kind: "Array"; elementKind: string; elements: Value[] }
+  | { kind: "Map"; keyKind: string; valueKind: string; entries: Value[][] }
+  | { kind: "Tuple"; fields: Value[] }
+  | { kind: "Enum"; discriminator: number; fields: Value[] }
+  | { kind: "Any"; value: ManifestValue };
+
+function encodeBool(value: boolean): Value {
+  return { kind: "Bool", value };
+}
+
+function encodeInteger(value: number | bigint, valueKind: string): Value {
+  return { kind: "Integer", valueKind, value };
+}
+
+function encodeString(value: string): Value {
+  return { kind: "String", value };
+}
+
+function encodeBytes(value: Uint8Array): Value {
+  return { kind: "Bytes", value };
+}
+
+function encodeCustom(valueKind: string, value: string): Value {
+  return { kind: "Custom", valueKind, value };
+}
+
+function encodeArray(elementKind: string, elements: Value[]): Value {
+  return { kind: "Array", elementKind, elements };
+}
+
+function encodeMap(keyKind: string, valueKind: string, entries: Value[][]): Value {
+  return { kind: "Map", keyKind, valueKind, entries };
+}
+
+function encodeTuple(fields: Value[]): Value {
+  return { kind: "Tuple", fields };
+}
+
+function encodeEnum(discriminator: number, fields: Value[]): Value {
+  return { kind: "Enum", discriminator, fields };
+}
+
+function encodeOption<T>(value: T | null, encodeSome: (value: T) => Value): Value {
+  return value === null ? encodeEnum(0, []) : encodeEnum(1, [encodeSome(value)]);
+}
+
+function encodeResult<T, E>(
+  value: Result<T, E>,
+  encodeOk: (value: T) => Value,
+  encodeErr: (value: E) => Value
+): Value {
+  return "Ok" in value ? encodeEnum(0, [encodeOk(value.Ok)]) : encodeEnum(1, [encodeErr(value.Err)]);
+}
+
+function encodeAny(value: ManifestValue): Value {
+  return { kind: "Any", value };
+}
+
+function valueKindOf(values: ManifestValue[]): string {
+  return values.length > 0 ? values[0].kind : "Tuple";
+}
+
+function toHex(bytes: Uint8Array): string {
+  return Array.from(bytes, (byte) => byte.toString(16).padStart(2, "0")).join("");
+}
+
+function fromHex(hex: string): Uint8Array {
+  if (!/^([0-9a-fA-F]{2})*$/.test(hex)) {
+    throw new Error(`Invalid hex: ${hex}`);
+  }
+  return new Uint8Array((hex.match(/../g) ?? []).map((byte) => parseInt(byte, 16)));
+}
+
+/** Writes a value as manifest text */
+export function toManifest(value: Value): string {
+  switch (value.kind) {
+    case "Bool":
+      return value.value ? "true" : "false";
+    case "Integer":
+      return `${value.value}${value.valueKind.toLowerCase()}`;
+    case "String":
+      return JSON.stringify(value.value);
+    case "Bytes":
+      return `Bytes("${toHex(value.value)}")`;
+    case "Custom":
+      return `${value.valueKind}(${JSON.stringify(value.value)})`;
+    case "Array":
+      return `Array<${value.elementKind}>(${value.elements.map(toManifest).join(", ")})`;
+    case "Map": {
+      const flattened = value.entries.map((entry) => entry.map(toManifest).join(", "));
+      return `Map<${value.keyKind}, ${value.valueKind}>(${flattened.join(", ")})`;
+    }
+    case "Tuple":
+      return `Tuple(${value.fields.map(toManifest).join(", ")})`;
+    case "Enum":
+      return `Enum(${[`${value.discriminator}u8`, ...value.fields.map(toManifest)].join(", ")})`;
+    case "Any":
+      return value.value.text;
+  }
+}
+
+const SBOR_PAYLOAD_PREFIX = 0x5c;
+
+const SBOR_VALUE_KINDS: Record<string, number> = {
+  Bool: 0x01,
+  I8: 0x02,
+  I16: 0x03,
+  I32: 0x04,
+  I64: 0x05,
+  I128: 0x06,
+  U8: 0x07,
+  U16: 0x08,
+  U32: 0x09,
+  U64: 0x0a,
+  U128: 0x0b,
+  String: 0x0c,
+  Array: 0x20,
+  Tuple: 0x21,
+  Enum: 0x22,
+  Map: 0x23,
+  PackageAddress: 0x80,
+  ComponentAddress: 0x81,
+  ResourceAddress: 0x82,
+  Own: 0x90,
+  Bucket: 0xa0,
+  Proof: 0xa1,
+  Expression: 0xa2,
+  Blob: 0xa3,
+  Hash: 0xb0,
+  EcdsaSecp256k1PublicKey: 0xb1,
+  EcdsaSecp256k1Signature: 0xb2,
+  EddsaEd25519PublicKey: 0xb3,
+  EddsaEd25519Signature: 0xb4,
+  Decimal: 0xb5,
+  PreciseDecimal: 0xb6,
+  NonFungibleLocalId: 0xb7,
+};
+
+function littleEndian(value: bigint, bits: number, signed: boolean): Uint8Array {
+  const min = signed ? -(1n << BigInt(bits - 1)) : 0n;
+  const max = (1n << BigInt(signed ? bits - 1 : bits)) - 1n;
+  if (value < min || value > max) {
+    throw new RangeError(`${value} doesn't fit into ${bits} bits`);
+  }
+  let remaining = value < 0n ? value + (1n << BigInt(bits)) : value;
+  const bytes = new Uint8Array(bits / 8);
+  for (let i = 0; i < bytes.length; i++) {
+    bytes[i] = Number(remaining & 0xffn);
+    remaining >>= 8n;
+  }
+  return bytes;
+}
+
+function decimalBytes(value: string, scale: number, bits: number): Uint8Array {
+  const match = /^(-?)(\d+)(?:\.(\d+))?$/.exec(value);
+  if (match === null || (match[3] ?? "").length > scale) {
+    throw new Error(`Invalid decimal: ${value}`);
+  }
+  const units = BigInt(match[2] + (match[3] ?? "").padEnd(scale, "0"));
+  return littleEndian(match[1] === "-" ? -units : units, bits, true);
+}
+
+const BECH32_CHARSET = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32M_CONSTANT = 0x2bc830a3;
+
+function bech32Polymod(values: number[]): number {
+  const generators = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+  let checksum = 1;
+  for (const value of values) {
+    const top = checksum >>> 25;
+    checksum = ((checksum & 0x1ffffff) << 5) ^ value;
+    generators.forEach((generator, i) => {
+      if ((top >>> i) & 1) {
+        checksum ^= generator;
+      }
+    });
+  }
+  return checksum;
+}
+
+function addressBytes(address: string): Uint8Array {
+  const lowercase = address.toLowerCase();
+  const separator = lowercase.lastIndexOf("1");
+  const hrp = lowercase.slice(0, separator);
+  const data = Array.from(lowercase.slice(separator + 1), (c) => BECH32_CHARSET.indexOf(c));
+  const hrpValues = Array.from(hrp, (c) => c.charCodeAt(0));
+  const checked = [...hrpValues.map((c) => c >> 5), 0, ...hrpValues.map((c) => c & 31), ...data];
+  if (separator < 1 || data.length < 6 || data.includes(-1) || bech32Polymod(checked) !== BECH32M_CONSTANT) {
+    throw new Error(`Invalid address: ${address}`);
+  }
+  const bytes: number[] = [];
+  let accumulator = 0;
+  let bits = 0;
+  for (const value of data.slice(0, -6)) {
+    accumulator = ((accumulator << 5) | value) & 0xfff;
+    bits += 5;
+    if (bits >= 8) {
+      bits -= 8;
+      bytes.push((accumulator >> bits) & 0xff);
+    }
+  }
+  return new Uint8Array(bytes);
+}
+
+function expressionBytes(value: string): Uint8Array {
+  const expression = ["ENTIRE_WORKTOP", "ENTIRE_AUTH_ZONE"].indexOf(value);
+  if (expression < 0) {
+    throw new Error(`Invalid expression: ${value}`);
+  }
+  return new Uint8Array([expression]);
+}
+
+/**
+ * The SBOR encoders of custom values, from their manifest text, by value kind.
+ * Buckets and proofs are identified by their numeric id. Other value kinds can't be encoded
+ * as SBOR unless an encoder is registered here.
+ */
+export const customSborEncoders: Record<string, (value: string) => Uint8Array> = {
+  PackageAddress: addressBytes,
+  ComponentAddress: addressBytes,
+  ResourceAddress: addressBytes,
+  Bucket: (value) => littleEndian(BigInt(value), 32, false),
+  Proof: (value) => littleEndian(BigInt(value), 32, false),
+  Expression: expressionBytes,
+  Blob: fromHex,
+  Hash: fromHex,
+  EcdsaSecp256k1PublicKey: fromHex,
+  EcdsaSecp256k1Signature: fromHex,
+  EddsaEd25519PublicKey: fromHex,
+  EddsaEd25519Signature: fromHex,
+  Decimal: (value) => decimalBytes(value, 18, 256),
+  PreciseDecimal: (value) => decimalBytes(value, 64, 512),
+};
+
+function sborValueKind(value: Value): number {
+  const valueKind =
+    value.kind === "Integer" || value.kind === "Custom"
+      ? value.valueKind
+      : value.kind === "Bytes"
+      ? "Array"
+      : value.kind === "Any"
+      ? value.value.kind
+      : value.kind;
+  return sborValueKindOf(valueKind);
+}
+
+function sborValueKindOf(valueKind: string): number {
+  const id = SBOR_VALUE_KINDS[valueKind];
+  if (id === undefined) {
+    throw new Error(`Unknown value kind: ${valueKind}`);
+  }
+  return id;
+}
+
+function writeBytes(out: number[], bytes: Uint8Array): void {
+  bytes.forEach((byte) => out.push(byte));
+}
+
+function writeSize(out: number[], size: number): void {
+  do {
+    out.push((size & 0x7f) | (size > 0x7f ? 0x80 : 0));
+    size >>>= 7;
+  } while (size > 0);
+}
+
+function writeSborValue(out: number[], value: Value): void {
+  out.push(sborValueKind(value));
+  writeSborBody(out, value);
+}
+
+function writeSborBody(out: number[], value: Value): void {
+  switch (value.kind) {
+    case "Bool":
+      out.push(value.value ? 1 : 0);
+      break;
+    case "Integer": {
+      const bits = Number(value.valueKind.slice(1));
+      writeBytes(out, littleEndian(BigInt(value.value), bits, value.valueKind.startsWith("I")));
+      break;
+    }
+    case "String": {
+      const utf8 = new TextEncoder().encode(value.value);
+      writeSize(out, utf8.length);
+      writeBytes(out, utf8);
+      break;
+    }
+    case "Bytes":
+      out.push(sborValueKindOf("U8"));
+      writeSize(out, value.value.length);
+      writeBytes(out, value.value);
+      break;
+    case "Custom": {
+      const encoder = customSborEncoders[value.valueKind];
+      if (encoder === undefined) {
+        throw new Error(`${value.valueKind} values can't be encoded as SBOR`);
+      }
+      writeBytes(out, encoder(value.value));
+      break;
+    }
+    case "Array":
+      out.push(sborValueKindOf(value.elementKind));
+      writeSize(out, value.elements.length);
+      value.elements.forEach((element) => writeSborBody(out, element));
+      break;
+    case "Map":
+      out.push(sborValueKindOf(value.keyKind), sborValueKindOf(value.valueKind));
+      writeSize(out, value.entries.length);
+      value.entries.forEach((entry) => entry.forEach((element) => writeSborBody(out, element)));
+      break;
+    case "Tuple":
+      writeSize(out, value.fields.length);
+      value.fields.forEach((field) => writeSborValue(out, field));
+      break;
+    case "Enum":
+      out.push(value.discriminator);
+      writeSize(out, value.fields.length);
+      value.fields.forEach((field) => writeSborValue(out, field));
+      break;
+    case "Any":
+      if (value.value.sbor === undefined) {
+        throw new Error(`The SBOR encoding of ${value.value.text} is missing`);
+      }
+      writeBytes(out, value.value.sbor);
+      break;
+  }
+}
+
+/** Encodes a value as an SBOR payload */
+export function toSbor(value: Value): Uint8Array {
+  const out = [SBOR_PAYLOAD_PREFIX];
+  writeSborValue(out, value);
+  return new Uint8Array(out);
+}
+
+function callFunction(
+  packageAddress: string,
+  blueprintName: string,
+  functionName: string,
+  args: Value[]
+): string {
+  const parts = [encodeCustom("PackageAddress", packageAddress), encodeString(blueprintName)];
+  return ["CALL_FUNCTION", ...[...parts, encodeString(functionName), ...args].map(toManifest)].join(" ") + ";";
+}
+
+function callMethod(componentAddress: string, methodName: string, args: Value[]): string {
+  const parts = [encodeCustom("ComponentAddress", componentAddress), encodeString(methodName)];
+  return ["CALL_METHOD", ...[...parts, ...args].map(toManifest)].join(" ") + ";";
+}
+"#;
+
+pub(super) fn generate(bindings: &Bindings) -> String {
+    let mut out = PRELUDE.to_owned();
+
+    for blueprint in &bindings.blueprints {
+        out.push('\n');
+        writeln!(out, "export namespace {} {{", blueprint.name).unwrap();
+        for (i, (name, named_type)) in blueprint.types.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            match named_type {
+                NamedType::Struct(BindingFields::Named(fields)) => {
+                    writeln!(out, "  export interface {} {{", name).unwrap();
+                    for (field_name, ty) in fields {
+                        writeln!(out, "    {}: {};", field_name, type_name(ty)).unwrap();
+                    }
+                    writeln!(out, "  }}").unwrap();
+                }
+                NamedType::Struct(BindingFields::Unnamed(fields)) => {
+                    writeln!(out, "  export type {} = {};", name, tuple_type(fields)).unwrap();
+                }
+                NamedType::Enum(variants) => {
+                    writeln!(out, "  export type {} =", name).unwrap();
+                    for (i, variant) in variants.iter().enumerate() {
+                        let terminator = if i == variants.len() - 1 { ";" } else { "" };
+                        let fields = match &variant.fields {
+                            fields if fields.is_empty() => String::new(),
+                            BindingFields::Named(fields) => {
+                                format!("; fields: {}", object_type(fields))
+                            }
+                            BindingFields::Unnamed(fields) => {
+                                format!("; fields: {}", tuple_type(fields))
+                            }
+                        };
+                        writeln!(
+                            out,
+                            "    | {{ variant: \"{}\"{} }}{}",
+                            variant.name, fields, terminator
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+
+            out.push('\n');
+            writeln!(
+                out,
+                "  export function encode{}(value: {}): Value {{",
+                name, name
+            )
+            .unwrap();
+            match named_type {
+                NamedType::Struct(fields) => {
+                    writeln!(
+                        out,
+                        "    return encodeTuple({});",
+                        encode_fields(blueprint, fields, "value")
+                    )
+                    .unwrap();
+                }
+                NamedType::Enum(variants) => {
+                    writeln!(out, "    switch (value.variant) {{").unwrap();
+                    for variant in variants {
+                        writeln!(out, "      case \"{}\":", variant.name).unwrap();
+                        writeln!(
+                            out,
+                            "        return encodeEnum({}, {});",
+                            variant.discriminator,
+                            encode_fields(blueprint, &variant.fields, "value.fields")
+                        )
+                        .unwrap();
+                    }
+                    writeln!(out, "    }}").unwrap();
+                }
+            }
+            writeln!(out, "  }}").unwrap();
+        }
+
+        if !blueprint.types.is_empty() {
+            out.push('\n');
+        }
+        for function in &blueprint.functions {
+            writeln!(
+                out,
+                "  export type {}_Output = {};",
+                function.ident,
+                type_name(&function.output)
+            )
+            .unwrap();
+        }
+
+        for function in &blueprint.functions {
+            let address = if function.is_method {
+                "componentAddress"
+            } else {
+                "packageAddress"
+            };
+            let params = function
+                .args
+                .iter()
+                .map(|(name, ty)| format!("{}: {}", name, type_name(ty)))
+                .collect::<Vec<_>>();
+            let args = function
+                .args
+                .iter()
+                .map(|(name, ty)| encode(blueprint, ty, name, 0))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            out.push('\n');
+            writeln!(
+                out,
+                "  export function {}({}): string {{",
+                function.ident,
+                [vec![format!("{}: string", address)], params.clone()]
+                    .concat()
+                    .join(", ")
+            )
+            .unwrap();
+            if function.is_method {
+                writeln!(
+                    out,
+                    "    return callMethod({}, \"{}\", [{}]);",
+                    address, function.ident, args
+                )
+                .unwrap();
+            } else {
+                writeln!(
+                    out,
+                    "    return callFunction({}, \"{}\", \"{}\", [{}]);",
+                    address, blueprint.name, function.ident, args
+                )
+                .unwrap();
+            }
+            writeln!(out, "  }}").unwrap();
+
+            out.push('\n');
+            writeln!(
+                out,
+                "  export function {}_args({}): Uint8Array {{",
+                function.ident,
+                params.join(", ")
+            )
+            .unwrap();
+            writeln!(out, "    return toSbor(encodeTuple([{}]));", args).unwrap();
+            writeln!(out, "  }}").unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+
+    out
+}
+
+fn type_name(ty: &BindingType) -> String {
+    match ty {
+        BindingType::Bool => "boolean".to_owned(),
+        BindingType::Integer(_) if ty.is_big_integer() => "bigint".to_owned(),
+        BindingType::Integer(_) => "number".to_owned(),
+        BindingType::String | BindingType::Custom(_) => "string".to_owned(),
+        BindingType::Bytes => "Uint8Array".to_owned(),
+        BindingType::Array(element_type) => format!("Array<{}>", type_name(element_type)),
+        BindingType::Map(key_type, value_type) => {
+            format!("Map<{}, {}>", type_name(key_type), type_name(value_type))
+        }
+        BindingType::Tuple(field_types) => tuple_type(field_types),
+        BindingType::Option(some_type) => format!("{} | null", type_name(some_type)),
+        BindingType::Result(okay_type, err_type) => {
+            format!("Result<{}, {}>", type_name(okay_type), type_name(err_type))
+        }
+        BindingType::Named(name) => name.clone(),
+        BindingType::Any => "ManifestValue".to_owned(),
+    }
+}
+
+fn tuple_type(field_types: &[BindingType]) -> String {
+    let field_types = field_types.iter().map(type_name).collect::<Vec<_>>();
+    format!("[{}]", field_types.join(", "))
+}
+
+fn object_type(fields: &[(String, BindingType)]) -> String {
+    let fields = fields
+        .iter()
+        .map(|(name, ty)| format!("{}: {}", name, type_name(ty)))
+        .collect::<Vec<_>>();
+    format!("{{ {} }}", fields.join("; "))
+}
+
+/// The fields of a struct or enum variant, encoded as an array of values.
+fn encode_fields(blueprint: &BindingBlueprint, fields: &BindingFields, value: &str) -> String {
+    let fields = match fields {
+        BindingFields::Named(fields) => fields
+            .iter()
+            .map(|(name, ty)| encode(blueprint, ty, &format!("{}.{}", value, name), 0))
+            .collect::<Vec<_>>(),
+        BindingFields::Unnamed(fields) => fields
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| encode(blueprint, ty, &format!("{}[{}]", value, i), 0))
+            .collect(),
+    };
+    format!("[{}]", fields.join(", "))
+}
+
+/// An expression which encodes `value` as a value, which can be written as manifest text or SBOR.
+/// The `depth` is used to give the parameters of nested closures distinct names.
+fn encode(blueprint: &BindingBlueprint, ty: &BindingType, value: &str, depth: usize) -> String {
+    match ty {
+        BindingType::Bool => format!("encodeBool({})", value),
+        BindingType::Integer(value_kind) => {
+            format!("encodeInteger({}, \"{}\")", value, value_kind)
+        }
+        BindingType::String => format!("encodeString({})", value),
+        BindingType::Bytes => format!("encodeBytes({})", value),
+        BindingType::Array(element_type) => {
+            let element = format!("v{}", depth);
+            let value_kind = value_kind(blueprint, element_type, value);
+            format!(
+                "encodeArray({}, {}.map(({}) => {}))",
+                value_kind,
+                value,
+                element,
+                encode(blueprint, element_type, &element, depth + 1)
+            )
+        }
+        BindingType::Map(key_type, value_type) => {
+            let key = format!("k{}", depth);
+            let element = format!("v{}", depth);
+            format!(
+                "encodeMap({}, {}, Array.from({}, ([{}, {}]) => [{}, {}]))",
+                value_kind(
+                    blueprint,
+                    key_type,
+                    &format!("Array.from({}.keys())", value)
+                ),
+                value_kind(
+                    blueprint,
+                    value_type,
+                    &format!("Array.from({}.values())", value)
+                ),
+                value,
+                key,
+                element,
+                encode(blueprint, key_type, &key, depth + 1),
+                encode(blueprint, value_type, &element, depth + 1)
+            )
+        }
+        BindingType::Tuple(field_types) => {
+            let fields = field_types
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| encode(blueprint, ty, &format!("{}[{}]", value, i), depth))
+                .collect::<Vec<_>>();
+            format!("encodeTuple([{}])", fields.join(", "))
+        }
+        BindingType::Option(some_type) => {
+            let element = format!("v{}", depth);
+            format!(
+                "encodeOption({}, ({}) => {})",
+                value,
+                element,
+                encode(blueprint, some_type, &element, depth + 1)
+            )
+        }
+        BindingType::Result(okay_type, err_type) => {
+            let element = format!("v{}", depth);
+            format!(
+                "encodeResult({}, ({}) => {}, ({}) => {})",
+                value,
+                element,
+                encode(blueprint, okay_type, &element, depth + 1),
+                element,
+                encode(blueprint, err_type, &element, depth + 1)
+            )
+        }
+        BindingType::Named(name) => format!("encode{}({})", name, value),
+        BindingType::Custom(value_kind) => {
+            format!("encodeCustom(\"{}\", {})", value_kind, value)
+        }
+        BindingType::Any => format!("encodeAny({})", value),
+    }
+}
+
+/// An expression for the value kind of the elements of a collection.
+fn value_kind(blueprint: &BindingBlueprint, ty: &BindingType, elements: &str) -> String {
+    match blueprint.value_kind(ty) {
+        Some(value_kind) => format!("\"{}\"", value_kind),
+        None => format!("valueKindOf({})", elements),
+    }
+}
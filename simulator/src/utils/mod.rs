@@ -1,8 +1,12 @@
+mod abi_compat;
+mod bindings;
 mod cargo;
 mod display;
 mod iter;
 mod prepare_instruction;
 
+pub use abi_compat::*;
+pub use bindings::*;
 pub use cargo::*;
 pub use display::list_item_prefix;
 pub use iter::{IdentifyLast, Iter};
@@ -0,0 +1,171 @@
+use radix_engine::types::*;
+use radix_engine_interface::abi::SelfMutability;
+use simulator::utils::{generate_bindings, BindingLanguage, BindingsError};
+use std::path::PathBuf;
+
+fn function(
+    ident: &str,
+    mutability: Option<SelfMutability>,
+    args: Vec<(&str, Type)>,
+    output: Type,
+) -> Fn {
+    Fn {
+        ident: ident.to_owned(),
+        mutability,
+        input: Type::Struct {
+            name: format!("Counter_{}_Input", ident),
+            fields: Fields::Named {
+                named: args
+                    .into_iter()
+                    .map(|(name, ty)| (name.to_owned(), ty))
+                    .collect(),
+            },
+        },
+        output,
+        export_name: format!("Counter_{}", ident),
+    }
+}
+
+fn counter_abi() -> BTreeMap<String, BlueprintAbi> {
+    let config = Type::Struct {
+        name: "Config".to_owned(),
+        fields: Fields::Named {
+            named: vec![
+                ("label".to_owned(), Type::String),
+                (
+                    "limit".to_owned(),
+                    Type::Option {
+                        some_type: Box::new(Type::U64),
+                    },
+                ),
+                (
+                    "tags".to_owned(),
+                    Type::Vec {
+                        element_type: Box::new(Type::String),
+                    },
+                ),
+            ],
+        },
+    };
+    let status = Type::Enum {
+        name: "Status".to_owned(),
+        variants: vec![
+            Variant {
+                name: "Pending".to_owned(),
+                fields: Fields::Unit,
+            },
+            Variant {
+                name: "Shipped".to_owned(),
+                fields: Fields::Unnamed {
+                    unnamed: vec![Type::U64],
+                },
+            },
+            Variant {
+                name: "Cancelled".to_owned(),
+                fields: Fields::Named {
+                    named: vec![("reason".to_owned(), Type::String)],
+                },
+            },
+        ],
+    };
+
+    let abi = BlueprintAbi {
+        structure: Type::Struct {
+            name: "Counter".to_owned(),
+            fields: Fields::Named {
+                named: vec![("count".to_owned(), Type::U32)],
+            },
+        },
+        fns: vec![
+            function(
+                "instantiate",
+                None,
+                vec![("start", Type::U32), ("config", config)],
+                Type::ComponentAddress,
+            ),
+            function(
+                "increment",
+                Some(SelfMutability::Mutable),
+                vec![("by", Type::Decimal), ("payment", Type::Bucket)],
+                Type::Bucket,
+            ),
+            function(
+                "set_status",
+                Some(SelfMutability::Mutable),
+                vec![
+                    ("status", status),
+                    (
+                        "data",
+                        Type::Vec {
+                            element_type: Box::new(Type::U8),
+                        },
+                    ),
+                ],
+                Type::Tuple {
+                    element_types: vec![],
+                },
+            ),
+            function(
+                "stats",
+                Some(SelfMutability::Immutable),
+                vec![],
+                Type::Tuple {
+                    element_types: vec![
+                        Type::U32,
+                        Type::HashMap {
+                            key_type: Box::new(Type::String),
+                            value_type: Box::new(Type::Decimal),
+                        },
+                    ],
+                },
+            ),
+        ],
+    };
+    BTreeMap::from([("Counter".to_owned(), abi)])
+}
+
+/// Compares the generated bindings with the checked in file.
+/// Run with `UPDATE_GOLDEN_FILES=1` to update the file instead.
+fn assert_matches_golden_file(actual: &str, file_name: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("bindings")
+        .join(file_name);
+    if std::env::var("UPDATE_GOLDEN_FILES").is_ok() {
+        std::fs::write(&path, actual).unwrap();
+    }
+    let expected = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        actual, expected,
+        "{} is out of date - rerun with UPDATE_GOLDEN_FILES=1 to update it",
+        file_name
+    );
+}
+
+#[test]
+fn test_typescript_bindings() {
+    let bindings = generate_bindings(&counter_abi(), BindingLanguage::TypeScript).unwrap();
+    assert_matches_golden_file(&bindings, "counter.ts");
+}
+
+#[test]
+fn test_python_bindings() {
+    let bindings = generate_bindings(&counter_abi(), BindingLanguage::Python).unwrap();
+    assert_matches_golden_file(&bindings, "counter.py");
+}
+
+#[test]
+fn test_function_input_must_be_a_struct() {
+    let mut abis = counter_abi();
+    abis.get_mut("Counter").unwrap().fns[0].input = Type::U32;
+
+    let result = generate_bindings(&abis, BindingLanguage::TypeScript);
+
+    assert_eq!(
+        result,
+        Err(BindingsError::InvalidFunctionInput {
+            blueprint: "Counter".to_owned(),
+            function: "instantiate".to_owned(),
+        })
+    );
+}
@@ -0,0 +1,394 @@
+# Generated by `scrypto generate-bindings`. Do not edit.
+
+from __future__ import annotations
+
+import json
+import re
+from dataclasses import dataclass, field
+from typing import Any, Callable, Dict, Generic, List, Optional, Tuple, TypeVar, Union
+
+T = TypeVar("T")
+E = TypeVar("E")
+
+
+@dataclass
+class ManifestValue:
+    """A value of any type, as manifest text along with its value kind"""
+
+    kind: str
+    text: str
+    # The SBOR encoding of the value without its value kind, needed to encode SBOR arguments
+    sbor: Optional[bytes] = None
+
+
+@dataclass
+class Ok(Generic[T]):
+    value: T
+
+
+@dataclass
+class Err(Generic[E]):
+    value: E
+
+
+@dataclass
+class Value:
+    """An encoded value, which can be written as manifest text or as SBOR
+
+    The `kind` is one of `Bool`, `Integer`, `String`, `Bytes`, `Custom`, `Array`, `Map`, `Tuple`,
+    `Enum` and `Any`. The `value_kinds` are the value kind of an integer or custom value, the
+    element kind of an array, or the key and value kinds of a map. The `children` are the
+    elements of an array, the flattened entries of a map, or the fields of a tuple or enum.
+    """
+
+    kind: str
+    value: Any = None
+    value_kinds: List[str] = field(default_factory=list)
+    children: List[Value] = field(default_factory=list)
+
+
+def encode_bool(value: bool) -> Value:
+    return Value("Bool", value)
+
+
+def encode_integer(value: int, value_kind: str) -> Value:
+    return Value("Integer", value, [value_kind])
+
+
+def encode_string(value: str) -> Value:
+    return Value("String", value)
+
+
+def encode_bytes(value: bytes) -> Value:
+    return Value("Bytes", value)
+
+
+def encode_custom(value_kind: str, value: str) -> Value:
+    return Value("Custom", value, [value_kind])
+
+
+def encode_array(element_kind: str, elements: List[Value]) -> Value:
+    return Value("Array", None, [element_kind], elements)
+
+
+def encode_map(key_kind: str, value_kind: str, entries: List[Tuple[Value, Value]]) -> Value:
+    return Value("Map", None, [key_kind, value_kind], [child for entry in entries for child in entry])
+
+
+def encode_tuple(fields: List[Value]) -> Value:
+    return Value("Tuple", None, [], fields)
+
+
+def encode_enum(discriminator: int, fields: List[Value]) -> Value:
+    return Value("Enum", discriminator, [], fields)
+
+
+def encode_option(value: Optional[T], encode_some: Callable[[T], Value]) -> Value:
+    return encode_enum(0, []) if value is None else encode_enum(1, [encode_some(value)])
+
+
+def encode_result(
+    value: Union[Ok[T], Err[E]],
+    encode_ok: Callable[[T], Value],
+    encode_err: Callable[[E], Value],
+) -> Value:
+    if isinstance(value, Ok):
+        return encode_enum(0, [encode_ok(value.value)])
+    return encode_enum(1, [encode_err(value.value)])
+
+
+def encode_any(value: ManifestValue) -> Value:
+    return Value("Any", value)
+
+
+def value_kind_of(values: List[ManifestValue]) -> str:
+    return values[0].kind if values else "Tuple"
+
+
+def to_manifest(value: Value) -> str:
+    """Writes a value as manifest text"""
+    children = [to_manifest(child) for child in value.children]
+    if value.kind == "Bool":
+        return "true" if value.value else "false"
+    if value.kind == "Integer":
+        return f"{value.value}{value.value_kinds[0].lower()}"
+    if value.kind == "String":
+        return json.dumps(value.value)
+    if value.kind == "Bytes":
+        return f'Bytes("{value.value.hex()}")'
+    if value.kind == "Custom":
+        return f"{value.value_kinds[0]}({json.dumps(value.value)})"
+    if value.kind == "Array":
+        return f"Array<{value.value_kinds[0]}>({', '.join(children)})"
+    if value.kind == "Map":
+        return f"Map<{', '.join(value.value_kinds)}>({', '.join(children)})"
+    if value.kind == "Tuple":
+        return f"Tuple({', '.join(children)})"
+    if value.kind == "Enum":
+        return f"Enum({', '.join([f'{value.value}u8', *children])})"
+    return value.value.text
+
+
+SBOR_PAYLOAD_PREFIX = 0x5C
+
+SBOR_VALUE_KINDS = {
+    "Bool": 0x01,
+    "I8": 0x02,
+    "I16": 0x03,
+    "I32": 0x04,
+    "I64": 0x05,
+    "I128": 0x06,
+    "U8": 0x07,
+    "U16": 0x08,
+    "U32": 0x09,
+    "U64": 0x0A,
+    "U128": 0x0B,
+    "String": 0x0C,
+    "Array": 0x20,
+    "Tuple": 0x21,
+    "Enum": 0x22,
+    "Map": 0x23,
+    "PackageAddress": 0x80,
+    "ComponentAddress": 0x81,
+    "ResourceAddress": 0x82,
+    "Own": 0x90,
+    "Bucket": 0xA0,
+    "Proof": 0xA1,
+    "Expression": 0xA2,
+    "Blob": 0xA3,
+    "Hash": 0xB0,
+    "EcdsaSecp256k1PublicKey": 0xB1,
+    "EcdsaSecp256k1Signature": 0xB2,
+    "EddsaEd25519PublicKey": 0xB3,
+    "EddsaEd25519Signature": 0xB4,
+    "Decimal": 0xB5,
+    "PreciseDecimal": 0xB6,
+    "NonFungibleLocalId": 0xB7,
+}
+
+
+def decimal_bytes(value: str, scale: int, size: int) -> bytes:
+    match = re.fullmatch(r"(-?)(\d+)(?:\.(\d+))?", value)
+    if match is None or len(match[3] or "") > scale:
+        raise ValueError(f"Invalid decimal: {value}")
+    units = int(match[2] + (match[3] or "").ljust(scale, "0"))
+    return (-units if match[1] else units).to_bytes(size, "little", signed=True)
+
+
+BECH32_CHARSET = "qpzry9x8gf2tvdw0s3jn54khce6mua7l"
+BECH32M_CONSTANT = 0x2BC830A3
+
+
+def bech32_polymod(values: List[int]) -> int:
+    generators = [0x3B6A57B2, 0x26508E6D, 0x1EA119FA, 0x3D4233DD, 0x2A1462B3]
+    checksum = 1
+    for value in values:
+        top = checksum >> 25
+        checksum = (checksum & 0x1FFFFFF) << 5 ^ value
+        for i, generator in enumerate(generators):
+            if (top >> i) & 1:
+                checksum ^= generator
+    return checksum
+
+
+def address_bytes(address: str) -> bytes:
+    lowercase = address.lower()
+    hrp, _, encoded = lowercase.rpartition("1")
+    data = [BECH32_CHARSET.find(c) for c in encoded]
+    checked = [ord(c) >> 5 for c in hrp] + [0] + [ord(c) & 31 for c in hrp] + data
+    if not hrp or len(data) < 6 or -1 in data or bech32_polymod(checked) != BECH32M_CONSTANT:
+        raise ValueError(f"Invalid address: {address}")
+    accumulator, bits, result = 0, 0, bytearray()
+    for value in data[:-6]:
+        accumulator = ((accumulator << 5) | value) & 0xFFF
+        bits += 5
+        if bits >= 8:
+            bits -= 8
+            result.append((accumulator >> bits) & 0xFF)
+    return bytes(result)
+
+
+def expression_bytes(value: str) -> bytes:
+    expressions = ["ENTIRE_WORKTOP", "ENTIRE_AUTH_ZONE"]
+    if value not in expressions:
+        raise ValueError(f"Invalid expression: {value}")
+    return bytes([expressions.index(value)])
+
+
+# The SBOR encoders of custom values, from their manifest text, by value kind.
+# Buckets and proofs are identified by their numeric id. Other value kinds can't be encoded
+# as SBOR unless an encoder is registered here.
+CUSTOM_SBOR_ENCODERS: Dict[str, Callable[[str], bytes]] = {
+    "PackageAddress": address_bytes,
+    "ComponentAddress": address_bytes,
+    "ResourceAddress": address_bytes,
+    "Bucket": lambda value: int(value).to_bytes(4, "little"),
+    "Proof": lambda value: int(value).to_bytes(4, "little"),
+    "Expression": expression_bytes,
+    "Blob": bytes.fromhex,
+    "Hash": bytes.fromhex,
+    "EcdsaSecp256k1PublicKey": bytes.fromhex,
+    "EcdsaSecp256k1Signature": bytes.fromhex,
+    "EddsaEd25519PublicKey": bytes.fromhex,
+    "EddsaEd25519Signature": bytes.fromhex,
+    "Decimal": lambda value: decimal_bytes(value, 18, 32),
+    "PreciseDecimal": lambda value: decimal_bytes(value, 64, 64),
+}
+
+
+def sbor_value_kind(value_kind: str) -> int:
+    if value_kind not in SBOR_VALUE_KINDS:
+        raise ValueError(f"Unknown value kind: {value_kind}")
+    return SBOR_VALUE_KINDS[value_kind]
+
+
+def write_size(out: bytearray, size: int) -> None:
+    while True:
+        out.append((size & 0x7F) | (0x80 if size > 0x7F else 0))
+        size >>= 7
+        if size == 0:
+            break
+
+
+def write_sbor_value(out: bytearray, value: Value) -> None:
+    if value.kind in ("Integer", "Custom"):
+        value_kind = value.value_kinds[0]
+    elif value.kind == "Bytes":
+        value_kind = "Array"
+    elif value.kind == "Any":
+        value_kind = value.value.kind
+    else:
+        value_kind = value.kind
+    out.append(sbor_value_kind(value_kind))
+    write_sbor_body(out, value)
+
+
+def write_sbor_body(out: bytearray, value: Value) -> None:
+    if value.kind == "Bool":
+        out.append(1 if value.value else 0)
+    elif value.kind == "Integer":
+        value_kind = value.value_kinds[0]
+        out += value.value.to_bytes(int(value_kind[1:]) // 8, "little", signed=value_kind.startswith("I"))
+    elif value.kind == "String":
+        utf8 = value.value.encode("utf-8")
+        write_size(out, len(utf8))
+        out += utf8
+    elif value.kind == "Bytes":
+        out.append(sbor_value_kind("U8"))
+        write_size(out, len(value.value))
+        out += value.value
+    elif value.kind == "Custom":
+        if value.value_kinds[0] not in CUSTOM_SBOR_ENCODERS:
+            raise ValueError(f"{value.value_kinds[0]} values can't be encoded as SBOR")
+        out += CUSTOM_SBOR_ENCODERS[value.value_kinds[0]](value.value)
+    elif value.kind in ("Array", "Map"):
+        out += bytes(sbor_value_kind(value_kind) for value_kind in value.value_kinds)
+        write_size(out, len(value.children) // len(value.value_kinds))
+        for child in value.children:
+            write_sbor_body(out, child)
+    elif value.kind in ("Tuple", "Enum"):
+        if value.kind == "Enum":
+            out.append(value.value)
+        write_size(out, len(value.children))
+        for child in value.children:
+            write_sbor_value(out, child)
+    elif value.value.sbor is None:
+        raise ValueError(f"The SBOR encoding of {value.value.text} is missing")
+    else:
+        out += value.value.sbor
+
+
+def to_sbor(value: Value) -> bytes:
+    """Encodes a value as an SBOR payload"""
+    out = bytearray([SBOR_PAYLOAD_PREFIX])
+    write_sbor_value(out, value)
+    return bytes(out)
+
+
+def call_function(package_address: str, blueprint_name: str, function_name: str, args: List[Value]) -> str:
+    parts = [encode_custom("PackageAddress", package_address), encode_string(blueprint_name)]
+    return " ".join(["CALL_FUNCTION", *map(to_manifest, [*parts, encode_string(function_name), *args])]) + ";"
+
+
+def call_method(component_address: str, method_name: str, args: List[Value]) -> str:
+    parts = [encode_custom("ComponentAddress", component_address), encode_string(method_name)]
+    return " ".join(["CALL_METHOD", *map(to_manifest, [*parts, *args])]) + ";"
+
+
+@dataclass
+class Counter_Config:
+    label: str
+    limit: Optional[int]
+    tags: List[str]
+
+
+def encode_counter_config(value: Counter_Config) -> Value:
+    return encode_tuple([encode_string(value.label), encode_option(value.limit, lambda v0: encode_integer(v0, "U64")), encode_array("String", [encode_string(v0) for v0 in value.tags])])
+
+
+@dataclass
+class Counter_StatusPending:
+    pass
+
+
+@dataclass
+class Counter_StatusShipped:
+    field_0: int
+
+
+@dataclass
+class Counter_StatusCancelled:
+    reason: str
+
+
+Counter_Status = Union[Counter_StatusPending, Counter_StatusShipped, Counter_StatusCancelled]
+
+
+def encode_counter_status(value: Counter_Status) -> Value:
+    if isinstance(value, Counter_StatusPending):
+        return encode_enum(0, [])
+    if isinstance(value, Counter_StatusShipped):
+        return encode_enum(1, [encode_integer(value.field_0, "U64")])
+    if isinstance(value, Counter_StatusCancelled):
+        return encode_enum(2, [encode_string(value.reason)])
+    raise TypeError(f"Not a Counter_Status: {value!r}")
+
+
+Counter_instantiate_Output = str
+Counter_increment_Output = str
+Counter_set_status_Output = Tuple[()]
+Counter_stats_Output = Tuple[int, Dict[str, str]]
+
+
+class Counter:
+    @staticmethod
+    def instantiate(package_address: str, start: int, config: Counter_Config) -> str:
+        return call_function(package_address, "Counter", "instantiate", [encode_integer(start, "U32"), encode_counter_config(config)])
+
+    @staticmethod
+    def instantiate_args(start: int, config: Counter_Config) -> bytes:
+        return to_sbor(encode_tuple([encode_integer(start, "U32"), encode_counter_config(config)]))
+
+    @staticmethod
+    def increment(component_address: str, by: str, payment: str) -> str:
+        return call_method(component_address, "increment", [encode_custom("Decimal", by), encode_custom("Bucket", payment)])
+
+    @staticmethod
+    def increment_args(by: str, payment: str) -> bytes:
+        return to_sbor(encode_tuple([encode_custom("Decimal", by), encode_custom("Bucket", payment)]))
+
+    @staticmethod
+    def set_status(component_address: str, status: Counter_Status, data: bytes) -> str:
+        return call_method(component_address, "set_status", [encode_counter_status(status), encode_bytes(data)])
+
+    @staticmethod
+    def set_status_args(status: Counter_Status, data: bytes) -> bytes:
+        return to_sbor(encode_tuple([encode_counter_status(status), encode_bytes(data)]))
+
+    @staticmethod
+    def stats(component_address: str) -> str:
+        return call_method(component_address, "stats", [])
+
+    @staticmethod
+    def stats_args() -> bytes:
+        return to_sbor(encode_tuple([]))
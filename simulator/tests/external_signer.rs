@@ -0,0 +1,58 @@
+use radix_engine::types::*;
+use radix_engine_interface::node::NetworkDefinition;
+use std::process::Command;
+use transaction::builder::{ManifestBuilder, TransactionBuilder};
+use transaction::model::TransactionHeader;
+use transaction::signing::{EcdsaSecp256k1PrivateKey, ExternalSigner};
+use transaction::validation::{
+    NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator, ValidationConfig,
+};
+
+fn spawn_stub_signer(private_key: &EcdsaSecp256k1PrivateKey) -> ExternalSigner {
+    ExternalSigner::spawn(
+        Command::new(env!("CARGO_BIN_EXE_stub-signer")).arg(hex::encode(private_key.to_bytes())),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_external_signer_reports_public_key() {
+    let private_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+    let signer = spawn_stub_signer(&private_key);
+
+    assert_eq!(signer.public_key(), private_key.public_key().into());
+}
+
+#[test]
+fn test_transaction_signed_and_notarized_by_external_signers_is_valid() {
+    let signer = spawn_stub_signer(&EcdsaSecp256k1PrivateKey::from_u64(1).unwrap());
+    let notary = spawn_stub_signer(&EcdsaSecp256k1PrivateKey::from_u64(2).unwrap());
+
+    let transaction = TransactionBuilder::new()
+        .header(TransactionHeader {
+            version: 1,
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 100,
+            nonce: 5,
+            notary_public_key: notary.public_key(),
+            notary_as_signatory: false,
+            cost_unit_limit: 1_000_000,
+            tip_percentage: 5,
+        })
+        .manifest(ManifestBuilder::new().clear_auth_zone().build())
+        .try_sign(&signer)
+        .unwrap()
+        .try_notarize(&notary)
+        .unwrap()
+        .build();
+
+    let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+    let executable = validator
+        .validate(&transaction, 0, &TestIntentHashManager::new())
+        .unwrap();
+    assert_eq!(
+        executable.auth_zone_params().initial_proofs,
+        vec![NonFungibleGlobalId::from_public_key(&signer.public_key())]
+    );
+}
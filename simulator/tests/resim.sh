@@ -75,6 +75,38 @@ $resim run ./target/temp3.rtm
 $resim generate-key-pair
 $resim run ./target/temp2.rtm --blobs $blobs
 
+# Test - deterministic key derivation from a mnemonic
+mnemonic="abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+derived_key=`$resim new-account --mnemonic "$mnemonic" --index 1 | awk '/Public key:/ {print $NF}'`
+if [[ ${derived_key} != "021683e443f3212daeba3c71dd33e4aed9b01e9414a8eb351033715a80f8680bdc" ]];then
+    echo "Unexpected derived key!"
+    exit 1
+fi
+
+# Test - multi-party signing with partially signed transactions
+key_pair1=`$resim generate-key-pair`
+public_key1=`echo "$key_pair1" | awk '/Public key:/ {print $NF}'`
+private_key1=`echo "$key_pair1" | awk '/Private key:/ {print $NF}'`
+key_pair2=`$resim generate-key-pair`
+public_key2=`echo "$key_pair2" | awk '/Public key:/ {print $NF}'`
+private_key2=`echo "$key_pair2" | awk '/Private key:/ {print $NF}'`
+$resim new-partial-transaction ./target/temp3.rtm --signers $public_key1,$public_key2 --output ./target/partial.tx
+cp ./target/partial.tx ./target/partial2.tx
+$resim sign-partial-transaction ./target/partial.tx --signing-keys $private_key1
+$resim sign-partial-transaction ./target/partial2.tx --external-signer "cargo run -q --bin stub-signer -- $private_key2"
+$resim merge-partial-transactions ./target/partial.tx ./target/partial2.tx --output ./target/merged.tx
+$resim show-partial-transaction ./target/merged.tx
+$resim notarize-partial-transaction ./target/merged.tx --output ./target/notarized.tx
+
+# Test - derive virtual addresses offline
+$resim derive-address --public-key $public_key1,$public_key2
+$resim derive-address --public-key $public_key1 --kind identity --network mainnet
+vanity_address=`$resim derive-address --vanity q | awk '/Address:/ {print $NF}'`
+if [[ ${vanity_address} != account_sim1??q* ]];then
+    echo "Unexpected vanity address!"
+    exit 1
+fi
+
 # Test - nft
 package=`$resim publish ./tests/blueprints --owner-badge $owner_badge | awk '/Package:/ {print $NF}'`
 $resim call-function $package Foo nfts
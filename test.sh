@@ -22,6 +22,11 @@ test_crates_features \
     radix-engine \
     transaction"
 
+echo "Testing crates with serde..."
+test_crates_features \
+    "radix-engine-interface" \
+    "--features serde"
+
 echo "Testing scrypto packages..."
 test_packages \
     "assets/blueprints/account \
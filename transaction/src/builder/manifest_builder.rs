@@ -511,7 +511,7 @@ impl ManifestBuilder {
         &mut self,
         entity_address: GlobalAddress,
         key: String,
-        value: String,
+        value: MetadataEntry,
     ) -> &mut Self {
         self.add_instruction(BasicInstruction::SetMetadata {
             entity_address,
@@ -521,6 +521,22 @@ impl ManifestBuilder {
         .0
     }
 
+    pub fn remove_metadata(&mut self, entity_address: GlobalAddress, key: String) -> &mut Self {
+        self.add_instruction(BasicInstruction::RemoveMetadata {
+            entity_address,
+            key,
+        })
+        .0
+    }
+
+    pub fn lock_metadata(&mut self, entity_address: GlobalAddress, key: String) -> &mut Self {
+        self.add_instruction(BasicInstruction::LockMetadata {
+            entity_address,
+            key,
+        })
+        .0
+    }
+
     /// Publishes a package.
     pub fn publish_package(
         &mut self,
@@ -709,6 +725,30 @@ impl ManifestBuilder {
         self
     }
 
+    pub fn freeze_vault(
+        &mut self,
+        resource_address: ResourceAddress,
+        vault_id: VaultId,
+    ) -> &mut Self {
+        self.add_instruction(BasicInstruction::FreezeVault {
+            resource_address,
+            vault_id,
+        });
+        self
+    }
+
+    pub fn unfreeze_vault(
+        &mut self,
+        resource_address: ResourceAddress,
+        vault_id: VaultId,
+    ) -> &mut Self {
+        self.add_instruction(BasicInstruction::UnfreezeVault {
+            resource_address,
+            vault_id,
+        });
+        self
+    }
+
     pub fn burn_non_fungible(&mut self, non_fungible_global_id: NonFungibleGlobalId) -> &mut Self {
         let mut ids = BTreeSet::new();
         ids.insert(non_fungible_global_id.local_id().clone());
@@ -1,12 +1,16 @@
 use radix_engine_interface::crypto::{Signature, SignatureWithPublicKey};
 use radix_engine_interface::data::scrypto_encode;
+use radix_engine_interface::model::ComponentAddress;
 
-use crate::{model::*, signing::Signer};
+use crate::model::*;
+use crate::signing::{Signer, TrySigner};
 
 pub struct TransactionBuilder {
     manifest: Option<TransactionManifest>,
     header: Option<TransactionHeader>,
+    message: Option<TransactionMessage>,
     intent_signatures: Vec<SignatureWithPublicKey>,
+    fee_payer: Option<TransactionFeePayer>,
     notary_signature: Option<Signature>,
 }
 
@@ -15,7 +19,9 @@ impl TransactionBuilder {
         Self {
             manifest: None,
             header: None,
+            message: None,
             intent_signatures: vec![],
+            fee_payer: None,
             notary_signature: None,
         }
     }
@@ -30,6 +36,11 @@ impl TransactionBuilder {
         self
     }
 
+    pub fn message(mut self, message: TransactionMessage) -> Self {
+        self.message = Some(message);
+        self
+    }
+
     pub fn sign<S: Signer>(mut self, signer: &S) -> Self {
         let intent = self.transaction_intent();
         let intent_payload = scrypto_encode(&intent).unwrap();
@@ -37,11 +48,45 @@ impl TransactionBuilder {
         self
     }
 
+    /// Signs the intent with a signer which may fail, returning its error if it does.
+    pub fn try_sign<S: TrySigner>(mut self, signer: &S) -> Result<Self, S::Error> {
+        let intent = self.transaction_intent();
+        let intent_payload = scrypto_encode(&intent).unwrap();
+        self.intent_signatures
+            .push(signer.try_sign(&intent_payload)?);
+        Ok(self)
+    }
+
     pub fn signer_signatures(mut self, sigs: Vec<SignatureWithPublicKey>) -> Self {
         self.intent_signatures.extend(sigs);
         self
     }
 
+    pub fn fee_payer<S: Signer>(mut self, account: ComponentAddress, signer: &S) -> Self {
+        let intent = self.transaction_intent();
+        let fee_payer_payload = TransactionFeePayer::signed_payload(&intent, account).unwrap();
+        self.fee_payer = Some(TransactionFeePayer {
+            account,
+            signature: signer.sign(&fee_payer_payload),
+        });
+        self
+    }
+
+    /// Sets the fee payer with a signer which may fail, returning its error if it does.
+    pub fn try_fee_payer<S: TrySigner>(
+        mut self,
+        account: ComponentAddress,
+        signer: &S,
+    ) -> Result<Self, S::Error> {
+        let intent = self.transaction_intent();
+        let fee_payer_payload = TransactionFeePayer::signed_payload(&intent, account).unwrap();
+        self.fee_payer = Some(TransactionFeePayer {
+            account,
+            signature: signer.try_sign(&fee_payer_payload)?,
+        });
+        Ok(self)
+    }
+
     pub fn notarize<S: Signer>(mut self, signer: &S) -> Self {
         let signed_intent = self.signed_transaction_intent();
         let signed_intent_payload = scrypto_encode(&signed_intent).unwrap();
@@ -49,6 +94,14 @@ impl TransactionBuilder {
         self
     }
 
+    /// Notarizes with a signer which may fail, returning its error if it does.
+    pub fn try_notarize<S: TrySigner>(mut self, signer: &S) -> Result<Self, S::Error> {
+        let signed_intent = self.signed_transaction_intent();
+        let signed_intent_payload = scrypto_encode(&signed_intent).unwrap();
+        self.notary_signature = Some(signer.try_sign(&signed_intent_payload)?.signature());
+        Ok(self)
+    }
+
     pub fn notary_signature(mut self, signature: Signature) -> Self {
         self.notary_signature = Some(signature);
         self
@@ -65,6 +118,7 @@ impl TransactionBuilder {
         TransactionIntent {
             manifest: self.manifest.clone().expect("Manifest not specified"),
             header: self.header.clone().expect("Header not specified"),
+            message: self.message.clone(),
         }
     }
 
@@ -73,6 +127,7 @@ impl TransactionBuilder {
         SignedTransactionIntent {
             intent,
             intent_signatures: self.intent_signatures.clone(),
+            fee_payer: self.fee_payer.clone(),
         }
     }
 }
@@ -1,4 +1,7 @@
+use radix_engine_interface::crypto::PublicKey;
 use radix_engine_interface::data::types::*;
+use sbor::rust::string::String;
+use sbor::rust::vec::Vec;
 use sbor::*;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -11,11 +14,24 @@ pub enum HeaderValidationError {
     InvalidTipBps,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageValidationError {
+    MessageTooLarge,
+    MimeTypeTooLong,
+    NoDecryptors,
+    TooManyDecryptors,
+    DuplicateDecryptor,
+    MismatchingDecryptorCurves,
+    WrappedKeyTooLong,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SignatureValidationError {
     TooManySignatures,
     InvalidIntentSignature,
     InvalidNotarySignature,
+    InvalidFeePayerSignature,
+    FeePayerIsIntentSigner,
     DuplicateSigner,
     SerializationError(EncodeError),
 }
@@ -26,6 +42,36 @@ impl From<EncodeError> for SignatureValidationError {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartialTransactionError {
+    InvalidSignature,
+    UnexpectedSigner(PublicKey),
+    DuplicateSigner(PublicKey),
+    MissingSignatures(Vec<PublicKey>),
+    UnexpectedNotary(PublicKey),
+    MismatchingIntent,
+    MismatchingRequiredSigners,
+    MismatchingFeePayer,
+    FeePayerIsSigner(PublicKey),
+    SignerError(String),
+    SerializationError(EncodeError),
+}
+
+impl From<EncodeError> for PartialTransactionError {
+    fn from(err: EncodeError) -> Self {
+        Self::SerializationError(err)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyDerivationError {
+    InvalidMnemonic,
+    InvalidEntropy,
+    InvalidDerivationPath,
+    NonHardenedDerivation,
+    InvalidChildKey,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Categorize)]
 pub enum ManifestIdAllocationError {
     OutOfID,
@@ -53,6 +99,7 @@ pub enum TransactionValidationError {
     DeserializationError(DecodeError),
     IntentHashRejected,
     HeaderValidationError(HeaderValidationError),
+    MessageValidationError(MessageValidationError),
     SignatureValidationError(SignatureValidationError),
     IdValidationError(ManifestIdValidationError),
     CallDataValidationError(CallDataValidationError),
@@ -117,12 +117,32 @@ pub enum Instruction {
         amount: Value,
     },
 
+    FreezeVault {
+        resource_address: Value,
+        vault_id: Value,
+    },
+
+    UnfreezeVault {
+        resource_address: Value,
+        vault_id: Value,
+    },
+
     SetMetadata {
         entity_address: Value,
         key: Value,
         value: Value,
     },
 
+    RemoveMetadata {
+        entity_address: Value,
+        key: Value,
+    },
+
+    LockMetadata {
+        entity_address: Value,
+        key: Value,
+    },
+
     SetPackageRoyaltyConfig {
         package_address: Value,
         royalty_config: Value,
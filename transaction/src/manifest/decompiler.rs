@@ -2,7 +2,7 @@ use radix_engine_interface::address::{AddressError, Bech32Encoder};
 use radix_engine_interface::api::types::GlobalAddress;
 use radix_engine_interface::data::types::{ManifestBucket, ManifestProof};
 use radix_engine_interface::data::*;
-use radix_engine_interface::model::NonFungibleLocalId;
+use radix_engine_interface::model::{MetadataEntry, MetadataValue, NonFungibleLocalId};
 use radix_engine_interface::node::NetworkDefinition;
 use sbor::rust::collections::*;
 use sbor::rust::fmt;
@@ -393,6 +393,24 @@ pub fn decompile_instruction<F: fmt::Write>(
             format_typed_value(f, context, amount)?;
             f.write_str(";")?;
         }
+        BasicInstruction::FreezeVault {
+            resource_address,
+            vault_id,
+        } => {
+            f.write_str("FREEZE_VAULT")?;
+            format_typed_value(f, context, resource_address)?;
+            format_typed_value(f, context, vault_id)?;
+            f.write_str(";")?;
+        }
+        BasicInstruction::UnfreezeVault {
+            resource_address,
+            vault_id,
+        } => {
+            f.write_str("UNFREEZE_VAULT")?;
+            format_typed_value(f, context, resource_address)?;
+            format_typed_value(f, context, vault_id)?;
+            f.write_str(";")?;
+        }
         BasicInstruction::SetMetadata {
             entity_address,
             key,
@@ -401,7 +419,30 @@ pub fn decompile_instruction<F: fmt::Write>(
             f.write_str("SET_METADATA")?;
             format_entity_address(f, context, entity_address)?;
             format_typed_value(f, context, key)?;
-            format_typed_value(f, context, value)?;
+            match value {
+                MetadataEntry::Value(MetadataValue::String(value)) => {
+                    format_typed_value(f, context, value)?
+                }
+                value => format_typed_value(f, context, value)?,
+            }
+            f.write_str(";")?;
+        }
+        BasicInstruction::RemoveMetadata {
+            entity_address,
+            key,
+        } => {
+            f.write_str("REMOVE_METADATA")?;
+            format_entity_address(f, context, entity_address)?;
+            format_typed_value(f, context, key)?;
+            f.write_str(";")?;
+        }
+        BasicInstruction::LockMetadata {
+            entity_address,
+            key,
+        } => {
+            f.write_str("LOCK_METADATA")?;
+            format_entity_address(f, context, entity_address)?;
+            format_typed_value(f, context, key)?;
             f.write_str(";")?;
         }
         BasicInstruction::SetPackageRoyaltyConfig {
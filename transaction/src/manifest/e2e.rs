@@ -130,6 +130,23 @@ RECALL_RESOURCE
         );
     }
 
+    #[test]
+    fn test_resource_freeze() {
+        compile_and_decompile_with_inversion_test(
+            include_str!("../../examples/resources/freeze.rtm"),
+            &NetworkDefinition::simulator(),
+            vec![],
+            r##"
+FREEZE_VAULT
+    ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag")
+    Bytes("49cd9235ba62b2c217e32e5b4754c08219ef16389761356eaccbf6f6bdbfa44d00000000");
+UNFREEZE_VAULT
+    ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag")
+    Bytes("49cd9235ba62b2c217e32e5b4754c08219ef16389761356eaccbf6f6bdbfa44d00000000");
+"##,
+        );
+    }
+
     #[test]
     fn test_invocation() {
         compile_and_decompile_with_inversion_test(
@@ -264,6 +281,10 @@ SET_METADATA ComponentAddress("component_sim1qg2jwzl3hxnkqye8tfj5v3p2wp7cv9xdcjv
 SET_METADATA ResourceAddress("resource_sim1qq8cays25704xdyap2vhgmshkkfyr023uxdtk59ddd4qs8cr5v")
     "k"
     "v";
+REMOVE_METADATA ResourceAddress("resource_sim1qq8cays25704xdyap2vhgmshkkfyr023uxdtk59ddd4qs8cr5v")
+    "k";
+LOCK_METADATA ResourceAddress("resource_sim1qq8cays25704xdyap2vhgmshkkfyr023uxdtk59ddd4qs8cr5v")
+    "k";
 "##,
         );
     }
@@ -122,6 +122,8 @@ lazy_static! {
             enum MetadataFn {
                 Set = 0;
                 Get = 1;
+                Remove = 2;
+                Lock = 3;
             }
         );
 
@@ -135,6 +137,9 @@ lazy_static! {
                 Withdraw = 4;
                 Deposit = 5;
                 Recall = 6;
+                Freeze = 7;
+                UpdateMaxSupply = 8;
+                UpdateMintLimitPerEpoch = 9;
             }
         );
 
@@ -407,6 +407,20 @@ pub fn generate_instruction(
             vault_id: generate_typed_value(vault_id, resolver, bech32_decoder, blobs)?,
             amount: generate_decimal(amount)?,
         },
+        ast::Instruction::FreezeVault {
+            resource_address,
+            vault_id,
+        } => BasicInstruction::FreezeVault {
+            resource_address: generate_resource_address(resource_address, bech32_decoder)?,
+            vault_id: generate_typed_value(vault_id, resolver, bech32_decoder, blobs)?,
+        },
+        ast::Instruction::UnfreezeVault {
+            resource_address,
+            vault_id,
+        } => BasicInstruction::UnfreezeVault {
+            resource_address: generate_resource_address(resource_address, bech32_decoder)?,
+            vault_id: generate_typed_value(vault_id, resolver, bech32_decoder, blobs)?,
+        },
         ast::Instruction::SetMetadata {
             entity_address,
             key,
@@ -414,7 +428,21 @@ pub fn generate_instruction(
         } => BasicInstruction::SetMetadata {
             entity_address: generate_entity_address(entity_address, bech32_decoder)?,
             key: generate_string(key)?,
-            value: generate_string(value)?,
+            value: generate_metadata_entry(value, resolver, bech32_decoder, blobs)?,
+        },
+        ast::Instruction::RemoveMetadata {
+            entity_address,
+            key,
+        } => BasicInstruction::RemoveMetadata {
+            entity_address: generate_entity_address(entity_address, bech32_decoder)?,
+            key: generate_string(key)?,
+        },
+        ast::Instruction::LockMetadata {
+            entity_address,
+            key,
+        } => BasicInstruction::LockMetadata {
+            entity_address: generate_entity_address(entity_address, bech32_decoder)?,
+            key: generate_string(key)?,
         },
         ast::Instruction::SetPackageRoyaltyConfig {
             package_address,
@@ -607,6 +635,19 @@ fn generate_typed_value<T: ScryptoDecode>(
     Ok(decoded)
 }
 
+/// A string metadata value may be written as a plain string, any other entry as its enum value.
+fn generate_metadata_entry(
+    value: &ast::Value,
+    resolver: &mut NameResolver,
+    bech32_decoder: &Bech32Decoder,
+    blobs: &BTreeMap<Hash, Vec<u8>>,
+) -> Result<MetadataEntry, GeneratorError> {
+    match value {
+        ast::Value::String(s) => Ok(MetadataEntry::Value(MetadataValue::String(s.clone()))),
+        v => generate_typed_value(v, resolver, bech32_decoder, blobs),
+    }
+}
+
 fn generate_args(
     values: &Vec<ast::Value>,
     resolver: &mut NameResolver,
@@ -116,7 +116,11 @@ pub enum TokenKind {
     PublishPackageWithOwner,
     BurnResource,
     RecallResource,
+    FreezeVault,
+    UnfreezeVault,
     SetMetadata,
+    RemoveMetadata,
+    LockMetadata,
     SetPackageRoyaltyConfig,
     SetComponentRoyaltyConfig,
     ClaimPackageRoyalty,
@@ -459,7 +463,11 @@ impl Lexer {
             "PUBLISH_PACKAGE_WITH_OWNER" => Ok(TokenKind::PublishPackageWithOwner),
             "BURN_RESOURCE" => Ok(TokenKind::BurnResource),
             "RECALL_RESOURCE" => Ok(TokenKind::RecallResource),
+            "FREEZE_VAULT" => Ok(TokenKind::FreezeVault),
+            "UNFREEZE_VAULT" => Ok(TokenKind::UnfreezeVault),
             "SET_METADATA" => Ok(TokenKind::SetMetadata),
+            "REMOVE_METADATA" => Ok(TokenKind::RemoveMetadata),
+            "LOCK_METADATA" => Ok(TokenKind::LockMetadata),
             "SET_PACKAGE_ROYALTY_CONFIG" => Ok(TokenKind::SetPackageRoyaltyConfig),
             "SET_COMPONENT_ROYALTY_CONFIG" => Ok(TokenKind::SetComponentRoyaltyConfig),
             "CLAIM_PACKAGE_ROYALTY" => Ok(TokenKind::ClaimPackageRoyalty),
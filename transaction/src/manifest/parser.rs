@@ -180,11 +180,27 @@ impl Parser {
                 vault_id: self.parse_value()?,
                 amount: self.parse_value()?,
             },
+            TokenKind::FreezeVault => Instruction::FreezeVault {
+                resource_address: self.parse_value()?,
+                vault_id: self.parse_value()?,
+            },
+            TokenKind::UnfreezeVault => Instruction::UnfreezeVault {
+                resource_address: self.parse_value()?,
+                vault_id: self.parse_value()?,
+            },
             TokenKind::SetMetadata => Instruction::SetMetadata {
                 entity_address: self.parse_value()?,
                 key: self.parse_value()?,
                 value: self.parse_value()?,
             },
+            TokenKind::RemoveMetadata => Instruction::RemoveMetadata {
+                entity_address: self.parse_value()?,
+                key: self.parse_value()?,
+            },
+            TokenKind::LockMetadata => Instruction::LockMetadata {
+                entity_address: self.parse_value()?,
+                key: self.parse_value()?,
+            },
             TokenKind::SetPackageRoyaltyConfig => Instruction::SetPackageRoyaltyConfig {
                 package_address: self.parse_value()?,
                 royalty_config: self.parse_value()?,
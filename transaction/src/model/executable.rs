@@ -4,7 +4,6 @@ use radix_engine_interface::model::*;
 use radix_engine_interface::*;
 use sbor::rust::collections::BTreeSet;
 use sbor::rust::vec::Vec;
-use sbor::{Categorize, Decode, Encode};
 
 use crate::model::*;
 
@@ -18,18 +17,29 @@ pub struct AuthZoneParams {
 pub struct ExecutionContext {
     pub transaction_hash: Hash,
     pub pre_allocated_ids: BTreeSet<RENodeId>,
+    /// The size of the transaction payload, including the message
     pub payload_size: usize,
+    /// The size of the message, which is charged for on top of the payload
+    pub message_size: usize,
     pub auth_zone_params: AuthZoneParams,
     pub fee_payment: FeePayment,
     pub runtime_validations: Vec<RuntimeValidationRequest>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Categorize, Encode, Decode)]
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub enum FeePayment {
     User {
         cost_unit_limit: u32,
         tip_percentage: u16,
     },
+    /// The fee is locked from the `fee_payer` account before the manifest is executed, with
+    /// only the `fee_payer_proofs` in the auth zone.
+    Sponsored {
+        cost_unit_limit: u32,
+        tip_percentage: u16,
+        fee_payer: ComponentAddress,
+        fee_payer_proofs: Vec<NonFungibleGlobalId>,
+    },
     NoFee,
 }
 
@@ -132,6 +142,10 @@ impl<'a> Executable<'a> {
         self.context.payload_size
     }
 
+    pub fn message_size(&self) -> usize {
+        self.context.message_size
+    }
+
     pub fn runtime_validations(&self) -> &[RuntimeValidationRequest] {
         &self.context.runtime_validations
     }
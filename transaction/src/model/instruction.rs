@@ -120,10 +120,30 @@ pub enum BasicInstruction {
         amount: Decimal,
     },
 
+    FreezeVault {
+        resource_address: ResourceAddress,
+        vault_id: VaultId,
+    },
+
+    UnfreezeVault {
+        resource_address: ResourceAddress,
+        vault_id: VaultId,
+    },
+
     SetMetadata {
         entity_address: GlobalAddress,
         key: String,
-        value: String,
+        value: MetadataEntry,
+    },
+
+    RemoveMetadata {
+        entity_address: GlobalAddress,
+        key: String,
+    },
+
+    LockMetadata {
+        entity_address: GlobalAddress,
+        key: String,
     },
 
     SetPackageRoyaltyConfig {
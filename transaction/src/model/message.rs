@@ -0,0 +1,71 @@
+use radix_engine_interface::crypto::PublicKey;
+use radix_engine_interface::data::scrypto_encode;
+use radix_engine_interface::*;
+use sbor::rust::string::String;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+/// A message attached to a transaction intent, e.g. a memo for a deposit.
+///
+/// Messages are not interpreted by the engine, but are paid for by the byte.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub enum TransactionMessage {
+    Plaintext(PlaintextMessage),
+    Encrypted(EncryptedMessage),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct PlaintextMessage {
+    pub mime_type: String,
+    pub message: MessageContent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub enum MessageContent {
+    String(String),
+    Bytes(Vec<u8>),
+}
+
+/// A message encrypted with a symmetric key, which is in turn wrapped once for each recipient.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct EncryptedMessage {
+    pub encrypted: Vec<u8>,
+    pub decryptors: Vec<MessageDecryptor>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct MessageDecryptor {
+    /// The public key of the recipient
+    pub recipient: PublicKey,
+    /// The ephemeral public key used for the Diffie-Hellman key exchange with the recipient;
+    /// it must be on the same curve as the recipient's key
+    pub ephemeral_public_key: PublicKey,
+    /// The symmetric key, wrapped with the shared secret
+    pub wrapped_key: Vec<u8>,
+}
+
+impl TransactionMessage {
+    pub fn plaintext(mime_type: &str, message: &str) -> Self {
+        Self::Plaintext(PlaintextMessage {
+            mime_type: mime_type.into(),
+            message: MessageContent::String(message.into()),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        scrypto_encode(self)
+    }
+}
+
+impl MessageContent {
+    pub fn len(&self) -> usize {
+        match self {
+            MessageContent::String(string) => string.len(),
+            MessageContent::Bytes(bytes) => bytes.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
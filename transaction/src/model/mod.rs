@@ -1,7 +1,9 @@
 mod executable;
 mod instruction;
 mod manifest;
+mod message;
 mod notarized_transaction;
+mod partially_signed_transaction;
 mod preview_transaction;
 mod system_transaction;
 mod test_transaction;
@@ -9,7 +11,9 @@ mod test_transaction;
 pub use executable::*;
 pub use instruction::*;
 pub use manifest::*;
+pub use message::*;
 pub use notarized_transaction::*;
+pub use partially_signed_transaction::*;
 pub use preview_transaction::*;
 pub use system_transaction::*;
 pub use test_transaction::*;
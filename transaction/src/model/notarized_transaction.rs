@@ -1,11 +1,12 @@
 use radix_engine_interface::crypto::{hash, Hash, PublicKey, Signature, SignatureWithPublicKey};
 use radix_engine_interface::data::{scrypto_decode, scrypto_encode};
+use radix_engine_interface::model::ComponentAddress;
 use radix_engine_interface::node::NetworkDefinition;
 use radix_engine_interface::*;
 use sbor::*;
 
 use crate::manifest::{compile, CompileError};
-use crate::model::TransactionManifest;
+use crate::model::{TransactionManifest, TransactionMessage};
 
 // TODO: add versioning of transaction schema
 
@@ -27,12 +28,28 @@ pub struct TransactionHeader {
 pub struct TransactionIntent {
     pub header: TransactionHeader,
     pub manifest: TransactionManifest,
+    pub message: Option<TransactionMessage>,
+}
+
+/// The domain separator of the payload signed by a fee payer.
+pub const FEE_PAYER_SIGNATURE_PREFIX: &[u8] = b"TransactionFeePayer";
+
+/// A sponsor paying the fee of a transaction on behalf of its signers.
+///
+/// The fee payer signs the intent and its own account (see [`TransactionFeePayer::signed_payload`]),
+/// and the fee is locked from `account` before the manifest
+/// is executed, under an auth zone which holds the fee payer's signature proof only.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct TransactionFeePayer {
+    pub account: ComponentAddress,
+    pub signature: SignatureWithPublicKey,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
 pub struct SignedTransactionIntent {
     pub intent: TransactionIntent,
     pub intent_signatures: Vec<SignatureWithPublicKey>,
+    pub fee_payer: Option<TransactionFeePayer>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
@@ -76,6 +93,7 @@ impl TransactionIntent {
         Ok(Self {
             header,
             manifest: compile(manifest, &network, blobs)?,
+            message: None,
         })
     }
 
@@ -92,6 +110,23 @@ impl TransactionIntent {
     }
 }
 
+impl TransactionFeePayer {
+    /// The payload signed by a fee payer: a distinct prefix followed by the hash of the intent
+    /// and the fee payer account.
+    ///
+    /// This differs from the payload signed by the intent signers, so that a fee payer signature
+    /// can't be replayed as an intent signature, which would give the manifest the fee payer's
+    /// signature proof.
+    pub fn signed_payload(
+        intent: &TransactionIntent,
+        account: ComponentAddress,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let mut payload = FEE_PAYER_SIGNATURE_PREFIX.to_vec();
+        payload.extend(hash(scrypto_encode(&(intent.clone(), account))?).to_vec());
+        Ok(payload)
+    }
+}
+
 impl SignedTransactionIntent {
     pub fn from_slice(slice: &[u8]) -> Result<Self, DecodeError> {
         scrypto_decode(slice)
@@ -157,6 +192,7 @@ mod tests {
         let signed_intent = SignedTransactionIntent {
             intent,
             intent_signatures: vec![signature1.into(), signature2.into()],
+            fee_payer: None,
         };
 
         // notarize
@@ -167,18 +203,18 @@ mod tests {
         };
 
         assert_eq!(
-            "dc5ecacf6a3ceb4fef2e58deaa030d64edbfe6028eaf619f19fb411fc6223eba",
+            "0d94de2fa4762271d046a875a01f68286be27727e6c479a8ca64b515d9d1174b",
             transaction.signed_intent.intent.hash().unwrap().to_string()
         );
         assert_eq!(
-            "279fb956a9143c0591424482c1bdfcc36c442485bdc8290c9f9f01ce9a15f99f",
+            "1c7370412236b2ca3068e8dedd736386c4a06818253d93f41cc061980d6cd254",
             transaction.signed_intent.hash().unwrap().to_string()
         );
         assert_eq!(
-            "d447fd70de1e7727067b6282ed11d2cc215e08ab495f5a017d2f4cc8628a9ebf",
+            "882d6d50d5ddb9fbafeebf7344ccd49be57c55998b841fde316947123ccc151c",
             transaction.hash().unwrap().to_string()
         );
-        assert_eq!("5c2102210221022109070107f20a00000000000000000a64000000000000000a0500000000000000220001b102f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f901000940420f00080500210220220109002020002022020001b20093ce440fadbd89f53ec11e8f33a9f52073a4fb1447e4ca2fae5cc6951a8950a62d7cb46ee8b398796b630db258da0bf0bdb8a7d7dfad483e14ebf0eb2f6870da0001b201f6ab8f5364c763ad822790649738ee7ec9c69141bff73887e2124ffcd836c8ce703269f29484d8e7d2fbbd89c5f7960490b264de0ae1f3acdce3cc4dc4db9d6c220001b200b4fafc970acef2d0d63df34abac36e2f26e212d939c2d0aeeb14493b4e54ef0e27902de7e88e1864a56ce7f0cdcb797f1919d9e4f2c7c6262ef4958c2a777c08", hex::encode(scrypto_encode(&transaction).unwrap()));
+        assert_eq!("5c2102210321032109070107f20a00000000000000000a64000000000000000a0500000000000000220001b102f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f901000940420f00080500210220220109002020002200002022020001b2006a51bb95f85174f9bf7ffa279234d2df74ee3171309d13776d425ea1bb58457e0d3c66f19e86be1e48b8c100304b7e28cf8f415c49cfdb3dbc50e8b0110c66e20001b200cef067716812302d3b516aef64534131798e3266ab52e9c27e74ca5d1064cd5c6a58fac2e496eac59ce823f95628a2b4395e08f26426259ee8a407a3ae77ebd8220000220001b20169b55b0de85d3c839fff501393fae813e3355face4c8ff1d2e9b4251f370203e119ff9afe4f732e93867cd9bd241efc4026b250a19f35539061f304673067b93", hex::encode(scrypto_encode(&transaction).unwrap()));
     }
 
     #[test]
@@ -213,6 +249,7 @@ mod tests {
         let signed_intent = SignedTransactionIntent {
             intent,
             intent_signatures: vec![signature1.into(), signature2.into()],
+            fee_payer: None,
         };
 
         // notarize
@@ -223,17 +260,17 @@ mod tests {
         };
 
         assert_eq!(
-            "ba55b1c9725753da65d708e7f88e894accec5e57da1c17c889b421cd49898abd",
+            "a5857973775063131e9cf862013b30b2404ba6e64ac89150df9b777fb1b19ded",
             transaction.signed_intent.intent.hash().unwrap().to_string()
         );
         assert_eq!(
-            "dca89a7072a349b7d222b57852ccdd369eedf87997b9e79aee22d5ce7d11c02d",
+            "b45f1b74fc44f0916868a6a6e299bf450ad09a93a2a76b491a8fddf6f3cf6486",
             transaction.signed_intent.hash().unwrap().to_string()
         );
         assert_eq!(
-            "6973345b165c9efb8f926b6decff306cd641d044518fdb15979cba801292a21d",
+            "da3a3093336226727d0b6b80124c7e1859dba86518d25bcd75327976c30a450d",
             transaction.hash().unwrap().to_string()
         );
-        assert_eq!("5c2102210221022109070107f20a00000000000000000a64000000000000000a0500000000000000220101b3f381626e41e7027ea431bfe3009e94bdd25a746beec468948d6c3c7c5dc9a54b01000940420f00080500210220220109002020002022020102b34cb5abf6ad79fbf5abbccafcc269d85cd2651ed4b885b5869f241aedf0a5ba29b4e756712638dc7deeabdee71bddc156f84cb69b24ed2bac7a0806a25a9831c1d63e26dfdb402313a07c9f3f1c4d2862dfb97b968f1dfbacc532eb0ce65ba66d090102b37422b9887598068e32c4448a949adb290d0f4e35b9e01b0ee5f1a1e600fe2674b4400bf5f21b9427bd6d379ee9200804066cf219044ac7f2cb9c1e22dccb122befee9e513a63f6f56ca120d91c04a00d7f250d80afcaaf089b942e4e631ed8d804220101b48b0b9f9ebe27b6a16158a91413488b3c9718cd0280d505e79efed54fc0edd7dfc2e31a4707925a642dd8d00b61c46cda670c16e8750bab5755cfa9f0f4092e0b", hex::encode(scrypto_encode(&transaction).unwrap()));
+        assert_eq!("5c2102210321032109070107f20a00000000000000000a64000000000000000a0500000000000000220101b3f381626e41e7027ea431bfe3009e94bdd25a746beec468948d6c3c7c5dc9a54b01000940420f00080500210220220109002020002200002022020102b34cb5abf6ad79fbf5abbccafcc269d85cd2651ed4b885b5869f241aedf0a5ba29b4b66c4994ee8856fc79f193fee568a88b2ba95bb41941e6010bb6930021687c44533546f9c71124acdf29b5bf1072cc666b49d49b68784d096b083b8773b4d9080102b37422b9887598068e32c4448a949adb290d0f4e35b9e01b0ee5f1a1e600fe2674b4255f368eed74f6ebb9abca349a6f55e0c710a07cb934205708a62b9b232e26ca066cda18be3c4f8032f95fa3c32c885fabbae521ff4e76575dafbed233e4d80d220000220101b49966da9ecbdfddbeb107dd0d2fde6d8304b6595d1505a84bf9ad65a5ff733380daf2e61f455ff7f5002e178e46dfdfc1dd5b4ef7f682a8bc322a9ddce7090a0b", hex::encode(scrypto_encode(&transaction).unwrap()));
     }
 }
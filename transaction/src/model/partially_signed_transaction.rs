@@ -0,0 +1,442 @@
+use radix_engine_interface::crypto::{hash, Hash, PublicKey, SignatureWithPublicKey};
+use radix_engine_interface::data::{scrypto_decode, scrypto_encode};
+use radix_engine_interface::model::ComponentAddress;
+use radix_engine_interface::*;
+use sbor::rust::format;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+use crate::errors::PartialTransactionError;
+use crate::model::{
+    NotarizedTransaction, SignedTransactionIntent, TransactionFeePayer, TransactionIntent,
+};
+use crate::signing::TrySigner;
+use crate::validation::{recover, verify};
+
+/// A transaction intent which is in the process of collecting signatures.
+///
+/// Unlike `TransactionBuilder`, this doesn't assume that a single party holds all the signers:
+/// it can be encoded, passed around between (possibly offline) signers, merged with other
+/// copies of itself and finally notarized once all the required signatures are present.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoCategorize, ScryptoEncode, ScryptoDecode)]
+pub struct PartiallySignedTransaction {
+    pub intent: TransactionIntent,
+    pub required_signers: Vec<PublicKey>,
+    pub intent_signatures: Vec<SignatureWithPublicKey>,
+    pub fee_payer: Option<TransactionFeePayer>,
+}
+
+impl PartiallySignedTransaction {
+    pub fn new(intent: TransactionIntent, required_signers: Vec<PublicKey>) -> Self {
+        let mut deduplicated_signers = Vec::new();
+        for signer in required_signers {
+            if !deduplicated_signers.contains(&signer) {
+                deduplicated_signers.push(signer);
+            }
+        }
+
+        Self {
+            intent,
+            required_signers: deduplicated_signers,
+            intent_signatures: Vec::new(),
+            fee_payer: None,
+        }
+    }
+
+    pub fn sign<S: TrySigner>(&mut self, signer: &S) -> Result<PublicKey, PartialTransactionError> {
+        let signature = try_sign(signer, &self.intent.to_bytes()?)?;
+        self.add_signature(signature)
+    }
+
+    /// Adds an intent signature, which must be valid and come from one of the required signers.
+    ///
+    /// Returns the public key of the signer.
+    pub fn add_signature(
+        &mut self,
+        signature: SignatureWithPublicKey,
+    ) -> Result<PublicKey, PartialTransactionError> {
+        let public_key = self.verify_intent_signature(&signature)?;
+        if !self.required_signers.contains(&public_key) {
+            return Err(PartialTransactionError::UnexpectedSigner(public_key));
+        }
+        if self.signers().contains(&public_key) {
+            return Err(PartialTransactionError::DuplicateSigner(public_key));
+        }
+
+        self.intent_signatures.push(signature);
+        Ok(public_key)
+    }
+
+    pub fn set_fee_payer<S: TrySigner>(
+        &mut self,
+        account: ComponentAddress,
+        signer: &S,
+    ) -> Result<(), PartialTransactionError> {
+        let signature = try_sign(
+            signer,
+            &TransactionFeePayer::signed_payload(&self.intent, account)?,
+        )?;
+        let fee_payer = TransactionFeePayer { account, signature };
+        self.verify_fee_payer_signature(&fee_payer)?;
+        self.fee_payer = Some(fee_payer);
+        Ok(())
+    }
+
+    /// The public keys of the signers whose signatures have been collected so far.
+    ///
+    /// Signatures which don't verify against the intent (e.g. forged ones in a decoded copy)
+    /// are ignored.
+    pub fn signers(&self) -> Vec<PublicKey> {
+        self.verified_intent_signatures()
+            .into_iter()
+            .map(|(public_key, _)| public_key)
+            .collect()
+    }
+
+    /// The required signers whose signatures are yet to be collected.
+    pub fn missing_signers(&self) -> Vec<PublicKey> {
+        let signers = self.signers();
+        self.required_signers
+            .iter()
+            .filter(|signer| !signers.contains(signer))
+            .cloned()
+            .collect()
+    }
+
+    pub fn is_fully_signed(&self) -> bool {
+        self.missing_signers().is_empty()
+    }
+
+    /// Merges the signatures collected by another copy of the same partially signed transaction.
+    pub fn merge(
+        &mut self,
+        other: PartiallySignedTransaction,
+    ) -> Result<(), PartialTransactionError> {
+        if self.intent != other.intent {
+            return Err(PartialTransactionError::MismatchingIntent);
+        }
+        if self.required_signers != other.required_signers {
+            return Err(PartialTransactionError::MismatchingRequiredSigners);
+        }
+        match (&self.fee_payer, other.fee_payer) {
+            (Some(fee_payer), Some(other_fee_payer)) if fee_payer != &other_fee_payer => {
+                return Err(PartialTransactionError::MismatchingFeePayer);
+            }
+            (None, Some(other_fee_payer)) => {
+                self.verify_fee_payer_signature(&other_fee_payer)?;
+                self.fee_payer = Some(other_fee_payer);
+            }
+            _ => {}
+        }
+
+        for signature in other.intent_signatures {
+            match self.add_signature(signature) {
+                Ok(_) | Err(PartialTransactionError::DuplicateSigner(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Notarizes the transaction, once all the required signatures have been collected.
+    pub fn notarize<S: TrySigner>(
+        self,
+        notary: &S,
+    ) -> Result<NotarizedTransaction, PartialTransactionError> {
+        let missing_signers = self.missing_signers();
+        if !missing_signers.is_empty() {
+            return Err(PartialTransactionError::MissingSignatures(missing_signers));
+        }
+
+        // Only the verified signatures of required signers make it into the transaction
+        let mut signers = Vec::new();
+        let mut intent_signatures = Vec::new();
+        for (public_key, signature) in self.verified_intent_signatures() {
+            if self.required_signers.contains(&public_key) && !signers.contains(&public_key) {
+                signers.push(public_key);
+                intent_signatures.push(signature.clone());
+            }
+        }
+
+        let signed_intent = SignedTransactionIntent {
+            intent: self.intent,
+            intent_signatures,
+            fee_payer: self.fee_payer,
+        };
+        let signed_intent_payload = signed_intent.to_bytes()?;
+        let notary_signature = try_sign(notary, &signed_intent_payload)?;
+        let notary_public_key = recover(&signed_intent_payload, &notary_signature)
+            .ok_or(PartialTransactionError::InvalidSignature)?;
+        if notary_public_key != signed_intent.intent.header.notary_public_key {
+            return Err(PartialTransactionError::UnexpectedNotary(notary_public_key));
+        }
+
+        Ok(NotarizedTransaction {
+            signed_intent,
+            notary_signature: notary_signature.signature(),
+        })
+    }
+
+    pub fn from_slice(slice: &[u8]) -> Result<Self, DecodeError> {
+        scrypto_decode(slice)
+    }
+
+    pub fn hash(&self) -> Result<Hash, EncodeError> {
+        Ok(hash(self.to_bytes()?))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        scrypto_encode(self)
+    }
+
+    fn verified_intent_signatures(&self) -> Vec<(PublicKey, &SignatureWithPublicKey)> {
+        match self.intent.to_bytes() {
+            Ok(intent_payload) => self
+                .intent_signatures
+                .iter()
+                .filter_map(|signature| {
+                    recover(&intent_payload, signature)
+                        .filter(|public_key| {
+                            verify(&intent_payload, public_key, &signature.signature())
+                        })
+                        .map(|public_key| (public_key, signature))
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn verify_intent_signature(
+        &self,
+        signature: &SignatureWithPublicKey,
+    ) -> Result<PublicKey, PartialTransactionError> {
+        let intent_payload = self.intent.to_bytes()?;
+        let public_key =
+            recover(&intent_payload, signature).ok_or(PartialTransactionError::InvalidSignature)?;
+        if !verify(&intent_payload, &public_key, &signature.signature()) {
+            return Err(PartialTransactionError::InvalidSignature);
+        }
+        Ok(public_key)
+    }
+
+    fn verify_fee_payer_signature(
+        &self,
+        fee_payer: &TransactionFeePayer,
+    ) -> Result<PublicKey, PartialTransactionError> {
+        let fee_payer_payload =
+            TransactionFeePayer::signed_payload(&self.intent, fee_payer.account)?;
+        let public_key = recover(&fee_payer_payload, &fee_payer.signature)
+            .ok_or(PartialTransactionError::InvalidSignature)?;
+        if !verify(
+            &fee_payer_payload,
+            &public_key,
+            &fee_payer.signature.signature(),
+        ) {
+            return Err(PartialTransactionError::InvalidSignature);
+        }
+        if self.required_signers.contains(&public_key) {
+            return Err(PartialTransactionError::FeePayerIsSigner(public_key));
+        }
+        Ok(public_key)
+    }
+}
+
+fn try_sign<S: TrySigner>(
+    signer: &S,
+    message: &[u8],
+) -> Result<SignatureWithPublicKey, PartialTransactionError> {
+    signer
+        .try_sign(message)
+        .map_err(|e| PartialTransactionError::SignerError(format!("{:?}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use radix_engine_interface::node::NetworkDefinition;
+
+    use super::*;
+    use crate::model::TransactionHeader;
+    use crate::signing::*;
+
+    fn create_partially_signed_transaction(
+        signers: &[&EcdsaSecp256k1PrivateKey],
+        notary: &EcdsaSecp256k1PrivateKey,
+    ) -> PartiallySignedTransaction {
+        let intent = TransactionIntent::new(
+            &NetworkDefinition::simulator(),
+            TransactionHeader {
+                version: 1,
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: 0,
+                end_epoch_exclusive: 100,
+                nonce: 5,
+                notary_public_key: notary.public_key().into(),
+                notary_as_signatory: false,
+                cost_unit_limit: 1_000_000,
+                tip_percentage: 5,
+            },
+            "CLEAR_AUTH_ZONE;",
+            Vec::new(),
+        )
+        .unwrap();
+
+        PartiallySignedTransaction::new(
+            intent,
+            signers.iter().map(|sk| sk.public_key().into()).collect(),
+        )
+    }
+
+    #[test]
+    fn sign_merge_and_notarize() {
+        let sk1 = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+        let sk2 = EddsaEd25519PrivateKey::from_u64(2).unwrap();
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+
+        let mut partial = create_partially_signed_transaction(&[&sk1], &sk_notary);
+        partial.required_signers.push(sk2.public_key().into());
+        assert_eq!(
+            partial.missing_signers(),
+            vec![sk1.public_key().into(), sk2.public_key().into()]
+        );
+
+        // Each party signs their own copy, e.g. on an offline machine
+        let bytes = partial.to_bytes().unwrap();
+        let mut copy1 = PartiallySignedTransaction::from_slice(&bytes).unwrap();
+        let mut copy2 = PartiallySignedTransaction::from_slice(&bytes).unwrap();
+        copy1.sign(&sk1).unwrap();
+        copy2.sign(&sk2).unwrap();
+        assert_eq!(copy1.missing_signers(), vec![sk2.public_key().into()]);
+        assert!(!copy1.is_fully_signed());
+
+        copy1.merge(copy2.clone()).unwrap();
+        copy1.merge(copy2).unwrap();
+        assert!(copy1.is_fully_signed());
+        assert_eq!(copy1.intent_signatures.len(), 2);
+
+        let transaction = copy1.notarize(&sk_notary).unwrap();
+        assert_eq!(transaction.signed_intent.intent, partial.intent);
+        assert_eq!(transaction.signed_intent.intent_signatures.len(), 2);
+    }
+
+    #[test]
+    fn rejects_unexpected_and_duplicate_signers() {
+        let sk1 = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+        let sk2 = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+        let mut partial = create_partially_signed_transaction(&[&sk1], &sk_notary);
+
+        assert_eq!(
+            partial.sign(&sk2),
+            Err(PartialTransactionError::UnexpectedSigner(
+                sk2.public_key().into()
+            ))
+        );
+        partial.sign(&sk1).unwrap();
+        assert_eq!(
+            partial.sign(&sk1),
+            Err(PartialTransactionError::DuplicateSigner(
+                sk1.public_key().into()
+            ))
+        );
+        // A signature over another payload recovers to some other key
+        assert!(matches!(
+            partial.add_signature(sk1.sign(b"not the intent").into()),
+            Err(PartialTransactionError::UnexpectedSigner(_))
+        ));
+    }
+
+    #[test]
+    fn forged_signatures_are_not_counted() {
+        let sk1 = EddsaEd25519PrivateKey::from_u64(1).unwrap();
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+        let mut partial = create_partially_signed_transaction(&[], &sk_notary);
+        partial.required_signers.push(sk1.public_key().into());
+
+        // An Ed25519 signature embeds the claimed public key, so it must be verified
+        let forged_signature = SignatureWithPublicKey::EddsaEd25519 {
+            public_key: sk1.public_key(),
+            signature: sk1.sign(b"not the intent"),
+        };
+        assert_eq!(
+            partial.add_signature(forged_signature.clone()),
+            Err(PartialTransactionError::InvalidSignature)
+        );
+
+        // A forged signature smuggled into an encoded copy doesn't count either
+        partial.intent_signatures.push(forged_signature);
+        let decoded = PartiallySignedTransaction::from_slice(&partial.to_bytes().unwrap()).unwrap();
+        assert!(decoded.signers().is_empty());
+        assert_eq!(decoded.missing_signers(), vec![sk1.public_key().into()]);
+        assert_eq!(
+            decoded.notarize(&sk_notary),
+            Err(PartialTransactionError::MissingSignatures(vec![sk1
+                .public_key()
+                .into()]))
+        );
+    }
+
+    #[test]
+    fn forged_signatures_are_dropped_when_notarizing() {
+        let sk1 = EddsaEd25519PrivateKey::from_u64(1).unwrap();
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+        let mut partial = create_partially_signed_transaction(&[], &sk_notary);
+        partial.required_signers.push(sk1.public_key().into());
+        partial.sign(&sk1).unwrap();
+
+        // A forged signature smuggled in next to the valid one doesn't end up in the transaction
+        partial
+            .intent_signatures
+            .push(SignatureWithPublicKey::EddsaEd25519 {
+                public_key: sk1.public_key(),
+                signature: sk1.sign(b"not the intent"),
+            });
+        assert!(partial.is_fully_signed());
+
+        let transaction = partial.notarize(&sk_notary).unwrap();
+        assert_eq!(
+            transaction.signed_intent.intent_signatures,
+            vec![SignatureWithPublicKey::EddsaEd25519 {
+                public_key: sk1.public_key(),
+                signature: sk1.sign(&transaction.signed_intent.intent.to_bytes().unwrap()),
+            }]
+        );
+    }
+
+    #[test]
+    fn cannot_notarize_before_fully_signed() {
+        let sk1 = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+        let sk2 = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+        let mut partial = create_partially_signed_transaction(&[&sk1, &sk2], &sk_notary);
+        partial.sign(&sk2).unwrap();
+
+        assert_eq!(
+            partial.clone().notarize(&sk_notary),
+            Err(PartialTransactionError::MissingSignatures(vec![sk1
+                .public_key()
+                .into()]))
+        );
+        partial.sign(&sk1).unwrap();
+        assert_eq!(
+            partial.notarize(&sk1),
+            Err(PartialTransactionError::UnexpectedNotary(
+                sk1.public_key().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn cannot_merge_different_intents() {
+        let sk1 = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+        let mut partial1 = create_partially_signed_transaction(&[&sk1], &sk_notary);
+        let mut partial2 = partial1.clone();
+        partial2.intent.header.nonce += 1;
+
+        assert_eq!(
+            partial1.merge(partial2),
+            Err(PartialTransactionError::MismatchingIntent)
+        );
+    }
+}
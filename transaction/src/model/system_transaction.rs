@@ -33,6 +33,7 @@ impl SystemTransaction {
             ExecutionContext {
                 transaction_hash,
                 payload_size: 0,
+                message_size: 0,
                 auth_zone_params,
                 fee_payment: FeePayment::NoFee,
                 runtime_validations: vec![],
@@ -37,6 +37,7 @@ impl TestTransaction {
             ExecutionContext {
                 transaction_hash,
                 payload_size,
+                message_size: 0,
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
                     virtualizable_proofs_resource_addresses: BTreeSet::new(),
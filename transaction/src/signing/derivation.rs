@@ -0,0 +1,315 @@
+use hmac::{Hmac, Mac};
+use sbor::rust::fmt;
+use sbor::rust::str::FromStr;
+use sbor::rust::string::{String, ToString};
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
+use secp256k1::{PublicKey, Scalar, SecretKey};
+use sha2::Sha512;
+
+use super::{EcdsaSecp256k1PrivateKey, EddsaEd25519PrivateKey};
+use crate::errors::KeyDerivationError;
+
+/// The SLIP-0044 coin type of Radix.
+pub const RADIX_COIN_TYPE: u32 = 1022;
+
+/// The offset of hardened child indices.
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// A BIP-39 mnemonic sentence, in English.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mnemonic(bip39::Mnemonic);
+
+impl Mnemonic {
+    /// Creates a mnemonic from 16, 20, 24, 28 or 32 bytes of entropy.
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self, KeyDerivationError> {
+        bip39::Mnemonic::from_entropy(entropy)
+            .map(Self)
+            .map_err(|_| KeyDerivationError::InvalidEntropy)
+    }
+
+    pub fn from_phrase(phrase: &str) -> Result<Self, KeyDerivationError> {
+        let normalized = phrase.split_whitespace().collect::<Vec<&str>>().join(" ");
+        bip39::Mnemonic::parse_in_normalized(bip39::Language::English, &normalized.to_lowercase())
+            .map(Self)
+            .map_err(|_| KeyDerivationError::InvalidMnemonic)
+    }
+
+    pub fn to_phrase(&self) -> String {
+        self.0.to_string()
+    }
+
+    pub fn to_entropy(&self) -> Vec<u8> {
+        self.0.to_entropy()
+    }
+
+    /// Returns the 64-byte seed, with the given passphrase.
+    ///
+    /// The passphrase is NFKD-normalized first, as required by BIP-39.
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        self.0.to_seed(passphrase)
+    }
+}
+
+/// A BIP-32 derivation path, e.g. `m/44'/1022'/0'/0'/0'`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationPath(pub Vec<u32>);
+
+impl DerivationPath {
+    /// The path of the account key with the given index, i.e. `m/44'/1022'/0'/0'/{index}'`.
+    ///
+    /// All the components are hardened, so that the same path can be used on every curve.
+    pub fn account(index: u32) -> Result<Self, KeyDerivationError> {
+        if index >= HARDENED_OFFSET {
+            return Err(KeyDerivationError::InvalidDerivationPath);
+        }
+        Ok(Self(vec![
+            44 + HARDENED_OFFSET,
+            RADIX_COIN_TYPE + HARDENED_OFFSET,
+            HARDENED_OFFSET,
+            HARDENED_OFFSET,
+            index + HARDENED_OFFSET,
+        ]))
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = KeyDerivationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.split('/');
+        if components.next() != Some("m") {
+            return Err(KeyDerivationError::InvalidDerivationPath);
+        }
+
+        let mut path = Vec::new();
+        for component in components {
+            let (index, hardened) = match component
+                .strip_suffix('\'')
+                .or_else(|| component.strip_suffix('H'))
+            {
+                Some(index) => (index, true),
+                None => (component, false),
+            };
+            let index =
+                u32::from_str(index).map_err(|_| KeyDerivationError::InvalidDerivationPath)?;
+            if index >= HARDENED_OFFSET {
+                return Err(KeyDerivationError::InvalidDerivationPath);
+            }
+            path.push(if hardened {
+                index + HARDENED_OFFSET
+            } else {
+                index
+            });
+        }
+        Ok(Self(path))
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "m")?;
+        for index in &self.0 {
+            if *index >= HARDENED_OFFSET {
+                write!(f, "/{}'", index - HARDENED_OFFSET)?;
+            } else {
+                write!(f, "/{}", index)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Derives a secp256k1 private key from a seed, following BIP-32 (equivalently, SLIP-0010).
+pub fn derive_ecdsa_secp256k1_private_key(
+    seed: &[u8],
+    path: &DerivationPath,
+) -> Result<EcdsaSecp256k1PrivateKey, KeyDerivationError> {
+    let (mut key, mut chain_code) = split(hmac_sha512(b"Bitcoin seed", &[seed]));
+    let mut secret_key =
+        SecretKey::from_slice(&key).map_err(|_| KeyDerivationError::InvalidChildKey)?;
+
+    for index in &path.0 {
+        let index_bytes = index.to_be_bytes();
+        let digest = if *index >= HARDENED_OFFSET {
+            hmac_sha512(
+                &chain_code,
+                &[&[0u8], &secret_key.secret_bytes(), &index_bytes],
+            )
+        } else {
+            let public_key = PublicKey::from_secret_key_global(&secret_key);
+            hmac_sha512(&chain_code, &[&public_key.serialize(), &index_bytes])
+        };
+        (key, chain_code) = split(digest);
+
+        let tweak = Scalar::from_be_bytes(key).map_err(|_| KeyDerivationError::InvalidChildKey)?;
+        secret_key = secret_key
+            .add_tweak(&tweak)
+            .map_err(|_| KeyDerivationError::InvalidChildKey)?;
+    }
+
+    EcdsaSecp256k1PrivateKey::from_bytes(&secret_key.secret_bytes())
+        .map_err(|_| KeyDerivationError::InvalidChildKey)
+}
+
+/// Derives an Ed25519 private key from a seed, following SLIP-0010.
+///
+/// Only hardened derivation is defined for Ed25519.
+pub fn derive_eddsa_ed25519_private_key(
+    seed: &[u8],
+    path: &DerivationPath,
+) -> Result<EddsaEd25519PrivateKey, KeyDerivationError> {
+    let (mut key, mut chain_code) = split(hmac_sha512(b"ed25519 seed", &[seed]));
+
+    for index in &path.0 {
+        if *index < HARDENED_OFFSET {
+            return Err(KeyDerivationError::NonHardenedDerivation);
+        }
+        (key, chain_code) = split(hmac_sha512(
+            &chain_code,
+            &[&[0u8], &key, &index.to_be_bytes()],
+        ));
+    }
+
+    EddsaEd25519PrivateKey::from_bytes(&key).map_err(|_| KeyDerivationError::InvalidChildKey)
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for part in data {
+        mac.update(part);
+    }
+    let mut digest = [0u8; 64];
+    digest.copy_from_slice(&mac.finalize().into_bytes());
+    digest
+}
+
+fn split(digest: [u8; 64]) -> ([u8; 32], [u8; 32]) {
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&digest[..32]);
+    chain_code.copy_from_slice(&digest[32..]);
+    (key, chain_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+    #[test]
+    fn test_mnemonic_to_seed() {
+        let mnemonic = Mnemonic::from_entropy(&[0u8; 16]).unwrap();
+        assert_eq!(
+            mnemonic.to_phrase(),
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        );
+        assert_eq!(
+            hex::encode(mnemonic.to_seed("TREZOR")),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+        // The passphrase is NFKD-normalized before use
+        assert_eq!(
+            hex::encode(mnemonic.to_seed("㍍ガバヴァぱばぐゞちぢ十人十色")),
+            "ba553eedefe76e67e2602dc20184c564010859faada929a090dd2c57aacb204ceefd15404ab50ef3e8dbeae5195aeae64b0def4d2eead1cdc728a33ced520ffd"
+        );
+        assert_eq!(
+            Mnemonic::from_phrase(&format!("  {}\n", mnemonic.to_phrase().to_uppercase())),
+            Ok(mnemonic)
+        );
+        assert_eq!(
+            Mnemonic::from_phrase("abandon abandon abandon"),
+            Err(KeyDerivationError::InvalidMnemonic)
+        );
+    }
+
+    #[test]
+    fn test_derivation_path() {
+        let path = DerivationPath::from_str("m/44'/1022'/0'/0/5H").unwrap();
+        assert_eq!(
+            path,
+            DerivationPath(vec![
+                44 + HARDENED_OFFSET,
+                1022 + HARDENED_OFFSET,
+                HARDENED_OFFSET,
+                0,
+                5 + HARDENED_OFFSET
+            ])
+        );
+        assert_eq!(path.to_string(), "m/44'/1022'/0'/0/5'");
+        assert_eq!(
+            DerivationPath::account(3).unwrap().to_string(),
+            "m/44'/1022'/0'/0'/3'"
+        );
+        assert!(DerivationPath::from_str("44'/0").is_err());
+        assert!(DerivationPath::from_str("m/x").is_err());
+    }
+
+    #[test]
+    fn test_ecdsa_secp256k1_derivation() {
+        // BIP-32 test vector 1
+        let seed = hex::decode(SEED).unwrap();
+        for (path, private_key) in [
+            (
+                "m",
+                "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+            ),
+            (
+                "m/0'",
+                "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+            ),
+            (
+                "m/0'/1",
+                "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+            ),
+        ] {
+            let path = DerivationPath::from_str(path).unwrap();
+            let key = derive_ecdsa_secp256k1_private_key(&seed, &path).unwrap();
+            assert_eq!(hex::encode(key.to_bytes()), private_key);
+        }
+    }
+
+    #[test]
+    fn test_eddsa_ed25519_derivation() {
+        // SLIP-0010 test vector 1 for ed25519
+        let seed = hex::decode(SEED).unwrap();
+        for (path, private_key) in [
+            (
+                "m",
+                "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+            ),
+            (
+                "m/0'",
+                "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+            ),
+        ] {
+            let path = DerivationPath::from_str(path).unwrap();
+            let key = derive_eddsa_ed25519_private_key(&seed, &path).unwrap();
+            assert_eq!(hex::encode(key.to_bytes()), private_key);
+        }
+        assert_eq!(
+            derive_eddsa_ed25519_private_key(&seed, &DerivationPath::from_str("m/0").unwrap())
+                .err(),
+            Some(KeyDerivationError::NonHardenedDerivation)
+        );
+    }
+
+    #[test]
+    fn test_account_keys_are_reproducible() {
+        let mnemonic = Mnemonic::from_entropy(&[0u8; 16]).unwrap();
+        let seed = mnemonic.to_seed("");
+        let path = DerivationPath::account(1).unwrap();
+
+        let key = derive_ecdsa_secp256k1_private_key(&seed, &path).unwrap();
+        assert_eq!(
+            key.public_key().to_string(),
+            "021683e443f3212daeba3c71dd33e4aed9b01e9414a8eb351033715a80f8680bdc"
+        );
+        let key = derive_eddsa_ed25519_private_key(&seed, &path).unwrap();
+        assert_eq!(
+            key.public_key().to_string(),
+            "da08ce3b410aff691bbda0243e1d0046930b49fa16ebab3aec9893268ed6e749"
+        );
+    }
+}
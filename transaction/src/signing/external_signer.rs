@@ -0,0 +1,319 @@
+//! A signer backed by an external process, e.g. a signing daemon or a hardware wallet bridge.
+//!
+//! The two sides talk JSON-RPC 2.0 over a pair of byte streams (typically the stdin and stdout
+//! of a subprocess), one message per line:
+//!
+//! ```text
+//! --> {"jsonrpc":"2.0","id":1,"method":"get_public_key","params":{}}
+//! <-- {"jsonrpc":"2.0","id":1,"result":{"public_key":"<hex>"}}
+//! --> {"jsonrpc":"2.0","id":2,"method":"sign","params":{"hash":"<hex>","payload":"<hex>"}}
+//! <-- {"jsonrpc":"2.0","id":2,"result":{"public_key":"<hex>","signature":"<hex>"}}
+//! ```
+//!
+//! `hash` is the hash of `payload`, i.e. the intent hash when signing an intent. A secp256k1
+//! signer only needs the hash, as it signs `sha256(hash)`; an Ed25519 signer signs the payload.
+
+use radix_engine_interface::crypto::{
+    hash, EcdsaSecp256k1PublicKey, EcdsaSecp256k1Signature, EddsaEd25519PublicKey,
+    EddsaEd25519Signature, PublicKey, SignatureWithPublicKey,
+};
+use serde_json::{json, Value};
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+
+use super::TrySigner;
+use crate::validation::{recover, verify};
+
+#[derive(Debug)]
+pub enum ExternalSignerError {
+    IOError(io::Error),
+    JSONError(serde_json::Error),
+    SignerClosed,
+    SignerError { code: i64, message: String },
+    InvalidResponse(String),
+    InvalidSignature,
+    UnexpectedPublicKey(PublicKey),
+}
+
+impl From<io::Error> for ExternalSignerError {
+    fn from(err: io::Error) -> Self {
+        Self::IOError(err)
+    }
+}
+
+impl From<serde_json::Error> for ExternalSignerError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::JSONError(err)
+    }
+}
+
+struct Channel {
+    reader: Box<dyn BufRead + Send>,
+    writer: Box<dyn Write + Send>,
+    next_id: u64,
+}
+
+/// A `Signer` which delegates signing to an external process, so that keys never have to be
+/// loaded into this one.
+pub struct ExternalSigner {
+    channel: Mutex<Channel>,
+    child: Option<Child>,
+    public_key: PublicKey,
+}
+
+impl ExternalSigner {
+    /// Spawns the given command and talks to it over its stdin and stdout.
+    pub fn spawn(command: &mut Command) -> Result<Self, ExternalSignerError> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let writer = child.stdin.take().expect("Stdin is piped");
+        let reader = child.stdout.take().expect("Stdout is piped");
+
+        let mut signer = Self::connect(BufReader::new(reader), writer)?;
+        signer.child = Some(child);
+        Ok(signer)
+    }
+
+    /// Talks to a signer over an existing pair of streams.
+    pub fn connect<R: BufRead + Send + 'static, W: Write + Send + 'static>(
+        reader: R,
+        writer: W,
+    ) -> Result<Self, ExternalSignerError> {
+        let mut channel = Channel {
+            reader: Box::new(reader),
+            writer: Box::new(writer),
+            next_id: 1,
+        };
+        let result = channel.call("get_public_key", json!({}))?;
+        let public_key = parse_public_key(&result)?;
+
+        Ok(Self {
+            channel: Mutex::new(channel),
+            child: None,
+            public_key,
+        })
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    pub fn try_sign(&self, message: &[u8]) -> Result<SignatureWithPublicKey, ExternalSignerError> {
+        let result = self.channel.lock().unwrap().call(
+            "sign",
+            json!({
+                "hash": hash(message).to_string(),
+                "payload": hex::encode(message),
+            }),
+        )?;
+        let public_key = parse_public_key(&result)?;
+        if public_key != self.public_key {
+            return Err(ExternalSignerError::UnexpectedPublicKey(public_key));
+        }
+        let signature_bytes = parse_hex_field(&result, "signature")?;
+        let signature = match public_key {
+            PublicKey::EcdsaSecp256k1(_) => {
+                EcdsaSecp256k1Signature::try_from(signature_bytes.as_slice())
+                    .map(SignatureWithPublicKey::from)
+                    .map_err(|_| ExternalSignerError::InvalidSignature)?
+            }
+            PublicKey::EddsaEd25519(public_key) => {
+                EddsaEd25519Signature::try_from(signature_bytes.as_slice())
+                    .map(|signature| (public_key, signature).into())
+                    .map_err(|_| ExternalSignerError::InvalidSignature)?
+            }
+        };
+
+        // Don't trust the signer blindly, an invalid signature would only surface on submission
+        if recover(message, &signature) != Some(public_key)
+            || !verify(message, &public_key, &signature.signature())
+        {
+            return Err(ExternalSignerError::InvalidSignature);
+        }
+
+        Ok(signature)
+    }
+}
+
+impl TrySigner for ExternalSigner {
+    type Error = ExternalSignerError;
+
+    fn try_sign(&self, message: &[u8]) -> Result<SignatureWithPublicKey, Self::Error> {
+        ExternalSigner::try_sign(self, message)
+    }
+}
+
+impl Drop for ExternalSigner {
+    fn drop(&mut self) {
+        if let Some(child) = &mut self.child {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+impl Channel {
+    fn call(&mut self, method: &str, params: Value) -> Result<Value, ExternalSignerError> {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+        writeln!(self.writer, "{}", request)?;
+        self.writer.flush()?;
+
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(ExternalSignerError::SignerClosed);
+        }
+        let mut response: Value = serde_json::from_str(&line)?;
+        if response["id"] != json!(id) {
+            return Err(ExternalSignerError::InvalidResponse(line));
+        }
+        if let Some(error) = response.get("error") {
+            return Err(ExternalSignerError::SignerError {
+                code: error["code"].as_i64().unwrap_or_default(),
+                message: error["message"].as_str().unwrap_or_default().to_owned(),
+            });
+        }
+        match response.get_mut("result") {
+            Some(result) => Ok(result.take()),
+            None => Err(ExternalSignerError::InvalidResponse(line)),
+        }
+    }
+}
+
+/// Serves signing requests from an `ExternalSigner` until the input is closed.
+///
+/// This is the signer side of the protocol, for building signing daemons around any `Signer`.
+/// Malformed requests and failed signings are answered with a JSON-RPC error, and don't stop
+/// the server.
+pub fn serve_external_signer<S: TrySigner, R: BufRead, W: Write>(
+    signer: &S,
+    public_key: PublicKey,
+    reader: R,
+    mut writer: W,
+) -> Result<(), ExternalSignerError> {
+    for line in reader.lines() {
+        let request: Value = match serde_json::from_str(&line?) {
+            Ok(request) => request,
+            Err(_) => {
+                let error = json!({ "code": -32700, "message": "Parse error" });
+                writeln!(
+                    writer,
+                    "{}",
+                    json!({ "jsonrpc": "2.0", "id": null, "error": error })
+                )?;
+                writer.flush()?;
+                continue;
+            }
+        };
+        let result = match request["method"].as_str() {
+            Some("get_public_key") => Ok(json!({ "public_key": format_public_key(&public_key) })),
+            Some("sign") => match parse_hex_field(&request["params"], "payload") {
+                Ok(payload) => match signer.try_sign(&payload) {
+                    Ok(signature) => Ok(json!({
+                        "public_key": format_public_key(&public_key),
+                        "signature": format_signature(&signature),
+                    })),
+                    Err(e) => Err(json!({ "code": -32000, "message": format!("{:?}", e) })),
+                },
+                Err(_) => Err(json!({ "code": -32602, "message": "Invalid params" })),
+            },
+            _ => Err(json!({ "code": -32601, "message": "Method not found" })),
+        };
+        let response = match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+            Err(error) => json!({ "jsonrpc": "2.0", "id": request["id"], "error": error }),
+        };
+        writeln!(writer, "{}", response)?;
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+fn parse_hex_field(value: &Value, field: &str) -> Result<Vec<u8>, ExternalSignerError> {
+    value[field]
+        .as_str()
+        .and_then(|s| hex::decode(s).ok())
+        .ok_or_else(|| ExternalSignerError::InvalidResponse(value.to_string()))
+}
+
+fn parse_public_key(value: &Value) -> Result<PublicKey, ExternalSignerError> {
+    let bytes = parse_hex_field(value, "public_key")?;
+    if let Ok(public_key) = EcdsaSecp256k1PublicKey::try_from(bytes.as_slice()) {
+        return Ok(public_key.into());
+    }
+    EddsaEd25519PublicKey::try_from(bytes.as_slice())
+        .map(Into::into)
+        .map_err(|_| ExternalSignerError::InvalidResponse(value.to_string()))
+}
+
+fn format_public_key(public_key: &PublicKey) -> String {
+    match public_key {
+        PublicKey::EcdsaSecp256k1(public_key) => public_key.to_string(),
+        PublicKey::EddsaEd25519(public_key) => public_key.to_string(),
+    }
+}
+
+fn format_signature(signature: &SignatureWithPublicKey) -> String {
+    match signature {
+        SignatureWithPublicKey::EcdsaSecp256k1 { signature } => signature.to_string(),
+        SignatureWithPublicKey::EddsaEd25519 { signature, .. } => signature.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::EcdsaSecp256k1PrivateKey;
+    use std::io::Cursor;
+
+    #[test]
+    fn malformed_requests_do_not_stop_the_server() {
+        let private_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+        let public_key: PublicKey = private_key.public_key().into();
+        let requests = "not json\n{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"get_public_key\",\"params\":{}}\n";
+        let mut output = Vec::new();
+
+        serve_external_signer(&private_key, public_key, Cursor::new(requests), &mut output)
+            .unwrap();
+
+        let responses: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["error"]["code"], json!(-32700));
+        assert_eq!(
+            responses[1]["result"]["public_key"],
+            json!(format_public_key(&public_key))
+        );
+    }
+
+    #[test]
+    fn signer_errors_are_propagated() {
+        let private_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+        let public_key: PublicKey = private_key.public_key().into();
+        let responses = format!(
+            "{}\n{}\n",
+            json!({ "jsonrpc": "2.0", "id": 1, "result": { "public_key": format_public_key(&public_key) } }),
+            json!({ "jsonrpc": "2.0", "id": 2, "error": { "code": -32000, "message": "Rejected" } }),
+        );
+        let signer = ExternalSigner::connect(Cursor::new(responses), Vec::new()).unwrap();
+
+        assert!(matches!(
+            TrySigner::try_sign(&signer, b"message"),
+            Err(ExternalSignerError::SignerError { code: -32000, .. })
+        ));
+    }
+}
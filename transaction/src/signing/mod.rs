@@ -1,7 +1,13 @@
+mod derivation;
 mod ecdsa_secp256k1;
 mod eddsa_ed25519;
+#[cfg(feature = "std")]
+mod external_signer;
 mod signer;
 
+pub use derivation::*;
 pub use ecdsa_secp256k1::*;
 pub use eddsa_ed25519::*;
+#[cfg(feature = "std")]
+pub use external_signer::*;
 pub use signer::*;
@@ -1,4 +1,5 @@
 use radix_engine_interface::crypto::SignatureWithPublicKey;
+use sbor::rust::convert::Infallible;
 
 use super::{EcdsaSecp256k1PrivateKey, EddsaEd25519PrivateKey};
 
@@ -6,6 +7,23 @@ pub trait Signer {
     fn sign(&self, message: &[u8]) -> SignatureWithPublicKey;
 }
 
+/// A signer which may fail to sign, e.g. because it's backed by another process or a device.
+///
+/// Every `Signer` is a `TrySigner` which never fails.
+pub trait TrySigner {
+    type Error: sbor::rust::fmt::Debug;
+
+    fn try_sign(&self, message: &[u8]) -> Result<SignatureWithPublicKey, Self::Error>;
+}
+
+impl<S: Signer> TrySigner for S {
+    type Error = Infallible;
+
+    fn try_sign(&self, message: &[u8]) -> Result<SignatureWithPublicKey, Self::Error> {
+        Ok(self.sign(message))
+    }
+}
+
 impl Signer for EcdsaSecp256k1PrivateKey {
     fn sign(&self, message: &[u8]) -> SignatureWithPublicKey {
         self.sign(message).into()
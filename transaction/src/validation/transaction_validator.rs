@@ -5,6 +5,7 @@ use radix_engine_interface::data::*;
 use radix_engine_interface::modules::auth::AuthAddresses;
 use radix_engine_interface::node::NetworkDefinition;
 use sbor::rust::collections::{BTreeSet, HashSet};
+use sbor::EncodeError;
 
 use crate::errors::{SignatureValidationError, *};
 use crate::model::*;
@@ -85,20 +86,38 @@ impl TransactionValidator<NotarizedTransaction> for NotarizedTransactionValidato
 
         let header = &intent.header;
 
+        let message_size = Self::message_size(intent)?;
+
+        let fee_payment = match &transaction.signed_intent.fee_payer {
+            Some(fee_payer) => {
+                let fee_payer_key = self
+                    .validate_fee_payer_signature(intent, fee_payer, &signer_keys)
+                    .map_err(TransactionValidationError::SignatureValidationError)?;
+                FeePayment::Sponsored {
+                    cost_unit_limit: header.cost_unit_limit,
+                    tip_percentage: header.tip_percentage,
+                    fee_payer: fee_payer.account,
+                    fee_payer_proofs: AuthAddresses::signer_set(&[fee_payer_key]),
+                }
+            }
+            None => FeePayment::User {
+                cost_unit_limit: header.cost_unit_limit,
+                tip_percentage: header.tip_percentage,
+            },
+        };
+
         Ok(Executable::new(
             InstructionList::Basic(&intent.manifest.instructions),
             &intent.manifest.blobs,
             ExecutionContext {
                 transaction_hash,
                 payload_size,
+                message_size,
                 auth_zone_params: AuthZoneParams {
                     initial_proofs: AuthAddresses::signer_set(&signer_keys),
                     virtualizable_proofs_resource_addresses: BTreeSet::new(),
                 },
-                fee_payment: FeePayment::User {
-                    cost_unit_limit: header.cost_unit_limit,
-                    tip_percentage: header.tip_percentage,
-                },
+                fee_payment,
                 runtime_validations: vec![
                     RuntimeValidation::IntentHashUniqueness { intent_hash }.enforced(),
                     RuntimeValidation::WithinEpochRange {
@@ -155,6 +174,7 @@ impl NotarizedTransactionValidator {
             ExecutionContext {
                 transaction_hash,
                 payload_size: 0,
+                message_size: Self::message_size(intent)?,
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
                     virtualizable_proofs_resource_addresses,
@@ -191,9 +211,65 @@ impl NotarizedTransactionValidator {
 
         Self::validate_manifest(&intent.manifest)?;
 
+        if let Some(message) = &intent.message {
+            Self::validate_message(message)
+                .map_err(TransactionValidationError::MessageValidationError)?;
+        }
+
         return Ok(());
     }
 
+    pub fn validate_message(message: &TransactionMessage) -> Result<(), MessageValidationError> {
+        match message {
+            TransactionMessage::Plaintext(PlaintextMessage { mime_type, message }) => {
+                if mime_type.len() > MAX_MESSAGE_MIME_TYPE_LENGTH {
+                    return Err(MessageValidationError::MimeTypeTooLong);
+                }
+                if message.len() > MAX_MESSAGE_SIZE {
+                    return Err(MessageValidationError::MessageTooLarge);
+                }
+            }
+            TransactionMessage::Encrypted(EncryptedMessage {
+                encrypted,
+                decryptors,
+            }) => {
+                if encrypted.len() > MAX_MESSAGE_SIZE {
+                    return Err(MessageValidationError::MessageTooLarge);
+                }
+                if decryptors.is_empty() {
+                    return Err(MessageValidationError::NoDecryptors);
+                }
+                if decryptors.len() > MAX_NUMBER_OF_MESSAGE_DECRYPTORS {
+                    return Err(MessageValidationError::TooManyDecryptors);
+                }
+
+                let mut recipients = HashSet::new();
+                for decryptor in decryptors {
+                    if !recipients.insert(decryptor.recipient) {
+                        return Err(MessageValidationError::DuplicateDecryptor);
+                    }
+                    match (&decryptor.recipient, &decryptor.ephemeral_public_key) {
+                        (PublicKey::EcdsaSecp256k1(..), PublicKey::EcdsaSecp256k1(..))
+                        | (PublicKey::EddsaEd25519(..), PublicKey::EddsaEd25519(..)) => {}
+                        _ => return Err(MessageValidationError::MismatchingDecryptorCurves),
+                    }
+                    if decryptor.wrapped_key.len() > MAX_MESSAGE_WRAPPED_KEY_LENGTH {
+                        return Err(MessageValidationError::WrappedKeyTooLong);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn message_size(intent: &TransactionIntent) -> Result<usize, EncodeError> {
+        match &intent.message {
+            Some(message) => Ok(message.to_bytes()?.len()),
+            None => Ok(0),
+        }
+    }
+
     pub fn validate_manifest(
         manifest: &TransactionManifest,
     ) -> Result<(), TransactionValidationError> {
@@ -291,7 +367,11 @@ impl NotarizedTransactionValidator {
                         .map_err(TransactionValidationError::IdValidationError)?;
                 }
                 BasicInstruction::RecallResource { .. }
+                | BasicInstruction::FreezeVault { .. }
+                | BasicInstruction::UnfreezeVault { .. }
                 | BasicInstruction::SetMetadata { .. }
+                | BasicInstruction::RemoveMetadata { .. }
+                | BasicInstruction::LockMetadata { .. }
                 | BasicInstruction::SetPackageRoyaltyConfig { .. }
                 | BasicInstruction::SetComponentRoyaltyConfig { .. }
                 | BasicInstruction::ClaimPackageRoyalty { .. }
@@ -393,6 +473,32 @@ impl NotarizedTransactionValidator {
         Ok(signers.into_iter().collect())
     }
 
+    pub fn validate_fee_payer_signature(
+        &self,
+        intent: &TransactionIntent,
+        fee_payer: &TransactionFeePayer,
+        signer_keys: &[PublicKey],
+    ) -> Result<PublicKey, SignatureValidationError> {
+        let fee_payer_payload = TransactionFeePayer::signed_payload(intent, fee_payer.account)?;
+        let public_key = recover(&fee_payer_payload, &fee_payer.signature)
+            .ok_or(SignatureValidationError::InvalidFeePayerSignature)?;
+
+        if !verify(
+            &fee_payer_payload,
+            &public_key,
+            &fee_payer.signature.signature(),
+        ) {
+            return Err(SignatureValidationError::InvalidFeePayerSignature);
+        }
+
+        // The fee payer only authorizes locking the fee, so its key mustn't also be an intent signer
+        if signer_keys.contains(&public_key) {
+            return Err(SignatureValidationError::FeePayerIsIntentSigner);
+        }
+
+        Ok(public_key)
+    }
+
     pub fn validate_call_args(
         args: &[u8],
         id_validator: &mut ManifestIdValidator,
@@ -418,11 +524,13 @@ impl NotarizedTransactionValidator {
 
 #[cfg(test)]
 mod tests {
+    use radix_engine_interface::model::{ComponentAddress, FromPublicKey, NonFungibleGlobalId};
     use radix_engine_interface::node::NetworkDefinition;
 
     use super::*;
     use crate::{
         builder::ManifestBuilder, builder::TransactionBuilder, signing::EcdsaSecp256k1PrivateKey,
+        signing::EddsaEd25519PrivateKey, signing::Signer,
     };
 
     macro_rules! assert_invalid_tx {
@@ -513,6 +621,240 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    fn create_sponsored_transaction(
+        sk_signer: &EddsaEd25519PrivateKey,
+        sk_fee_payer: &EddsaEd25519PrivateKey,
+        sk_notary: &EcdsaSecp256k1PrivateKey,
+    ) -> NotarizedTransaction {
+        TransactionBuilder::new()
+            .header(TransactionHeader {
+                version: 1,
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: 0,
+                end_epoch_exclusive: 100,
+                nonce: 5,
+                notary_public_key: sk_notary.public_key().into(),
+                notary_as_signatory: false,
+                cost_unit_limit: 1_000_000,
+                tip_percentage: 5,
+            })
+            .manifest(ManifestBuilder::new().clear_auth_zone().build())
+            .sign(sk_signer)
+            .fee_payer(
+                ComponentAddress::virtual_account_from_public_key(&sk_fee_payer.public_key()),
+                sk_fee_payer,
+            )
+            .notarize(sk_notary)
+            .build()
+    }
+
+    fn renotarize(
+        signed_intent: SignedTransactionIntent,
+        sk_notary: &EcdsaSecp256k1PrivateKey,
+    ) -> NotarizedTransaction {
+        NotarizedTransaction {
+            notary_signature: sk_notary.sign(&signed_intent.to_bytes().unwrap()).into(),
+            signed_intent,
+        }
+    }
+
+    #[test]
+    fn test_fee_payer_signature() {
+        let intent_hash_manager: TestIntentHashManager = TestIntentHashManager::new();
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let sk_signer = EddsaEd25519PrivateKey::from_u64(1).unwrap();
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
+        let sk_fee_payer = EddsaEd25519PrivateKey::from_u64(3).unwrap();
+        let fee_payer_account =
+            ComponentAddress::virtual_account_from_public_key(&sk_fee_payer.public_key());
+
+        let transaction = create_sponsored_transaction(&sk_signer, &sk_fee_payer, &sk_notary);
+
+        let executable = validator
+            .validate(&transaction, 0, &intent_hash_manager)
+            .unwrap();
+        assert_eq!(
+            executable.fee_payment(),
+            &FeePayment::Sponsored {
+                cost_unit_limit: 1_000_000,
+                tip_percentage: 5,
+                fee_payer: fee_payer_account,
+                fee_payer_proofs: vec![NonFungibleGlobalId::from_public_key(
+                    &sk_fee_payer.public_key()
+                )],
+            }
+        );
+
+        // A fee payer signature which doesn't cover the intent is rejected
+        let mut signed_intent = transaction.signed_intent.clone();
+        signed_intent.fee_payer = Some(TransactionFeePayer {
+            account: fee_payer_account,
+            signature: Signer::sign(&sk_fee_payer, b"not the intent"),
+        });
+        assert_eq!(
+            validator
+                .validate(
+                    &renotarize(signed_intent, &sk_notary),
+                    0,
+                    &intent_hash_manager
+                )
+                .expect_err("Should be an error"),
+            TransactionValidationError::SignatureValidationError(
+                SignatureValidationError::InvalidFeePayerSignature
+            )
+        );
+
+        // So is a plain intent signature used as a fee payer signature
+        let mut signed_intent = transaction.signed_intent.clone();
+        signed_intent.fee_payer = Some(TransactionFeePayer {
+            account: fee_payer_account,
+            signature: Signer::sign(&sk_fee_payer, &signed_intent.intent.to_bytes().unwrap()),
+        });
+        assert_eq!(
+            validator
+                .validate(
+                    &renotarize(signed_intent, &sk_notary),
+                    0,
+                    &intent_hash_manager
+                )
+                .expect_err("Should be an error"),
+            TransactionValidationError::SignatureValidationError(
+                SignatureValidationError::InvalidFeePayerSignature
+            )
+        );
+    }
+
+    #[test]
+    fn test_fee_payer_signature_cannot_be_replayed_as_intent_signature() {
+        let intent_hash_manager: TestIntentHashManager = TestIntentHashManager::new();
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let sk_signer = EddsaEd25519PrivateKey::from_u64(1).unwrap();
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
+        let sk_fee_payer = EddsaEd25519PrivateKey::from_u64(3).unwrap();
+        let transaction = create_sponsored_transaction(&sk_signer, &sk_fee_payer, &sk_notary);
+
+        let mut signed_intent = transaction.signed_intent.clone();
+        let fee_payer_signature = signed_intent.fee_payer.as_ref().unwrap().signature.clone();
+        signed_intent.intent_signatures.push(fee_payer_signature);
+
+        assert_eq!(
+            validator
+                .validate(
+                    &renotarize(signed_intent, &sk_notary),
+                    0,
+                    &intent_hash_manager
+                )
+                .expect_err("Should be an error"),
+            TransactionValidationError::SignatureValidationError(
+                SignatureValidationError::InvalidIntentSignature
+            )
+        );
+    }
+
+    #[test]
+    fn test_fee_payer_cannot_be_intent_signer() {
+        let intent_hash_manager: TestIntentHashManager = TestIntentHashManager::new();
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let sk_signer = EddsaEd25519PrivateKey::from_u64(1).unwrap();
+        let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
+        let transaction = create_sponsored_transaction(&sk_signer, &sk_signer, &sk_notary);
+
+        assert_eq!(
+            validator
+                .validate(&transaction, 0, &intent_hash_manager)
+                .expect_err("Should be an error"),
+            TransactionValidationError::SignatureValidationError(
+                SignatureValidationError::FeePayerIsIntentSigner
+            )
+        );
+    }
+
+    #[test]
+    fn test_message_validation() {
+        let secp_key = |n: u64| -> PublicKey {
+            EcdsaSecp256k1PrivateKey::from_u64(n)
+                .unwrap()
+                .public_key()
+                .into()
+        };
+        let ed_key = |n: u64| -> PublicKey {
+            EddsaEd25519PrivateKey::from_u64(n)
+                .unwrap()
+                .public_key()
+                .into()
+        };
+        let encrypted_with_key_length =
+            |decryptors: Vec<(PublicKey, PublicKey)>, wrapped_key_length: usize| {
+                TransactionMessage::Encrypted(EncryptedMessage {
+                    encrypted: vec![0u8; 32],
+                    decryptors: decryptors
+                        .into_iter()
+                        .map(|(recipient, ephemeral_public_key)| MessageDecryptor {
+                            recipient,
+                            ephemeral_public_key,
+                            wrapped_key: vec![0u8; wrapped_key_length],
+                        })
+                        .collect(),
+                })
+            };
+        let encrypted =
+            |decryptors: Vec<(PublicKey, PublicKey)>| encrypted_with_key_length(decryptors, 40);
+
+        assert_eq!(
+            NotarizedTransactionValidator::validate_message(&TransactionMessage::plaintext(
+                "text/plain",
+                "Hello"
+            )),
+            Ok(())
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_message(&TransactionMessage::plaintext(
+                &"a".repeat(MAX_MESSAGE_MIME_TYPE_LENGTH + 1),
+                "Hello"
+            )),
+            Err(MessageValidationError::MimeTypeTooLong)
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_message(&TransactionMessage::plaintext(
+                "text/plain",
+                &"a".repeat(MAX_MESSAGE_SIZE + 1)
+            )),
+            Err(MessageValidationError::MessageTooLarge)
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_message(&encrypted(vec![
+                (secp_key(1), secp_key(2)),
+                (ed_key(1), ed_key(2)),
+            ])),
+            Ok(())
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_message(&encrypted(vec![])),
+            Err(MessageValidationError::NoDecryptors)
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_message(&encrypted(vec![
+                (secp_key(1), secp_key(2)),
+                (secp_key(1), secp_key(3)),
+            ])),
+            Err(MessageValidationError::DuplicateDecryptor)
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_message(&encrypted(vec![(
+                secp_key(1),
+                ed_key(2)
+            )])),
+            Err(MessageValidationError::MismatchingDecryptorCurves)
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_message(&encrypted_with_key_length(
+                vec![(secp_key(1), secp_key(2))],
+                MAX_MESSAGE_WRAPPED_KEY_LENGTH + 1
+            )),
+            Err(MessageValidationError::WrappedKeyTooLong)
+        );
+    }
+
     fn create_transaction(
         version: u8,
         start_epoch: u64,